@@ -22,7 +22,7 @@ use chrono::{DateTime, Utc};
 use futures::Future;
 use itertools::Itertools;
 use mz_audit_log::{
-    EventDetails, EventType, FullNameV1, IdFullNameV1, ObjectType, VersionedEvent,
+    ChainAnchor, EventDetails, EventType, FullNameV1, IdFullNameV1, ObjectType, VersionedEvent,
     VersionedStorageUsage,
 };
 use mz_build_info::DUMMY_BUILD_INFO;
@@ -125,12 +125,14 @@ mod config;
 mod consistency;
 mod error;
 mod migrate;
+mod name_history;
 
 mod inner;
 
 pub use crate::catalog::builtin_table_updates::BuiltinTableUpdate;
 pub use crate::catalog::config::{AwsPrincipalContext, ClusterReplicaSizeMap, Config};
 pub use crate::catalog::error::{AmbiguousRename, Error, ErrorKind};
+pub use crate::catalog::name_history::{ItemNameHistory, NameBinding};
 
 pub static SYSTEM_CONN_ID: ConnectionId = ConnectionId::Static(0);
 
@@ -219,6 +221,31 @@ pub struct CatalogState {
     default_privileges: DefaultPrivileges,
     system_privileges: PrivilegeMap,
     comments: CommentsMap,
+    /// Row-level security policies, keyed by the ID of the object they apply
+    /// to and then by policy name.
+    #[serde(serialize_with = "mz_ore::serde::map_key_to_string")]
+    policies: BTreeMap<GlobalId, BTreeMap<String, String>>,
+    /// Privileges on columns, keyed by the ID of the relation they belong to
+    /// and then by column name.
+    #[serde(serialize_with = "mz_ore::serde::map_key_to_string")]
+    column_privileges: BTreeMap<GlobalId, BTreeMap<String, PrivilegeMap>>,
+    /// Which item each name referred to over time, as recorded by the audit
+    /// log.
+    #[serde(skip)]
+    item_name_history: Arc<ItemNameHistory>,
+    /// Dropped tables and materialized views whose storage collections remain
+    /// readable by queries with an explicit `AS OF`, until they are released.
+    #[serde(skip)]
+    retained_items: BTreeMap<GlobalId, RetainedItem>,
+}
+
+/// A dropped relation whose storage collection remains readable by queries
+/// with an explicit `AS OF`.
+#[derive(Debug, Clone)]
+struct RetainedItem {
+    entry: CatalogEntry,
+    /// The transient revision of the catalog that dropped the item.
+    dropped_at_revision: u64,
 }
 
 fn skip_temp_items<S>(
@@ -270,6 +297,10 @@ impl CatalogState {
             default_privileges: Default::default(),
             system_privileges: Default::default(),
             comments: Default::default(),
+            policies: Default::default(),
+            column_privileges: Default::default(),
+            item_name_history: Default::default(),
+            retained_items: Default::default(),
         }
     }
 
@@ -606,7 +637,8 @@ impl CatalogState {
     }
 
     pub fn get_entry(&self, id: &GlobalId) -> &CatalogEntry {
-        &self.entry_by_id[id]
+        self.try_get_entry(id)
+            .unwrap_or_else(|| panic!("unknown catalog item {id}"))
     }
 
     pub fn get_entry_mut(&mut self, id: &GlobalId) -> &mut CatalogEntry {
@@ -672,10 +704,29 @@ impl CatalogState {
         name
     }
 
+    /// Returns the entry for `id`, which may be a dropped relation that is
+    /// retained for queries with an explicit `AS OF`.
     pub fn try_get_entry(&self, id: &GlobalId) -> Option<&CatalogEntry> {
+        self.entry_by_id
+            .get(id)
+            .or_else(|| self.retained_items.get(id).map(|item| &item.entry))
+    }
+
+    /// Returns the entry for `id`, unless the item has been dropped.
+    ///
+    /// Anything that changes an item, like writing to a table, must use this
+    /// rather than [`CatalogState::try_get_entry`], as retained items must
+    /// not change after they were dropped.
+    pub fn try_get_live_entry(&self, id: &GlobalId) -> Option<&CatalogEntry> {
         self.entry_by_id.get(id)
     }
 
+    /// Reports whether `id` is a dropped relation that is retained for
+    /// queries with an explicit `AS OF`.
+    pub fn is_retained_item(&self, id: &GlobalId) -> bool {
+        self.retained_items.contains_key(id)
+    }
+
     fn get_cluster(&self, cluster_id: ClusterId) -> &Cluster {
         self.try_get_cluster(cluster_id)
             .unwrap_or_else(|| panic!("unknown cluster {cluster_id}"))
@@ -811,6 +862,7 @@ impl CatalogState {
                     desc,
                     conn_id: None,
                     resolved_ids,
+                    column_reads: view.column_reads,
                 })
             }
             _ => bail!("Expected valid CREATE VIEW statement"),
@@ -1607,6 +1659,80 @@ impl CatalogState {
         )
     }
 
+    /// Resolves `name` to the non-function [`CatalogEntry`] it referred to at
+    /// time `as_of`, according to the [`ItemNameHistory`].
+    ///
+    /// Names whose binding at `as_of` is unknown, e.g. because they refer to
+    /// builtin items or their history has been pruned from the audit log,
+    /// resolve to the item they currently refer to. Temporary items are not
+    /// recorded in the audit log, so they always resolve as usual.
+    pub fn resolve_entry_as_of(
+        &self,
+        current_database: Option<&DatabaseId>,
+        search_path: &Vec<(ResolvedDatabaseSpecifier, SchemaSpecifier)>,
+        name: &PartialItemName,
+        conn_id: &ConnectionId,
+        as_of: mz_repr::Timestamp,
+    ) -> Result<&CatalogEntry, SqlCatalogError> {
+        let schemas = match &name.schema {
+            Some(schema_name) => {
+                let schema = self.resolve_schema(
+                    current_database,
+                    name.database.as_deref(),
+                    schema_name,
+                    conn_id,
+                )?;
+                vec![(schema.name.database.clone(), schema.id.clone())]
+            }
+            None => match self
+                .get_schema(
+                    &ResolvedDatabaseSpecifier::Ambient,
+                    &SchemaSpecifier::Temporary,
+                    conn_id,
+                )
+                .items
+                .get(&name.item)
+            {
+                Some(id) => return Ok(self.get_entry(id)),
+                None => search_path.to_vec(),
+            },
+        };
+
+        let as_of = EpochMillis::from(as_of);
+        for (database_spec, schema_spec) in schemas {
+            let full_name = self.resolve_full_name(
+                &QualifiedItemName {
+                    qualifiers: ItemQualifiers {
+                        database_spec: database_spec.clone(),
+                        schema_spec: schema_spec.clone(),
+                    },
+                    item: name.item.clone(),
+                },
+                Some(conn_id),
+            );
+            let full_name = Catalog::full_name_detail(&full_name);
+
+            if let Some(binding) = self.item_name_history.resolve(&full_name, as_of) {
+                // Dropped tables and materialized views are retained for a
+                // while, the storage collections of all other dropped items
+                // are dropped with them, so there is nothing left to read.
+                return self
+                    .try_get_entry(&binding.id)
+                    .ok_or_else(|| SqlCatalogError::DroppedItem(name.to_string()));
+            }
+
+            // Items that are only known to have been bound to the name after
+            // `as_of` did not exist yet.
+            let schema = self.get_schema(&database_spec, &schema_spec, conn_id);
+            if let Some(id) = schema.items.get(&name.item) {
+                if !self.item_name_history.contains(&full_name, *id) {
+                    return Ok(self.get_entry(id));
+                }
+            }
+        }
+        Err(SqlCatalogError::UnknownItem(name.to_string()))
+    }
+
     /// For an [`ObjectId`] gets the corresponding [`CommentObjectId`].
     fn get_comment_id(&self, object_id: ObjectId) -> CommentObjectId {
         match object_id {
@@ -1642,6 +1768,18 @@ impl CatalogState {
         &self.system_configuration
     }
 
+    /// Returns the row-level security policies on the item `id`, keyed by
+    /// policy name.
+    pub fn get_item_policies(&self, id: &GlobalId) -> Option<&BTreeMap<String, String>> {
+        self.policies.get(id)
+    }
+
+    /// Returns the privileges on the columns of the item `id`, keyed by column
+    /// name.
+    pub fn get_column_privileges(&self, id: &GlobalId) -> Option<&BTreeMap<String, PrivilegeMap>> {
+        self.column_privileges.get(id)
+    }
+
     /// Serializes the catalog's in-memory state.
     ///
     /// There are no guarantees about the format of the serialized state, except
@@ -1895,6 +2033,12 @@ pub struct ConnCatalog<'a> {
     /// Note that uses of this should field should be used by short-lived
     /// catalogs.
     unresolvable_ids: BTreeSet<GlobalId>,
+    /// If set, names resolve to the items they referred to at this time,
+    /// rather than to the items they refer to now.
+    ///
+    /// This allows queries with an explicit `AS OF` to read the items they
+    /// read at that time, even if they have since been renamed.
+    resolve_items_as_of: Option<mz_repr::Timestamp>,
     conn_id: ConnectionId,
     cluster: String,
     database: Option<DatabaseId>,
@@ -1930,6 +2074,12 @@ impl ConnCatalog<'_> {
         self.unresolvable_ids.insert(id);
     }
 
+    /// Resolve names to the items they referred to at time `as_of`, as
+    /// recorded by the audit log.
+    pub fn set_resolve_items_as_of(&mut self, as_of: mz_repr::Timestamp) {
+        self.resolve_items_as_of = Some(as_of);
+    }
+
     /// Returns the schemas:
     /// - mz_catalog
     /// - pg_catalog
@@ -2519,6 +2669,8 @@ pub struct View {
     pub desc: RelationDesc,
     pub conn_id: Option<ConnectionId>,
     pub resolved_ids: ResolvedIds,
+    /// The columns the view reads from each relation.
+    pub column_reads: BTreeMap<GlobalId, BTreeSet<usize>>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -2528,6 +2680,8 @@ pub struct MaterializedView {
     pub desc: RelationDesc,
     pub resolved_ids: ResolvedIds,
     pub cluster_id: ClusterId,
+    /// The columns the materialized view reads from each relation.
+    pub column_reads: BTreeMap<GlobalId, BTreeSet<usize>>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -3598,6 +3752,10 @@ impl Catalog {
                 default_privileges: DefaultPrivileges::default(),
                 system_privileges: PrivilegeMap::default(),
                 comments: CommentsMap::default(),
+                policies: BTreeMap::new(),
+                column_privileges: BTreeMap::new(),
+                item_name_history: Default::default(),
+                retained_items: BTreeMap::new(),
             },
             plans: CatalogPlans {
                 optimized_plan_by_id: Default::default(),
@@ -3761,6 +3919,28 @@ impl Catalog {
                 .update_comment(object_id, sub_component, Some(comment));
         }
 
+        let policies = catalog.storage().await.get_policies().await?;
+        for (object_id, name, predicate) in policies {
+            catalog
+                .state
+                .policies
+                .entry(object_id)
+                .or_default()
+                .insert(name, predicate);
+        }
+
+        let column_privileges = catalog.storage().await.get_column_privileges().await?;
+        for (object_id, column, privilege) in column_privileges {
+            catalog
+                .state
+                .column_privileges
+                .entry(object_id)
+                .or_default()
+                .entry(column)
+                .or_default()
+                .grant(privilege);
+        }
+
         // Now that LD is loaded, set the intended stash timeout.
         // TODO: Move this into the stash constructor.
         catalog
@@ -4230,6 +4410,24 @@ impl Catalog {
                 1,
             ));
         }
+        for (object_id, policies) in &catalog.state.policies {
+            for (name, predicate) in policies {
+                builtin_table_updates.push(
+                    catalog
+                        .state
+                        .pack_policy_update(*object_id, name, predicate, 1),
+                );
+            }
+        }
+        for (object_id, columns) in &catalog.state.column_privileges {
+            for (column, privileges) in columns {
+                builtin_table_updates.push(
+                    catalog
+                        .state
+                        .pack_column_privileges_update(*object_id, column, privileges, 1),
+                );
+            }
+        }
         for (_id, role) in &catalog.state.roles_by_id {
             if let Some(builtin_update) = catalog.state.pack_role_update(role.id, 1) {
                 builtin_table_updates.push(builtin_update);
@@ -4304,10 +4502,19 @@ impl Catalog {
                 _ => unreachable!("all operators must be scalar functions"),
             }
         }
-        let audit_logs = catalog.storage().await.get_audit_logs().await?;
+        let audit_log_retention = catalog.system_config().audit_log_retention();
+        let (mut audit_logs, _) = catalog
+            .storage()
+            .await
+            .get_and_prune_audit_logs(audit_log_retention, boot_ts, config.audit_log_retain_from)
+            .await?;
+        audit_logs.sort_by_key(|event| event.sortable_id());
+        let mut item_name_history = ItemNameHistory::default();
         for event in audit_logs {
             builtin_table_updates.push(catalog.state.pack_audit_log_update(&event)?);
+            item_name_history.apply(&event);
         }
+        catalog.state.item_name_history = Arc::new(item_name_history);
 
         // To avoid reading over storage_usage events multiple times, do both
         // the table updates and delete calculations in a single read over the
@@ -5132,6 +5339,7 @@ impl Catalog {
             system_parameter_sync_config: None,
             // when debugging, no reaping
             storage_usage_retention_period: None,
+            audit_log_retain_from: None,
             http_host_name: None,
             connection_context: None,
             active_connection_count,
@@ -5153,6 +5361,7 @@ impl Catalog {
         ConnCatalog {
             state: Cow::Borrowed(state),
             unresolvable_ids: BTreeSet::new(),
+            resolve_items_as_of: None,
             conn_id: session.conn_id().clone(),
             cluster: session.vars().cluster().into(),
             database,
@@ -5172,6 +5381,7 @@ impl Catalog {
         ConnCatalog {
             state: Cow::Borrowed(state),
             unresolvable_ids: BTreeSet::new(),
+            resolve_items_as_of: None,
             conn_id: SYSTEM_CONN_ID.clone(),
             cluster: "default".into(),
             database: state
@@ -5297,6 +5507,16 @@ impl Catalog {
         self.storage().await.get_timestamps().await.err_into()
     }
 
+    /// Get all events in the audit log, along with the anchor of the hash
+    /// chain over them.
+    pub async fn get_audit_log_chain(&self) -> Result<(Vec<VersionedEvent>, ChainAnchor), Error> {
+        self.storage()
+            .await
+            .get_and_prune_audit_logs(None, mz_repr::Timestamp::MIN, None)
+            .await
+            .err_into()
+    }
+
     /// Get the next system replica id without allocating it.
     pub async fn get_next_system_replica_id(&self) -> Result<u64, Error> {
         self.storage()
@@ -5484,10 +5704,59 @@ impl Catalog {
         self.state.try_get_entry(id)
     }
 
+    pub fn try_get_live_entry(&self, id: &GlobalId) -> Option<&CatalogEntry> {
+        self.state.try_get_live_entry(id)
+    }
+
     pub fn get_entry(&self, id: &GlobalId) -> &CatalogEntry {
         self.state.get_entry(id)
     }
 
+    /// Keeps the entries of dropped relations around, so that queries with an
+    /// explicit `AS OF` can still resolve them until they are released with
+    /// [`Catalog::release_retained_items`].
+    ///
+    /// Whatever depended on the relations has been dropped with them, so the
+    /// retained entries are not used by anything.
+    pub fn retain_dropped_items(&mut self, entries: impl IntoIterator<Item = CatalogEntry>) {
+        for mut entry in entries {
+            entry.used_by.clear();
+            self.state.retained_items.insert(
+                entry.id,
+                RetainedItem {
+                    entry,
+                    dropped_at_revision: self.transient_revision,
+                },
+            );
+        }
+    }
+
+    /// Forgets the entries of retained relations, after which they can no
+    /// longer be resolved.
+    pub fn release_retained_items(&mut self, ids: &BTreeSet<GlobalId>) {
+        self.state.retained_items.retain(|id, _| !ids.contains(id));
+    }
+
+    /// Returns the entries of all dropped relations that are currently
+    /// retained.
+    pub fn retained_items(&self) -> impl Iterator<Item = &CatalogEntry> {
+        self.state.retained_items.values().map(|item| &item.entry)
+    }
+
+    /// Returns the items that the audit log records as dropped.
+    pub fn dropped_item_ids(&self) -> BTreeSet<GlobalId> {
+        self.state.item_name_history.dropped_ids()
+    }
+
+    /// Returns the transient revision of the catalog that dropped `id`, if
+    /// it is a retained relation.
+    pub fn retained_item_dropped_at(&self, id: &GlobalId) -> Option<u64> {
+        self.state
+            .retained_items
+            .get(id)
+            .map(|item| item.dropped_at_revision)
+    }
+
     pub fn get_schema(
         &self,
         database_spec: &ResolvedDatabaseSpecifier,
@@ -5895,6 +6164,13 @@ impl Catalog {
         self.state = state;
         self.transient_revision += 1;
 
+        if !audit_events.is_empty() {
+            let item_name_history = Arc::make_mut(&mut self.state.item_name_history);
+            for event in &audit_events {
+                item_name_history.apply(event);
+            }
+        }
+
         for id in drop_ids {
             self.drop_plans_and_metainfos(id);
         }
@@ -6090,6 +6366,55 @@ impl Catalog {
                         drop_ids,
                     )?;
                 }
+                Op::AlterSinkFrom {
+                    id,
+                    from,
+                    create_sql,
+                    connection,
+                } => {
+                    let entry = state.get_entry(&id);
+                    let name = entry.name().clone();
+                    let mut new_sink = match entry.item() {
+                        CatalogItem::Sink(sink) => sink.clone(),
+                        other => {
+                            coord_bail!("ALTER SINK entry was not a sink: {}", other.typ())
+                        }
+                    };
+                    new_sink.resolved_ids.0.remove(&new_sink.from);
+                    new_sink.resolved_ids.0.insert(from);
+                    new_sink.create_sql = create_sql;
+                    new_sink.from = from;
+                    new_sink.connection = StorageSinkConnectionState::Ready(connection);
+
+                    builtin_table_updates.extend(state.pack_item_update(id, -1));
+                    Self::update_item(
+                        state,
+                        builtin_table_updates,
+                        id,
+                        name.clone(),
+                        CatalogItem::Sink(new_sink),
+                        drop_ids,
+                    )?;
+                    let entry = state.get_entry(&id);
+                    tx.update_item(id, entry.clone().into())?;
+
+                    state.add_to_audit_log(
+                        oracle_write_ts,
+                        session,
+                        tx,
+                        builtin_table_updates,
+                        audit_events,
+                        EventType::Alter,
+                        ObjectType::Sink,
+                        EventDetails::UpdateItemV1(mz_audit_log::UpdateItemV1 {
+                            id: id.to_string(),
+                            name: Self::full_name_detail(&state.resolve_full_name(
+                                &name,
+                                session.map(|session| session.conn_id()),
+                            )),
+                        }),
+                    )?;
+                }
                 Op::AlterSource { id, cluster_config } => {
                     use mz_sql::ast::Value;
                     use mz_sql_parser::ast::CreateSourceOptionName::*;
@@ -6720,7 +7045,87 @@ impl Catalog {
                         ));
                     }
                 }
+                Op::CreatePolicy {
+                    object_id,
+                    name,
+                    predicate,
+                } => {
+                    tx.insert_policy(object_id, name.clone(), predicate.clone())?;
+                    builtin_table_updates
+                        .push(state.pack_policy_update(object_id, &name, &predicate, 1));
+                    state.add_to_audit_log(
+                        oracle_write_ts,
+                        session,
+                        tx,
+                        builtin_table_updates,
+                        audit_events,
+                        EventType::Create,
+                        ObjectType::Policy,
+                        EventDetails::IdNameV1(mz_audit_log::IdNameV1 {
+                            id: object_id.to_string(),
+                            name: name.clone(),
+                        }),
+                    )?;
+                    state
+                        .policies
+                        .entry(object_id)
+                        .or_default()
+                        .insert(name, predicate);
+                }
+                Op::DropPolicy { object_id, name } => {
+                    tx.remove_policy(object_id, &name)?;
+                    let policies = state
+                        .policies
+                        .get_mut(&object_id)
+                        .expect("catalog out of sync");
+                    let predicate = policies.remove(&name).expect("catalog out of sync");
+                    if policies.is_empty() {
+                        state.policies.remove(&object_id);
+                    }
+                    builtin_table_updates
+                        .push(state.pack_policy_update(object_id, &name, &predicate, -1));
+                    state.add_to_audit_log(
+                        oracle_write_ts,
+                        session,
+                        tx,
+                        builtin_table_updates,
+                        audit_events,
+                        EventType::Drop,
+                        ObjectType::Policy,
+                        EventDetails::IdNameV1(mz_audit_log::IdNameV1 {
+                            id: object_id.to_string(),
+                            name,
+                        }),
+                    )?;
+                }
                 Op::DropObject(id) => {
+                    // Drop any associated policies.
+                    if let ObjectId::Item(item_id) = &id {
+                        let deleted = tx.drop_policies(*item_id)?;
+                        let dropped = state.policies.remove(item_id).unwrap_or_default();
+                        mz_ore::soft_assert_eq!(
+                            deleted.len(),
+                            dropped.len(),
+                            "transaction and state out of sync"
+                        );
+                        for (name, predicate) in dropped {
+                            builtin_table_updates
+                                .push(state.pack_policy_update(*item_id, &name, &predicate, -1));
+                        }
+
+                        // Drop any associated column privileges.
+                        tx.drop_column_privileges(*item_id)?;
+                        let dropped = state.column_privileges.remove(item_id).unwrap_or_default();
+                        for (column, privileges) in dropped {
+                            builtin_table_updates.push(state.pack_column_privileges_update(
+                                *item_id,
+                                &column,
+                                &privileges,
+                                -1,
+                            ));
+                        }
+                    }
+
                     // Drop any associated comments.
                     let comment_id = state.get_comment_id(id.clone());
                     let deleted = tx.drop_comments(comment_id)?;
@@ -7204,6 +7609,82 @@ impl Catalog {
                         }),
                     )?;
                 }
+                Op::UpdateColumnPrivilege {
+                    object_id,
+                    column,
+                    privilege,
+                    variant,
+                } => {
+                    let privileges = state
+                        .column_privileges
+                        .entry(object_id)
+                        .or_default()
+                        .entry(column.clone())
+                        .or_default();
+                    let old_privileges = privileges.clone();
+                    match variant {
+                        UpdatePrivilegeVariant::Grant => privileges.grant(privilege.clone()),
+                        UpdatePrivilegeVariant::Revoke => privileges.revoke(&privilege),
+                    }
+                    let new_acl_mode = privileges
+                        .get_acl_item(&privilege.grantee, &privilege.grantor)
+                        .map(|new_privilege| new_privilege.acl_mode);
+                    let new_privileges = privileges.clone();
+                    tx.set_column_privilege(
+                        object_id,
+                        column.clone(),
+                        privilege.grantee,
+                        privilege.grantor,
+                        new_acl_mode,
+                    )?;
+
+                    if old_privileges.all_values().next().is_some() {
+                        builtin_table_updates.push(state.pack_column_privileges_update(
+                            object_id,
+                            &column,
+                            &old_privileges,
+                            -1,
+                        ));
+                    }
+                    if new_privileges.all_values().next().is_none() {
+                        let columns = state
+                            .column_privileges
+                            .get_mut(&object_id)
+                            .expect("inserted above");
+                        columns.remove(&column);
+                        if columns.is_empty() {
+                            state.column_privileges.remove(&object_id);
+                        }
+                    } else {
+                        builtin_table_updates.push(state.pack_column_privileges_update(
+                            object_id,
+                            &column,
+                            &new_privileges,
+                            1,
+                        ));
+                    }
+
+                    let object_type =
+                        state.get_system_object_type(&SystemObjectId::Object(object_id.into()));
+                    state.add_to_audit_log(
+                        oracle_write_ts,
+                        session,
+                        tx,
+                        builtin_table_updates,
+                        audit_events,
+                        variant.into(),
+                        system_object_type_to_audit_object_type(&object_type),
+                        EventDetails::UpdateColumnPrivilegeV1(
+                            mz_audit_log::UpdateColumnPrivilegeV1 {
+                                object_id: object_id.to_string(),
+                                column,
+                                grantee_id: privilege.grantee.to_string(),
+                                grantor_id: privilege.grantor.to_string(),
+                                privileges: privilege.acl_mode.to_string(),
+                            },
+                        ),
+                    )?;
+                }
                 Op::UpdateDefaultPrivilege {
                     privilege_object,
                     privilege_acl_item,
@@ -7765,25 +8246,6 @@ impl Catalog {
             id
         );
 
-        // Ensure any removal from the uses is accompanied by a drop.
-        let to_item_uses_and_dropped_ids: BTreeSet<&GlobalId> =
-            drop_ids.iter().chain(&to_item.uses().0).collect();
-
-        assert!(
-            old_entry
-                .uses()
-                .0
-                .iter()
-                .all(|id| to_item_uses_and_dropped_ids.contains(id)),
-            "all of the old entries used items must be accompanied by a drop \
-                old_entry.uses: {:?}\
-                to_item.uses: {:?}\
-                drop_ids {:?}",
-            old_entry.uses(),
-            to_item.uses(),
-            drop_ids,
-        );
-
         let conn_id = old_entry.item().conn_id().unwrap_or(&SYSTEM_CONN_ID);
         let schema = state.get_schema_mut(
             &old_entry.name().qualifiers.database_spec,
@@ -7800,6 +8262,15 @@ impl Catalog {
             .difference(&old_entry.uses().0)
             .cloned()
             .collect();
+        // And to remove it from the `used_by` of items it no longer depends on,
+        // unless they are dropped anyway.
+        let removed_deps: Vec<_> = old_entry
+            .uses()
+            .0
+            .difference(&to_item.uses().0)
+            .filter(|u| !drop_ids.contains(u))
+            .cloned()
+            .collect();
 
         let mut new_entry = old_entry.clone();
         new_entry.name = to_name;
@@ -7817,6 +8288,16 @@ impl Catalog {
                 ),
             }
         }
+        for u in removed_deps {
+            match state.entry_by_id.get_mut(&u) {
+                Some(metadata) => metadata.used_by.retain(|u| *u != id),
+                None => panic!(
+                    "Catalog: missing dependency catalog item {} while updating {}",
+                    &u,
+                    state.resolve_full_name(&new_entry.name, new_entry.conn_id())
+                ),
+            }
+        }
 
         state.entry_by_id.insert(id, new_entry);
         builtin_table_updates.extend(state.pack_item_update(id, 1));
@@ -8027,6 +8508,7 @@ impl Catalog {
                     desc,
                     conn_id: None,
                     resolved_ids,
+                    column_reads: view.column_reads,
                 })
             }
             Plan::CreateMaterializedView(CreateMaterializedViewPlan {
@@ -8042,6 +8524,7 @@ impl Catalog {
                     desc,
                     resolved_ids,
                     cluster_id: materialized_view.cluster_id,
+                    column_reads: materialized_view.column_reads,
                 })
             }
             Plan::CreateIndex(CreateIndexPlan { index, .. }) => CatalogItem::Index(Index {
@@ -8394,6 +8877,14 @@ pub enum Op {
         id: GlobalId,
         cluster_config: plan::SourceSinkClusterConfig,
     },
+    /// Repoints the sink `id` at the relation `from`, which `create_sql` and
+    /// `connection` were planned and built for.
+    AlterSinkFrom {
+        id: GlobalId,
+        from: GlobalId,
+        create_sql: String,
+        connection: StorageSinkConnection<ReferencedConnection>,
+    },
     AlterSource {
         id: GlobalId,
         cluster_config: plan::SourceSinkClusterConfig,
@@ -8447,7 +8938,16 @@ pub enum Op {
         sub_component: Option<usize>,
         comment: Option<String>,
     },
+    CreatePolicy {
+        object_id: GlobalId,
+        name: String,
+        predicate: String,
+    },
     DropObject(ObjectId),
+    DropPolicy {
+        object_id: GlobalId,
+        name: String,
+    },
     DropTimeline(Timeline),
     GrantRole {
         role_id: RoleId,
@@ -8479,6 +8979,12 @@ pub enum Op {
         privilege: MzAclItem,
         variant: UpdatePrivilegeVariant,
     },
+    UpdateColumnPrivilege {
+        object_id: GlobalId,
+        column: String,
+        privilege: MzAclItem,
+        variant: UpdatePrivilegeVariant,
+    },
     UpdateDefaultPrivilege {
         privilege_object: DefaultPrivilegeObject,
         privilege_acl_item: DefaultPrivilegeAclItem,
@@ -8895,12 +9401,21 @@ impl SessionCatalog for ConnCatalog<'_> {
         &self,
         name: &PartialItemName,
     ) -> Result<&dyn mz_sql::catalog::CatalogItem, SqlCatalogError> {
-        let r = self.state.resolve_entry(
-            self.database.as_ref(),
-            &self.effective_search_path(true),
-            name,
-            &self.conn_id,
-        )?;
+        let r = match self.resolve_items_as_of {
+            Some(as_of) => self.state.resolve_entry_as_of(
+                self.database.as_ref(),
+                &self.effective_search_path(true),
+                name,
+                &self.conn_id,
+                as_of,
+            )?,
+            None => self.state.resolve_entry(
+                self.database.as_ref(),
+                &self.effective_search_path(true),
+                name,
+                &self.conn_id,
+            )?,
+        };
         if self.unresolvable_ids.contains(&r.id()) {
             Err(SqlCatalogError::UnknownItem(name.to_string()))
         } else {
@@ -8930,6 +9445,11 @@ impl SessionCatalog for ConnCatalog<'_> {
     }
 
     fn try_get_item(&self, id: &GlobalId) -> Option<&dyn mz_sql::catalog::CatalogItem> {
+        // Retained items can only be referred to by queries with an explicit
+        // `AS OF`.
+        if self.resolve_items_as_of.is_none() && self.state.is_retained_item(id) {
+            return None;
+        }
         Some(self.state.try_get_entry(id)?)
     }
 
@@ -9047,6 +9567,14 @@ impl SessionCatalog for ConnCatalog<'_> {
         }
     }
 
+    fn get_item_policies(&self, id: &GlobalId) -> Option<&BTreeMap<String, String>> {
+        self.state.get_item_policies(id)
+    }
+
+    fn get_column_privileges(&self, id: &GlobalId) -> Option<&BTreeMap<String, PrivilegeMap>> {
+        self.state.get_column_privileges(id)
+    }
+
     fn object_dependents(&self, ids: &Vec<ObjectId>) -> Vec<ObjectId> {
         let mut seen = BTreeSet::new();
         self.state.object_dependents(ids, &self.conn_id, &mut seen)
@@ -9347,6 +9875,16 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
         }
     }
 
+    fn view_column_reads(&self) -> Option<&BTreeMap<GlobalId, BTreeSet<usize>>> {
+        match self.item() {
+            CatalogItem::View(View { column_reads, .. })
+            | CatalogItem::MaterializedView(MaterializedView { column_reads, .. }) => {
+                Some(column_reads)
+            }
+            _ => None,
+        }
+    }
+
     fn uses(&self) -> &ResolvedIds {
         self.uses()
     }
@@ -9387,6 +9925,9 @@ mod tests {
     use std::{env, iter};
 
     use itertools::Itertools;
+    use mz_audit_log::{
+        ChainAnchor, EventDetails, EventType, FullNameV1, IdFullNameV1, ObjectType, VersionedEvent,
+    };
     use mz_controller_types::{ClusterId, ReplicaId};
     use mz_expr::{MirRelationExpr, MirScalarExpr, OptimizedMirRelationExpr};
     use mz_ore::collections::CollectionExt;
@@ -9537,6 +10078,130 @@ mod tests {
         }
     }
 
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+    async fn test_audit_log_pruning() {
+        /// Returns the anchor obtained by chaining `events` onto `anchor`.
+        fn chain<'a>(
+            mut anchor: ChainAnchor,
+            events: impl IntoIterator<Item = &'a VersionedEvent>,
+        ) -> ChainAnchor {
+            for event in events {
+                anchor.extend(event);
+            }
+            anchor
+        }
+
+        let debug_stash_factory = DebugStashFactory::new().await;
+        let stash = debug_stash_factory.open_debug().await;
+        let catalog = Catalog::open_debug_stash(stash, NOW_ZERO.clone())
+            .await
+            .expect("unable to open debug catalog");
+
+        // Append events that occurred at 1s, 2s, and 3s to the events written
+        // while bootstrapping the catalog, which all occurred at 0s.
+        let new_events = {
+            let mut storage = catalog.storage().await;
+            let mut tx = storage
+                .transaction()
+                .await
+                .expect("failed to start transaction");
+            let mut new_events = Vec::new();
+            for occurred_at in [1_000, 2_000, 3_000] {
+                let id = tx
+                    .get_and_increment_id(mz_catalog::AUDIT_LOG_ID_ALLOC_KEY.to_string())
+                    .expect("failed to allocate audit log id");
+                let event = VersionedEvent::new(
+                    id,
+                    EventType::Create,
+                    ObjectType::Table,
+                    EventDetails::IdFullNameV1(IdFullNameV1 {
+                        id: format!("u{id}"),
+                        name: FullNameV1 {
+                            database: "materialize".to_string(),
+                            schema: "public".to_string(),
+                            item: format!("t{id}"),
+                        },
+                    }),
+                    None,
+                    occurred_at,
+                );
+                tx.insert_audit_log_event(event.clone());
+                new_events.push(event);
+            }
+            tx.commit().await.expect("failed to commit");
+            new_events
+        };
+
+        let (mut all_events, anchor) = catalog
+            .get_audit_log_chain()
+            .await
+            .expect("failed to read audit log");
+        all_events.sort_by_key(|event| event.sortable_id());
+        assert_eq!(anchor, ChainAnchor::default());
+        assert_eq!(&all_events[all_events.len() - 3..], &new_events[..]);
+        let head = chain(ChainAnchor::default(), &all_events);
+
+        // Booting at 10s with a retention of 7.5s makes every event but the
+        // last old enough to prune, but only the events before the second new
+        // event have been exported.
+        let boot_ts = Timestamp::from(10_000u64);
+        let retention = Some(Duration::from_millis(7_500));
+        let retain_from = Some(new_events[1].sortable_id());
+        let (events, anchor) = catalog
+            .storage()
+            .await
+            .get_and_prune_audit_logs(retention, boot_ts, retain_from)
+            .await
+            .expect("failed to prune audit log");
+        assert_eq!(events, &new_events[1..]);
+        assert_eq!(
+            anchor,
+            chain(ChainAnchor::default(), &all_events[..all_events.len() - 2])
+        );
+        assert_eq!(chain(anchor.clone(), &events), head);
+
+        // The new anchor is persisted along with the pruned log.
+        let (persisted_events, persisted_anchor) = catalog
+            .get_audit_log_chain()
+            .await
+            .expect("failed to read audit log");
+        assert_eq!(persisted_events, events);
+        assert_eq!(persisted_anchor, anchor);
+
+        // Unexported events are never pruned, no matter how old they are.
+        let (events, unchanged_anchor) = catalog
+            .storage()
+            .await
+            .get_and_prune_audit_logs(Some(Duration::ZERO), boot_ts, retain_from)
+            .await
+            .expect("failed to prune audit log");
+        assert_eq!(events, &new_events[1..]);
+        assert_eq!(unchanged_anchor, anchor);
+
+        // Once every event is exported, the second new event is pruned and the
+        // anchor advances past it, while the last event is still too new to
+        // prune.
+        let retain_from = Some(new_events[2].sortable_id() + 1);
+        let (events, anchor) = catalog
+            .storage()
+            .await
+            .get_and_prune_audit_logs(retention, boot_ts, retain_from)
+            .await
+            .expect("failed to prune audit log");
+        assert_eq!(events, &new_events[2..]);
+        assert_eq!(
+            anchor,
+            chain(ChainAnchor::default(), &all_events[..all_events.len() - 1])
+        );
+        assert_eq!(chain(anchor.clone(), &events), head);
+        let (_, persisted_anchor) = catalog
+            .get_audit_log_chain()
+            .await
+            .expect("failed to read audit log");
+        assert_eq!(persisted_anchor, anchor);
+    }
+
     #[mz_ore::test(tokio::test)]
     #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
     async fn test_effective_search_path() {
@@ -9741,6 +10406,7 @@ mod tests {
                                 .with_key(vec![0]),
                             resolved_ids: ResolvedIds(BTreeSet::from_iter(resolved_ids)),
                             cluster_id: ClusterId::User(1),
+                            column_reads: BTreeMap::new(),
                         })
                     }
                     SimplifiedItem::Index { on } => {
@@ -10972,10 +11638,22 @@ mod tests {
                 "avg_internal_v1",
                 "bool_and",
                 "bool_or",
+                "corr",
+                "covar_pop",
+                "covar_samp",
                 "mod",
                 "mz_panic",
                 "mz_sleep",
                 "pow",
+                "regr_avgx",
+                "regr_avgy",
+                "regr_count",
+                "regr_intercept",
+                "regr_r2",
+                "regr_slope",
+                "regr_sxx",
+                "regr_sxy",
+                "regr_syy",
                 "stddev_pop",
                 "stddev_samp",
                 "stddev",
@@ -21,12 +21,13 @@ use mz_expr::MirScalarExpr;
 use mz_orchestrator::{CpuLimit, DiskLimit, MemoryLimit, NotReadyReason, ServiceProcessMetrics};
 use mz_ore::cast::CastFrom;
 use mz_ore::collections::CollectionExt;
+use mz_ore::str::separated;
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::jsonb::Jsonb;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem, PrivilegeMap};
 use mz_repr::role_id::RoleId;
 use mz_repr::{Datum, Diff, GlobalId, Row};
-use mz_sql::ast::{CreateIndexStatement, Statement};
+use mz_sql::ast::{CreateIndexStatement, Ident, Statement, UnresolvedItemName};
 use mz_sql::catalog::{CatalogCluster, CatalogDatabase, CatalogSchema, CatalogType, TypeCategory};
 use mz_sql::func::FuncImplCatalogDetails;
 use mz_sql::names::{CommentObjectId, ResolvedDatabaseSpecifier, SchemaId, SchemaSpecifier};
@@ -37,25 +38,27 @@ use mz_storage_types::sinks::{KafkaSinkConnection, StorageSinkConnection};
 use mz_storage_types::sources::{
     GenericSourceConnection, KafkaSourceConnection, PostgresSourceConnection,
 };
+use mz_transform::index_advice::IndexUsage;
 
 use crate::catalog::{
     AwsPrincipalContext, CatalogItem, CatalogState, ClusterVariant, Connection, DataSourceDesc,
     Database, DefaultPrivilegeObject, Error, ErrorKind, Func, Index, MaterializedView, Sink,
     StorageSinkConnectionState, Type, View, SYSTEM_CONN_ID,
 };
+use crate::coord::index_advice::IndexAdvice;
 use crate::coord::ConnMeta;
 use crate::subscribe::ActiveSubscribe;
 use mz_catalog::builtin::{
     MZ_AGGREGATES, MZ_ARRAY_TYPES, MZ_AUDIT_EVENTS, MZ_AWS_PRIVATELINK_CONNECTIONS, MZ_BASE_TYPES,
     MZ_CLUSTERS, MZ_CLUSTER_LINKS, MZ_CLUSTER_REPLICAS, MZ_CLUSTER_REPLICA_HEARTBEATS,
     MZ_CLUSTER_REPLICA_METRICS, MZ_CLUSTER_REPLICA_SIZES, MZ_CLUSTER_REPLICA_STATUSES, MZ_COLUMNS,
-    MZ_COMMENTS, MZ_COMPUTE_DEPENDENCIES, MZ_CONNECTIONS, MZ_DATABASES, MZ_DEFAULT_PRIVILEGES,
-    MZ_EGRESS_IPS, MZ_FUNCTIONS, MZ_INDEXES, MZ_INDEX_COLUMNS, MZ_KAFKA_CONNECTIONS,
-    MZ_KAFKA_SINKS, MZ_KAFKA_SOURCES, MZ_LIST_TYPES, MZ_MAP_TYPES, MZ_MATERIALIZED_VIEWS,
-    MZ_OBJECT_DEPENDENCIES, MZ_OPERATORS, MZ_POSTGRES_SOURCES, MZ_PSEUDO_TYPES, MZ_ROLES,
-    MZ_ROLE_MEMBERS, MZ_SCHEMAS, MZ_SECRETS, MZ_SESSIONS, MZ_SINKS, MZ_SOURCES,
-    MZ_SSH_TUNNEL_CONNECTIONS, MZ_STORAGE_USAGE_BY_SHARD, MZ_SUBSCRIPTIONS, MZ_SYSTEM_PRIVILEGES,
-    MZ_TABLES, MZ_TYPES, MZ_TYPE_PG_METADATA, MZ_VIEWS, MZ_WEBHOOKS_SOURCES,
+    MZ_COLUMN_PRIVILEGES, MZ_COMMENTS, MZ_COMPUTE_DEPENDENCIES, MZ_CONNECTIONS, MZ_DATABASES,
+    MZ_DEFAULT_PRIVILEGES, MZ_EGRESS_IPS, MZ_FUNCTIONS, MZ_INDEXES, MZ_INDEX_COLUMNS,
+    MZ_KAFKA_CONNECTIONS, MZ_KAFKA_SINKS, MZ_KAFKA_SOURCES, MZ_LIST_TYPES, MZ_MAP_TYPES,
+    MZ_MATERIALIZED_VIEWS, MZ_OBJECT_DEPENDENCIES, MZ_OPERATORS, MZ_POLICIES, MZ_POSTGRES_SOURCES,
+    MZ_PSEUDO_TYPES, MZ_ROLES, MZ_ROLE_MEMBERS, MZ_SCHEMAS, MZ_SECRETS, MZ_SESSIONS, MZ_SINKS,
+    MZ_SOURCES, MZ_SSH_TUNNEL_CONNECTIONS, MZ_STORAGE_USAGE_BY_SHARD, MZ_SUBSCRIPTIONS,
+    MZ_SYSTEM_PRIVILEGES, MZ_TABLES, MZ_TYPES, MZ_TYPE_PG_METADATA, MZ_VIEWS, MZ_WEBHOOKS_SOURCES,
 };
 
 /// An update to a built-in table.
@@ -769,6 +772,7 @@ impl CatalogState {
                         diff,
                     });
                 }
+                StorageSinkConnection::Postgres(_) => {}
             };
 
             let envelope = sink.envelope();
@@ -1285,6 +1289,50 @@ impl CatalogState {
         }
     }
 
+    /// Packs a row of `mz_index_advice`. Returns the row rather than an update so that the
+    /// caller can hold on to it and retract it later, even if the described objects are renamed in
+    /// the meantime.
+    pub fn pack_index_advice_row(&self, advice: &IndexAdvice) -> Row {
+        let entry = self.get_entry(&advice.on_id);
+        let full_name = self.resolve_full_name(entry.name(), None);
+        let desc = entry
+            .desc(&full_name)
+            .expect("index advice is only given for relations");
+        let key: Vec<_> = advice
+            .key
+            .iter()
+            .map(|c| desc.get_name(*c).as_str())
+            .collect();
+        let create_sql = format!(
+            "CREATE INDEX ON {} ({})",
+            UnresolvedItemName::from(full_name).to_ast_string_stable(),
+            separated(
+                ", ",
+                key.iter()
+                    .map(|name| Ident::new(*name).to_ast_string_stable())
+            ),
+        );
+        let usage = match advice.usage {
+            IndexUsage::Lookup => "lookup",
+            IndexUsage::Join => "join",
+        };
+
+        let mut row = Row::default();
+        let mut packer = row.packer();
+        packer.push(Datum::String(&advice.on_id.to_string()));
+        packer.push_list(key.iter().map(|name| Datum::String(name)));
+        packer.push(Datum::String(usage));
+        packer.push(Datum::UInt64(advice.query_count));
+        let last_seen_dt = mz_ore::now::to_datetime(advice.last_seen_at);
+        packer.push(Datum::TimestampTz(
+            last_seen_dt.try_into().expect("must fit"),
+        ));
+        packer.push(Datum::from(advice.size_bytes));
+        packer.push(Datum::from(advice.estimated_benefit_bytes()));
+        packer.push(Datum::String(&create_sql));
+        row
+    }
+
     pub fn pack_default_privileges_update(
         &self,
         default_privilege_object: &DefaultPrivilegeObject,
@@ -1410,6 +1458,46 @@ impl CatalogState {
         }
     }
 
+    pub fn pack_policy_update(
+        &self,
+        object_id: GlobalId,
+        name: &str,
+        predicate: &str,
+        diff: Diff,
+    ) -> BuiltinTableUpdate {
+        let object_id_str = object_id.to_string();
+        BuiltinTableUpdate {
+            id: self.resolve_builtin_table(&MZ_POLICIES),
+            row: Row::pack_slice(&[
+                Datum::String(&object_id_str),
+                Datum::String(name),
+                Datum::String(predicate),
+            ]),
+            diff,
+        }
+    }
+
+    pub fn pack_column_privileges_update(
+        &self,
+        object_id: GlobalId,
+        column: &str,
+        privileges: &PrivilegeMap,
+        diff: Diff,
+    ) -> BuiltinTableUpdate {
+        let object_id_str = object_id.to_string();
+        let privileges_row = self.pack_privilege_array_row(privileges);
+        let privileges = privileges_row.unpack_first();
+        BuiltinTableUpdate {
+            id: self.resolve_builtin_table(&MZ_COLUMN_PRIVILEGES),
+            row: Row::pack_slice(&[
+                Datum::String(&object_id_str),
+                Datum::String(column),
+                privileges,
+            ]),
+            diff,
+        }
+    }
+
     pub fn pack_webhook_source_update(
         &self,
         source_id: GlobalId,
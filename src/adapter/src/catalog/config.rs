@@ -69,6 +69,10 @@ pub struct Config<'a> {
     pub system_parameter_sync_config: Option<SystemParameterSyncConfig>,
     /// How long to retain storage usage records
     pub storage_usage_retention_period: Option<Duration>,
+    /// The ID of the first audit log event that must not be pruned, no matter
+    /// how old it is. Set when the audit log is exported, so that events are
+    /// never pruned before they have been exported.
+    pub audit_log_retain_from: Option<u64>,
     /// Host name or URL for connecting to the HTTP server of this instance.
     pub http_host_name: Option<String>,
     /// Needed only for migrating PG source column metadata. If `None`, will
@@ -0,0 +1,244 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A history of which item each name referred to over time.
+//!
+//! Items are dropped and recreated or renamed all the time (e.g. to swap in a
+//! fixed version of a view), which changes the [`GlobalId`] a name resolves to.
+//! Queries with an explicit `AS OF` are about the past, so they resolve names
+//! to the items they referred to at the requested time. The catalog only
+//! tracks the current bindings, but every create, drop and rename of an item is
+//! recorded in the audit log along with the oracle write timestamp of the
+//! catalog transaction. Replaying those events yields the full history of
+//! bindings, as far back as the audit log is retained.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use mz_audit_log::{EventDetails, EventType, FullNameV1, VersionedEvent};
+use mz_ore::now::EpochMillis;
+use mz_repr::GlobalId;
+
+/// The period during which a name was bound to an item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameBinding {
+    /// The item the name referred to.
+    pub id: GlobalId,
+    /// The time at which the item was created or renamed to this name.
+    pub bound_at: EpochMillis,
+    /// The time at which the item was dropped or renamed away from this name,
+    /// if it has been.
+    pub unbound_at: Option<EpochMillis>,
+}
+
+impl NameBinding {
+    /// Reports whether the name referred to this item at time `at`.
+    pub fn contains(&self, at: EpochMillis) -> bool {
+        self.bound_at <= at && self.unbound_at.map_or(true, |unbound_at| at < unbound_at)
+    }
+}
+
+/// The history of name to item bindings, as recorded by the audit log.
+#[derive(Debug, Clone, Default)]
+pub struct ItemNameHistory {
+    bindings: BTreeMap<FullNameV1, Vec<NameBinding>>,
+}
+
+impl ItemNameHistory {
+    /// Updates the history with an audit log event.
+    ///
+    /// Events must be applied in the order of their
+    /// [`VersionedEvent::sortable_id`]. Events that do not affect name bindings
+    /// are ignored.
+    pub fn apply(&mut self, event: &VersionedEvent) {
+        let VersionedEvent::V1(event) = event;
+        let at = event.occurred_at;
+        match (&event.event_type, &event.details) {
+            (EventType::Create, EventDetails::IdFullNameV1(details)) => {
+                self.bind(details.name.clone(), &details.id, at)
+            }
+            (EventType::Create, EventDetails::CreateSourceSinkV1(details)) => {
+                self.bind(details.name.clone(), &details.id, at)
+            }
+            (EventType::Create, EventDetails::CreateSourceSinkV2(details)) => {
+                self.bind(details.name.clone(), &details.id, at)
+            }
+            (EventType::Drop, EventDetails::IdFullNameV1(details)) => {
+                self.unbind(&details.name, &details.id, at)
+            }
+            (EventType::Alter, EventDetails::RenameItemV1(details)) => {
+                self.unbind(&details.old_name, &details.id, at);
+                self.bind(details.new_name.clone(), &details.id, at);
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the binding of `name` at time `at`, if the name referred to an
+    /// item at that time.
+    pub fn resolve(&self, name: &FullNameV1, at: EpochMillis) -> Option<&NameBinding> {
+        self.bindings
+            .get(name)?
+            .iter()
+            .rev()
+            .find(|binding| binding.contains(at))
+    }
+
+    /// Reports whether the history knows when the name was bound to `id`.
+    ///
+    /// This is not the case for items that are not recorded in the audit log,
+    /// like builtin items, or whose creation has been pruned from it.
+    pub fn contains(&self, name: &FullNameV1, id: GlobalId) -> bool {
+        self.bindings.get(name).map_or(false, |bindings| {
+            bindings.iter().any(|binding| binding.id == id)
+        })
+    }
+
+    /// Returns the items that have been dropped, i.e. that the history knows
+    /// of but that are not bound to any name anymore.
+    pub fn dropped_ids(&self) -> BTreeSet<GlobalId> {
+        let mut dropped = BTreeSet::new();
+        let mut bound = BTreeSet::new();
+        for binding in self.bindings.values().flatten() {
+            match binding.unbound_at {
+                Some(_) => dropped.insert(binding.id),
+                None => bound.insert(binding.id),
+            };
+        }
+        dropped.retain(|id| !bound.contains(id));
+        dropped
+    }
+
+    fn bind(&mut self, name: FullNameV1, id: &str, at: EpochMillis) {
+        // Events for objects that don't have a `GlobalId` (or that were
+        // recorded with an id format we no longer understand) can't be used to
+        // resolve names, so skip them.
+        let Ok(id) = id.parse() else {
+            return;
+        };
+        self.bindings.entry(name).or_default().push(NameBinding {
+            id,
+            bound_at: at,
+            unbound_at: None,
+        });
+    }
+
+    fn unbind(&mut self, name: &FullNameV1, id: &str, at: EpochMillis) {
+        let Ok(id) = id.parse::<GlobalId>() else {
+            return;
+        };
+        let binding = self.bindings.get_mut(name).and_then(|bindings| {
+            bindings
+                .iter_mut()
+                .rev()
+                .find(|binding| binding.id == id && binding.unbound_at.is_none())
+        });
+        if let Some(binding) = binding {
+            binding.unbound_at = Some(at);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_audit_log::{IdFullNameV1, ObjectType, RenameItemV1};
+
+    use super::*;
+
+    fn name(item: &str) -> FullNameV1 {
+        FullNameV1 {
+            database: "materialize".into(),
+            schema: "public".into(),
+            item: item.into(),
+        }
+    }
+
+    fn event(
+        id: u64,
+        event_type: EventType,
+        details: EventDetails,
+        at: EpochMillis,
+    ) -> VersionedEvent {
+        VersionedEvent::new(id, event_type, ObjectType::View, details, None, at)
+    }
+
+    fn id_full_name(id: &str, item: &str) -> EventDetails {
+        EventDetails::IdFullNameV1(IdFullNameV1 {
+            id: id.into(),
+            name: name(item),
+        })
+    }
+
+    #[mz_ore::test]
+    fn test_replaced_item() {
+        let mut history = ItemNameHistory::default();
+        for event in [
+            event(1, EventType::Create, id_full_name("u1", "v"), 10),
+            event(2, EventType::Drop, id_full_name("u1", "v"), 20),
+            event(3, EventType::Create, id_full_name("u2", "v"), 20),
+        ] {
+            history.apply(&event);
+        }
+
+        assert_eq!(history.resolve(&name("v"), 5), None);
+        assert_eq!(
+            history.resolve(&name("v"), 10).map(|b| b.id),
+            Some(GlobalId::User(1))
+        );
+        assert_eq!(
+            history.resolve(&name("v"), 19).map(|b| b.id),
+            Some(GlobalId::User(1))
+        );
+        assert_eq!(
+            history.resolve(&name("v"), 20).map(|b| b.id),
+            Some(GlobalId::User(2))
+        );
+        assert!(history.contains(&name("v"), GlobalId::User(1)));
+        assert!(history.contains(&name("v"), GlobalId::User(2)));
+        assert!(!history.contains(&name("v"), GlobalId::User(3)));
+        assert_eq!(history.dropped_ids(), BTreeSet::from([GlobalId::User(1)]));
+    }
+
+    #[mz_ore::test]
+    fn test_renamed_item() {
+        let mut history = ItemNameHistory::default();
+        for event in [
+            event(1, EventType::Create, id_full_name("u1", "a"), 10),
+            event(
+                2,
+                EventType::Alter,
+                EventDetails::RenameItemV1(RenameItemV1 {
+                    id: "u1".into(),
+                    old_name: name("a"),
+                    new_name: name("b"),
+                }),
+                20,
+            ),
+            event(3, EventType::Create, id_full_name("u2", "a"), 30),
+        ] {
+            history.apply(&event);
+        }
+
+        assert_eq!(
+            history.resolve(&name("a"), 15).map(|b| b.id),
+            Some(GlobalId::User(1))
+        );
+        assert_eq!(history.resolve(&name("a"), 25), None);
+        assert_eq!(
+            history.resolve(&name("a"), 30).map(|b| b.id),
+            Some(GlobalId::User(2))
+        );
+        assert_eq!(history.resolve(&name("b"), 15), None);
+        assert_eq!(
+            history.resolve(&name("b"), 25).map(|b| b.id),
+            Some(GlobalId::User(1))
+        );
+        // Renamed items have not been dropped.
+        assert_eq!(history.dropped_ids(), BTreeSet::new());
+    }
+}
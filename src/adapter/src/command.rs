@@ -508,6 +508,8 @@ pub enum ExecuteResponse {
     CreatedViews,
     /// The requested materialized view was created.
     CreatedMaterializedView,
+    /// The requested policy was created.
+    CreatedPolicy,
     /// The requested type was created.
     CreatedType,
     /// The requested prepared statement was removed.
@@ -524,6 +526,8 @@ pub enum ExecuteResponse {
     DroppedObject(ObjectType),
     /// The requested objects were dropped.
     DroppedOwned,
+    /// The requested policy was dropped.
+    DroppedPolicy,
     /// The provided query was empty.
     EmptyQuery,
     /// Fetch results from a cursor.
@@ -677,6 +681,7 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::CreatedMaterializedView => {
                 Ok(ExecuteResponse::CreatedMaterializedView)
             }
+            ExecuteResponseKind::CreatedPolicy => Ok(ExecuteResponse::CreatedPolicy),
             ExecuteResponseKind::CreatedType => Ok(ExecuteResponse::CreatedType),
             ExecuteResponseKind::Deallocate => Err(()),
             ExecuteResponseKind::DeclaredCursor => Ok(ExecuteResponse::DeclaredCursor),
@@ -685,6 +690,7 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::DiscardedAll => Ok(ExecuteResponse::DiscardedAll),
             ExecuteResponseKind::DroppedObject => Err(()),
             ExecuteResponseKind::DroppedOwned => Ok(ExecuteResponse::DroppedOwned),
+            ExecuteResponseKind::DroppedPolicy => Ok(ExecuteResponse::DroppedPolicy),
             ExecuteResponseKind::EmptyQuery => Ok(ExecuteResponse::EmptyQuery),
             ExecuteResponseKind::Fetch => Err(()),
             ExecuteResponseKind::GrantedPrivilege => Ok(ExecuteResponse::GrantedPrivilege),
@@ -736,6 +742,7 @@ impl ExecuteResponse {
             CreatedView { .. } => Some("CREATE VIEW".into()),
             CreatedViews { .. } => Some("CREATE VIEWS".into()),
             CreatedMaterializedView { .. } => Some("CREATE MATERIALIZED VIEW".into()),
+            CreatedPolicy => Some("CREATE POLICY".into()),
             CreatedType => Some("CREATE TYPE".into()),
             Deallocate { all } => Some(format!("DEALLOCATE{}", if *all { " ALL" } else { "" })),
             DeclaredCursor => Some("DECLARE CURSOR".into()),
@@ -744,6 +751,7 @@ impl ExecuteResponse {
             DiscardedAll => Some("DISCARD ALL".into()),
             DroppedObject(o) => Some(format!("DROP {o}")),
             DroppedOwned => Some("DROP OWNED".into()),
+            DroppedPolicy => Some("DROP POLICY".into()),
             EmptyQuery => None,
             Fetch { .. } => None,
             GrantedPrivilege => Some("GRANT".into()),
@@ -822,6 +830,7 @@ impl ExecuteResponse {
             CreateTable => vec![CreatedTable],
             CreateView => vec![CreatedView],
             CreateMaterializedView => vec![CreatedMaterializedView],
+            CreatePolicy => vec![CreatedPolicy],
             CreateIndex => vec![CreatedIndex],
             CreateType => vec![CreatedType],
             PlanKind::Deallocate => vec![ExecuteResponseKind::Deallocate],
@@ -830,6 +839,7 @@ impl ExecuteResponse {
             DiscardAll => vec![DiscardedAll],
             DropObjects => vec![DroppedObject],
             DropOwned => vec![DroppedOwned],
+            DropPolicy => vec![DroppedPolicy],
             PlanKind::EmptyQuery => vec![ExecuteResponseKind::EmptyQuery],
             ExplainPlan | ExplainTimestamp | Select | ShowAllVariables | ShowCreate
             | ShowColumns | ShowVariable | InspectShard => {
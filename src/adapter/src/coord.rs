@@ -133,6 +133,7 @@ use crate::client::{Client, ConnectionId, Handle};
 use crate::command::{Canceled, Command, ExecuteResponse};
 use crate::config::SystemParameterSyncConfig;
 use crate::coord::appends::{Deferred, GroupCommitPermit, PendingWriteTxn};
+use crate::coord::audit_log_export::{AuditLogExportConfig, AuditLogExporter};
 use crate::coord::dataflows::dataflow_import_id_bundle;
 use crate::coord::id_bundle::CollectionIdBundle;
 use crate::coord::peek::PendingPeek;
@@ -148,10 +149,13 @@ use crate::util::{ClientTransmitter, CompletedClientTransmitter, ComputeSinkId,
 use crate::{flags, AdapterNotice, TimestampProvider};
 use mz_catalog::builtin::{BUILTINS, MZ_VIEW_FOREIGN_KEYS, MZ_VIEW_KEYS};
 
+pub(crate) mod audit_log_export;
 pub(crate) mod dataflows;
+use self::index_advice::IndexAdvisor;
 use self::statement_logging::{StatementLogging, StatementLoggingId};
 
 pub(crate) mod id_bundle;
+pub(crate) mod index_advice;
 pub(crate) mod peek;
 pub(crate) mod statement_logging;
 pub(crate) mod timeline;
@@ -194,6 +198,8 @@ pub enum Message<T = mz_repr::Timestamp> {
     PurifiedStatementReady(PurifiedStatementReady),
     CreateConnectionValidationReady(CreateConnectionValidationReady),
     SinkConnectionReady(SinkConnectionReady),
+    AlterSinkReady(AlterSinkReady),
+    SinkProgressResetReady(SinkProgressResetReady),
     WriteLockGrant(tokio::sync::OwnedMutexGuard<()>),
     /// Initiates a group commit.
     GroupCommitInitiate(Span, Option<GroupCommitPermit>),
@@ -245,6 +251,9 @@ pub enum Message<T = mz_repr::Timestamp> {
         stage: PeekStage,
     },
     DrainStatementLog,
+    /// Drops the storage collections of dropped relations that were retained
+    /// for queries with an explicit `AS OF`.
+    ReleaseRetainedRelations(Vec<GlobalId>),
 }
 
 impl Message {
@@ -258,6 +267,8 @@ impl Message {
             PurifiedStatementReady(_) => "purified_statement_ready",
             CreateConnectionValidationReady(_) => "create_connection_validation_ready",
             SinkConnectionReady(_) => "sink_connection_ready",
+            AlterSinkReady(_) => "alter_sink_ready",
+            SinkProgressResetReady(_) => "sink_progress_reset_ready",
             WriteLockGrant(_) => "write_lock_grant",
             GroupCommitInitiate(..) => "group_commit_initiate",
             GroupCommitApply(..) => "group_commit_apply",
@@ -272,6 +283,7 @@ impl Message {
             ExecuteSingleStatementTransaction { .. } => "execute_single_statement_transaction",
             PeekStageReady { .. } => "peek_stage_ready",
             DrainStatementLog => "drain_statement_log",
+            ReleaseRetainedRelations(_) => "release_retained_relations",
         }
     }
 }
@@ -315,6 +327,29 @@ pub struct SinkConnectionReady {
     pub result: Result<StorageSinkConnection<ReferencedConnection>, AdapterError>,
 }
 
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct AlterSinkReady {
+    #[derivative(Debug = "ignore")]
+    pub ctx: ExecuteContext,
+    pub id: GlobalId,
+    /// The input of the sink when the sink was altered.
+    pub previous_from: GlobalId,
+    pub sink: mz_sql::plan::Sink,
+    pub plan_validity: PlanValidity,
+    pub result: Result<StorageSinkConnection<ReferencedConnection>, AdapterError>,
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct SinkProgressResetReady {
+    #[derivative(Debug = "ignore")]
+    pub ctx: ExecuteContext,
+    pub id: GlobalId,
+    pub plan_validity: PlanValidity,
+    pub result: Result<(), AdapterError>,
+}
+
 #[derive(Debug)]
 pub enum RealTimeRecencyContext {
     ExplainTimestamp {
@@ -481,8 +516,15 @@ impl PlanValidity {
         // - Ids do not mutate.
         // - Ids are not reused.
         // - If an id was dropped, this will detect it and error.
+        //
+        // Dropped relations that are retained for queries with an explicit
+        // `AS OF` are only valid dependencies of plans that were made after
+        // they had been dropped.
         for id in &self.dependency_ids {
-            if catalog.try_get_entry(id).is_none() {
+            let retained_before_plan = catalog
+                .retained_item_dropped_at(id)
+                .map_or(false, |revision| revision <= self.transient_revision);
+            if catalog.try_get_live_entry(id).is_none() && !retained_before_plan {
                 return Err(AdapterError::ChangedPlan);
             }
         }
@@ -539,6 +581,7 @@ pub struct Config {
     pub storage_usage_collection_interval: Duration,
     pub storage_usage_retention_period: Option<Duration>,
     pub segment_client: Option<mz_segment::Client>,
+    pub audit_log_export: Option<AuditLogExportConfig>,
     pub egress_ips: Vec<Ipv4Addr>,
     pub system_parameter_sync_config: Option<SystemParameterSyncConfig>,
     pub aws_account_id: Option<String>,
@@ -1023,6 +1066,9 @@ pub struct Coordinator {
     #[derivative(Debug = "ignore")]
     segment_client: Option<mz_segment::Client>,
 
+    /// Exporter of audit log events to an external file.
+    audit_log_exporter: Option<AuditLogExporter>,
+
     /// Coordinator metrics.
     metrics: Metrics,
 
@@ -1032,6 +1078,9 @@ pub struct Coordinator {
     /// Data used by the statement logging feature.
     statement_logging: StatementLogging,
 
+    /// Indexes that would have sped up observed slow-path peeks.
+    index_advisor: IndexAdvisor,
+
     /// Whether to start replicas with the new variable-length row encoding scheme.
     variable_length_row_encoding: bool,
 }
@@ -1710,6 +1759,15 @@ impl Coordinator {
         // state with what it has learned from the adapter.
         self.controller.storage.reconcile_state().await;
 
+        // Relations that are retained for queries with an explicit `AS OF` are
+        // forgotten on restart, so reclaim the storage collections of any that
+        // were not yet released.
+        let dropped_ids = self.catalog().dropped_item_ids();
+        self.controller
+            .storage
+            .drop_orphaned_collections(dropped_ids)
+            .await;
+
         // Cleanup orphaned secrets. Errors during list() or delete() do not
         // need to prevent bootstrap from succeeding; we will retry next
         // startup.
@@ -2040,6 +2098,7 @@ pub async fn serve(
         storage_usage_collection_interval,
         storage_usage_retention_period,
         segment_client,
+        audit_log_export,
         egress_ips,
         aws_account_id,
         aws_privatelink_availability_zones,
@@ -2078,6 +2137,22 @@ pub async fn serve(
     let aws_privatelink_availability_zones = aws_privatelink_availability_zones
         .map(|azs_vec| BTreeSet::from_iter(azs_vec.iter().cloned()));
 
+    // Never prune audit log events that have not been exported yet.
+    let audit_log_retain_from = match &audit_log_export {
+        Some(config) => {
+            let exported = audit_log_export::last_exported_id(config.path.clone())
+                .await
+                .map_err(|e| {
+                    AdapterError::Unstructured(anyhow::anyhow!(
+                        "failed to read audit log export file {}: {e:#}",
+                        config.path.display()
+                    ))
+                })?;
+            Some(exported.map_or(0, |id| id + 1))
+        }
+        None => None,
+    };
+
     info!("coordinator init: opening catalog");
     let (catalog, builtin_migration_metadata, builtin_table_updates, _last_catalog_version) =
         Catalog::open(catalog::Config {
@@ -2099,11 +2174,28 @@ pub async fn serve(
             aws_privatelink_availability_zones,
             system_parameter_sync_config,
             storage_usage_retention_period,
+            audit_log_retain_from,
             connection_context: Some(connection_context.clone()),
             active_connection_count,
             http_host_name,
         })
         .await?;
+    let audit_log_exporter = match audit_log_export {
+        Some(config) => {
+            let (events, anchor) = catalog.get_audit_log_chain().await?;
+            let path = config.path.clone();
+            let exporter = AuditLogExporter::open(config, anchor, events)
+                .await
+                .map_err(|e| {
+                    AdapterError::Unstructured(anyhow::anyhow!(
+                        "failed to open audit log export file {}: {e:#}",
+                        path.display()
+                    ))
+                })?;
+            Some(exporter)
+        }
+        None => None,
+    };
     let session_id = catalog.config().session_id;
     let start_instant = catalog.config().start_instant;
 
@@ -2175,9 +2267,11 @@ pub async fn serve(
                 storage_usage_client,
                 storage_usage_collection_interval,
                 segment_client,
+                audit_log_exporter,
                 metrics,
                 tracing_handle,
                 statement_logging: StatementLogging::new(),
+                index_advisor: IndexAdvisor::default(),
                 variable_length_row_encoding,
             };
             let bootstrap = handle.block_on(async {
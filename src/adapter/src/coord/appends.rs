@@ -308,7 +308,7 @@ impl Coordinator {
                         // client that the write was successful. This is only possible if the write
                         // and the delete were concurrent. Therefore, we are free to order the
                         // write before the delete without violating any consistency guarantees.
-                        if self.catalog().try_get_entry(&id).is_some() {
+                        if self.catalog().try_get_live_entry(&id).is_some() {
                             appends.entry(id).or_default().extend(rows);
                         }
                     }
@@ -0,0 +1,501 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Export of the audit log to an append-only JSON lines file.
+//!
+//! Each line of the file describes one audit log event:
+//!
+//! ```text
+//! {"id":42,"prev_hash":"<hex>","hash":"<hex>","signature":"<hex>","event":{"V1":{...}}}
+//! ```
+//!
+//! `hash` is the event's link in the audit log's hash chain (see
+//! [`ChainAnchor`]), and `prev_hash` is the link of the event preceding it.
+//! `signature` signs the event's ID and link with a key that is not stored in
+//! the catalog (see [`ChainAnchor::sign`]). External systems can verify that
+//! the exported log has not been tampered with by recomputing the chain, and
+//! when opening the file we verify that the audit log in the catalog still
+//! matches the events that were exported from it.
+//!
+//! Events are only pruned from the audit log once they have been exported (see
+//! [`last_exported_id`]), so an event that fails to be exported is exported
+//! again the next time the file is opened, if not before.
+
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context};
+use mz_audit_log::{ChainAnchor, VersionedEvent};
+use mz_ore::cast::CastFrom;
+use serde::{Deserialize, Serialize};
+use tracing::{error, info, warn};
+
+/// How long to wait before retrying to export events after a failure.
+const RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Configures the export of the audit log to a file.
+#[derive(Debug, Clone)]
+pub struct AuditLogExportConfig {
+    /// The file to which to append audit log events.
+    pub path: PathBuf,
+    /// The key with which to sign the exported hash chain.
+    pub signing_key: Vec<u8>,
+}
+
+/// A line in the audit log export file.
+#[derive(Debug, Serialize, Deserialize)]
+struct ExportedEvent {
+    id: u64,
+    prev_hash: String,
+    hash: String,
+    signature: String,
+    event: VersionedEvent,
+}
+
+/// Appends audit log events to an export file.
+///
+/// The file is written by a background thread, so that the coordinator never
+/// blocks on file I/O.
+#[derive(Debug)]
+pub struct AuditLogExporter {
+    tx: mpsc::Sender<Vec<VersionedEvent>>,
+}
+
+impl AuditLogExporter {
+    /// Opens the export file described by `config`, creating it if necessary,
+    /// verifies that `events` match the events it contains, and exports any of
+    /// `events` that it does not yet contain.
+    ///
+    /// `anchor` and `events` must describe the full hash chain over the audit
+    /// log, as returned by [`crate::catalog::Catalog::get_audit_log_chain`].
+    /// Returns an error if the audit log does not match the exported chain,
+    /// which indicates that either has been tampered with.
+    pub async fn open(
+        config: AuditLogExportConfig,
+        anchor: ChainAnchor,
+        events: Vec<VersionedEvent>,
+    ) -> Result<AuditLogExporter, anyhow::Error> {
+        let mut writer = mz_ore::task::spawn_blocking(
+            || "audit_log_export_open",
+            move || ExportWriter::open(config, anchor, events),
+        )
+        .await
+        .context("joining on audit log export")??;
+        let (tx, rx) = mpsc::channel::<Vec<VersionedEvent>>();
+        thread::Builder::new()
+            .name("audit-log-export".into())
+            .spawn(move || loop {
+                // While some events could not be exported, wake up regularly
+                // to retry.
+                let events = if writer.pending.is_empty() {
+                    match rx.recv() {
+                        Ok(events) => events,
+                        Err(_) => break,
+                    }
+                } else {
+                    match rx.recv_timeout(RETRY_INTERVAL) {
+                        Ok(events) => events,
+                        Err(RecvTimeoutError::Timeout) => vec![],
+                        Err(RecvTimeoutError::Disconnected) => break,
+                    }
+                };
+                writer.export(events);
+            })?;
+        Ok(AuditLogExporter { tx })
+    }
+
+    /// Exports newly committed audit log events.
+    pub fn export(&self, events: Vec<VersionedEvent>) {
+        // The export thread only stops on its own if it panics. Events that it
+        // drops are exported the next time the file is opened, as they can't
+        // be pruned from the audit log before then.
+        if let Err(mpsc::SendError(events)) = self.tx.send(events) {
+            error!(
+                "audit log export thread stopped, not exporting {} audit log events",
+                events.len()
+            );
+        }
+    }
+}
+
+/// Returns the ID of the last event in the export file at `path`, if any.
+///
+/// The file is not verified; that happens when it is opened by
+/// [`AuditLogExporter::open`].
+pub async fn last_exported_id(path: PathBuf) -> Result<Option<u64>, anyhow::Error> {
+    mz_ore::task::spawn_blocking(
+        || "audit_log_export_last_id",
+        move || {
+            let file = match File::open(&path) {
+                Ok(file) => file,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                Err(e) => return Err(e.into()),
+            };
+            let mut last = None;
+            for line in BufReader::new(file).lines() {
+                let line = line?;
+                if !line.trim().is_empty() {
+                    last = Some(line);
+                }
+            }
+            match last {
+                Some(line) => {
+                    let exported: ExportedEvent =
+                        serde_json::from_str(&line).with_context(|| {
+                            format!("invalid audit log export file {}", path.display())
+                        })?;
+                    Ok(Some(exported.id))
+                }
+                None => Ok(None),
+            }
+        },
+    )
+    .await
+    .context("joining on audit log export")?
+}
+
+/// The state of the export file, owned by the thread that writes it.
+#[derive(Debug)]
+struct ExportWriter {
+    path: PathBuf,
+    signing_key: Vec<u8>,
+    file: File,
+    /// The length of the complete lines in the file.
+    len: u64,
+    /// The position in the hash chain of the last exported event.
+    chain: ChainAnchor,
+    /// The events that are yet to be exported, in order.
+    pending: VecDeque<VersionedEvent>,
+}
+
+impl ExportWriter {
+    fn open(
+        AuditLogExportConfig { path, signing_key }: AuditLogExportConfig,
+        pruned: ChainAnchor,
+        mut events: Vec<VersionedEvent>,
+    ) -> Result<ExportWriter, anyhow::Error> {
+        events.sort_by_key(|event| event.sortable_id());
+        let mut anchor = pruned.clone();
+        let mut chain = Vec::with_capacity(events.len());
+        for event in &events {
+            anchor.extend(event);
+            chain.push(anchor.clone());
+        }
+        let last_exported = verify_export_file(&path, &signing_key, &pruned, &chain)?;
+
+        // Pruning never passes the last exported event, so this indicates that
+        // the audit log was pruned by something other than us.
+        if let (Some(exported), Some(pruned_id)) = (last_exported, pruned.last_id) {
+            if exported < pruned_id {
+                bail!(
+                    "audit log events {} through {pruned_id} were pruned before they were exported to {}",
+                    exported + 1,
+                    path.display(),
+                );
+            }
+        }
+
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let len = file.metadata()?.len();
+        let mut writer = ExportWriter {
+            path,
+            signing_key,
+            file,
+            len,
+            chain: pruned,
+            pending: VecDeque::new(),
+        };
+        for event in events {
+            if last_exported.map_or(false, |exported| event.sortable_id() <= exported) {
+                writer.chain.extend(&event);
+            } else {
+                writer.pending.push_back(event);
+            }
+        }
+        if !writer.pending.is_empty() {
+            info!(
+                "exporting {} audit log events to {}",
+                writer.pending.len(),
+                writer.path.display()
+            );
+        }
+        writer.flush()?;
+        Ok(writer)
+    }
+
+    /// Exports `events` along with any events that previously failed to be
+    /// exported.
+    fn export(&mut self, mut events: Vec<VersionedEvent>) {
+        events.sort_by_key(|event| event.sortable_id());
+        self.pending.extend(events);
+        if let Err(e) = self.flush() {
+            warn!(
+                "failed to export {} audit log events to {}, retrying: {e}",
+                self.pending.len(),
+                self.path.display()
+            );
+        }
+    }
+
+    /// Writes the pending events to the file, in order. Stops at the first
+    /// event that cannot be written, which remains pending.
+    fn flush(&mut self) -> Result<(), anyhow::Error> {
+        while let Some(event) = self.pending.front() {
+            let mut link = self.chain.clone();
+            link.extend(event);
+            let line = ExportedEvent {
+                id: event.sortable_id(),
+                prev_hash: hex::encode(self.chain.hash),
+                hash: hex::encode(link.hash),
+                signature: link.sign(&self.signing_key),
+                event: event.clone(),
+            };
+            let mut buf = serde_json::to_vec(&line)?;
+            buf.push(b'\n');
+            if let Err(e) = self.file.write_all(&buf).and_then(|()| self.file.flush()) {
+                // Remove what was written of the line, so that the next
+                // attempt starts on a line of its own.
+                self.file
+                    .set_len(self.len)
+                    .context("truncating partially written audit log event")?;
+                return Err(e.into());
+            }
+            self.len += u64::cast_from(buf.len());
+            self.chain = link;
+            self.pending.pop_front();
+        }
+        Ok(())
+    }
+}
+
+/// Verifies the export file at `path` against the audit log, and returns the
+/// ID of the last exported event, if any.
+///
+/// `pruned` is the link of the last event pruned from the audit log and
+/// `chain` holds the links of the events still in it, in order. Every exported
+/// line must be signed with `signing_key` and follow the line before it, and
+/// every exported event that was not pruned must still be in the audit log
+/// with the same link, and vice versa.
+fn verify_export_file(
+    path: &Path,
+    signing_key: &[u8],
+    pruned: &ChainAnchor,
+    chain: &[ChainAnchor],
+) -> Result<Option<u64>, anyhow::Error> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let mismatch = |id| {
+        anyhow!(
+            "audit log event {id} does not match the version exported to {}",
+            path.display()
+        )
+    };
+
+    let mut last: Option<ExportedEvent> = None;
+    // The number of events in `chain` that were found in the file.
+    let mut matched = 0;
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let exported: ExportedEvent = serde_json::from_str(&line)
+            .with_context(|| format!("invalid audit log export file {}", path.display()))?;
+        let mut link = ChainAnchor {
+            last_id: Some(exported.id),
+            hash: [0; 32],
+        };
+        hex::decode_to_slice(&exported.hash, &mut link.hash)
+            .with_context(|| format!("invalid audit log export file {}", path.display()))?;
+        if !link.verify(signing_key, &exported.signature) {
+            bail!(
+                "audit log event {} in {} has an invalid signature",
+                exported.id,
+                path.display()
+            );
+        }
+        if let Some(prev) = &last {
+            if exported.id <= prev.id || exported.prev_hash != prev.hash {
+                bail!(
+                    "audit log event {} in {} does not follow the event before it",
+                    exported.id,
+                    path.display()
+                );
+            }
+        }
+
+        if pruned.last_id == Some(exported.id) && pruned.hash != link.hash {
+            return Err(mismatch(exported.id));
+        }
+        if pruned
+            .last_id
+            .map_or(true, |pruned_id| exported.id > pruned_id)
+        {
+            match chain.binary_search_by_key(&Some(exported.id), |link| link.last_id) {
+                Ok(index) if chain[index].hash == link.hash => matched += 1,
+                Ok(_) => return Err(mismatch(exported.id)),
+                Err(_) => bail!(
+                    "audit log event {} was exported to {} but is missing from the audit log",
+                    exported.id,
+                    path.display()
+                ),
+            }
+        }
+        last = Some(exported);
+    }
+
+    let last_id = last.map(|exported| exported.id);
+    if let Some(last_id) = last_id {
+        let expected = chain
+            .iter()
+            .filter(|link| link.last_id <= Some(last_id))
+            .count();
+        if expected != matched {
+            bail!(
+                "the audit log contains events up to {last_id} that were not exported to {}",
+                path.display()
+            );
+        }
+    }
+    Ok(last_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_audit_log::{EventDetails, EventType, IdNameV1, ObjectType};
+
+    use super::*;
+
+    fn event(id: u64, name: &str) -> VersionedEvent {
+        VersionedEvent::new(
+            id,
+            EventType::Create,
+            ObjectType::Cluster,
+            EventDetails::IdNameV1(IdNameV1 {
+                id: format!("u{id}"),
+                name: name.into(),
+            }),
+            None,
+            id,
+        )
+    }
+
+    fn events(ids: &[u64]) -> Vec<VersionedEvent> {
+        ids.iter().map(|id| event(*id, &format!("c{id}"))).collect()
+    }
+
+    fn config(path: &Path, key: &[u8]) -> AuditLogExportConfig {
+        AuditLogExportConfig {
+            path: path.to_owned(),
+            signing_key: key.to_vec(),
+        }
+    }
+
+    fn read_ids(path: &Path) -> Vec<u64> {
+        std::fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<ExportedEvent>(line).unwrap().id)
+            .collect()
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `mkdir`
+    fn test_audit_log_export() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+
+        let mut writer = ExportWriter::open(
+            config(&path, b"key"),
+            ChainAnchor::default(),
+            events(&[2, 1]),
+        )
+        .unwrap();
+        writer.export(events(&[3]));
+        drop(writer);
+        assert_eq!(read_ids(&path), vec![1, 2, 3]);
+
+        // Reopening only exports events that are missing from the file, even
+        // if older events have since been pruned.
+        let mut anchor = ChainAnchor::default();
+        anchor.extend(&event(1, "c1"));
+        let writer =
+            ExportWriter::open(config(&path, b"key"), anchor.clone(), events(&[2, 3, 4])).unwrap();
+        drop(writer);
+        assert_eq!(read_ids(&path), vec![1, 2, 3, 4]);
+
+        // The exported lines form a chain.
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<ExportedEvent> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        for pair in lines.windows(2) {
+            assert_eq!(pair[0].hash, pair[1].prev_hash);
+        }
+
+        // Rewriting an exported event in the audit log is detected.
+        let mut tampered = events(&[2, 3, 4]);
+        tampered[1] = event(3, "x");
+        let err = ExportWriter::open(config(&path, b"key"), anchor.clone(), tampered).unwrap_err();
+        assert!(err.to_string().contains("event 3 does not match"), "{err}");
+
+        // So is deleting an exported event, or changing the anchor.
+        let err =
+            ExportWriter::open(config(&path, b"key"), anchor.clone(), events(&[2, 4])).unwrap_err();
+        assert!(err.to_string().contains("event 3 was exported"), "{err}");
+        let mut bad_anchor = anchor.clone();
+        bad_anchor.hash = [1; 32];
+        let err =
+            ExportWriter::open(config(&path, b"key"), bad_anchor, events(&[2, 3, 4])).unwrap_err();
+        assert!(err.to_string().contains("does not match"), "{err}");
+
+        // As is a file that was not signed with the expected key.
+        let err =
+            ExportWriter::open(config(&path, b"other"), anchor, events(&[2, 3, 4])).unwrap_err();
+        assert!(err.to_string().contains("invalid signature"), "{err}");
+
+        // Events must not be pruned before they are exported.
+        let mut pruned = ChainAnchor::default();
+        for event in events(&[1, 2, 3, 4, 5]) {
+            pruned.extend(&event);
+        }
+        let err = ExportWriter::open(config(&path, b"key"), pruned, events(&[6])).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("events 5 through 5 were pruned before they were exported"),
+            "{err}"
+        );
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `mkdir`
+    async fn test_last_exported_id() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        assert_eq!(last_exported_id(path.clone()).await.unwrap(), None);
+
+        let writer = ExportWriter::open(
+            config(&path, b"key"),
+            ChainAnchor::default(),
+            events(&[1, 2]),
+        )
+        .unwrap();
+        drop(writer);
+        assert_eq!(last_exported_id(path).await.unwrap(), Some(2));
+    }
+}
@@ -18,12 +18,14 @@ use mz_ore::task;
 use mz_ore::tracing::OpenTelemetryContext;
 use mz_repr::role_id::RoleId;
 use mz_sql::ast::{
-    CopyRelation, CopyStatement, InsertSource, Query, Raw, SetExpr, Statement, SubscribeStatement,
+    AsOf, CopyRelation, CopyStatement, InsertSource, Query, Raw, SelectStatement, SetExpr,
+    Statement, SubscribeStatement,
 };
 use mz_sql::catalog::RoleAttributes;
 use mz_sql::names::{PartialItemName, ResolvedIds};
 use mz_sql::plan::{
-    AbortTransactionPlan, CommitTransactionPlan, CreateRolePlan, Params, Plan, TransactionType,
+    plan_as_of, AbortTransactionPlan, CommitTransactionPlan, CreateRolePlan, Params, Plan,
+    StatementContext, TransactionType,
 };
 use mz_sql::rbac;
 use mz_sql::session::user::User;
@@ -35,7 +37,7 @@ use tokio::sync::{mpsc, oneshot, watch};
 use tracing::Instrument;
 use tracing_opentelemetry::OpenTelemetrySpanExt;
 
-use crate::catalog::{CatalogItem, DataSourceDesc, Source};
+use crate::catalog::{CatalogItem, ConnCatalog, DataSourceDesc, Source};
 use crate::client::{ConnectionId, ConnectionIdType};
 use crate::command::{
     AppendWebhookResponse, AppendWebhookValidator, Canceled, CatalogSnapshot, Command,
@@ -423,6 +425,32 @@ impl Coordinator {
         self.handle_execute_inner(stmt, params, ctx).await
     }
 
+    /// Returns the time at which names in `stmt` must be resolved, if it reads
+    /// at an explicit `AS OF` time.
+    ///
+    /// Invalid `AS OF` clauses are ignored here, planning reports them.
+    pub(crate) fn resolve_items_as_of(
+        catalog: &ConnCatalog,
+        session: &Session,
+        stmt: &Statement<Raw>,
+    ) -> Option<mz_repr::Timestamp> {
+        let as_of = match stmt {
+            Statement::Select(SelectStatement {
+                as_of: Some(as_of @ AsOf::At(_)),
+                ..
+            })
+            | Statement::Subscribe(SubscribeStatement {
+                as_of: Some(as_of @ AsOf::At(_)),
+                ..
+            }) => as_of.clone(),
+            _ => return None,
+        };
+        let (as_of, _) = mz_sql::names::resolve(catalog, as_of).ok()?;
+        let scx = StatementContext::new(None, catalog);
+        let when = plan_as_of(&scx, Some(as_of)).ok()?;
+        Self::evaluate_when(catalog.state(), when.advance_to_timestamp()?, session).ok()
+    }
+
     #[tracing::instrument(level = "trace", skip(self, ctx))]
     pub(crate) async fn handle_execute_inner(
         &mut self,
@@ -538,6 +566,7 @@ impl Coordinator {
                     | Statement::CreateDatabase(_)
                     | Statement::CreateIndex(_)
                     | Statement::CreateMaterializedView(_)
+                    | Statement::CreatePolicy(_)
                     | Statement::CreateRole(_)
                     | Statement::CreateSchema(_)
                     | Statement::CreateSecret(_)
@@ -551,6 +580,7 @@ impl Coordinator {
                     | Statement::Delete(_)
                     | Statement::DropObjects(_)
                     | Statement::DropOwned(_)
+                    | Statement::DropPolicy(_)
                     | Statement::GrantPrivileges(_)
                     | Statement::GrantRole(_)
                     | Statement::Insert(_)
@@ -588,7 +618,12 @@ impl Coordinator {
         }
 
         let catalog = self.catalog();
-        let catalog = catalog.for_session(ctx.session());
+        let mut catalog = catalog.for_session(ctx.session());
+        // Queries at an explicit time read the items their names referred to
+        // at that time.
+        if let Some(as_of) = Self::resolve_items_as_of(&catalog, ctx.session(), &stmt) {
+            catalog.set_resolve_items_as_of(as_of);
+        }
         let original_stmt = stmt.clone();
         let (stmt, resolved_ids) = match mz_sql::names::resolve(&catalog, stmt) {
             Ok(resolved) => resolved,
@@ -556,7 +556,11 @@ impl<'a> DataflowBuilder<'a> {
         memo: &mut BTreeMap<GlobalId, bool>,
     ) -> Result<bool, RecursionLimitError> {
         self.checked_recur(|_| {
-            match self.catalog.get_entry(&id).item() {
+            // The inputs of retained materialized views may have been dropped.
+            let Some(entry) = self.catalog.try_get_entry(&id) else {
+                return Ok(false);
+            };
+            match entry.item() {
                 CatalogItem::Source(source) => Ok(self.monotonic_source(source)),
                 CatalogItem::View(View { optimized_expr, .. })
                 | CatalogItem::MaterializedView(MaterializedView { optimized_expr, .. }) => {
@@ -782,6 +786,12 @@ fn eval_unmaterializable_func(
         UnmaterializableFunc::ViewableVariables => pack_dict(
             viewable_variables(state, session)
                 .map(|var| (var.name().to_lowercase(), var.value()))
+                .chain(
+                    session
+                        .vars()
+                        .iter_custom()
+                        .map(|(name, value)| (name.to_string(), value.to_string())),
+                )
                 .collect(),
         ),
         UnmaterializableFunc::CurrentTimestamp => {
@@ -41,15 +41,16 @@ use mz_storage_types::sources::{GenericSourceConnection, Timeline};
 use serde_json::json;
 use timely::progress::Antichain;
 use tracing::{event, warn, Level};
+use uuid::Uuid;
 
 use crate::catalog::{
-    CatalogItem, CatalogState, DataSourceDesc, Op, Sink, StorageSinkConnectionState,
+    CatalogEntry, CatalogItem, CatalogState, DataSourceDesc, Op, Sink, StorageSinkConnectionState,
     TransactionResult, SYSTEM_CONN_ID,
 };
 use crate::client::ConnectionId;
 use crate::coord::read_policy::SINCE_GRANULARITY;
 use crate::coord::timeline::{TimelineContext, TimelineState};
-use crate::coord::{Coordinator, ReplicaMetadata};
+use crate::coord::{Coordinator, Message, ReplicaMetadata};
 use crate::session::Session;
 use crate::statement_logging::StatementEndedExecutionReason;
 use crate::telemetry::SegmentClientExt;
@@ -118,6 +119,9 @@ impl Coordinator {
         let mut clusters_to_drop = vec![];
         let mut cluster_replicas_to_drop = vec![];
         let mut peeks_to_drop = vec![];
+        let mut databases_to_drop = vec![];
+        let mut schemas_to_drop = vec![];
+        let mut roles_to_drop = vec![];
         let mut update_tracing_config = false;
         let mut update_compute_config = false;
         let mut update_storage_config = false;
@@ -203,6 +207,15 @@ impl Coordinator {
                     // Drop the cluster replica itself.
                     cluster_replicas_to_drop.push((*cluster_id, *replica_id));
                 }
+                catalog::Op::DropObject(ObjectId::Database(id)) => {
+                    databases_to_drop.push(*id);
+                }
+                catalog::Op::DropObject(ObjectId::Schema(schema)) => {
+                    schemas_to_drop.push(*schema);
+                }
+                catalog::Op::DropObject(ObjectId::Role(id)) => {
+                    roles_to_drop.push(*id);
+                }
                 catalog::Op::ResetSystemConfiguration { name }
                 | catalog::Op::UpdateSystemConfiguration { name, .. } => {
                     update_tracing_config |= vars::is_tracing_var(name);
@@ -227,48 +240,53 @@ impl Coordinator {
             }
         }
 
+        // Dropped tables and materialized views remain readable by queries with an
+        // explicit `AS OF` until their retention has passed, and their storage
+        // collections are only dropped once they are released. Retained relations
+        // can't outlive the databases, schemas and roles they refer to, so dropping
+        // any of those releases them once the transaction commits. Their readers are
+        // canceled along with those of the dropped relations.
+        let refers_to_dropped_objects = |entry: &CatalogEntry| {
+            let qualifiers = &entry.name().qualifiers;
+            let in_dropped_database = match &qualifiers.database_spec {
+                ResolvedDatabaseSpecifier::Id(id) => databases_to_drop.contains(id),
+                ResolvedDatabaseSpecifier::Ambient => false,
+            };
+            let in_dropped_schema = schemas_to_drop.iter().any(|(database_spec, schema_spec)| {
+                *database_spec == qualifiers.database_spec && *schema_spec == qualifiers.schema_spec
+            });
+            let refers_to_dropped_role = roles_to_drop.contains(entry.owner_id())
+                || entry.privileges().all_values().any(|item| {
+                    roles_to_drop.contains(&item.grantee) || roles_to_drop.contains(&item.grantor)
+                });
+            in_dropped_database || in_dropped_schema || refers_to_dropped_role
+        };
+        let retained_relations_to_release: BTreeSet<_> = self
+            .catalog()
+            .retained_items()
+            .filter(|entry| refers_to_dropped_objects(*entry))
+            .map(|entry| entry.id())
+            .collect();
+
         let relations_to_drop: BTreeSet<_> = sources_to_drop
             .iter()
             .chain(tables_to_drop.iter())
             .chain(storage_sinks_to_drop.iter())
             .chain(indexes_to_drop.iter().map(|(_, id)| id))
             .chain(materialized_views_to_drop.iter().map(|(_, id)| id))
+            .chain(retained_relations_to_release.iter())
+            .copied()
             .collect();
 
         // Clean up any active subscribes that rely on dropped relations.
-        let subscribe_sinks_to_drop: Vec<_> = self
-            .active_subscribes
-            .iter()
-            .filter(|(_id, sub)| !sub.dropping)
-            .filter_map(|(sink_id, sub)| {
-                sub.depends_on
-                    .iter()
-                    .find(|id| relations_to_drop.contains(id))
-                    .map(|dependent_id| (dependent_id, sink_id, sub))
-            })
-            .map(|(dependent_id, sink_id, active_subscribe)| {
-                let conn_id = &active_subscribe.conn_id;
-                let entry = self.catalog().get_entry(dependent_id);
-                let name = self
-                    .catalog()
-                    .resolve_full_name(entry.name(), Some(conn_id));
-
-                (
-                    (conn_id.clone(), name.to_string()),
-                    ComputeSinkId {
-                        cluster_id: active_subscribe.cluster_id,
-                        global_id: *sink_id,
-                    },
-                )
-            })
-            .collect();
+        let subscribe_sinks_to_drop = self.subscribes_depending_on(&relations_to_drop);
 
         // Clean up any pending peeks that rely on dropped relations.
         for (uuid, pending_peek) in &self.pending_peeks {
             if let Some(id) = pending_peek
                 .depends_on
                 .iter()
-                .find(|id| relations_to_drop.contains(id))
+                .find(|id| relations_to_drop.contains(*id))
             {
                 let entry = self.catalog().get_entry(id);
                 let name = self
@@ -333,6 +351,23 @@ impl Coordinator {
                 .map(catalog::Op::DropTimeline),
         );
 
+        let relation_retention = self.catalog().system_config().dropped_relation_retention();
+        let relations_to_retain: Vec<CatalogEntry> = if relation_retention.is_zero() {
+            Vec::new()
+        } else {
+            tables_to_drop
+                .iter()
+                .chain(materialized_views_to_drop.iter().map(|(_, id)| id))
+                .map(|id| self.catalog().get_entry(id))
+                .filter(|entry| {
+                    entry.id().is_user()
+                        && entry.conn_id().is_none()
+                        && !refers_to_dropped_objects(*entry)
+                })
+                .cloned()
+                .collect()
+        };
+
         self.validate_resource_limits(&ops, conn_id.unwrap_or(&SYSTEM_CONN_ID))?;
 
         // This will produce timestamps that are guaranteed to increase on each
@@ -372,6 +407,14 @@ impl Coordinator {
             self.send_builtin_table_updates_blocking(builtin_table_updates)
                 .await;
 
+            let collections_to_drop = sources_to_drop
+                .iter()
+                .chain(tables_to_drop.iter())
+                .chain(materialized_views_to_drop.iter().map(|(_, id)| id))
+                .copied()
+                .collect();
+            self.drop_index_advice(&collections_to_drop);
+
             if !timeline_associations.is_empty() {
                 for (timeline, (should_be_empty, id_bundle)) in timeline_associations {
                     let became_empty =
@@ -379,9 +422,15 @@ impl Coordinator {
                     assert_eq!(should_be_empty, became_empty, "emptiness did not match!");
                 }
             }
+            let retained_relations: BTreeSet<_> =
+                relations_to_retain.iter().map(|entry| entry.id()).collect();
+            if !relations_to_retain.is_empty() {
+                self.retain_dropped_relations(relations_to_retain, relation_retention);
+            }
             if !sources_to_drop.is_empty() {
                 self.drop_sources(sources_to_drop);
             }
+            tables_to_drop.retain(|id| !retained_relations.contains(id));
             if !tables_to_drop.is_empty() {
                 self.drop_sources(tables_to_drop);
             }
@@ -389,44 +438,19 @@ impl Coordinator {
                 self.drop_storage_sinks(storage_sinks_to_drop);
             }
             if !subscribe_sinks_to_drop.is_empty() {
-                let (dropped_metadata, subscribe_sinks_to_drop): (Vec<_>, BTreeSet<_>) =
-                    subscribe_sinks_to_drop.into_iter().unzip();
-                for (conn_id, dropped_name) in dropped_metadata {
-                    if let Some(conn_meta) = self.active_conns.get_mut(&conn_id) {
-                        conn_meta
-                            .drop_sinks
-                            .retain(|sink| !subscribe_sinks_to_drop.contains(sink));
-                        // Send notice on a best effort basis.
-                        let _ = conn_meta
-                            .notice_tx
-                            .send(AdapterNotice::DroppedSubscribe { dropped_name });
-                    }
-                }
-                self.drop_compute_sinks(subscribe_sinks_to_drop);
+                self.drop_dependent_subscribes(subscribe_sinks_to_drop);
             }
             if !peeks_to_drop.is_empty() {
-                for (dropped_name, uuid) in peeks_to_drop {
-                    if let Some(pending_peek) = self.remove_pending_peek(&uuid) {
-                        self.controller
-                            .active_compute()
-                            .cancel_peek(pending_peek.cluster_id, uuid)
-                            .unwrap_or_terminate("unable to cancel peek");
-                        self.retire_execution(
-                            StatementEndedExecutionReason::Canceled,
-                            pending_peek.ctx_extra,
-                        );
-                        // Client may have left.
-                        let _ = pending_peek.sender.send(PeekResponse::Error(format!(
-                            "query could not complete because {dropped_name} was dropped"
-                        )));
-                    }
-                }
+                self.cancel_dependent_peeks(peeks_to_drop);
+            }
+            if !retained_relations_to_release.is_empty() {
+                self.drop_retained_relations(retained_relations_to_release);
             }
             if !indexes_to_drop.is_empty() {
                 self.drop_indexes(indexes_to_drop);
             }
             if !materialized_views_to_drop.is_empty() {
-                self.drop_materialized_views(materialized_views_to_drop);
+                self.drop_materialized_views(materialized_views_to_drop, &retained_relations);
             }
             if !secrets_to_drop.is_empty() {
                 self.drop_secrets(secrets_to_drop).await;
@@ -496,7 +520,7 @@ impl Coordinator {
             &self.segment_client,
             conn.and_then(|s| s.user().external_metadata.as_ref()),
         ) {
-            for VersionedEvent::V1(event) in audit_events {
+            for VersionedEvent::V1(event) in &audit_events {
                 let event_type = format!(
                     "{} {}",
                     event.object_type.as_title_case(),
@@ -515,6 +539,10 @@ impl Coordinator {
             }
         }
 
+        if let Some(audit_log_exporter) = &self.audit_log_exporter {
+            audit_log_exporter.export(audit_events);
+        }
+
         // Note: It's important that we keep the function call inside macro, this way we only run
         // the consistency checks if sort assertions are enabled.
         mz_ore::soft_assert_eq!(self.catalog().check_consistency(), Ok(()));
@@ -562,6 +590,149 @@ impl Coordinator {
             .unwrap_or_terminate("cannot fail to drop sources");
     }
 
+    /// Keeps the storage collections of dropped relations readable by queries
+    /// with an explicit `AS OF`, and releases them once `retention` has passed.
+    fn retain_dropped_relations(&mut self, entries: Vec<CatalogEntry>, retention: Duration) {
+        let ids: Vec<_> = entries.iter().map(|entry| entry.id()).collect();
+        self.catalog_mut().retain_dropped_items(entries);
+
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(|| "release_retained_relations", async move {
+            tokio::time::sleep(retention).await;
+            if internal_cmd_tx
+                .send(Message::ReleaseRetainedRelations(ids))
+                .is_err()
+            {
+                // If sending fails, the main thread has shutdown.
+            }
+        });
+    }
+
+    /// Drops the storage collections of retained relations, after which they
+    /// can no longer be read, not even by queries with an explicit `AS OF`.
+    ///
+    /// Peeks and subscribes that still read from them are canceled, just like
+    /// when a relation is dropped.
+    pub(crate) fn release_retained_relations(&mut self, ids: BTreeSet<GlobalId>) {
+        // Relations may have been released early, e.g. because their schema
+        // was dropped.
+        let ids: BTreeSet<_> = ids
+            .into_iter()
+            .filter(|id| self.catalog().state().is_retained_item(id))
+            .collect();
+        if ids.is_empty() {
+            return;
+        }
+
+        let subscribe_sinks_to_drop = self.subscribes_depending_on(&ids);
+        let mut peeks_to_drop = vec![];
+        for (uuid, pending_peek) in &self.pending_peeks {
+            if let Some(id) = pending_peek.depends_on.iter().find(|id| ids.contains(*id)) {
+                let entry = self.catalog().get_entry(id);
+                let name = self
+                    .catalog()
+                    .resolve_full_name(entry.name(), Some(&pending_peek.conn_id));
+                peeks_to_drop.push((
+                    format!("relation {}", name.to_string().quoted()),
+                    uuid.clone(),
+                ));
+            }
+        }
+        if !subscribe_sinks_to_drop.is_empty() {
+            self.drop_dependent_subscribes(subscribe_sinks_to_drop);
+        }
+        if !peeks_to_drop.is_empty() {
+            self.cancel_dependent_peeks(peeks_to_drop);
+        }
+        self.drop_retained_relations(ids);
+    }
+
+    /// Forgets retained relations and drops their storage collections. Their
+    /// readers must have been canceled already.
+    fn drop_retained_relations(&mut self, ids: BTreeSet<GlobalId>) {
+        // AS OF peeks may have recorded advice for the relations, which can't
+        // be described once they are released.
+        self.drop_index_advice(&ids);
+        self.catalog_mut().release_retained_items(&ids);
+        self.drop_sources(ids.into_iter().collect());
+    }
+
+    /// Returns the active subscribes that read from any of `ids`, along with
+    /// the connection they belong to and the name of the relation they read.
+    fn subscribes_depending_on(
+        &self,
+        ids: &BTreeSet<GlobalId>,
+    ) -> Vec<((ConnectionId, String), ComputeSinkId)> {
+        self.active_subscribes
+            .iter()
+            .filter(|(_id, sub)| !sub.dropping)
+            .filter_map(|(sink_id, sub)| {
+                sub.depends_on
+                    .iter()
+                    .find(|id| ids.contains(*id))
+                    .map(|dependent_id| (dependent_id, sink_id, sub))
+            })
+            .map(|(dependent_id, sink_id, active_subscribe)| {
+                let conn_id = &active_subscribe.conn_id;
+                let entry = self.catalog().get_entry(dependent_id);
+                let name = self
+                    .catalog()
+                    .resolve_full_name(entry.name(), Some(conn_id));
+
+                (
+                    (conn_id.clone(), name.to_string()),
+                    ComputeSinkId {
+                        cluster_id: active_subscribe.cluster_id,
+                        global_id: *sink_id,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Drops subscribes that read from dropped relations, and notifies their
+    /// connections.
+    fn drop_dependent_subscribes(
+        &mut self,
+        subscribe_sinks_to_drop: Vec<((ConnectionId, String), ComputeSinkId)>,
+    ) {
+        let (dropped_metadata, subscribe_sinks_to_drop): (Vec<_>, BTreeSet<_>) =
+            subscribe_sinks_to_drop.into_iter().unzip();
+        for (conn_id, dropped_name) in dropped_metadata {
+            if let Some(conn_meta) = self.active_conns.get_mut(&conn_id) {
+                conn_meta
+                    .drop_sinks
+                    .retain(|sink| !subscribe_sinks_to_drop.contains(sink));
+                // Send notice on a best effort basis.
+                let _ = conn_meta
+                    .notice_tx
+                    .send(AdapterNotice::DroppedSubscribe { dropped_name });
+            }
+        }
+        self.drop_compute_sinks(subscribe_sinks_to_drop);
+    }
+
+    /// Cancels peeks that read from dropped objects, and reports the object
+    /// to their clients.
+    fn cancel_dependent_peeks(&mut self, peeks_to_drop: Vec<(String, Uuid)>) {
+        for (dropped_name, uuid) in peeks_to_drop {
+            if let Some(pending_peek) = self.remove_pending_peek(&uuid) {
+                self.controller
+                    .active_compute()
+                    .cancel_peek(pending_peek.cluster_id, uuid)
+                    .unwrap_or_terminate("unable to cancel peek");
+                self.retire_execution(
+                    StatementEndedExecutionReason::Canceled,
+                    pending_peek.ctx_extra,
+                );
+                // Client may have left.
+                let _ = pending_peek.sender.send(PeekResponse::Error(format!(
+                    "query could not complete because {dropped_name} was dropped"
+                )));
+            }
+        }
+    }
+
     pub(crate) fn drop_compute_sinks(&mut self, sinks: impl IntoIterator<Item = ComputeSinkId>) {
         let mut by_cluster: BTreeMap<_, Vec<_>> = BTreeMap::new();
         for sink in sinks {
@@ -635,13 +806,21 @@ impl Coordinator {
         }
     }
 
-    fn drop_materialized_views(&mut self, mviews: Vec<(ClusterId, GlobalId)>) {
+    /// Drops the given materialized views. The storage collections of
+    /// `retained` ones are only dropped once they are released.
+    fn drop_materialized_views(
+        &mut self,
+        mviews: Vec<(ClusterId, GlobalId)>,
+        retained: &BTreeSet<GlobalId>,
+    ) {
         let mut by_cluster: BTreeMap<_, Vec<_>> = BTreeMap::new();
         let mut source_ids = Vec::new();
         for (cluster_id, id) in mviews {
             if self.drop_compute_read_policy(&id) {
                 by_cluster.entry(cluster_id).or_default().push(id);
-                source_ids.push(id);
+                if !retained.contains(&id) {
+                    source_ids.push(id);
+                }
             } else {
                 tracing::error!("Instructed to drop a materialized view that isn't one");
             }
@@ -807,6 +986,8 @@ impl Coordinator {
             as_of,
             status_id,
             from_storage_metadata: (),
+            previous_from: None,
+            resuming: false,
         };
 
         Ok(self
@@ -1048,10 +1229,12 @@ impl Coordinator {
                 },
                 Op::AlterRole { .. }
                 | Op::AlterSink { .. }
+                | Op::AlterSinkFrom { .. }
                 | Op::AlterSource { .. }
                 | Op::AlterSetCluster { .. }
                 | Op::DropTimeline(_)
                 | Op::UpdatePrivilege { .. }
+                | Op::UpdateColumnPrivilege { .. }
                 | Op::UpdateDefaultPrivilege { .. }
                 | Op::GrantRole { .. }
                 | Op::RenameCluster { .. }
@@ -1067,7 +1250,9 @@ impl Coordinator {
                 | Op::ResetAllSystemConfiguration { .. }
                 | Op::UpdateItem { .. }
                 | Op::UpdateRotatedKeys { .. }
-                | Op::Comment { .. } => {}
+                | Op::Comment { .. }
+                | Op::CreatePolicy { .. }
+                | Op::DropPolicy { .. } => {}
             }
         }
 
@@ -0,0 +1,251 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Advice about indexes that would speed up observed queries.
+//!
+//! Whenever a peek has to build a dataflow that reads collections from persist, we look for the
+//! indexes that would have let it use a fast path or reuse an arrangement (see
+//! [`mz_transform::index_advice`]), and record them in `mz_internal.mz_index_advice`. Advice is
+//! kept in memory only, so it starts out empty after a restart.
+//!
+//! The size of a collection in persist approximates both the cost of an index on it, which has to
+//! hold an arrangement of the whole collection, and the work that each query reading the collection
+//! from persist could have saved. We report the latter as the estimated benefit of the advice, and
+//! keep at most [`MAX_INDEX_ADVICE`] pieces of advice by dropping the ones with the smallest
+//! benefit first.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use mz_catalog::builtin::MZ_INDEX_ADVICE;
+use mz_compute_types::dataflows::DataflowDescription;
+use mz_expr::OptimizedMirRelationExpr;
+use mz_ore::now::EpochMillis;
+use mz_repr::{Diff, GlobalId, Row};
+use mz_transform::index_advice::{index_candidates, IndexCandidate, IndexUsage};
+
+use crate::catalog::BuiltinTableUpdate;
+use crate::coord::Coordinator;
+
+/// The maximum number of indexes that we keep advice for.
+pub const MAX_INDEX_ADVICE: usize = 1000;
+
+/// An index that would have helped some of the observed queries.
+#[derive(Debug, Clone)]
+pub struct IndexAdvice {
+    /// The collection to index.
+    pub on_id: GlobalId,
+    /// The columns of `on_id` to use as the index key.
+    pub key: Vec<usize>,
+    /// How the queries would have used the index.
+    pub usage: IndexUsage,
+    /// The number of queries that would have used the index.
+    pub query_count: u64,
+    /// When the index was last needed.
+    pub last_seen_at: EpochMillis,
+    /// The most recently observed size of `on_id` in persist, which approximates the size of an
+    /// arrangement of it.
+    pub size_bytes: Option<u64>,
+}
+
+impl IndexAdvice {
+    /// Estimates the number of bytes that the queries would have read from persist less if the
+    /// index had existed, or `None` if the size of `on_id` is not known yet.
+    pub fn estimated_benefit_bytes(&self) -> Option<u64> {
+        self.size_bytes
+            .map(|size_bytes| size_bytes.saturating_mul(self.query_count))
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct IndexAdvisor {
+    /// The current advice and the row that describes it in `mz_index_advice`, so that it can be
+    /// retracted.
+    advice: BTreeMap<IndexCandidate, (IndexAdvice, Row)>,
+    /// The most recently collected storage usage of each collection.
+    sizes: BTreeMap<GlobalId, u64>,
+}
+
+impl IndexAdvisor {
+    /// Removes the advice with the smallest estimated benefit, preferring the advice that was
+    /// needed least recently among equals, and returns the row that describes it.
+    fn evict(&mut self) -> Option<Row> {
+        let candidate = self
+            .advice
+            .iter()
+            .min_by_key(|(_, (advice, _))| (advice.estimated_benefit_bytes(), advice.last_seen_at))
+            .map(|(candidate, _)| candidate.clone())?;
+        self.advice.remove(&candidate).map(|(_, row)| row)
+    }
+}
+
+impl Coordinator {
+    /// Records the indexes that would have helped a peek that is about to run `dataflow` as a
+    /// slow-path dataflow.
+    pub(crate) fn record_index_advice(
+        &mut self,
+        dataflow: &DataflowDescription<OptimizedMirRelationExpr>,
+    ) {
+        // Only collections that are read from persist can benefit from a new index. Dropped
+        // relations that `AS OF` queries can still read can't be indexed anymore.
+        let candidates_for: BTreeSet<_> = dataflow
+            .source_imports
+            .keys()
+            .filter(|id| !self.catalog().state().is_retained_item(id))
+            .copied()
+            .collect();
+        if candidates_for.is_empty() {
+            return;
+        }
+        let candidates: BTreeSet<_> = dataflow
+            .objects_to_build
+            .iter()
+            .flat_map(|object| index_candidates(object.plan.as_inner(), &candidates_for))
+            .collect();
+        if candidates.is_empty() {
+            return;
+        }
+
+        let now = self.now();
+        let mut updates = Vec::new();
+        for candidate in candidates {
+            let advice = match self.index_advisor.advice.remove(&candidate) {
+                Some((mut advice, row)) => {
+                    updates.push(self.index_advice_update(row, -1));
+                    advice.query_count += 1;
+                    advice.last_seen_at = now;
+                    advice
+                }
+                None => {
+                    if self.index_advisor.advice.len() >= MAX_INDEX_ADVICE {
+                        if let Some(row) = self.index_advisor.evict() {
+                            updates.push(self.index_advice_update(row, -1));
+                        }
+                    }
+                    IndexAdvice {
+                        on_id: candidate.on_id,
+                        key: candidate.key.clone(),
+                        usage: candidate.usage,
+                        query_count: 1,
+                        last_seen_at: now,
+                        size_bytes: self.index_advisor.sizes.get(&candidate.on_id).copied(),
+                    }
+                }
+            };
+            let row = self.catalog().state().pack_index_advice_row(&advice);
+            updates.push(self.index_advice_update(row.clone(), 1));
+            self.index_advisor.advice.insert(candidate, (advice, row));
+        }
+        self.buffer_builtin_table_updates(updates);
+    }
+
+    /// Updates the sizes reported in `mz_index_advice` with the latest storage usage.
+    pub(crate) fn update_index_advice_sizes(&mut self, sizes: BTreeMap<GlobalId, u64>) {
+        let mut updates = Vec::new();
+        for (advice, row) in self.index_advisor.advice.values_mut() {
+            let size_bytes = sizes.get(&advice.on_id).copied();
+            if size_bytes != advice.size_bytes {
+                advice.size_bytes = size_bytes;
+                let new_row = self.catalog.state().pack_index_advice_row(advice);
+                let old_row = std::mem::replace(row, new_row.clone());
+                updates.push((old_row, -1));
+                updates.push((new_row, 1));
+            }
+        }
+        self.index_advisor.sizes = sizes;
+        if updates.is_empty() {
+            return;
+        }
+        let updates = updates
+            .into_iter()
+            .map(|(row, diff)| self.index_advice_update(row, diff))
+            .collect();
+        self.buffer_builtin_table_updates(updates);
+    }
+
+    /// Removes the advice for collections that have been dropped.
+    pub(crate) fn drop_index_advice(&mut self, dropped: &BTreeSet<GlobalId>) {
+        let mut retractions = Vec::new();
+        self.index_advisor.advice.retain(|candidate, (_, row)| {
+            let keep = !dropped.contains(&candidate.on_id);
+            if !keep {
+                retractions.push(row.clone());
+            }
+            keep
+        });
+        if retractions.is_empty() {
+            return;
+        }
+        let updates = retractions
+            .into_iter()
+            .map(|row| self.index_advice_update(row, -1))
+            .collect();
+        self.buffer_builtin_table_updates(updates);
+    }
+
+    fn index_advice_update(&self, row: Row, diff: Diff) -> BuiltinTableUpdate {
+        BuiltinTableUpdate {
+            id: self.catalog().resolve_builtin_table(&MZ_INDEX_ADVICE),
+            row,
+            diff,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_repr::{Datum, GlobalId, Row};
+    use mz_transform::index_advice::{IndexCandidate, IndexUsage};
+
+    use super::{IndexAdvice, IndexAdvisor};
+
+    fn insert(
+        advisor: &mut IndexAdvisor,
+        id: u64,
+        query_count: u64,
+        last_seen_at: u64,
+        size_bytes: Option<u64>,
+    ) {
+        let candidate = IndexCandidate {
+            on_id: GlobalId::User(id),
+            key: vec![0],
+            usage: IndexUsage::Lookup,
+        };
+        let advice = IndexAdvice {
+            on_id: candidate.on_id,
+            key: candidate.key.clone(),
+            usage: candidate.usage,
+            query_count,
+            last_seen_at,
+            size_bytes,
+        };
+        let row = Row::pack_slice(&[Datum::UInt64(id)]);
+        advisor.advice.insert(candidate, (advice, row));
+    }
+
+    #[mz_ore::test]
+    fn test_evict() {
+        let mut advisor = IndexAdvisor::default();
+        // 3 queries over 100 bytes.
+        insert(&mut advisor, 1, 3, 10, Some(100));
+        // 1 query over 1000 bytes.
+        insert(&mut advisor, 2, 1, 20, Some(1000));
+        // 5 queries over 100 bytes.
+        insert(&mut advisor, 3, 5, 30, Some(100));
+        // Unknown size, needed most recently.
+        insert(&mut advisor, 4, 10, 40, None);
+        // Unknown size, needed least recently.
+        insert(&mut advisor, 5, 10, 5, None);
+
+        let mut evicted = Vec::new();
+        while let Some(row) = advisor.evict() {
+            evicted.push(row.unpack_first().unwrap_uint64());
+        }
+        assert_eq!(evicted, vec![5, 4, 1, 3, 2]);
+    }
+}
@@ -13,6 +13,7 @@
 use std::collections::{BTreeMap, BTreeSet};
 use std::time::{Duration, Instant};
 
+use anyhow::anyhow;
 use chrono::DurationRound;
 use mz_controller::clusters::ClusterEvent;
 use mz_controller::ControllerResponse;
@@ -20,6 +21,7 @@ use mz_ore::now::EpochMillis;
 use mz_ore::task;
 use mz_persist_client::usage::ShardsUsageReferenced;
 use mz_sql::ast::Statement;
+use mz_sql::catalog::ObjectType;
 use mz_sql::names::ResolvedIds;
 use mz_sql::plan::{CreateSourcePlans, Plan};
 use mz_storage_types::controller::CollectionMetadata;
@@ -30,13 +32,13 @@ use crate::client::ConnectionId;
 use crate::command::{Command, ExecuteResponse};
 use crate::coord::appends::Deferred;
 use crate::coord::{
-    Coordinator, CreateConnectionValidationReady, Message, PeekStage, PeekStageFinish,
-    PendingReadTxn, PlanValidity, PurifiedStatementReady, RealTimeRecencyContext,
-    SinkConnectionReady,
+    AlterSinkReady, Coordinator, CreateConnectionValidationReady, Message, PeekStage,
+    PeekStageFinish, PendingReadTxn, PlanValidity, PurifiedStatementReady, RealTimeRecencyContext,
+    SinkConnectionReady, SinkProgressResetReady,
 };
 use crate::session::Session;
 use crate::util::{ComputeSinkId, ResultExt};
-use crate::{catalog, AdapterNotice, TimestampContext};
+use crate::{catalog, AdapterError, AdapterNotice, TimestampContext};
 
 impl Coordinator {
     pub(crate) async fn handle_message(&mut self, msg: Message) {
@@ -59,6 +61,8 @@ impl Coordinator {
                 self.message_create_connection_validation_ready(ready).await
             }
             Message::SinkConnectionReady(ready) => self.message_sink_connection_ready(ready).await,
+            Message::AlterSinkReady(ready) => self.message_alter_sink_ready(ready).await,
+            Message::SinkProgressResetReady(ready) => self.message_sink_progress_reset_ready(ready),
             Message::WriteLockGrant(write_lock_guard) => {
                 self.message_write_lock_grant(write_lock_guard).await;
             }
@@ -109,6 +113,9 @@ impl Coordinator {
             Message::DrainStatementLog => {
                 self.drain_statement_log().await;
             }
+            Message::ReleaseRetainedRelations(ids) => {
+                self.release_retained_relations(ids.into_iter().collect());
+            }
         }
     }
 
@@ -173,6 +180,21 @@ impl Coordinator {
         // timestamp in the past.
         let collection_timestamp: EpochMillis = self.get_local_write_ts().await.timestamp.into();
 
+        // Use the size of each collection's data shard to estimate the size of the indexes
+        // recommended for it.
+        let collection_sizes = self
+            .controller
+            .storage
+            .collections()
+            .filter_map(|(id, collection)| {
+                let usage = shards_usage
+                    .by_shard
+                    .get(&collection.collection_metadata.data_shard)?;
+                Some((*id, usage.size_bytes()))
+            })
+            .collect();
+        self.update_index_advice_sizes(collection_sizes);
+
         let mut ops = vec![];
         for (shard_id, shard_usage) in shards_usage.by_shard {
             ops.push(catalog::Op::UpdateStorageUsage {
@@ -581,6 +603,73 @@ impl Coordinator {
         }
     }
 
+    #[tracing::instrument(level = "debug", skip(self, ctx))]
+    async fn message_alter_sink_ready(
+        &mut self,
+        AlterSinkReady {
+            ctx,
+            id,
+            previous_from,
+            sink,
+            mut plan_validity,
+            result,
+        }: AlterSinkReady,
+    ) {
+        // Ensure that neither the sink nor its inputs were dropped, and that
+        // no other session repointed the sink, while we built its connection.
+        if let Err(e) = plan_validity.check(self.catalog()) {
+            return ctx.retire(Err(e));
+        }
+        let current_from = self
+            .catalog()
+            .get_entry(&id)
+            .sink()
+            .expect("known to be sink")
+            .from;
+        if current_from != previous_from {
+            return ctx.retire(Err(AdapterError::Unstructured(anyhow!(
+                "the input of the sink was changed concurrently"
+            ))));
+        }
+
+        let result = match result {
+            Ok(connection) => {
+                self.sequence_alter_sink_set_from_finish(ctx.session(), id, sink, connection)
+                    .await
+            }
+            Err(e) => Err(e),
+        };
+        ctx.retire(result);
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, ctx))]
+    fn message_sink_progress_reset_ready(
+        &mut self,
+        SinkProgressResetReady {
+            ctx,
+            id,
+            mut plan_validity,
+            result,
+        }: SinkProgressResetReady,
+    ) {
+        // Ensure that the sink was not dropped while we reset its progress.
+        if let Err(e) = plan_validity.check(self.catalog()) {
+            return ctx.retire(Err(e));
+        }
+
+        // The running sink was fenced out by the reset, so restart it to make it resume from the
+        // reset progress.
+        let result = result
+            .and_then(|()| {
+                self.controller
+                    .storage
+                    .restart_export(id)
+                    .map_err(AdapterError::from)
+            })
+            .map(|()| ExecuteResponse::AlteredObject(ObjectType::Sink));
+        ctx.retire(result);
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     async fn message_write_lock_grant(
         &mut self,
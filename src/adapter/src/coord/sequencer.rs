@@ -206,6 +206,10 @@ impl Coordinator {
                     .await;
                 ctx.retire(result);
             }
+            Plan::CreatePolicy(plan) => {
+                let result = self.sequence_create_policy(ctx.session(), plan).await;
+                ctx.retire(result);
+            }
             Plan::Comment(plan) => {
                 let result = self.sequence_comment_on(ctx.session(), plan).await;
                 ctx.retire(result);
@@ -218,6 +222,10 @@ impl Coordinator {
                 let result = self.sequence_drop_owned(ctx.session_mut(), plan).await;
                 ctx.retire(result);
             }
+            Plan::DropPolicy(plan) => {
+                let result = self.sequence_drop_policy(ctx.session(), plan).await;
+                ctx.retire(result);
+            }
             Plan::EmptyQuery => {
                 ctx.retire(Ok(ExecuteResponse::EmptyQuery));
             }
@@ -371,6 +379,20 @@ impl Coordinator {
                 let result = self.sequence_alter_secret(ctx.session(), plan).await;
                 ctx.retire(result);
             }
+            Plan::AlterSink(plan::AlterSinkPlan {
+                id,
+                reset_progress: Some(timestamp),
+                ..
+            }) => {
+                self.sequence_alter_sink_reset_progress(ctx, id, timestamp);
+            }
+            Plan::AlterSink(plan::AlterSinkPlan {
+                id,
+                set_from: Some(sink),
+                ..
+            }) => {
+                self.sequence_alter_sink_set_from(ctx, id, sink);
+            }
             Plan::AlterSink(plan) => {
                 let result = self.sequence_alter_sink(ctx.session(), plan).await;
                 ctx.retire(result);
@@ -671,7 +693,7 @@ impl Coordinator {
         constants: MirRelationExpr,
     ) -> Result<ExecuteResponse, AdapterError> {
         // Insert can be queued, so we need to re-verify the id exists.
-        let desc = match catalog.try_get_entry(&id) {
+        let desc = match catalog.try_get_live_entry(&id) {
             Some(table) => {
                 table.desc(&catalog.resolve_full_name(table.name(), Some(session.conn_id())))?
             }
@@ -28,11 +28,12 @@ use mz_expr::{
     RowSetFinishing,
 };
 use mz_ore::collections::CollectionExt;
+use mz_ore::str::StrExt;
 use mz_ore::tracing::OpenTelemetryContext;
 use mz_ore::vec::VecExt;
 use mz_ore::{soft_assert, task};
 use mz_repr::adt::jsonb::Jsonb;
-use mz_repr::adt::mz_acl_item::{MzAclItem, PrivilegeMap};
+use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem, PrivilegeMap};
 use mz_repr::explain::{ExplainFormat, ExprHumanizer, UsedIndexes};
 use mz_repr::role_id::RoleId;
 use mz_repr::{Datum, Diff, GlobalId, RelationDesc, RelationType, Row, RowArena, Timestamp};
@@ -67,9 +68,9 @@ use mz_ssh_util::keys::SshKeyPairSet;
 use mz_storage_client::controller::{
     CollectionDescription, DataSource, DataSourceOther, ReadPolicy,
 };
-use mz_storage_types::connections::inline::IntoInlineConnection;
+use mz_storage_types::connections::inline::{IntoInlineConnection, ReferencedConnection};
 use mz_storage_types::controller::StorageError;
-use mz_storage_types::sinks::StorageSinkConnectionBuilder;
+use mz_storage_types::sinks::StorageSinkConnection;
 use mz_transform::dataflow::DataflowMetainfo;
 use mz_transform::optimizer_notices::OptimizerNotice;
 use mz_transform::{EmptyStatisticsOracle, Optimizer, StatisticsOracle};
@@ -97,10 +98,11 @@ use crate::coord::timestamp_selection::{
     TimestampContext, TimestampDetermination, TimestampProvider, TimestampSource,
 };
 use crate::coord::{
-    peek, Coordinator, CreateConnectionValidationReady, ExecuteContext, Message, PeekStage,
-    PeekStageFinish, PeekStageOptimize, PeekStageTimestamp, PeekStageValidate, PendingRead,
-    PendingReadTxn, PendingTxn, PendingTxnResponse, PlanValidity, RealTimeRecencyContext,
-    SinkConnectionReady, TargetCluster, DEFAULT_LOGICAL_COMPACTION_WINDOW_TS,
+    peek, AlterSinkReady, Coordinator, CreateConnectionValidationReady, ExecuteContext, Message,
+    PeekStage, PeekStageFinish, PeekStageOptimize, PeekStageTimestamp, PeekStageValidate,
+    PendingRead, PendingReadTxn, PendingTxn, PendingTxnResponse, PlanValidity,
+    RealTimeRecencyContext, SinkConnectionReady, SinkProgressResetReady, TargetCluster,
+    DEFAULT_LOGICAL_COMPACTION_WINDOW_TS,
 };
 use crate::error::AdapterError;
 use crate::explain::explain_dataflow;
@@ -700,10 +702,6 @@ impl Coordinator {
             ctx
         );
 
-        // Knowing that we're only handling kafka sinks here helps us simplify.
-        let StorageSinkConnectionBuilder::Kafka(connection_builder) =
-            sink.connection_builder.clone();
-
         // Then try to create a placeholder catalog item with an unknown
         // connection. If that fails, we're done, though if the client specified
         // `if_not_exists` we'll tell the client we succeeded.
@@ -714,9 +712,7 @@ impl Coordinator {
         let catalog_sink = catalog::Sink {
             create_sql: sink.create_sql,
             from: sink.from,
-            connection: StorageSinkConnectionState::Pending(StorageSinkConnectionBuilder::Kafka(
-                connection_builder,
-            )),
+            connection: StorageSinkConnectionState::Pending(sink.connection_builder.clone()),
             envelope: sink.envelope,
             with_snapshot,
             resolved_ids,
@@ -916,6 +912,7 @@ impl Coordinator {
                 None
             },
             resolved_ids,
+            column_reads: view.column_reads.clone(),
         };
         ops.push(catalog::Op::CreateItem {
             id: view_id,
@@ -943,6 +940,7 @@ impl Coordinator {
                     expr: view_expr,
                     column_names,
                     cluster_id,
+                    column_reads,
                 },
             replace: _,
             drop_ids,
@@ -1017,6 +1015,7 @@ impl Coordinator {
                 desc: desc.clone(),
                 resolved_ids,
                 cluster_id,
+                column_reads,
             }),
             owner_id: *session.current_role_id(),
         });
@@ -1220,6 +1219,42 @@ impl Coordinator {
         Ok(ExecuteResponse::Comment)
     }
 
+    pub(super) async fn sequence_create_policy(
+        &mut self,
+        session: &Session,
+        plan: plan::CreatePolicyPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let op = catalog::Op::CreatePolicy {
+            object_id: plan.object_id,
+            name: plan.name,
+            predicate: plan.predicate,
+        };
+        self.catalog_transact(Some(session), vec![op]).await?;
+        Ok(ExecuteResponse::CreatedPolicy)
+    }
+
+    pub(super) async fn sequence_drop_policy(
+        &mut self,
+        session: &Session,
+        plan: plan::DropPolicyPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let exists = self
+            .catalog()
+            .state()
+            .get_item_policies(&plan.object_id)
+            .map_or(false, |policies| policies.contains_key(&plan.name));
+        if !exists && plan.if_exists {
+            // A notice was already emitted during planning.
+            return Ok(ExecuteResponse::DroppedPolicy);
+        }
+        let op = catalog::Op::DropPolicy {
+            object_id: plan.object_id,
+            name: plan.name,
+        };
+        self.catalog_transact(Some(session), vec![op]).await?;
+        Ok(ExecuteResponse::DroppedPolicy)
+    }
+
     pub(super) async fn sequence_drop_objects(
         &mut self,
         session: &mut Session,
@@ -1312,6 +1347,18 @@ impl Coordinator {
                 &id,
                 &catalog,
             );
+            if let Some(column_privileges) = self.catalog.state().get_column_privileges(&entry.id())
+            {
+                for privileges in column_privileges.values() {
+                    privilege_check(
+                        privileges,
+                        dropped_roles,
+                        &mut dependent_objects,
+                        &id,
+                        &catalog,
+                    );
+                }
+            }
         }
         for database in self.catalog.databases() {
             let database_id = SystemObjectId::Object(database.id().into());
@@ -1430,6 +1477,7 @@ impl Coordinator {
         }
 
         let mut privilege_revokes = plan.privilege_revokes;
+        let mut column_privilege_revokes = plan.column_privilege_revokes;
 
         // Make sure this stays in sync with the beginning of `rbac::check_plan`.
         let session_catalog = self.catalog().for_session(session);
@@ -1445,9 +1493,15 @@ impl Coordinator {
                 })
                 .map(|(object_id, _)| object_id)
                 .collect();
-            for invalid_revoke in invalid_revokes {
+            let invalid_column_revokes: BTreeSet<_> = column_privilege_revokes
+                .drain_filter_swapping(|(_, _, privilege)| {
+                    !role_membership.contains(&privilege.grantor)
+                })
+                .map(|(object_id, _, _)| SystemObjectId::Object(object_id.into()))
+                .collect();
+            for invalid_revoke in invalid_revokes.union(&invalid_column_revokes) {
                 let object_description =
-                    ErrorMessageObjectDescription::from_id(&invalid_revoke, &session_catalog);
+                    ErrorMessageObjectDescription::from_id(invalid_revoke, &session_catalog);
                 session.add_notice(AdapterNotice::CannotRevoke { object_description });
             }
         }
@@ -1459,6 +1513,17 @@ impl Coordinator {
                 variant: UpdatePrivilegeVariant::Revoke,
             }
         });
+        let column_privilege_revoke_ops =
+            column_privilege_revokes
+                .into_iter()
+                .map(
+                    |(object_id, column, privilege)| catalog::Op::UpdateColumnPrivilege {
+                        object_id,
+                        column: column.as_str().to_string(),
+                        privilege,
+                        variant: UpdatePrivilegeVariant::Revoke,
+                    },
+                );
         let default_privilege_revoke_ops = plan.default_privilege_revokes.into_iter().map(
             |(privilege_object, privilege_acl_item)| catalog::Op::UpdateDefaultPrivilege {
                 privilege_object,
@@ -1474,6 +1539,7 @@ impl Coordinator {
         } = self.sequence_drop_common(session, plan.drop_ids)?;
 
         let ops = privilege_revoke_ops
+            .chain(column_privilege_revoke_ops)
             .chain(default_privilege_revoke_ops)
             .chain(drop_ops.into_iter())
             .collect();
@@ -1708,6 +1774,11 @@ impl Coordinator {
             };
         }
 
+        if let Some(value) = session.vars().custom(&plan.name) {
+            let row = Row::pack_slice(&[Datum::String(value)]);
+            return Ok(Self::send_immediate_rows(vec![row]));
+        }
+
         let variable = session
             .vars()
             .get(Some(self.catalog().system_config()), &plan.name)
@@ -1968,7 +2039,7 @@ impl Coordinator {
                 if let TransactionOps::Writes(writes) = &mut ops {
                     for WriteOp { id, .. } in &mut writes.iter() {
                         // Re-verify this id exists.
-                        let _ = self.catalog().try_get_entry(id).ok_or_else(|| {
+                        let _ = self.catalog().try_get_live_entry(id).ok_or_else(|| {
                             AdapterError::Catalog(catalog::Error {
                                 kind: catalog::ErrorKind::Sql(CatalogError::UnknownItem(
                                     id.to_string(),
@@ -2088,6 +2159,7 @@ impl Coordinator {
             when,
             finishing,
             copy_to,
+            column_reads: _,
         } = plan;
 
         // Two transient allocations. We could reclaim these if we don't use them, potentially.
@@ -2613,6 +2685,12 @@ impl Coordinator {
 
         let (permutation, thinning) = permutation_for_arrangement(&key, typ.arity());
 
+        // Dataflows that read from persist never take the fast path, so this is our chance to
+        // note which indexes would have helped.
+        if !dataflow.source_imports.is_empty() {
+            self.record_index_advice(&dataflow);
+        }
+
         // At this point, `dataflow_plan` contains our best optimized dataflow.
         // We will check the plan to see if there is a fast path to escape full dataflow construction.
         let peek_plan = self.create_peek_plan(
@@ -2670,6 +2748,7 @@ impl Coordinator {
             emit_progress,
             up_to,
             output,
+            column_reads: _,
         } = plan;
 
         let cluster = self
@@ -3497,7 +3576,7 @@ impl Coordinator {
             }
             // All non-constant values must be planned as read-then-writes.
             selection => {
-                let desc_arity = match self.catalog().try_get_entry(&plan.id) {
+                let desc_arity = match self.catalog().try_get_live_entry(&plan.id) {
                     Some(table) => table
                         .desc(
                             &self
@@ -3537,6 +3616,7 @@ impl Coordinator {
                     assignments: BTreeMap::new(),
                     kind: MutationKind::Insert,
                     returning: plan.returning,
+                    column_reads: plan.column_reads,
                 };
 
                 self.sequence_read_then_write(ctx, read_then_write_plan)
@@ -3565,10 +3645,11 @@ impl Coordinator {
             assignments,
             finishing,
             returning,
+            column_reads,
         } = plan;
 
         // Read then writes can be queued, so re-verify the id exists.
-        let desc = match self.catalog().try_get_entry(&id) {
+        let desc = match self.catalog().try_get_live_entry(&id) {
             Some(table) => table
                 .desc(
                     &self
@@ -3655,6 +3736,7 @@ impl Coordinator {
                 when: QueryWhen::Freshest,
                 finishing,
                 copy_to: None,
+                column_reads,
             },
             TargetCluster::Active,
         )
@@ -3991,7 +4073,12 @@ impl Coordinator {
     pub(super) async fn sequence_alter_sink(
         &mut self,
         session: &Session,
-        plan::AlterSinkPlan { id, size }: plan::AlterSinkPlan,
+        plan::AlterSinkPlan {
+            id,
+            size,
+            reset_progress: _,
+            set_from: _,
+        }: plan::AlterSinkPlan,
     ) -> Result<ExecuteResponse, AdapterError> {
         let cluster_config = alter_storage_cluster_config(size);
         if let Some(cluster_config) = cluster_config {
@@ -4008,6 +4095,264 @@ impl Coordinator {
         Ok(ExecuteResponse::AlteredObject(ObjectType::Sink))
     }
 
+    /// Repoints the sink `id` at the relation `sink.from`.
+    ///
+    /// The sink keeps publishing its previous input up to a timestamp it has not yet published,
+    /// publishes the difference between its previous and new input at that timestamp, and
+    /// publishes its new input from there on.
+    ///
+    /// The connection of the sink is rebuilt for the new input off the main coordinator thread,
+    /// which checks that the schemas of the new input are compatible with those the sink has
+    /// published. The alteration completes in [`Self::sequence_alter_sink_set_from_finish`].
+    pub(super) fn sequence_alter_sink_set_from(
+        &mut self,
+        ctx: ExecuteContext,
+        id: GlobalId,
+        sink: plan::Sink,
+    ) {
+        let from = sink.from;
+        let catalog = self.catalog();
+        let entry = catalog.get_entry(&id);
+        let catalog_sink = entry.sink().expect("known to be sink");
+        if let StorageSinkConnectionState::Pending(_) = &catalog_sink.connection {
+            ctx.retire(Err(AdapterError::Unstructured(anyhow!(
+                "cannot change the input of a sink that is still being created"
+            ))));
+            return;
+        }
+        let current_from = catalog.get_entry(&catalog_sink.from);
+        let previous_from = current_from.id();
+        if previous_from == from {
+            ctx.retire(Ok(ExecuteResponse::AlteredObject(ObjectType::Sink)));
+            return;
+        }
+
+        let sink_name = catalog.resolve_full_name(entry.name(), Some(ctx.session().conn_id()));
+        let current_desc = current_from
+            .desc(&catalog.resolve_full_name(current_from.name(), current_from.conn_id()))
+            .expect("sinks can only be built on items with descs");
+        let new_from = catalog.get_entry(&from);
+        let new_from_name =
+            catalog.resolve_full_name(new_from.name(), Some(ctx.session().conn_id()));
+        let new_from_type = new_from.item().typ().to_string();
+        let new_desc = return_if_err!(new_from.desc(&new_from_name), ctx).into_owned();
+
+        // At the switch the sink retracts the contents of its previous input in terms of the
+        // columns of the new input, which it matches up by name. Columns that the previous input
+        // lacks are retracted as `NULL`.
+        for (name, typ) in new_desc.iter() {
+            let matches = match current_desc.get_by_name(name) {
+                Some((_, current_typ)) => current_typ.scalar_type == typ.scalar_type,
+                None => typ.nullable,
+            };
+            if !matches {
+                ctx.retire(Err(AdapterError::Unstructured(anyhow!(
+                    "column {} of {new_from_name} does not match a column of the current input \
+                    of sink {sink_name}",
+                    name.as_str().quoted()
+                ))));
+                return;
+            }
+        }
+
+        if self.get_timeline_context(previous_from).timeline()
+            != self.get_timeline_context(from).timeline()
+        {
+            ctx.retire(Err(AdapterError::Unstructured(anyhow!(
+                "{new_from_name} is not in the same timeline as the current input of sink \
+                {sink_name}"
+            ))));
+            return;
+        }
+
+        let mut description = return_if_err!(self.controller.storage.export(id), ctx)
+            .description
+            .clone();
+        description.sink.from = from;
+        description.sink.from_desc = new_desc;
+        let check = self
+            .controller
+            .storage
+            .check_alter_export(id, &description)
+            .map_err(|e| match e {
+                StorageError::IdentifierMissing(_) => AdapterError::Unstructured(anyhow!(
+                    "{new_from_name} is a {new_from_type}, which cannot be exported as a sink"
+                )),
+                e => AdapterError::Storage(e),
+            });
+        return_if_err!(check, ctx);
+
+        let plan_validity = PlanValidity {
+            transient_revision: self.catalog().transient_revision(),
+            dependency_ids: BTreeSet::from([id, previous_from, from]),
+            cluster_id: None,
+            replica_id: None,
+            role_metadata: ctx.session().role_metadata().clone(),
+        };
+        let referenced_builder = sink.connection_builder.clone();
+        let connection_builder = sink
+            .connection_builder
+            .clone()
+            .into_inline_connection(self.catalog().state());
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        let connection_context = self.connection_context.clone();
+        task::spawn(|| format!("alter_sink_ready:{id}"), async move {
+            let result = mz_storage_client::sink::build_altered_sink_connection(
+                connection_builder,
+                referenced_builder,
+                connection_context,
+            )
+            .await
+            .map_err(|e| AdapterError::from(StorageError::Generic(e)));
+            // It is not an error for the connection to become ready after `internal_cmd_rx` is
+            // dropped.
+            let result = internal_cmd_tx.send(Message::AlterSinkReady(AlterSinkReady {
+                ctx,
+                id,
+                previous_from,
+                sink,
+                plan_validity,
+                result,
+            }));
+            if let Err(e) = result {
+                warn!("internal_cmd_rx dropped before we could send: {:?}", e);
+            }
+        });
+    }
+
+    /// Completes repointing the sink `id` at `sink.from` once its `connection` for the new input
+    /// is ready.
+    pub(crate) async fn sequence_alter_sink_set_from_finish(
+        &mut self,
+        session: &Session,
+        id: GlobalId,
+        sink: plan::Sink,
+        connection: StorageSinkConnection<ReferencedConnection>,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let from = sink.from;
+        let from_entry = self.catalog().get_entry(&from);
+        let from_desc = from_entry
+            .desc(
+                &self
+                    .catalog()
+                    .resolve_full_name(from_entry.name(), from_entry.conn_id()),
+            )?
+            .into_owned();
+
+        let mut description = self.controller.storage.export(id)?.description.clone();
+        description.sink.from = from;
+        description.sink.from_desc = from_desc;
+        description.sink.connection = connection
+            .clone()
+            .into_inline_connection(self.catalog().state());
+        self.controller
+            .storage
+            .check_alter_export(id, &description)?;
+
+        self.catalog_transact(
+            Some(session),
+            vec![catalog::Op::AlterSinkFrom {
+                id,
+                from,
+                create_sql: sink.create_sql,
+                connection,
+            }],
+        )
+        .await?;
+
+        self.controller
+            .storage
+            .alter_export(id, description)
+            .await
+            .expect("altering export after txn must succeed");
+
+        Ok(ExecuteResponse::AlteredObject(ObjectType::Sink))
+    }
+
+    /// Makes the sink `id` resume from `timestamp`, by writing a progress record to its progress
+    /// topic and restarting it.
+    pub(super) fn sequence_alter_sink_reset_progress(
+        &mut self,
+        ctx: ExecuteContext,
+        id: GlobalId,
+        timestamp: Timestamp,
+    ) {
+        let sink = self
+            .catalog()
+            .get_entry(&id)
+            .sink()
+            .expect("known to be sink");
+        let connection = match &sink.connection {
+            StorageSinkConnectionState::Ready(StorageSinkConnection::Kafka(connection)) => {
+                connection
+                    .clone()
+                    .into_inline_connection(self.catalog().state())
+            }
+            StorageSinkConnectionState::Ready(StorageSinkConnection::Postgres(_)) => {
+                ctx.retire(Err(AdapterError::Unstructured(anyhow!(
+                    "cannot reset the progress of a Postgres sink"
+                ))));
+                return;
+            }
+            StorageSinkConnectionState::Pending(_) => {
+                ctx.retire(Err(AdapterError::Unstructured(anyhow!(
+                    "cannot reset the progress of a sink that is still being created"
+                ))));
+                return;
+            }
+        };
+
+        // The progress record is written with the transactional producer of the worker that
+        // writes out the sink, which depends on the number of workers of the sink's replica.
+        let cluster = self.catalog().get_cluster(sink.cluster_id);
+        let mut replicas = cluster.replicas_by_id.values();
+        let peers = match (replicas.next(), replicas.next()) {
+            (Some(replica), None) => {
+                replica.config.location.num_processes() * replica.config.location.workers()
+            }
+            _ => {
+                ctx.retire(Err(AdapterError::Unstructured(anyhow!(
+                    "cannot reset the progress of a sink whose cluster does not have exactly one \
+                    replica"
+                ))));
+                return;
+            }
+        };
+
+        let plan_validity = PlanValidity {
+            transient_revision: self.catalog().transient_revision(),
+            dependency_ids: BTreeSet::from([id]),
+            cluster_id: Some(sink.cluster_id),
+            replica_id: None,
+            role_metadata: ctx.session().role_metadata().clone(),
+        };
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        let connection_context = self.connection_context.clone();
+        task::spawn(|| format!("reset_sink_progress:{id}"), async move {
+            let result = mz_storage_client::sink::reset_kafka_sink_progress(
+                &connection,
+                id,
+                peers,
+                timestamp,
+                &connection_context,
+            )
+            .await
+            .map_err(|e| AdapterError::from(StorageError::Generic(e)));
+            // It is not an error for the progress to be reset after `internal_cmd_rx` is
+            // dropped.
+            let result =
+                internal_cmd_tx.send(Message::SinkProgressResetReady(SinkProgressResetReady {
+                    ctx,
+                    id,
+                    plan_validity,
+                    result,
+                }));
+            if let Err(e) = result {
+                warn!("internal_cmd_rx dropped before we could send: {:?}", e);
+            }
+        });
+    }
+
     pub(super) async fn sequence_alter_source(
         &mut self,
         session: &mut Session,
@@ -4134,18 +4479,27 @@ impl Coordinator {
 
                 referenced_subsources.sort();
 
-                // Remove dropped references from text columns.
+                // Remove dropped references from text columns, truncate behaviors and upsert tables.
                 match &mut create_source_stmt.connection {
                     CreateSourceConnection::Postgres { options, .. } => {
                         options.retain_mut(|option| {
-                            if option.name != PgConfigOptionName::TextColumns {
-                                return true;
-                            }
+                            let is_text_cols = match option.name {
+                                PgConfigOptionName::TextColumns => true,
+                                PgConfigOptionName::TruncateRetract
+                                | PgConfigOptionName::TruncateIgnore
+                                | PgConfigOptionName::UpsertTables => false,
+                                _ => return true,
+                            };
 
-                            // We know this is text_cols
+                            // We know this is text_cols or a list of tables
                             match &mut option.value {
                                 Some(WithOptionValue::Sequence(names)) => {
                                     names.retain(|name| match name {
+                                        WithOptionValue::UnresolvedItemName(table)
+                                            if !is_text_cols =>
+                                        {
+                                            !dropped_references.contains(table)
+                                        }
                                         WithOptionValue::UnresolvedItemName(
                                             column_qualified_reference,
                                         ) => {
@@ -4723,6 +5077,7 @@ impl Coordinator {
         for UpdatePrivilege {
             acl_mode,
             target_id,
+            columns,
             grantor,
         } in update_privileges
         {
@@ -4756,6 +5111,42 @@ impl Coordinator {
                     "GRANTs/REVOKEs on an object type with no privileges",
                 ))?;
 
+            if !columns.is_empty() {
+                let SystemObjectId::Object(ObjectId::Item(object_id)) = target_id else {
+                    coord_bail!("column privileges can only be granted on relations");
+                };
+                let column_privileges = self.catalog().state().get_column_privileges(&object_id);
+                for grantee in &grantees {
+                    self.catalog().ensure_not_system_role(grantee)?;
+                    for column in &columns {
+                        let existing_acl_mode = column_privileges
+                            .and_then(|column_privileges| column_privileges.get(column.as_str()))
+                            .and_then(|privileges| privileges.get_acl_item(grantee, &grantor))
+                            .map(|privilege| privilege.acl_mode)
+                            .unwrap_or_else(AclMode::empty);
+                        let changed = match variant {
+                            UpdatePrivilegeVariant::Grant => !existing_acl_mode.contains(acl_mode),
+                            UpdatePrivilegeVariant::Revoke => {
+                                !existing_acl_mode.intersection(acl_mode).is_empty()
+                            }
+                        };
+                        if changed {
+                            ops.push(catalog::Op::UpdateColumnPrivilege {
+                                object_id,
+                                column: column.as_str().to_string(),
+                                privilege: MzAclItem {
+                                    grantee: *grantee,
+                                    grantor,
+                                    acl_mode,
+                                },
+                                variant,
+                            });
+                        }
+                    }
+                }
+                continue;
+            }
+
             for grantee in &grantees {
                 self.catalog().ensure_not_system_role(grantee)?;
                 let existing_privilege = privileges
@@ -17,7 +17,8 @@ use mz_persist_client::cfg::{PersistParameters, RetryParameters};
 use mz_service::params::GrpcClientParameters;
 use mz_sql::session::vars::SystemVars;
 use mz_storage_types::parameters::{
-    StorageMaxInflightBytesConfig, StorageParameters, UpsertAutoSpillConfig,
+    PgSourceAuthRetryConfig, StorageMaxInflightBytesConfig, StorageParameters,
+    UpsertAutoSpillConfig,
 };
 use mz_tracing::params::TracingParameters;
 
@@ -46,6 +47,10 @@ pub fn storage_config(config: &SystemVars) -> StorageParameters {
             tcp_user_timeout: Some(config.pg_source_tcp_user_timeout()),
         },
         pg_source_snapshot_statement_timeout: config.pg_source_snapshot_statement_timeout(),
+        pg_source_auth_retry_config: PgSourceAuthRetryConfig {
+            max_backoff: config.pg_source_auth_retry_max_backoff(),
+            timeout: config.pg_source_auth_retry_timeout(),
+        },
         keep_n_source_status_history_entries: config.keep_n_source_status_history_entries(),
         keep_n_sink_status_history_entries: config.keep_n_source_status_history_entries(),
         upsert_rocksdb_tuning_config: {
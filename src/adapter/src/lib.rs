@@ -128,6 +128,7 @@ pub use crate::command::{
     AppendWebhookError, AppendWebhookResponse, AppendWebhookValidator, Canceled, ExecuteResponse,
     ExecuteResponseKind, RowsFuture, StartupResponse,
 };
+pub use crate::coord::audit_log_export::AuditLogExportConfig;
 pub use crate::coord::id_bundle::CollectionIdBundle;
 pub use crate::coord::peek::PeekResponseUnary;
 pub use crate::coord::timeline::TimelineContext;
@@ -167,6 +167,7 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::CreatedView
             | ExecuteResponse::CreatedViews
             | ExecuteResponse::CreatedMaterializedView
+            | ExecuteResponse::CreatedPolicy
             | ExecuteResponse::CreatedType
             | ExecuteResponse::Deallocate { .. }
             | ExecuteResponse::DeclaredCursor
@@ -175,6 +176,7 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::DiscardedAll
             | ExecuteResponse::DroppedObject(_)
             | ExecuteResponse::DroppedOwned
+            | ExecuteResponse::DroppedPolicy
             | ExecuteResponse::EmptyQuery
             | ExecuteResponse::GrantedPrivilege
             | ExecuteResponse::GrantedRole
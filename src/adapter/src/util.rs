@@ -30,7 +30,7 @@ use tokio::sync::oneshot;
 
 use crate::catalog::{Catalog, CatalogState};
 use crate::command::{Command, Response};
-use crate::coord::{Message, PendingTxnResponse};
+use crate::coord::{Coordinator, Message, PendingTxnResponse};
 use crate::error::AdapterError;
 use crate::session::{EndTransactionAction, Session};
 use crate::{ExecuteContext, ExecuteResponse};
@@ -238,11 +238,14 @@ pub fn describe(
             }
         }
         _ => {
-            let catalog = &catalog.for_session(session);
-            let (stmt, _) = mz_sql::names::resolve(catalog, stmt)?;
+            let mut catalog = catalog.for_session(session);
+            if let Some(as_of) = Coordinator::resolve_items_as_of(&catalog, session, &stmt) {
+                catalog.set_resolve_items_as_of(as_of);
+            }
+            let (stmt, _) = mz_sql::names::resolve(&catalog, stmt)?;
             Ok(mz_sql::plan::describe(
                 session.pcx(),
-                catalog,
+                &catalog,
                 stmt,
                 param_types,
             )?)
@@ -89,11 +89,13 @@
 //! be in different processes and production is not allowed to specify private
 //! data structures unknown to the reader.
 
+use hmac::{Hmac, Mac};
 use mz_ore::now::EpochMillis;
 use mz_proto::{IntoRustIfSome, ProtoType};
 use mz_stash::objects::{proto, RustType, TryFromProtoError};
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 /// New version variants should be added if fields need to be added, changed, or removed.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
@@ -142,6 +144,98 @@ impl VersionedEvent {
             VersionedEvent::V1(ev) => ev.id,
         }
     }
+
+    /// Returns the time at which the event occurred. All event versions must
+    /// have this field.
+    pub fn occurred_at(&self) -> EpochMillis {
+        match self {
+            VersionedEvent::V1(ev) => ev.occurred_at,
+        }
+    }
+}
+
+/// A link in the tamper-evident hash chain over the audit log.
+pub type ChainHash = [u8; 32];
+
+/// The position in the hash chain over the audit log from which the chain over
+/// the events still in the log continues.
+///
+/// The chain hash of an event is the SHA-256 digest of the chain hash of the
+/// event preceding it, in [`VersionedEvent::sortable_id`] order, followed by
+/// the [`VersionedEvent::serialize`]d event. The first event in the log is
+/// preceded by an all-zero hash. When old events are pruned from the log, the
+/// chain hash of the last pruned event is retained as the anchor, so that the
+/// integrity of the remaining events can still be verified against the chain
+/// hashes previously exported for them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChainAnchor {
+    /// The ID of the last event covered by `hash`, or `None` if no events have
+    /// been pruned.
+    pub last_id: Option<u64>,
+    /// The chain hash of the event with ID `last_id`.
+    pub hash: ChainHash,
+}
+
+impl ChainAnchor {
+    /// Advances the anchor past `event`, which must be the event following the
+    /// one the anchor currently points at.
+    pub fn extend(&mut self, event: &VersionedEvent) {
+        let mut hasher = Sha256::new();
+        hasher.update(self.hash);
+        hasher.update(event.serialize());
+        self.hash = hasher.finalize().into();
+        self.last_id = Some(event.sortable_id());
+    }
+
+    /// Encodes the anchor as `<last id>:<hex hash>`, or as the empty string if
+    /// no events have been pruned.
+    pub fn encode(&self) -> String {
+        match self.last_id {
+            Some(id) => format!("{}:{}", id, hex::encode(self.hash)),
+            None => String::new(),
+        }
+    }
+
+    /// Decodes an anchor previously encoded with [`ChainAnchor::encode`].
+    pub fn decode(s: &str) -> Result<Self, anyhow::Error> {
+        if s.is_empty() {
+            return Ok(ChainAnchor::default());
+        }
+        let (id, hash) = s
+            .split_once(':')
+            .ok_or_else(|| anyhow::anyhow!("invalid audit log chain anchor: {s}"))?;
+        let mut anchor = ChainAnchor {
+            last_id: Some(id.parse()?),
+            hash: [0; 32],
+        };
+        hex::decode_to_slice(hash, &mut anchor.hash)?;
+        Ok(anchor)
+    }
+
+    /// Signs the anchor with `key`, as the hex-encoded HMAC-SHA256 of its
+    /// [`ChainAnchor::encode`]d form.
+    ///
+    /// Exported links in the hash chain are signed, so that anyone who can
+    /// modify the audit log, but does not hold the key, cannot rewrite its
+    /// history along with the exported chain.
+    pub fn sign(&self, key: &[u8]) -> String {
+        hex::encode(self.mac(key).finalize().into_bytes())
+    }
+
+    /// Reports whether `signature` was produced by [`ChainAnchor::sign`] for
+    /// this anchor and `key`.
+    pub fn verify(&self, key: &[u8], signature: &str) -> bool {
+        match hex::decode(signature) {
+            Ok(signature) => self.mac(key).verify_slice(&signature).is_ok(),
+            Err(_) => false,
+        }
+    }
+
+    fn mac(&self, key: &[u8]) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC can take a key of any size");
+        mac.update(self.encode().as_bytes());
+        mac
+    }
 }
 
 impl RustType<proto::audit_log_key::Event> for VersionedEvent {
@@ -219,6 +313,7 @@ pub enum ObjectType {
     Func,
     Index,
     MaterializedView,
+    Policy,
     Role,
     Secret,
     Schema,
@@ -240,6 +335,7 @@ impl ObjectType {
             ObjectType::Func => "Function",
             ObjectType::Index => "Index",
             ObjectType::MaterializedView => "Materialized View",
+            ObjectType::Policy => "Policy",
             ObjectType::Role => "Role",
             ObjectType::Schema => "Schema",
             ObjectType::Secret => "Secret",
@@ -263,6 +359,7 @@ impl RustType<proto::audit_log_event_v1::ObjectType> for ObjectType {
             ObjectType::Func => proto::audit_log_event_v1::ObjectType::Func,
             ObjectType::Index => proto::audit_log_event_v1::ObjectType::Index,
             ObjectType::MaterializedView => proto::audit_log_event_v1::ObjectType::MaterializedView,
+            ObjectType::Policy => proto::audit_log_event_v1::ObjectType::Policy,
             ObjectType::Role => proto::audit_log_event_v1::ObjectType::Role,
             ObjectType::Secret => proto::audit_log_event_v1::ObjectType::Secret,
             ObjectType::Schema => proto::audit_log_event_v1::ObjectType::Schema,
@@ -286,6 +383,7 @@ impl RustType<proto::audit_log_event_v1::ObjectType> for ObjectType {
             proto::audit_log_event_v1::ObjectType::MaterializedView => {
                 Ok(ObjectType::MaterializedView)
             }
+            proto::audit_log_event_v1::ObjectType::Policy => Ok(ObjectType::Policy),
             proto::audit_log_event_v1::ObjectType::Role => Ok(ObjectType::Role),
             proto::audit_log_event_v1::ObjectType::Secret => Ok(ObjectType::Secret),
             proto::audit_log_event_v1::ObjectType::Schema => Ok(ObjectType::Schema),
@@ -319,6 +417,7 @@ pub enum EventDetails {
     RevokeRoleV1(RevokeRoleV1),
     RevokeRoleV2(RevokeRoleV2),
     UpdatePrivilegeV1(UpdatePrivilegeV1),
+    UpdateColumnPrivilegeV1(UpdateColumnPrivilegeV1),
     AlterDefaultPrivilegeV1(AlterDefaultPrivilegeV1),
     UpdateOwnerV1(UpdateOwnerV1),
     IdFullNameV1(IdFullNameV1),
@@ -836,6 +935,39 @@ impl RustType<proto::audit_log_event_v1::UpdatePrivilegeV1> for UpdatePrivilegeV
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct UpdateColumnPrivilegeV1 {
+    pub object_id: String,
+    pub column: String,
+    pub grantee_id: String,
+    pub grantor_id: String,
+    pub privileges: String,
+}
+
+impl RustType<proto::audit_log_event_v1::UpdateColumnPrivilegeV1> for UpdateColumnPrivilegeV1 {
+    fn into_proto(&self) -> proto::audit_log_event_v1::UpdateColumnPrivilegeV1 {
+        proto::audit_log_event_v1::UpdateColumnPrivilegeV1 {
+            object_id: self.object_id.to_string(),
+            column: self.column.to_string(),
+            grantee_id: self.grantee_id.to_string(),
+            grantor_id: self.grantor_id.to_string(),
+            privileges: self.privileges.to_string(),
+        }
+    }
+
+    fn from_proto(
+        proto: proto::audit_log_event_v1::UpdateColumnPrivilegeV1,
+    ) -> Result<Self, TryFromProtoError> {
+        Ok(UpdateColumnPrivilegeV1 {
+            object_id: proto.object_id,
+            column: proto.column,
+            grantee_id: proto.grantee_id,
+            grantor_id: proto.grantor_id,
+            privileges: proto.privileges,
+        })
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
 pub struct AlterDefaultPrivilegeV1 {
     pub role_id: String,
@@ -1004,6 +1136,9 @@ impl EventDetails {
             EventDetails::RevokeRoleV1(v) => serde_json::to_value(v).expect("must serialize"),
             EventDetails::RevokeRoleV2(v) => serde_json::to_value(v).expect("must serialize"),
             EventDetails::UpdatePrivilegeV1(v) => serde_json::to_value(v).expect("must serialize"),
+            EventDetails::UpdateColumnPrivilegeV1(v) => {
+                serde_json::to_value(v).expect("must serialize")
+            }
             EventDetails::AlterDefaultPrivilegeV1(v) => {
                 serde_json::to_value(v).expect("must serialize")
             }
@@ -1033,6 +1168,9 @@ impl RustType<proto::audit_log_event_v1::Details> for EventDetails {
             EventDetails::RevokeRoleV1(details) => RevokeRoleV1(details.into_proto()),
             EventDetails::RevokeRoleV2(details) => RevokeRoleV2(details.into_proto()),
             EventDetails::UpdatePrivilegeV1(details) => UpdatePrivilegeV1(details.into_proto()),
+            EventDetails::UpdateColumnPrivilegeV1(details) => {
+                UpdateColumnPrivilegeV1(details.into_proto())
+            }
             EventDetails::AlterDefaultPrivilegeV1(details) => {
                 AlterDefaultPrivilegeV1(details.into_proto())
             }
@@ -1073,6 +1211,9 @@ impl RustType<proto::audit_log_event_v1::Details> for EventDetails {
             RevokeRoleV1(details) => Ok(EventDetails::RevokeRoleV1(details.into_rust()?)),
             RevokeRoleV2(details) => Ok(EventDetails::RevokeRoleV2(details.into_rust()?)),
             UpdatePrivilegeV1(details) => Ok(EventDetails::UpdatePrivilegeV1(details.into_rust()?)),
+            UpdateColumnPrivilegeV1(details) => {
+                Ok(EventDetails::UpdateColumnPrivilegeV1(details.into_rust()?))
+            }
             AlterDefaultPrivilegeV1(details) => {
                 Ok(EventDetails::AlterDefaultPrivilegeV1(details.into_rust()?))
             }
@@ -1277,7 +1418,7 @@ impl RustType<proto::storage_usage_key::Usage> for VersionedStorageUsage {
 #[cfg(test)]
 mod tests {
     use super::{
-        EventDetails, EventType, EventV1, IdNameV1, ObjectType, VersionedEvent,
+        ChainAnchor, EventDetails, EventType, EventV1, IdNameV1, ObjectType, VersionedEvent,
         VersionedStorageUsage,
     };
     use mz_stash::objects::RustType;
@@ -1317,6 +1458,58 @@ mod tests {
         Ok(())
     }
 
+    #[mz_ore::test]
+    fn test_chain_anchor() {
+        let event = |id, name: &str| {
+            VersionedEvent::new(
+                id,
+                EventType::Create,
+                ObjectType::Cluster,
+                EventDetails::IdNameV1(IdNameV1 {
+                    id: format!("u{id}"),
+                    name: name.into(),
+                }),
+                None,
+                id,
+            )
+        };
+        let chain = |events: &[VersionedEvent]| {
+            let mut anchor = ChainAnchor::default();
+            for event in events {
+                anchor.extend(event);
+            }
+            anchor
+        };
+
+        let events = vec![event(1, "a"), event(2, "b"), event(3, "c")];
+        let anchor = chain(&events);
+        assert_eq!(anchor.last_id, Some(3));
+
+        // Continuing from an anchor yields the same chain.
+        let mut pruned = chain(&events[..1]);
+        for event in &events[1..] {
+            pruned.extend(event);
+        }
+        assert_eq!(pruned, anchor);
+
+        // Tampering with any event changes the chain.
+        let tampered = vec![event(1, "a"), event(2, "x"), event(3, "c")];
+        assert_ne!(chain(&tampered), anchor);
+
+        assert_eq!(ChainAnchor::decode(&anchor.encode()).unwrap(), anchor);
+        assert_eq!(
+            ChainAnchor::decode(&ChainAnchor::default().encode()).unwrap(),
+            ChainAnchor::default()
+        );
+        assert!(ChainAnchor::decode("1:zz").is_err());
+
+        let signature = anchor.sign(b"key");
+        assert!(anchor.verify(b"key", &signature));
+        assert!(!anchor.verify(b"other key", &signature));
+        assert!(!chain(&tampered).verify(b"key", &signature));
+        assert!(!anchor.verify(b"key", "zz"));
+    }
+
     proptest! {
         #[mz_ore::test]
         #[cfg_attr(miri, ignore)] // slow
@@ -2068,6 +2068,16 @@ WHERE
     mz_sources.id NOT LIKE 's%'",
 };
 
+pub const MZ_POSTGRES_SOURCE_SCHEMA_DRIFT: BuiltinView = BuiltinView {
+    name: "mz_postgres_source_schema_drift",
+    schema: MZ_INTERNAL_SCHEMA,
+    sql: "CREATE VIEW mz_internal.mz_postgres_source_schema_drift AS
+SELECT s.id, c.column_name
+FROM
+    mz_internal.mz_source_statuses AS s,
+    jsonb_array_elements_text(s.details->'ignored_columns') AS c (column_name)",
+};
+
 pub static MZ_SINK_STATUS_HISTORY: Lazy<BuiltinSource> = Lazy::new(|| BuiltinSource {
     name: "mz_sink_status_history",
     schema: MZ_INTERNAL_SCHEMA,
@@ -2223,6 +2233,31 @@ pub static MZ_SESSIONS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     is_retained_metrics_object: false,
 });
 
+pub static MZ_INDEX_ADVICE: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_index_advice",
+    schema: MZ_INTERNAL_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("object_id", ScalarType::String.nullable(false))
+        .with_column(
+            "key",
+            ScalarType::List {
+                element_type: Box::new(ScalarType::String),
+                custom_id: None,
+            }
+            .nullable(false),
+        )
+        .with_column("usage", ScalarType::String.nullable(false))
+        .with_column("query_count", ScalarType::UInt64.nullable(false))
+        .with_column(
+            "last_seen_at",
+            ScalarType::TimestampTz { precision: None }.nullable(false),
+        )
+        .with_column("size_bytes", ScalarType::UInt64.nullable(true))
+        .with_column("estimated_benefit_bytes", ScalarType::UInt64.nullable(true))
+        .with_column("create_sql", ScalarType::String.nullable(false)),
+    is_retained_metrics_object: false,
+});
+
 pub static MZ_DEFAULT_PRIVILEGES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     name: "mz_default_privileges",
     schema: MZ_CATALOG_SCHEMA,
@@ -2254,6 +2289,29 @@ pub static MZ_COMMENTS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     is_retained_metrics_object: false,
 });
 
+pub static MZ_POLICIES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_policies",
+    schema: MZ_INTERNAL_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("object_id", ScalarType::String.nullable(false))
+        .with_column("name", ScalarType::String.nullable(false))
+        .with_column("predicate", ScalarType::String.nullable(false)),
+    is_retained_metrics_object: false,
+});
+
+pub static MZ_COLUMN_PRIVILEGES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_column_privileges",
+    schema: MZ_INTERNAL_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("object_id", ScalarType::String.nullable(false))
+        .with_column("column_name", ScalarType::String.nullable(false))
+        .with_column(
+            "privileges",
+            ScalarType::Array(Box::new(ScalarType::MzAclItem)).nullable(false),
+        ),
+    is_retained_metrics_object: false,
+});
+
 pub static MZ_WEBHOOKS_SOURCES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     name: "mz_webhook_sources",
     schema: MZ_INTERNAL_SCHEMA,
@@ -2280,7 +2338,9 @@ pub static MZ_SOURCE_STATISTICS: Lazy<BuiltinSource> = Lazy::new(|| BuiltinSourc
         .with_column("bytes_received", ScalarType::UInt64.nullable(false))
         .with_column("envelope_state_bytes", ScalarType::UInt64.nullable(false))
         .with_column("envelope_state_count", ScalarType::UInt64.nullable(false))
-        .with_column("rehydration_latency_ms", ScalarType::UInt64.nullable(true)),
+        .with_column("rehydration_latency_ms", ScalarType::UInt64.nullable(true))
+        .with_column("keys_expired", ScalarType::UInt64.nullable(false))
+        .with_column("records_skipped", ScalarType::UInt64.nullable(false)),
     is_retained_metrics_object: false,
 });
 pub static MZ_SINK_STATISTICS: Lazy<BuiltinSource> = Lazy::new(|| BuiltinSource {
@@ -5137,9 +5197,12 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Table(&MZ_AWS_PRIVATELINK_CONNECTIONS),
         Builtin::Table(&MZ_SUBSCRIPTIONS),
         Builtin::Table(&MZ_SESSIONS),
+        Builtin::Table(&MZ_INDEX_ADVICE),
         Builtin::Table(&MZ_DEFAULT_PRIVILEGES),
         Builtin::Table(&MZ_SYSTEM_PRIVILEGES),
         Builtin::Table(&MZ_COMMENTS),
+        Builtin::Table(&MZ_POLICIES),
+        Builtin::Table(&MZ_COLUMN_PRIVILEGES),
         Builtin::Table(&MZ_WEBHOOKS_SOURCES),
         Builtin::View(&MZ_RELATIONS),
         Builtin::View(&MZ_OBJECTS),
@@ -5266,6 +5329,7 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Source(&MZ_PREPARED_STATEMENT_HISTORY),
         Builtin::Source(&MZ_SESSION_HISTORY),
         Builtin::View(&MZ_SOURCE_STATUSES),
+        Builtin::View(&MZ_POSTGRES_SOURCE_SCHEMA_DRIFT),
         Builtin::Source(&MZ_STORAGE_SHARDS),
         Builtin::Source(&MZ_SOURCE_STATISTICS),
         Builtin::Source(&MZ_SINK_STATISTICS),
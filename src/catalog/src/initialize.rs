@@ -60,6 +60,12 @@ pub const ITEM_COLLECTION: TypedCollection<proto::ItemKey, proto::ItemValue> =
     TypedCollection::new("item");
 pub const COMMENTS_COLLECTION: TypedCollection<proto::CommentKey, proto::CommentValue> =
     TypedCollection::new("comments");
+pub const POLICIES_COLLECTION: TypedCollection<proto::PolicyKey, proto::PolicyValue> =
+    TypedCollection::new("policies");
+pub const COLUMN_PRIVILEGES_COLLECTION: TypedCollection<
+    proto::ColumnPrivilegeKey,
+    proto::ColumnPrivilegeValue,
+> = TypedCollection::new("column_privileges");
 pub const TIMESTAMP_COLLECTION: TypedCollection<proto::TimestampKey, proto::TimestampValue> =
     TypedCollection::new("timestamp");
 pub const SYSTEM_CONFIGURATION_COLLECTION: TypedCollection<
@@ -824,6 +830,8 @@ pub async fn initialize(
         .await?;
     STORAGE_USAGE_COLLECTION.initialize(tx, vec![]).await?;
     COMMENTS_COLLECTION.initialize(tx, vec![]).await?;
+    POLICIES_COLLECTION.initialize(tx, vec![]).await?;
+    COLUMN_PRIVILEGES_COLLECTION.initialize(tx, vec![]).await?;
 
     // Set our initial version.
     CONFIG_COLLECTION
@@ -101,13 +101,14 @@ pub use crate::transaction::Transaction;
 use crate::transaction::TransactionBatch;
 pub use initialize::{
     AUDIT_LOG_COLLECTION, CLUSTER_COLLECTION, CLUSTER_INTROSPECTION_SOURCE_INDEX_COLLECTION,
-    CLUSTER_REPLICA_COLLECTION, COMMENTS_COLLECTION, CONFIG_COLLECTION, DATABASES_COLLECTION,
-    DEFAULT_PRIVILEGES_COLLECTION, ID_ALLOCATOR_COLLECTION, ITEM_COLLECTION, ROLES_COLLECTION,
+    CLUSTER_REPLICA_COLLECTION, COLUMN_PRIVILEGES_COLLECTION, COMMENTS_COLLECTION,
+    CONFIG_COLLECTION, DATABASES_COLLECTION, DEFAULT_PRIVILEGES_COLLECTION,
+    ID_ALLOCATOR_COLLECTION, ITEM_COLLECTION, POLICIES_COLLECTION, ROLES_COLLECTION,
     SCHEMAS_COLLECTION, SETTING_COLLECTION, STORAGE_USAGE_COLLECTION,
     SYSTEM_CONFIGURATION_COLLECTION, SYSTEM_GID_MAPPING_COLLECTION, SYSTEM_PRIVILEGES_COLLECTION,
     TIMESTAMP_COLLECTION,
 };
-use mz_audit_log::{VersionedEvent, VersionedStorageUsage};
+use mz_audit_log::{ChainAnchor, VersionedEvent, VersionedStorageUsage};
 use mz_controller_types::{ClusterId, ReplicaId};
 use mz_ore::collections::CollectionExt;
 use mz_repr::adt::mz_acl_item::MzAclItem;
@@ -132,6 +133,8 @@ const USER_REPLICA_ID_ALLOC_KEY: &str = "replica";
 const SYSTEM_REPLICA_ID_ALLOC_KEY: &str = "system_replica";
 pub const AUDIT_LOG_ID_ALLOC_KEY: &str = "auditlog";
 pub const STORAGE_USAGE_ID_ALLOC_KEY: &str = "storage_usage";
+/// The setting under which the anchor of the audit log hash chain is stored.
+const AUDIT_LOG_CHAIN_ANCHOR_KEY: &str = "audit_log_chain_anchor";
 
 #[derive(Debug)]
 pub enum Error {
@@ -258,6 +261,14 @@ pub trait ReadOnlyDurableCatalogState: Debug + Send {
         &mut self,
     ) -> Result<Vec<(CommentObjectId, Option<usize>, String)>, Error>;
 
+    /// Get all row-level security policies, as tuples of the object they apply
+    /// to, their name, and their predicate.
+    async fn get_policies(&mut self) -> Result<Vec<(GlobalId, String, String)>, Error>;
+
+    /// Get all column privileges, as tuples of the object and column they
+    /// apply to, and the privileges granted on that column.
+    async fn get_column_privileges(&mut self) -> Result<Vec<(GlobalId, String, MzAclItem)>, Error>;
+
     /// Get all timelines and their persisted timestamps.
     // TODO(jkosh44) This should be removed once the timestamp oracle is extracted.
     async fn get_timestamps(&mut self) -> Result<BTreeMap<Timeline, mz_repr::Timestamp>, Error>;
@@ -326,6 +337,21 @@ pub trait DurableCatalogState: ReadOnlyDurableCatalogState {
         boot_ts: mz_repr::Timestamp,
     ) -> Result<Vec<VersionedStorageUsage>, Error>;
 
+    /// Gets all audit log events, in order, and permanently deletes from the
+    /// catalog those that happened more than the retention period ago from
+    /// `boot_ts`.
+    ///
+    /// Only a prefix of the log is ever deleted, and if `retain_from` is set,
+    /// events with an ID of at least `retain_from` are never deleted. The
+    /// returned [`ChainAnchor`] is the point in the audit log's hash chain from
+    /// which the chain over the returned events continues.
+    async fn get_and_prune_audit_logs(
+        &mut self,
+        retention_period: Option<Duration>,
+        boot_ts: mz_repr::Timestamp,
+        retain_from: Option<u64>,
+    ) -> Result<(Vec<VersionedEvent>, ChainAnchor), Error>;
+
     /// Persist system items.
     async fn set_system_items(&mut self, mappings: Vec<SystemObjectMapping>) -> Result<(), Error>;
 
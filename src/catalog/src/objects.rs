@@ -822,6 +822,102 @@ impl RustType<proto::CommentValue> for CommentValue {
     }
 }
 
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord)]
+pub struct PolicyKey {
+    pub(crate) object_id: GlobalId,
+    pub(crate) name: String,
+}
+
+impl RustType<proto::PolicyKey> for PolicyKey {
+    fn into_proto(&self) -> proto::PolicyKey {
+        proto::PolicyKey {
+            object: Some(self.object_id.into_proto()),
+            name: self.name.clone(),
+        }
+    }
+
+    fn from_proto(proto: proto::PolicyKey) -> Result<Self, TryFromProtoError> {
+        Ok(PolicyKey {
+            object_id: proto.object.into_rust_if_some("PolicyKey::object")?,
+            name: proto.name,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Arbitrary)]
+pub struct PolicyValue {
+    pub(crate) predicate: String,
+}
+
+impl RustType<proto::PolicyValue> for PolicyValue {
+    fn into_proto(&self) -> proto::PolicyValue {
+        proto::PolicyValue {
+            predicate: self.predicate.clone(),
+        }
+    }
+
+    fn from_proto(proto: proto::PolicyValue) -> Result<Self, TryFromProtoError> {
+        Ok(PolicyValue {
+            predicate: proto.predicate,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord)]
+pub struct ColumnPrivilegeKey {
+    pub(crate) object_id: GlobalId,
+    pub(crate) column: String,
+    pub(crate) grantee: RoleId,
+    pub(crate) grantor: RoleId,
+}
+
+impl RustType<proto::ColumnPrivilegeKey> for ColumnPrivilegeKey {
+    fn into_proto(&self) -> proto::ColumnPrivilegeKey {
+        proto::ColumnPrivilegeKey {
+            object: Some(self.object_id.into_proto()),
+            column: self.column.clone(),
+            grantee: Some(self.grantee.into_proto()),
+            grantor: Some(self.grantor.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: proto::ColumnPrivilegeKey) -> Result<Self, TryFromProtoError> {
+        Ok(ColumnPrivilegeKey {
+            object_id: proto
+                .object
+                .into_rust_if_some("ColumnPrivilegeKey::object")?,
+            column: proto.column,
+            grantee: proto
+                .grantee
+                .into_rust_if_some("ColumnPrivilegeKey::grantee")?,
+            grantor: proto
+                .grantor
+                .into_rust_if_some("ColumnPrivilegeKey::grantor")?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord)]
+pub struct ColumnPrivilegeValue {
+    pub(crate) acl_mode: AclMode,
+}
+
+impl RustType<proto::ColumnPrivilegeValue> for ColumnPrivilegeValue {
+    fn into_proto(&self) -> proto::ColumnPrivilegeValue {
+        proto::ColumnPrivilegeValue {
+            acl_mode: Some(self.acl_mode.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: proto::ColumnPrivilegeValue) -> Result<Self, TryFromProtoError> {
+        Ok(ColumnPrivilegeValue {
+            acl_mode: proto
+                .acl_mode
+                .into_rust_if_some("ColumnPrivilegeValue::acl_mode")?,
+        })
+    }
+}
+
 #[derive(Clone, PartialOrd, PartialEq, Eq, Ord, Hash, Debug)]
 pub struct RoleKey {
     pub(crate) id: RoleId,
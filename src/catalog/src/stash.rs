@@ -18,7 +18,7 @@ use std::time::Duration;
 use futures::StreamExt;
 use itertools::Itertools;
 
-use mz_audit_log::{VersionedEvent, VersionedStorageUsage};
+use mz_audit_log::{ChainAnchor, VersionedEvent, VersionedStorageUsage};
 use mz_controller_types::{ClusterId, ReplicaId};
 use mz_ore::now::NowFn;
 use mz_ore::result::ResultExt;
@@ -38,12 +38,13 @@ use mz_storage_types::sources::Timeline;
 use crate::initialize::DEPLOY_GENERATION;
 use crate::objects::{
     AuditLogKey, Cluster, ClusterIntrospectionSourceIndexKey, ClusterIntrospectionSourceIndexValue,
-    ClusterKey, ClusterReplica, ClusterReplicaKey, ClusterReplicaValue, ClusterValue, CommentKey,
-    CommentValue, Database, DatabaseKey, DatabaseValue, DefaultPrivilegesKey,
-    DefaultPrivilegesValue, GidMappingKey, GidMappingValue, IdAllocKey, IdAllocValue,
-    ReplicaConfig, Role, RoleKey, RoleValue, Schema, SchemaKey, SchemaValue, StorageUsageKey,
-    SystemObjectDescription, SystemObjectMapping, SystemObjectUniqueIdentifier,
-    SystemPrivilegesKey, SystemPrivilegesValue, TimestampKey, TimestampValue,
+    ClusterKey, ClusterReplica, ClusterReplicaKey, ClusterReplicaValue, ClusterValue,
+    ColumnPrivilegeKey, ColumnPrivilegeValue, CommentKey, CommentValue, Database, DatabaseKey,
+    DatabaseValue, DefaultPrivilegesKey, DefaultPrivilegesValue, GidMappingKey, GidMappingValue,
+    IdAllocKey, IdAllocValue, PolicyKey, PolicyValue, ReplicaConfig, Role, RoleKey, RoleValue,
+    Schema, SchemaKey, SchemaValue, StorageUsageKey, SystemObjectDescription, SystemObjectMapping,
+    SystemObjectUniqueIdentifier, SystemPrivilegesKey, SystemPrivilegesValue, TimestampKey,
+    TimestampValue,
 };
 use crate::transaction::{
     add_new_builtin_cluster_replicas_migration, add_new_builtin_clusters_migration, Transaction,
@@ -51,10 +52,11 @@ use crate::transaction::{
 };
 use crate::{
     initialize, BootstrapArgs, DurableCatalogState, Error, ReadOnlyDurableCatalogState,
-    AUDIT_LOG_COLLECTION, CLUSTER_COLLECTION, CLUSTER_INTROSPECTION_SOURCE_INDEX_COLLECTION,
-    CLUSTER_REPLICA_COLLECTION, COMMENTS_COLLECTION, CONFIG_COLLECTION, DATABASES_COLLECTION,
-    DEFAULT_PRIVILEGES_COLLECTION, ID_ALLOCATOR_COLLECTION, ITEM_COLLECTION, ROLES_COLLECTION,
-    SCHEMAS_COLLECTION, SETTING_COLLECTION, STORAGE_USAGE_COLLECTION,
+    AUDIT_LOG_CHAIN_ANCHOR_KEY, AUDIT_LOG_COLLECTION, CLUSTER_COLLECTION,
+    CLUSTER_INTROSPECTION_SOURCE_INDEX_COLLECTION, CLUSTER_REPLICA_COLLECTION,
+    COLUMN_PRIVILEGES_COLLECTION, COMMENTS_COLLECTION, CONFIG_COLLECTION, DATABASES_COLLECTION,
+    DEFAULT_PRIVILEGES_COLLECTION, ID_ALLOCATOR_COLLECTION, ITEM_COLLECTION, POLICIES_COLLECTION,
+    ROLES_COLLECTION, SCHEMAS_COLLECTION, SETTING_COLLECTION, STORAGE_USAGE_COLLECTION,
     SYSTEM_CONFIGURATION_COLLECTION, SYSTEM_GID_MAPPING_COLLECTION, SYSTEM_PRIVILEGES_COLLECTION,
     TIMESTAMP_COLLECTION,
 };
@@ -442,6 +444,40 @@ impl ReadOnlyDurableCatalogState for Connection {
         Ok(comments)
     }
 
+    #[tracing::instrument(level = "info", skip_all)]
+    async fn get_policies(&mut self) -> Result<Vec<(GlobalId, String, String)>, Error> {
+        let policies = POLICIES_COLLECTION
+            .peek_one(&mut self.stash)
+            .await?
+            .into_iter()
+            .map(RustType::from_proto)
+            .map_ok(|(k, v): (PolicyKey, PolicyValue)| (k.object_id, k.name, v.predicate))
+            .collect::<Result<_, _>>()?;
+
+        Ok(policies)
+    }
+
+    #[tracing::instrument(level = "info", skip_all)]
+    async fn get_column_privileges(&mut self) -> Result<Vec<(GlobalId, String, MzAclItem)>, Error> {
+        Ok(COLUMN_PRIVILEGES_COLLECTION
+            .peek_one(&mut self.stash)
+            .await?
+            .into_iter()
+            .map(RustType::from_proto)
+            .map_ok(|(k, v): (ColumnPrivilegeKey, ColumnPrivilegeValue)| {
+                (
+                    k.object_id,
+                    k.column,
+                    MzAclItem {
+                        grantee: k.grantee,
+                        grantor: k.grantor,
+                        acl_mode: v.acl_mode,
+                    },
+                )
+            })
+            .collect::<Result<_, _>>()?)
+    }
+
     #[tracing::instrument(level = "info", skip_all)]
     async fn get_timestamps(&mut self) -> Result<BTreeMap<Timeline, Timestamp>, Error> {
         let entries = TIMESTAMP_COLLECTION.peek_one(&mut self.stash).await?;
@@ -512,6 +548,8 @@ impl DurableCatalogState for Connection {
             roles,
             items,
             comments,
+            policies,
+            column_privileges,
             clusters,
             cluster_replicas,
             introspection_sources,
@@ -534,6 +572,8 @@ impl DurableCatalogState for Connection {
                         tx.peek_one(tx.collection(ROLES_COLLECTION.name()).await?),
                         tx.peek_one(tx.collection(ITEM_COLLECTION.name()).await?),
                         tx.peek_one(tx.collection(COMMENTS_COLLECTION.name()).await?),
+                        tx.peek_one(tx.collection(POLICIES_COLLECTION.name()).await?),
+                        tx.peek_one(tx.collection(COLUMN_PRIVILEGES_COLLECTION.name()).await?),
                         tx.peek_one(tx.collection(CLUSTER_COLLECTION.name()).await?),
                         tx.peek_one(tx.collection(CLUSTER_REPLICA_COLLECTION.name()).await?),
                         tx.peek_one(
@@ -563,6 +603,8 @@ impl DurableCatalogState for Connection {
             roles,
             items,
             comments,
+            policies,
+            column_privileges,
             clusters,
             cluster_replicas,
             introspection_sources,
@@ -624,6 +666,14 @@ impl DurableCatalogState for Connection {
                     add_batch(&tx, &mut batches, &SCHEMAS_COLLECTION, &txn_batch.schemas).await?;
                     add_batch(&tx, &mut batches, &ITEM_COLLECTION, &txn_batch.items).await?;
                     add_batch(&tx, &mut batches, &COMMENTS_COLLECTION, &txn_batch.comments).await?;
+                    add_batch(&tx, &mut batches, &POLICIES_COLLECTION, &txn_batch.policies).await?;
+                    add_batch(
+                        &tx,
+                        &mut batches,
+                        &COLUMN_PRIVILEGES_COLLECTION,
+                        &txn_batch.column_privileges,
+                    )
+                    .await?;
                     add_batch(&tx, &mut batches, &ROLES_COLLECTION, &txn_batch.roles).await?;
                     add_batch(&tx, &mut batches, &CLUSTER_COLLECTION, &txn_batch.clusters).await?;
                     add_batch(
@@ -764,6 +814,92 @@ impl DurableCatalogState for Connection {
             .await?)
     }
 
+    async fn get_and_prune_audit_logs(
+        &mut self,
+        retention_period: Option<Duration>,
+        boot_ts: Timestamp,
+        retain_from: Option<u64>,
+    ) -> Result<(Vec<VersionedEvent>, ChainAnchor), Error> {
+        // If no retention period is set, set the cutoff to MIN so nothing is
+        // removed.
+        let cutoff_ts = match retention_period {
+            None => u128::MIN,
+            Some(period) => u128::from(boot_ts).saturating_sub(period.as_millis()),
+        };
+        let is_read_only = self.is_read_only();
+        Ok(self
+            .stash
+            .with_transaction(move |tx| {
+                Box::pin(async move {
+                    let collection = AUDIT_LOG_COLLECTION.from_tx(&tx).await?;
+                    let settings = SETTING_COLLECTION.from_tx(&tx).await?;
+                    let anchor_key = proto::SettingKey {
+                        name: AUDIT_LOG_CHAIN_ANCHOR_KEY.to_string(),
+                    };
+                    let prev_anchor = tx.peek_key_one(settings, &anchor_key).await?;
+                    let mut anchor = match &prev_anchor {
+                        Some(value) => ChainAnchor::decode(&value.value)
+                            .map_err(|e| StashError::from(e.to_string()))?,
+                        None => ChainAnchor::default(),
+                    };
+
+                    let mut rows = tx
+                        .peek_one(collection)
+                        .await?
+                        .into_keys()
+                        .map(|ev| {
+                            let event: AuditLogKey = ev.clone().into_rust()?;
+                            Ok((ev, event.event))
+                        })
+                        .collect::<Result<Vec<_>, StashError>>()?;
+                    rows.sort_by_key(|(_, event)| event.sortable_id());
+
+                    let mut events = Vec::with_capacity(rows.len());
+                    let mut batch = collection.make_batch_tx(&tx).await?;
+                    let mut pruned = false;
+                    for (ev, event) in rows {
+                        // Only prune a prefix of the log, so that the retained
+                        // events form an unbroken continuation of the chain.
+                        if retention_period.is_some()
+                            && events.is_empty()
+                            && u128::from(event.occurred_at()) < cutoff_ts
+                            && retain_from.map_or(true, |id| event.sortable_id() < id)
+                        {
+                            anchor.extend(&event);
+                            collection.append_to_batch(&mut batch, &ev, &(), -1);
+                            pruned = true;
+                        } else {
+                            events.push(event);
+                        }
+                    }
+                    // Delete things only if something was pruned (otherwise
+                    // opening readonly catalogs can fail).
+                    if pruned && !is_read_only {
+                        let mut settings_batch = settings.make_batch_tx(&tx).await?;
+                        if let Some(prev_anchor) = &prev_anchor {
+                            settings.append_to_batch(
+                                &mut settings_batch,
+                                &anchor_key,
+                                prev_anchor,
+                                -1,
+                            );
+                        }
+                        settings.append_to_batch(
+                            &mut settings_batch,
+                            &anchor_key,
+                            &proto::SettingValue {
+                                value: anchor.encode(),
+                            },
+                            1,
+                        );
+                        tx.append(vec![batch, settings_batch]).await?;
+                    }
+                    Ok((events, anchor))
+                })
+            })
+            .await?)
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     async fn set_system_items(&mut self, mappings: Vec<SystemObjectMapping>) -> Result<(), Error> {
         if mappings.is_empty() {
@@ -918,11 +1054,13 @@ pub const ALL_COLLECTIONS: &[&str] = &[
     CLUSTER_COLLECTION.name(),
     CLUSTER_INTROSPECTION_SOURCE_INDEX_COLLECTION.name(),
     CLUSTER_REPLICA_COLLECTION.name(),
+    COLUMN_PRIVILEGES_COLLECTION.name(),
     CONFIG_COLLECTION.name(),
     DATABASES_COLLECTION.name(),
     DEFAULT_PRIVILEGES_COLLECTION.name(),
     ID_ALLOCATOR_COLLECTION.name(),
     ITEM_COLLECTION.name(),
+    POLICIES_COLLECTION.name(),
     ROLES_COLLECTION.name(),
     SCHEMAS_COLLECTION.name(),
     SETTING_COLLECTION.name(),
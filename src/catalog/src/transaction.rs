@@ -10,13 +10,13 @@
 use crate::builtin::{BuiltinLog, BUILTIN_CLUSTERS, BUILTIN_CLUSTER_REPLICAS};
 use crate::objects::{
     AuditLogKey, Cluster, ClusterIntrospectionSourceIndexKey, ClusterIntrospectionSourceIndexValue,
-    ClusterKey, ClusterReplica, ClusterReplicaKey, ClusterReplicaValue, ClusterValue, CommentKey,
-    CommentValue, ConfigKey, ConfigValue, Database, DatabaseKey, DatabaseValue,
-    DefaultPrivilegesKey, DefaultPrivilegesValue, GidMappingKey, GidMappingValue, IdAllocKey,
-    IdAllocValue, Item, ItemKey, ItemValue, ReplicaConfig, Role, RoleKey, RoleValue, Schema,
-    SchemaKey, SchemaValue, ServerConfigurationKey, ServerConfigurationValue, SettingKey,
-    SettingValue, StorageUsageKey, SystemObjectMapping, SystemPrivilegesKey, SystemPrivilegesValue,
-    TimestampKey, TimestampValue,
+    ClusterKey, ClusterReplica, ClusterReplicaKey, ClusterReplicaValue, ClusterValue,
+    ColumnPrivilegeKey, ColumnPrivilegeValue, CommentKey, CommentValue, ConfigKey, ConfigValue,
+    Database, DatabaseKey, DatabaseValue, DefaultPrivilegesKey, DefaultPrivilegesValue,
+    GidMappingKey, GidMappingValue, IdAllocKey, IdAllocValue, Item, ItemKey, ItemValue, PolicyKey,
+    PolicyValue, ReplicaConfig, Role, RoleKey, RoleValue, Schema, SchemaKey, SchemaValue,
+    ServerConfigurationKey, ServerConfigurationValue, SettingKey, SettingValue, StorageUsageKey,
+    SystemObjectMapping, SystemPrivilegesKey, SystemPrivilegesValue, TimestampKey, TimestampValue,
 };
 use crate::objects::{ClusterConfig, ClusterVariant};
 use crate::{
@@ -147,6 +147,8 @@ pub struct Transaction<'a> {
     schemas: TableTransaction<SchemaKey, SchemaValue>,
     items: TableTransaction<ItemKey, ItemValue>,
     comments: TableTransaction<CommentKey, CommentValue>,
+    policies: TableTransaction<PolicyKey, PolicyValue>,
+    column_privileges: TableTransaction<ColumnPrivilegeKey, ColumnPrivilegeValue>,
     roles: TableTransaction<RoleKey, RoleValue>,
     clusters: TableTransaction<ClusterKey, ClusterValue>,
     cluster_replicas: TableTransaction<ClusterReplicaKey, ClusterReplicaValue>,
@@ -174,6 +176,8 @@ impl<'a> Transaction<'a> {
         roles: BTreeMap<proto::RoleKey, proto::RoleValue>,
         items: BTreeMap<proto::ItemKey, proto::ItemValue>,
         comments: BTreeMap<proto::CommentKey, proto::CommentValue>,
+        policies: BTreeMap<proto::PolicyKey, proto::PolicyValue>,
+        column_privileges: BTreeMap<proto::ColumnPrivilegeKey, proto::ColumnPrivilegeValue>,
         clusters: BTreeMap<proto::ClusterKey, proto::ClusterValue>,
         cluster_replicas: BTreeMap<proto::ClusterReplicaKey, proto::ClusterReplicaValue>,
         introspection_sources: BTreeMap<
@@ -204,6 +208,8 @@ impl<'a> Transaction<'a> {
             comments: TableTransaction::new(comments, |a: &CommentValue, b| {
                 a.comment == b.comment
             })?,
+            policies: TableTransaction::new(policies, |_a, _b| false)?,
+            column_privileges: TableTransaction::new(column_privileges, |_a, _b| false)?,
             roles: TableTransaction::new(roles, |a: &RoleValue, b| a.name == b.name)?,
             clusters: TableTransaction::new(clusters, |a: &ClusterValue, b| a.name == b.name)?,
             cluster_replicas: TableTransaction::new(
@@ -1016,6 +1022,70 @@ impl<'a> Transaction<'a> {
         Ok(deleted)
     }
 
+    pub fn insert_policy(
+        &mut self,
+        object_id: GlobalId,
+        name: String,
+        predicate: String,
+    ) -> Result<(), Error> {
+        match self.policies.insert(
+            PolicyKey {
+                object_id,
+                name: name.clone(),
+            },
+            PolicyValue { predicate },
+        ) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(SqlCatalogError::PolicyAlreadyExists(name).into()),
+        }
+    }
+
+    pub fn remove_policy(&mut self, object_id: GlobalId, name: &str) -> Result<(), Error> {
+        let deleted = self
+            .policies
+            .delete(|k, _v| k.object_id == object_id && k.name == name);
+        if deleted.is_empty() {
+            Err(SqlCatalogError::UnknownPolicy(name.to_string()).into())
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn drop_policies(&mut self, object_id: GlobalId) -> Result<Vec<String>, Error> {
+        let deleted = self.policies.delete(|k, _v| k.object_id == object_id);
+        Ok(deleted.into_iter().map(|(k, _v)| k.name).collect())
+    }
+
+    /// Set persisted privileges on `column` of `object_id`.
+    pub fn set_column_privilege(
+        &mut self,
+        object_id: GlobalId,
+        column: String,
+        grantee: RoleId,
+        grantor: RoleId,
+        acl_mode: Option<AclMode>,
+    ) -> Result<(), Error> {
+        self.column_privileges.set(
+            ColumnPrivilegeKey {
+                object_id,
+                column,
+                grantee,
+                grantor,
+            },
+            acl_mode.map(|acl_mode| ColumnPrivilegeValue { acl_mode }),
+        )?;
+        Ok(())
+    }
+
+    /// Removes all privileges on the columns of `object_id`, returning the
+    /// number of privileges removed.
+    pub fn drop_column_privileges(&mut self, object_id: GlobalId) -> Result<usize, Error> {
+        let deleted = self
+            .column_privileges
+            .delete(|k, _v| k.object_id == object_id);
+        Ok(deleted.len())
+    }
+
     /// Upserts persisted system configuration `name` to `value`.
     pub fn upsert_system_config(&mut self, name: &str, value: String) -> Result<(), Error> {
         let key = ServerConfigurationKey {
@@ -1061,6 +1131,8 @@ impl<'a> Transaction<'a> {
             schemas: self.schemas.pending(),
             items: self.items.pending(),
             comments: self.comments.pending(),
+            policies: self.policies.pending(),
+            column_privileges: self.column_privileges.pending(),
             roles: self.roles.pending(),
             clusters: self.clusters.pending(),
             cluster_replicas: self.cluster_replicas.pending(),
@@ -1087,6 +1159,9 @@ pub struct TransactionBatch {
     pub(crate) schemas: Vec<(proto::SchemaKey, proto::SchemaValue, Diff)>,
     pub(crate) items: Vec<(proto::ItemKey, proto::ItemValue, Diff)>,
     pub(crate) comments: Vec<(proto::CommentKey, proto::CommentValue, Diff)>,
+    pub(crate) policies: Vec<(proto::PolicyKey, proto::PolicyValue, Diff)>,
+    pub(crate) column_privileges:
+        Vec<(proto::ColumnPrivilegeKey, proto::ColumnPrivilegeValue, Diff)>,
     pub(crate) roles: Vec<(proto::RoleKey, proto::RoleValue, Diff)>,
     pub(crate) clusters: Vec<(proto::ClusterKey, proto::ClusterValue, Diff)>,
     pub(crate) cluster_replicas: Vec<(proto::ClusterReplicaKey, proto::ClusterReplicaValue, Diff)>,
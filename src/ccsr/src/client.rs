@@ -167,6 +167,33 @@ impl Client {
         Ok(res.id)
     }
 
+    /// Reports whether a schema is compatible with the latest version of the
+    /// specified subject, according to the subject's compatibility level.
+    ///
+    /// A schema is considered compatible with a subject that does not exist.
+    pub async fn is_compatible(
+        &self,
+        subject: &str,
+        schema: &str,
+        schema_type: SchemaType,
+        references: &[SchemaReference],
+    ) -> Result<bool, CompatibilityError> {
+        let req = self.make_request(
+            Method::POST,
+            &["compatibility", "subjects", subject, "versions", "latest"],
+        );
+        let req = req.json(&PublishRequest {
+            schema,
+            schema_type,
+            references,
+        });
+        match send_request::<CompatibilityResponse>(req).await {
+            Ok(res) => Ok(res.is_compatible),
+            Err(UnhandledError::Api { code, .. }) if code == 40401 || code == 40402 => Ok(true),
+            Err(err) => Err(err.into()),
+        }
+    }
+
     /// Lists the names of all subjects that the schema registry is aware of.
     pub async fn list_subjects(&self) -> Result<Vec<String>, ListError> {
         let req = self.make_request(Method::GET, &["subjects"]);
@@ -483,6 +510,55 @@ impl fmt::Display for PublishError {
     }
 }
 
+#[derive(Debug, Deserialize)]
+struct CompatibilityResponse {
+    is_compatible: bool,
+}
+
+/// Errors for compatibility checks.
+#[derive(Debug)]
+pub enum CompatibilityError {
+    /// The provided schema was malformed.
+    InvalidSchema { message: String },
+    /// The underlying HTTP transport failed.
+    Transport(reqwest::Error),
+    /// An internal server error occurred.
+    Server { code: i32, message: String },
+}
+
+impl From<UnhandledError> for CompatibilityError {
+    fn from(err: UnhandledError) -> CompatibilityError {
+        match err {
+            UnhandledError::Transport(err) => CompatibilityError::Transport(err),
+            UnhandledError::Api { code, message } => match code {
+                42201 => CompatibilityError::InvalidSchema { message },
+                _ => CompatibilityError::Server { code, message },
+            },
+        }
+    }
+}
+
+impl Error for CompatibilityError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CompatibilityError::InvalidSchema { .. } | CompatibilityError::Server { .. } => None,
+            CompatibilityError::Transport(err) => Some(err),
+        }
+    }
+}
+
+impl fmt::Display for CompatibilityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CompatibilityError::InvalidSchema { message } => write!(f, "{}", message),
+            CompatibilityError::Transport(err) => write!(f, "transport: {}", err),
+            CompatibilityError::Server { code, message } => {
+                write!(f, "server error {}: {}", code, message)
+            }
+        }
+    }
+}
+
 /// Errors for list operations.
 #[derive(Debug)]
 pub enum ListError {
@@ -870,6 +870,12 @@ pub fn convert_indexes_to_skips(mut indexes: Vec<usize>) -> Vec<usize> {
 /// significant input data). Hierarchical aggregates can be rendered more efficiently if the
 /// input stream is append-only as then we only need to retain the "currently winning" value.
 /// Every hierarchical aggregate needs to supply a corresponding ReductionMonoid implementation.
+///
+/// Ordered-set aggregates, like `percentile_cont` and `mode`, are basic aggregations. Their result
+/// depends on every distinct input of a group, so any partial result over a subset of the group
+/// is as large as the distinct inputs it covers, and evaluating them hierarchically would rewrite
+/// the distinct inputs of the whole group at every level. Instead, they are evaluated once over
+/// the distinct inputs of a group and their counts, which an update re-sorts in full.
 fn reduction_type(func: &AggregateFunc) -> ReductionType {
     match func {
         AggregateFunc::SumInt16
@@ -920,6 +926,9 @@ fn reduction_type(func: &AggregateFunc) -> ReductionType {
         | AggregateFunc::ArrayConcat { .. }
         | AggregateFunc::ListConcat { .. }
         | AggregateFunc::StringAgg { .. }
+        | AggregateFunc::PercentileCont { .. }
+        | AggregateFunc::PercentileDisc { .. }
+        | AggregateFunc::Mode { .. }
         | AggregateFunc::RowNumber { .. }
         | AggregateFunc::Rank { .. }
         | AggregateFunc::DenseRank { .. }
@@ -583,6 +583,14 @@ where
             {
                 let mut row_buf = Row::default();
                 move |_key, source, target| {
+                    if func.is_ordered_set() {
+                        // Ordered-set aggregates take the multiplicity of their distinct
+                        // inputs directly, rather than having them repeated.
+                        let iter = source.iter().map(|(v, w)| (v.iter().next().unwrap(), *w));
+                        row_buf.packer().push(func.eval_ordered_set(iter));
+                        target.push((row_buf.clone(), 1));
+                        return;
+                    }
                     // We respect the multiplicity here (unlike in hierarchical aggregation)
                     // because we don't know that the aggregation method is not sensitive
                     // to the number of records.
@@ -1892,6 +1900,9 @@ pub mod monoids {
             | AggregateFunc::ArrayConcat { .. }
             | AggregateFunc::ListConcat { .. }
             | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. }
             | AggregateFunc::RowNumber { .. }
             | AggregateFunc::Rank { .. }
             | AggregateFunc::DenseRank { .. }
@@ -117,6 +117,17 @@ impl ReplicaLocation {
             }
         }
     }
+
+    /// Returns the number of worker threads in each process specified by this
+    /// replica location.
+    pub fn workers(&self) -> usize {
+        match self {
+            ReplicaLocation::Unmanaged(UnmanagedReplicaLocation { workers, .. }) => *workers,
+            ReplicaLocation::Managed(ManagedReplicaLocation { allocation, .. }) => {
+                allocation.workers
+            }
+        }
+    }
 }
 
 /// The "role" of a cluster, which is currently used to determine the
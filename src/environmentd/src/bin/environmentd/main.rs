@@ -95,6 +95,7 @@ use http::header::HeaderValue;
 use itertools::Itertools;
 use jsonwebtoken::DecodingKey;
 use mz_adapter::catalog::ClusterReplicaSizeMap;
+use mz_adapter::AuditLogExportConfig;
 use mz_aws_secrets_controller::AwsSecretsController;
 use mz_build_info::BuildInfo;
 use mz_cloud_resources::{AwsExternalIdPrefix, CloudResourceController};
@@ -107,6 +108,7 @@ use mz_orchestrator::Orchestrator;
 use mz_orchestrator_kubernetes::{
     KubernetesImagePullPolicy, KubernetesOrchestrator, KubernetesOrchestratorConfig,
 };
+use mz_orchestrator_process::secrets::SecretsKeyring;
 use mz_orchestrator_process::{
     ProcessOrchestrator, ProcessOrchestratorConfig, ProcessOrchestratorTcpProxyConfig,
 };
@@ -129,6 +131,7 @@ use mz_service::secrets::{SecretsControllerKind, SecretsReaderCliArgs};
 use mz_sql::catalog::EnvironmentId;
 use mz_stash::StashFactory;
 use mz_storage_types::connections::ConnectionContext;
+use mz_vault_secrets_controller::{VaultAuth, VaultConfig, VaultSecretsController};
 use once_cell::sync::Lazy;
 use opentelemetry::trace::TraceContextExt;
 use prometheus::IntGauge;
@@ -344,6 +347,14 @@ pub struct Args {
     /// The optional fs group for service's pods' `securityContext`.
     #[clap(long, env = "ORCHESTRATOR_KUBERNETES_SERVICE_FS_GROUP")]
     orchestrator_kubernetes_service_fs_group: Option<i64>,
+    /// The name of a Kubernetes secret whose keys are exposed to services
+    /// created by the Kubernetes orchestrator as environment variables.
+    ///
+    /// Required when using the Vault secrets controller, in which case the
+    /// secret must contain the Vault credentials of clusterd, i.e. either
+    /// `VAULT_ROLE_ID` and `VAULT_SECRET_ID` or `VAULT_TOKEN`.
+    #[clap(long, env = "ORCHESTRATOR_KUBERNETES_SERVICE_ENV_SECRET")]
+    orchestrator_kubernetes_service_env_secret: Option<String>,
     #[clap(long, env = "ORCHESTRATOR_PROCESS_WRAPPER")]
     orchestrator_process_wrapper: Option<String>,
     /// Where the process orchestrator should store secrets.
@@ -354,6 +365,21 @@ pub struct Args {
         required_if_eq("orchestrator", "process")
     )]
     orchestrator_process_secrets_directory: Option<PathBuf>,
+    /// A file containing the master keys with which the process orchestrator
+    /// should encrypt secrets at rest, as hex-encoded 256-bit keys separated
+    /// by commas or whitespace. The first key is used to encrypt secrets; any
+    /// further keys are retired keys that are used only to decrypt secrets
+    /// during a key rotation.
+    ///
+    /// If unspecified, the keys are read from the `MZ_SECRETS_MASTER_KEY`
+    /// environment variable. If that is also unset, secrets are stored
+    /// unencrypted.
+    #[clap(
+        long,
+        env = "ORCHESTRATOR_PROCESS_SECRETS_MASTER_KEY_FILE",
+        value_name = "PATH"
+    )]
+    orchestrator_process_secrets_master_key_file: Option<PathBuf>,
     /// Whether the process orchestrator should handle crashes in child
     /// processes by crashing the parent process.
     #[clap(long, env = "ORCHESTRATOR_PROCESS_PROPAGATE_CRASHES")]
@@ -518,6 +544,26 @@ pub struct Args {
     /// An API key for Segment. Enables export of audit events to Segment.
     #[clap(long, env = "SEGMENT_API_KEY")]
     segment_api_key: Option<String>,
+    /// A file to which to export audit log events, as JSON lines that form a
+    /// tamper-evident hash chain. Events missing from the file are exported
+    /// at startup, after verifying that the audit log still matches the
+    /// events previously exported.
+    #[clap(
+        long,
+        env = "AUDIT_LOG_EXPORT_FILE",
+        requires = "audit-log-export-signing-key-file",
+        value_name = "PATH"
+    )]
+    audit_log_export_file: Option<PathBuf>,
+    /// A file containing the key with which to sign the hash chain exported to
+    /// `--audit-log-export-file`.
+    #[clap(
+        long,
+        env = "AUDIT_LOG_EXPORT_SIGNING_KEY_FILE",
+        requires = "audit-log-export-file",
+        value_name = "PATH"
+    )]
+    audit_log_export_signing_key_file: Option<PathBuf>,
     /// Public IP addresses which the cloud environment has configured for
     /// egress
     #[clap(
@@ -584,6 +630,49 @@ pub struct Args {
     )]
     aws_secrets_controller_tags: Vec<KeyValueArg<String, String>>,
 
+    /// The address of the Vault server used by the Vault secrets controller.
+    ///
+    /// Credentials are read from `--vault-secrets-controller-token-file`, or
+    /// from the `VAULT_ROLE_ID` and `VAULT_SECRET_ID` or `VAULT_TOKEN`
+    /// environment variables. The process orchestrator passes them on to
+    /// clusterd processes, while clusterd pods created by the Kubernetes
+    /// orchestrator read their own credentials from
+    /// `--orchestrator-kubernetes-service-env-secret`.
+    #[clap(
+        long,
+        env = "VAULT_SECRETS_CONTROLLER_ADDRESS",
+        required_if_eq("secrets-controller", "vault")
+    )]
+    vault_secrets_controller_address: Option<String>,
+    /// The mount path of the KV version 2 secrets engine used by the Vault
+    /// secrets controller.
+    #[clap(
+        long,
+        env = "VAULT_SECRETS_CONTROLLER_MOUNT",
+        default_value = mz_vault_secrets_controller::DEFAULT_KV_MOUNT
+    )]
+    vault_secrets_controller_mount: String,
+    /// The path under the mount beneath which the Vault secrets controller
+    /// stores the secrets of this environment, in a directory named after the
+    /// environment ID.
+    #[clap(
+        long,
+        env = "VAULT_SECRETS_CONTROLLER_PREFIX",
+        default_value = "materialize"
+    )]
+    vault_secrets_controller_prefix: String,
+    /// The Vault Enterprise namespace used by the Vault secrets controller.
+    #[clap(long, env = "VAULT_SECRETS_CONTROLLER_NAMESPACE")]
+    vault_secrets_controller_namespace: Option<String>,
+    /// A file containing the token with which the Vault secrets controller
+    /// authenticates, e.g. one maintained by a Vault agent.
+    #[clap(long, env = "VAULT_SECRETS_CONTROLLER_TOKEN_FILE", value_name = "PATH")]
+    vault_secrets_controller_token_file: Option<PathBuf>,
+    /// The mount path of the AppRole auth method used by the Vault secrets
+    /// controller.
+    #[clap(long, env = "VAULT_SECRETS_CONTROLLER_APPROLE_MOUNT")]
+    vault_secrets_controller_approle_mount: Option<String>,
+
     /// The external host name to connect to the HTTP server of this instance.
     ///
     /// Note: Primarily for user facing notices.
@@ -609,6 +698,9 @@ enum OrchestratorKind {
 fn aws_secrets_controller_prefix(env_id: &EnvironmentId) -> String {
     format!("/user-managed/{}/", env_id)
 }
+fn vault_secrets_controller_prefix(prefix: &str, env_id: &EnvironmentId) -> String {
+    format!("{}/{}/", prefix.trim_end_matches('/'), env_id)
+}
 fn aws_secrets_controller_key_alias(env_id: &EnvironmentId) -> String {
     // TODO [Alex Hunt] move this to a shared function that can be imported by the
     // region-controller.
@@ -749,6 +841,26 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
     let cors_allowed_origin = mz_http_util::build_cors_allowed_origin(&allowed_origins);
 
     // Configure controller.
+    let vault_prefix = vault_secrets_controller_prefix(
+        &args.vault_secrets_controller_prefix,
+        &args.environment_id,
+    );
+    let vault_config = match args.secrets_controller {
+        SecretsControllerKind::Vault => Some(VaultConfig {
+            address: args
+                .vault_secrets_controller_address
+                .clone()
+                .expect("clap enforced"),
+            mount: args.vault_secrets_controller_mount.clone(),
+            prefix: vault_prefix.clone(),
+            namespace: args.vault_secrets_controller_namespace.clone(),
+            auth: VaultAuth::from_env(
+                args.vault_secrets_controller_token_file.clone(),
+                args.vault_secrets_controller_approle_mount.clone(),
+            )?,
+        }),
+        _ => None,
+    };
     let (orchestrator, secrets_controller, cloud_resource_controller): (
         Arc<dyn Orchestrator>,
         Arc<dyn SecretsController>,
@@ -761,6 +873,15 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
                       not currently usable with the kubernetes orchestrator"
                 );
             }
+            if matches!(args.secrets_controller, SecretsControllerKind::Vault)
+                && args.orchestrator_kubernetes_service_env_secret.is_none()
+            {
+                bail!(
+                    "the Vault secrets controller requires \
+                      --orchestrator-kubernetes-service-env-secret with the Vault \
+                      credentials of clusterd when using the kubernetes orchestrator"
+                );
+            }
 
             let orchestrator = Arc::new(
                 runtime
@@ -785,6 +906,7 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
                             .orchestrator_kubernetes_ephemeral_volume_class
                             .clone(),
                         service_fs_group: args.orchestrator_kubernetes_service_fs_group.clone(),
+                        service_env_secret: args.orchestrator_kubernetes_service_env_secret,
                     }))
                     .context("creating kubernetes orchestrator")?,
             );
@@ -809,6 +931,10 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
                         )),
                     )
                 }
+                SecretsControllerKind::Vault => Arc::new(
+                    VaultSecretsController::new(vault_config.expect("configured above"))
+                        .context("creating vault secrets controller")?,
+                ),
                 SecretsControllerKind::LocalFile => bail!(
                     "SecretsControllerKind::LocalFile is not compatible with Orchestrator::Kubernetes."
                 ),
@@ -845,6 +971,9 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
                             .orchestrator_process_secrets_directory
                             .clone()
                             .expect("clap enforced"),
+                        secrets_keyring: SecretsKeyring::load(
+                            args.orchestrator_process_secrets_master_key_file.as_deref(),
+                        )?,
                         command_wrapper: args
                             .orchestrator_process_wrapper
                             .map_or(Ok(vec![]), |s| shell_words::split(&s))?,
@@ -879,6 +1008,10 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
                         )),
                     )
                 }
+                SecretsControllerKind::Vault => Arc::new(
+                    VaultSecretsController::new(vault_config.expect("configured above"))
+                        .context("creating vault secrets controller")?,
+                ),
                 SecretsControllerKind::LocalFile => {
                     let sc = Arc::clone(&orchestrator);
                     let sc: Arc<dyn SecretsController> = sc;
@@ -947,11 +1080,26 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
         secrets_args: SecretsReaderCliArgs {
             secrets_reader: args.secrets_controller,
             secrets_reader_local_file_dir: args.orchestrator_process_secrets_directory,
+            secrets_reader_local_file_master_key_file: args
+                .orchestrator_process_secrets_master_key_file,
             secrets_reader_kubernetes_context: Some(args.orchestrator_kubernetes_context),
             secrets_reader_aws_region: Some(
                 args.environment_id.cloud_provider_region().to_string(),
             ),
             secrets_reader_aws_prefix: Some(aws_secrets_controller_prefix(&args.environment_id)),
+            secrets_reader_vault_address: args.vault_secrets_controller_address,
+            secrets_reader_vault_mount: Some(args.vault_secrets_controller_mount),
+            secrets_reader_vault_prefix: Some(vault_prefix),
+            secrets_reader_vault_namespace: args.vault_secrets_controller_namespace,
+            // The token file is a path on the file system of environmentd,
+            // which only clusterd processes of the process orchestrator share.
+            // Pods of the Kubernetes orchestrator instead read their
+            // credentials from the service environment secret.
+            secrets_reader_vault_token_file: match args.orchestrator {
+                OrchestratorKind::Process => args.vault_secrets_controller_token_file,
+                OrchestratorKind::Kubernetes => None,
+            },
+            secrets_reader_vault_approle_mount: args.vault_secrets_controller_approle_mount,
         },
     };
 
@@ -970,6 +1118,22 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
         }
     }
 
+    let audit_log_export = match (
+        args.audit_log_export_file,
+        args.audit_log_export_signing_key_file,
+    ) {
+        (Some(path), Some(signing_key_file)) => {
+            let signing_key = std::fs::read(&signing_key_file).with_context(|| {
+                format!(
+                    "reading audit log export signing key from {}",
+                    signing_key_file.display()
+                )
+            })?;
+            Some(AuditLogExportConfig { path, signing_key })
+        }
+        _ => None,
+    };
+
     emit_boot_diagnostics!(&BUILD_INFO);
     sys::adjust_rlimits();
 
@@ -1016,6 +1180,7 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
                 storage_usage_collection_interval: args.storage_usage_collection_interval_sec,
                 storage_usage_retention_period: args.storage_usage_retention_period,
                 segment_api_key: args.segment_api_key,
+                audit_log_export,
                 egress_ips: args.announce_egress_ip,
                 aws_account_id: args.aws_account_id,
                 aws_privatelink_availability_zones: args.aws_privatelink_availability_zones,
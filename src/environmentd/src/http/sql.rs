@@ -1000,6 +1000,7 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::CreatedView { .. }
         | ExecuteResponse::CreatedViews { .. }
         | ExecuteResponse::CreatedMaterializedView { .. }
+        | ExecuteResponse::CreatedPolicy
         | ExecuteResponse::CreatedType
         | ExecuteResponse::Comment
         | ExecuteResponse::Deleted(_)
@@ -1007,6 +1008,7 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::DiscardedAll
         | ExecuteResponse::DroppedObject(_)
         | ExecuteResponse::DroppedOwned
+        | ExecuteResponse::DroppedPolicy
         | ExecuteResponse::EmptyQuery
         | ExecuteResponse::GrantedPrivilege
         | ExecuteResponse::GrantedRole
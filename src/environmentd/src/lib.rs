@@ -94,6 +94,7 @@ use std::time::Duration;
 use anyhow::{anyhow, bail, Context};
 use mz_adapter::catalog::ClusterReplicaSizeMap;
 use mz_adapter::config::{system_parameter_sync, SystemParameterSyncConfig};
+use mz_adapter::AuditLogExportConfig;
 use mz_build_info::{build_info, BuildInfo};
 use mz_catalog::{initialize, BootstrapArgs};
 use mz_cloud_resources::CloudResourceController;
@@ -189,6 +190,8 @@ pub struct Config {
     pub storage_usage_retention_period: Option<Duration>,
     /// An API key for Segment. Enables export of audit events to Segment.
     pub segment_api_key: Option<String>,
+    /// Configures the export of audit log events to a file, as JSON lines.
+    pub audit_log_export: Option<AuditLogExportConfig>,
     /// IP Addresses which will be used for egress.
     pub egress_ips: Vec<Ipv4Addr>,
     /// 12-digit AWS account id, which will be used to generate an AWS Principal.
@@ -511,6 +514,7 @@ impl Listeners {
             storage_usage_collection_interval: config.storage_usage_collection_interval,
             storage_usage_retention_period: config.storage_usage_retention_period,
             segment_client: segment_client.clone(),
+            audit_log_export: config.audit_log_export,
             egress_ips: config.egress_ips,
             system_parameter_sync_config: system_parameter_sync_config.clone(),
             aws_account_id: config.aws_account_id,
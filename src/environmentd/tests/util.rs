@@ -324,6 +324,7 @@ impl Listeners {
                     suppress_output: false,
                     environment_id: environment_id.to_string(),
                     secrets_dir: data_directory.join("secrets"),
+                    secrets_keyring: None,
                     command_wrapper: vec![],
                     propagate_crashes: config.propagate_crashes,
                     tcp_proxy: None,
@@ -426,9 +427,16 @@ impl Listeners {
                         secrets_args: mz_service::secrets::SecretsReaderCliArgs {
                             secrets_reader: mz_service::secrets::SecretsControllerKind::LocalFile,
                             secrets_reader_local_file_dir: Some(data_directory.join("secrets")),
+                            secrets_reader_local_file_master_key_file: None,
                             secrets_reader_kubernetes_context: None,
                             secrets_reader_aws_region: None,
                             secrets_reader_aws_prefix: None,
+                            secrets_reader_vault_address: None,
+                            secrets_reader_vault_mount: None,
+                            secrets_reader_vault_prefix: None,
+                            secrets_reader_vault_namespace: None,
+                            secrets_reader_vault_token_file: None,
+                            secrets_reader_vault_approle_mount: None,
                         },
                     },
                     secrets_controller,
@@ -453,6 +461,7 @@ impl Listeners {
                     storage_usage_collection_interval: config.storage_usage_collection_interval,
                     storage_usage_retention_period: config.storage_usage_retention_period,
                     segment_api_key: None,
+                    audit_log_export: None,
                     egress_ips: vec![],
                     aws_account_id: None,
                     aws_privatelink_availability_zones: None,
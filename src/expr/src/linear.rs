@@ -211,8 +211,16 @@ impl MapFilterProject {
         // Stable sort predicates by position at which they take effect.
         // We put literal errors at the end as a stop-gap to avoid erroring
         // before we are able to evaluate any predicates that might prevent it.
-        self.predicates
-            .sort_by_key(|(position, predicate)| (predicate.is_literal_err(), *position));
+        // Row-level security predicates go first among the predicates that
+        // take effect at the same position, so that no other predicate can
+        // observe (or error on) a row that they reject.
+        self.predicates.sort_by_key(|(position, predicate)| {
+            (
+                predicate.is_literal_err(),
+                *position,
+                !predicate.contains_security_barrier(),
+            )
+        });
         self
     }
 
@@ -1426,7 +1434,7 @@ pub mod util {
 }
 
 pub mod plan {
-    use std::collections::BTreeMap;
+    use std::collections::{BTreeMap, BTreeSet};
     use std::iter;
 
     use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
@@ -1719,6 +1727,44 @@ pub mod plan {
                 && self.upper_bounds.is_empty()
         }
 
+        /// Returns the input columns that are required to evaluate this plan.
+        ///
+        /// In addition to the columns demanded by the non-temporal operator, this
+        /// includes the support of the temporal bounds, which are evaluated
+        /// against the same datums.
+        pub fn demand(&self) -> BTreeSet<usize> {
+            let mfp = &self.mfp.mfp;
+            let mut demanded = BTreeSet::new();
+            for (_index, pred) in mfp.predicates.iter() {
+                demanded.extend(pred.support());
+            }
+            demanded.extend(mfp.projection.iter().cloned());
+            for bound in self.lower_bounds.iter().chain(self.upper_bounds.iter()) {
+                demanded.extend(bound.support());
+            }
+            for index in (0..mfp.expressions.len()).rev() {
+                if demanded.contains(&(mfp.input_arity + index)) {
+                    demanded.extend(mfp.expressions[index].support());
+                }
+            }
+            demanded.retain(|col| col < &mfp.input_arity);
+            demanded
+        }
+
+        /// Returns the predicates that evaluation of this plan starts with, i.e. those that
+        /// only read input columns and come before any map expression.
+        ///
+        /// If one of these predicates evaluates to anything other than true, while all those
+        /// before it evaluate to true, the plan filters out its input. Callers can use this to
+        /// skip work for inputs that the plan would filter out anyway.
+        pub fn leading_predicates(&self) -> impl Iterator<Item = &MirScalarExpr> {
+            let mfp = &self.mfp.mfp;
+            mfp.predicates
+                .iter()
+                .take_while(|(support, _)| *support <= mfp.input_arity)
+                .map(|(_, predicate)| predicate)
+        }
+
         /// Returns `self`, and leaves behind an identity operator that acts on its output.
         pub fn take(&mut self) -> Self {
             let mut identity = Self {
@@ -1886,8 +1932,10 @@ pub mod plan {
 #[cfg(test)]
 mod tests {
     use mz_proto::protobuf_roundtrip;
+    use mz_repr::ScalarType;
 
     use crate::linear::plan::*;
+    use crate::{BinaryFunc, UnmaterializableFunc};
 
     use super::*;
 
@@ -1902,4 +1950,40 @@ mod tests {
             assert_eq!(actual.unwrap(), expect);
         }
     }
+
+    #[mz_ore::test]
+    fn mfp_plan_demand() {
+        let mz_now = MirScalarExpr::CallUnmaterializable(UnmaterializableFunc::MzNow);
+        let mfp = MapFilterProject::new(5)
+            .filter(vec![
+                MirScalarExpr::column(1).call_binary(
+                    MirScalarExpr::literal_ok(Datum::Int64(5), ScalarType::Int64),
+                    BinaryFunc::Eq,
+                ),
+                mz_now.call_binary(MirScalarExpr::column(3), BinaryFunc::Lte),
+            ])
+            .project(vec![0]);
+        let plan = MfpPlan::create_from(mfp).unwrap();
+        // Column 3 is only referenced by the temporal bound and column 2 and 4
+        // are not referenced at all.
+        assert_eq!(plan.demand(), BTreeSet::from([0, 1, 3]));
+    }
+
+    #[mz_ore::test]
+    fn mfp_plan_leading_predicates() {
+        let five = MirScalarExpr::literal_ok(Datum::Int64(5), ScalarType::Int64);
+        let column_eq_five =
+            |col| MirScalarExpr::column(col).call_binary(five.clone(), BinaryFunc::Eq);
+        let mfp = MapFilterProject::new(3)
+            .filter(vec![column_eq_five(1)])
+            .map(vec![MirScalarExpr::column(0)
+                .call_binary(MirScalarExpr::column(2), BinaryFunc::AddInt64)])
+            .filter(vec![column_eq_five(3), column_eq_five(2)])
+            .project(vec![0]);
+        let plan = MfpPlan::create_from(mfp).unwrap();
+        // The predicate on the mapped column 3 needs the map expression to be
+        // evaluated first, but the one on column 2 doesn't.
+        let leading = plan.leading_predicates().cloned().collect::<Vec<_>>();
+        assert_eq!(leading, vec![column_eq_five(1), column_eq_five(2)]);
+    }
 }
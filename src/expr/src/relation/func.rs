@@ -17,7 +17,7 @@ use chrono::{DateTime, NaiveDateTime, Utc};
 use dec::OrderedDecimal;
 use itertools::Itertools;
 use mz_lowertest::MzReflect;
-use mz_ore::cast::CastFrom;
+use mz_ore::cast::{CastFrom, CastLossy};
 
 use mz_ore::str::separated;
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
@@ -42,7 +42,7 @@ use crate::relation::{
     compare_columns, proto_table_func, ColumnOrder, ProtoAggregateFunc, ProtoTableFunc,
     WindowFrame, WindowFrameBound, WindowFrameUnits,
 };
-use crate::scalar::func::{add_timestamp_months, jsonb_stringify};
+use crate::scalar::func::{add_timestamp_months, jsonb_path_query, jsonb_stringify};
 use crate::EvalError;
 
 include!(concat!(env!("OUT_DIR"), "/mz_expr.relation.func.rs"));
@@ -219,6 +219,131 @@ where
     Datum::String(temp_storage.push_string(s))
 }
 
+/// Sorts the inputs of an ordered-set aggregate by `order_by` and merges equal inputs into runs.
+///
+/// Each input comes with the number of times it occurs, and each run carries the total number of
+/// occurrences of its input, so the work only depends on the number of distinct inputs.
+/// Non-positive counts are ignored, like in other reductions, as they are reported as errors
+/// elsewhere.
+fn ordered_set_runs<'a, I>(inputs: I, order_by: &[ColumnOrder]) -> Vec<(Datum<'a>, usize)>
+where
+    I: IntoIterator<Item = (Datum<'a>, Diff)>,
+{
+    let mut rows: Vec<(Datum, Row, usize)> = inputs
+        .into_iter()
+        .filter_map(|(d, count)| {
+            let count = usize::try_from(count).ok().filter(|count| *count > 0)?;
+            let order_row = Row::pack(d.unwrap_list().iter().skip(1));
+            Some((d, order_row, count))
+        })
+        .collect();
+
+    let mut left_datum_vec = mz_repr::DatumVec::new();
+    let mut right_datum_vec = mz_repr::DatumVec::new();
+    // Break ties on the whole input, so that equal inputs are adjacent.
+    rows.sort_by(|left, right| {
+        let left_datums = left_datum_vec.borrow_with(&left.1);
+        let right_datums = right_datum_vec.borrow_with(&right.1);
+        compare_columns(order_by, &left_datums, &right_datums, || {
+            left.0.cmp(&right.0)
+        })
+    });
+    rows.into_iter()
+        .map(|(d, _order_row, count)| (d, count))
+        .coalesce(|(left, left_count), (right, right_count)| {
+            if left == right {
+                Ok((left, left_count + right_count))
+            } else {
+                Err(((left, left_count), (right, right_count)))
+            }
+        })
+        .collect()
+}
+
+/// Returns the requested fraction and the runs of the non-null values of the input of
+/// `percentile_cont` and `percentile_disc`, in order, along with the number of values.
+///
+/// Returns `None` if the fraction is null, in which case the result of the aggregation is null.
+/// A fraction outside of [0, 1] is rejected when evaluating the input of the aggregation.
+fn percentile_input<'a>(runs: Vec<(Datum<'a>, usize)>) -> Option<(f64, Vec<(Datum<'a>, usize)>)> {
+    let mut fraction = None;
+    let values = runs
+        .into_iter()
+        .filter_map(|(input, count)| {
+            let fraction_value = input.unwrap_list().iter().next().unwrap();
+            let mut fraction_value = fraction_value.unwrap_list().iter();
+            let f = fraction_value.next().unwrap();
+            let value = fraction_value.next().unwrap();
+            // The fraction is a direct argument, which planning ensures is the same for all rows
+            // in the group.
+            fraction.get_or_insert(f);
+            (!value.is_null()).then_some((value, count))
+        })
+        .collect();
+    match fraction {
+        Some(Datum::Float64(f)) => Some((f.into_inner().clamp(0.0, 1.0), values)),
+        _ => None,
+    }
+}
+
+/// Returns the value at `index` in the ordering described by `runs`.
+fn nth_run_value<'a>(runs: &[(Datum<'a>, usize)], mut index: usize) -> Datum<'a> {
+    for (value, count) in runs {
+        if index < *count {
+            return *value;
+        }
+        index -= count;
+    }
+    unreachable!("index out of bounds of the runs")
+}
+
+fn percentile_cont<'a>(runs: Vec<(Datum<'a>, usize)>) -> Datum<'a> {
+    let Some((fraction, values)) = percentile_input(runs) else {
+        return Datum::Null;
+    };
+    let len: usize = values.iter().map(|(_, count)| count).sum();
+    if len == 0 {
+        return Datum::Null;
+    }
+    // Interpolate between the two values that surround the requested position, like PostgreSQL.
+    let position = fraction * f64::cast_lossy(len - 1);
+    let lo = position.floor();
+    let hi = position.ceil();
+    let lo_value = nth_run_value(&values, usize::cast_lossy(lo)).unwrap_float64();
+    let hi_value = nth_run_value(&values, usize::cast_lossy(hi)).unwrap_float64();
+    Datum::from(lo_value + (position - lo) * (hi_value - lo_value))
+}
+
+fn percentile_disc<'a>(runs: Vec<(Datum<'a>, usize)>) -> Datum<'a> {
+    let Some((fraction, values)) = percentile_input(runs) else {
+        return Datum::Null;
+    };
+    let len: usize = values.iter().map(|(_, count)| count).sum();
+    if len == 0 {
+        return Datum::Null;
+    }
+    // Return the first value whose position in the ordering is at least the requested fraction,
+    // like PostgreSQL.
+    let position = (fraction * f64::cast_lossy(len)).ceil();
+    let index = usize::cast_lossy(position).saturating_sub(1);
+    nth_run_value(&values, index)
+}
+
+fn mode<'a>(runs: Vec<(Datum<'a>, usize)>) -> Datum<'a> {
+    // The runs are sorted and merged, so the first run of the maximum length is the one that
+    // comes first in the ordering.
+    let mut mode = Datum::Null;
+    let mut mode_count = 0;
+    for (input, count) in runs {
+        let value = input.unwrap_list().iter().next().unwrap();
+        if !value.is_null() && count > mode_count {
+            mode = value;
+            mode_count = count;
+        }
+    }
+    mode
+}
+
 fn jsonb_agg<'a, I>(datums: I, temp_storage: &'a RowArena, order_by: &[ColumnOrder]) -> Datum<'a>
 where
     I: IntoIterator<Item = Datum<'a>>,
@@ -853,6 +978,22 @@ pub enum AggregateFunc {
     StringAgg {
         order_by: Vec<ColumnOrder>,
     },
+    /// Computes the continuous percentile of `Datum::List`s whose first element is a record of
+    /// the requested fraction and a `Datum::Float64`. The other elements are columns used by
+    /// `order_by`.
+    PercentileCont {
+        order_by: Vec<ColumnOrder>,
+    },
+    /// Computes the discrete percentile of `Datum::List`s whose first element is a record of the
+    /// requested fraction and a value. The other elements are columns used by `order_by`.
+    PercentileDisc {
+        order_by: Vec<ColumnOrder>,
+    },
+    /// Computes the most frequent first element of `Datum::List`s. The other elements are
+    /// columns used by `order_by`, which breaks ties in favor of the first value.
+    Mode {
+        order_by: Vec<ColumnOrder>,
+    },
     RowNumber {
         order_by: Vec<ColumnOrder>,
     },
@@ -954,6 +1095,15 @@ impl Arbitrary for AggregateFunc {
             vec(proptest_any::<ColumnOrder>(), 1..4)
                 .prop_map(|order_by| AggregateFunc::StringAgg { order_by })
                 .boxed(),
+            vec(proptest_any::<ColumnOrder>(), 1..4)
+                .prop_map(|order_by| AggregateFunc::PercentileCont { order_by })
+                .boxed(),
+            vec(proptest_any::<ColumnOrder>(), 1..4)
+                .prop_map(|order_by| AggregateFunc::PercentileDisc { order_by })
+                .boxed(),
+            vec(proptest_any::<ColumnOrder>(), 1..4)
+                .prop_map(|order_by| AggregateFunc::Mode { order_by })
+                .boxed(),
             vec(proptest_any::<ColumnOrder>(), 1..4)
                 .prop_map(|order_by| AggregateFunc::RowNumber { order_by })
                 .boxed(),
@@ -1062,6 +1212,13 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
                 AggregateFunc::ArrayConcat { order_by } => Kind::ArrayConcat(order_by.into_proto()),
                 AggregateFunc::ListConcat { order_by } => Kind::ListConcat(order_by.into_proto()),
                 AggregateFunc::StringAgg { order_by } => Kind::StringAgg(order_by.into_proto()),
+                AggregateFunc::PercentileCont { order_by } => {
+                    Kind::PercentileCont(order_by.into_proto())
+                }
+                AggregateFunc::PercentileDisc { order_by } => {
+                    Kind::PercentileDisc(order_by.into_proto())
+                }
+                AggregateFunc::Mode { order_by } => Kind::Mode(order_by.into_proto()),
                 AggregateFunc::RowNumber { order_by } => Kind::RowNumber(order_by.into_proto()),
                 AggregateFunc::Rank { order_by } => Kind::Rank(order_by.into_proto()),
                 AggregateFunc::DenseRank { order_by } => Kind::DenseRank(order_by.into_proto()),
@@ -1161,6 +1318,15 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
             Kind::StringAgg(order_by) => AggregateFunc::StringAgg {
                 order_by: order_by.into_rust()?,
             },
+            Kind::PercentileCont(order_by) => AggregateFunc::PercentileCont {
+                order_by: order_by.into_rust()?,
+            },
+            Kind::PercentileDisc(order_by) => AggregateFunc::PercentileDisc {
+                order_by: order_by.into_rust()?,
+            },
+            Kind::Mode(order_by) => AggregateFunc::Mode {
+                order_by: order_by.into_rust()?,
+            },
             Kind::RowNumber(order_by) => AggregateFunc::RowNumber {
                 order_by: order_by.into_rust()?,
             },
@@ -1275,6 +1441,11 @@ impl AggregateFunc {
             AggregateFunc::ArrayConcat { order_by } => array_concat(datums, temp_storage, order_by),
             AggregateFunc::ListConcat { order_by } => list_concat(datums, temp_storage, order_by),
             AggregateFunc::StringAgg { order_by } => string_agg(datums, temp_storage, order_by),
+            AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. } => {
+                self.eval_ordered_set(datums.into_iter().map(|d| (d, 1)))
+            }
             AggregateFunc::RowNumber { order_by } => row_number(datums, temp_storage, order_by),
             AggregateFunc::Rank { order_by } => rank(datums, temp_storage, order_by),
             AggregateFunc::DenseRank { order_by } => dense_rank(datums, temp_storage, order_by),
@@ -1295,6 +1466,43 @@ impl AggregateFunc {
         }
     }
 
+    /// Reports whether this is an ordered-set aggregate, which is called with
+    /// `WITHIN GROUP`.
+    pub fn is_ordered_set(&self) -> bool {
+        matches!(
+            self,
+            AggregateFunc::PercentileCont { .. }
+                | AggregateFunc::PercentileDisc { .. }
+                | AggregateFunc::Mode { .. }
+        )
+    }
+
+    /// Evaluates an ordered-set aggregate over its distinct inputs, each with
+    /// the number of times it occurs.
+    ///
+    /// Unlike [`AggregateFunc::eval`], this does not need inputs to be repeated
+    /// for each of their occurrences, so evaluating a group takes time in the
+    /// number of its distinct inputs rather than its size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this is not an ordered-set aggregate.
+    pub fn eval_ordered_set<'a, I>(&self, inputs: I) -> Datum<'a>
+    where
+        I: IntoIterator<Item = (Datum<'a>, Diff)>,
+    {
+        match self {
+            AggregateFunc::PercentileCont { order_by } => {
+                percentile_cont(ordered_set_runs(inputs, order_by))
+            }
+            AggregateFunc::PercentileDisc { order_by } => {
+                percentile_disc(ordered_set_runs(inputs, order_by))
+            }
+            AggregateFunc::Mode { order_by } => mode(ordered_set_runs(inputs, order_by)),
+            _ => panic!("{self} is not an ordered-set aggregate"),
+        }
+    }
+
     /// Returns the output of the aggregation function when applied on an empty
     /// input relation.
     pub fn default(&self) -> Datum<'static> {
@@ -1355,6 +1563,16 @@ impl AggregateFunc {
                 }
             }
             AggregateFunc::StringAgg { .. } => ScalarType::String,
+            AggregateFunc::PercentileCont { .. } => ScalarType::Float64,
+            AggregateFunc::PercentileDisc { .. } => {
+                // The input is a Record of the ordered value and the ORDER BY keys, whose first
+                // field is a Record of the fraction and the value.
+                let fields = input_type.scalar_type.unwrap_record_element_type();
+                fields[0].unwrap_record_element_type()[1].clone()
+            }
+            AggregateFunc::Mode { .. } => {
+                input_type.scalar_type.unwrap_record_element_type()[0].clone()
+            }
             AggregateFunc::RowNumber { .. } => {
                 AggregateFunc::output_type_ranking_window_funcs(&input_type, "?row_number?")
             }
@@ -1451,6 +1669,10 @@ impl AggregateFunc {
                 },
                 _ => unreachable!(),
             },
+            // A null or out-of-range fraction, or a group of only null values, yields null.
+            AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. } => true,
             _ => input_type.nullable,
         };
         scalar_type.nullable(nullable)
@@ -1781,6 +2003,23 @@ impl fmt::Display for AggregateFunc {
             AggregateFunc::StringAgg { order_by } => {
                 write!(f, "string_agg[order_by=[{}]]", separated(", ", order_by))
             }
+            AggregateFunc::PercentileCont { order_by } => {
+                write!(
+                    f,
+                    "percentile_cont[order_by=[{}]]",
+                    separated(", ", order_by)
+                )
+            }
+            AggregateFunc::PercentileDisc { order_by } => {
+                write!(
+                    f,
+                    "percentile_disc[order_by=[{}]]",
+                    separated(", ", order_by)
+                )
+            }
+            AggregateFunc::Mode { order_by } => {
+                write!(f, "mode[order_by=[{}]]", separated(", ", order_by))
+            }
             AggregateFunc::RowNumber { order_by } => {
                 write!(f, "row_number[order_by=[{}]]", separated(", ", order_by))
             }
@@ -2025,6 +2264,8 @@ pub enum TableFunc {
     JsonbArrayElements {
         stringify: bool,
     },
+    /// Returns the items selected by the SQL/JSON path in its second argument.
+    JsonbPathQuery,
     RegexpExtract(AnalyzedRegex),
     CsvExtract(usize),
     GenerateSeriesInt32,
@@ -2066,6 +2307,7 @@ impl RustType<ProtoTableFunc> for TableFunc {
                 TableFunc::JsonbEach { stringify } => Kind::JsonbEach(*stringify),
                 TableFunc::JsonbObjectKeys => Kind::JsonbObjectKeys(()),
                 TableFunc::JsonbArrayElements { stringify } => Kind::JsonbArrayElements(*stringify),
+                TableFunc::JsonbPathQuery => Kind::JsonbPathQuery(()),
                 TableFunc::RegexpExtract(x) => Kind::RegexpExtract(x.into_proto()),
                 TableFunc::CsvExtract(x) => Kind::CsvExtract(x.into_proto()),
                 TableFunc::GenerateSeriesInt32 => Kind::GenerateSeriesInt32(()),
@@ -2103,6 +2345,7 @@ impl RustType<ProtoTableFunc> for TableFunc {
             Kind::JsonbEach(stringify) => TableFunc::JsonbEach { stringify },
             Kind::JsonbObjectKeys(()) => TableFunc::JsonbObjectKeys,
             Kind::JsonbArrayElements(stringify) => TableFunc::JsonbArrayElements { stringify },
+            Kind::JsonbPathQuery(()) => TableFunc::JsonbPathQuery,
             Kind::RegexpExtract(x) => TableFunc::RegexpExtract(x.into_rust()?),
             Kind::CsvExtract(x) => TableFunc::CsvExtract(x.into_rust()?),
             Kind::GenerateSeriesInt32(()) => TableFunc::GenerateSeriesInt32,
@@ -2152,6 +2395,10 @@ impl TableFunc {
                 temp_storage,
                 *stringify,
             ))),
+            TableFunc::JsonbPathQuery => {
+                let rows = jsonb_path_query(datums[0], datums[1], false)?.expect("not silent");
+                Ok(Box::new(rows.into_iter().map(|row| (row, 1))))
+            }
             TableFunc::RegexpExtract(a) => Ok(Box::new(regexp_extract(datums[0], a).into_iter())),
             TableFunc::CsvExtract(n_cols) => Ok(Box::new(csv_extract(datums[0], *n_cols))),
             TableFunc::GenerateSeriesInt32 => {
@@ -2261,6 +2508,11 @@ impl TableFunc {
                 let keys = vec![];
                 (column_types, keys)
             }
+            TableFunc::JsonbPathQuery => {
+                let column_types = vec![ScalarType::Jsonb.nullable(false)];
+                let keys = vec![];
+                (column_types, keys)
+            }
             TableFunc::RegexpExtract(a) => {
                 let column_types = a
                     .capture_groups_iter()
@@ -2341,6 +2593,7 @@ impl TableFunc {
             TableFunc::JsonbEach { .. } => 2,
             TableFunc::JsonbObjectKeys => 1,
             TableFunc::JsonbArrayElements { .. } => 1,
+            TableFunc::JsonbPathQuery => 1,
             TableFunc::RegexpExtract(a) => a.capture_groups_len(),
             TableFunc::CsvExtract(n_cols) => *n_cols,
             TableFunc::GenerateSeriesInt32 => 1,
@@ -2363,6 +2616,7 @@ impl TableFunc {
             | TableFunc::JsonbEach { .. }
             | TableFunc::JsonbObjectKeys
             | TableFunc::JsonbArrayElements { .. }
+            | TableFunc::JsonbPathQuery
             | TableFunc::GenerateSeriesInt32
             | TableFunc::GenerateSeriesInt64
             | TableFunc::GenerateSeriesTimestamp
@@ -2388,6 +2642,7 @@ impl TableFunc {
             TableFunc::JsonbEach { .. } => true,
             TableFunc::JsonbObjectKeys => true,
             TableFunc::JsonbArrayElements { .. } => true,
+            TableFunc::JsonbPathQuery => true,
             TableFunc::RegexpExtract(_) => true,
             TableFunc::CsvExtract(_) => true,
             TableFunc::GenerateSeriesInt32 => true,
@@ -2412,6 +2667,7 @@ impl fmt::Display for TableFunc {
             TableFunc::JsonbEach { .. } => f.write_str("jsonb_each"),
             TableFunc::JsonbObjectKeys => f.write_str("jsonb_object_keys"),
             TableFunc::JsonbArrayElements { .. } => f.write_str("jsonb_array_elements"),
+            TableFunc::JsonbPathQuery => f.write_str("jsonb_path_query"),
             TableFunc::RegexpExtract(a) => write!(f, "regexp_extract({:?}, _)", a.0),
             TableFunc::CsvExtract(n_cols) => write!(f, "csv_extract({}, _)", n_cols),
             TableFunc::GenerateSeriesInt32 => f.write_str("generate_series"),
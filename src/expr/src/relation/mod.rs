@@ -2449,6 +2449,31 @@ impl AggregateExpr {
                 .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)))
                 .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0))),
 
+            // PercentileCont and PercentileDisc take nested records of the fraction and a value
+            AggregateFunc::PercentileCont { .. } | AggregateFunc::PercentileDisc { .. } => {
+                let fraction_value = self
+                    .expr
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+                let fraction = fraction_value
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+                let value =
+                    fraction_value.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+                // The single value is every percentile, unless the fraction is null. Fractions
+                // out of range already produce an error when evaluating the input.
+                fraction.call_is_null().if_then_else(
+                    MirScalarExpr::literal_null(self.typ(input_type).scalar_type),
+                    value,
+                )
+            }
+
+            // Mode takes a single level of records and outputs its only value
+            AggregateFunc::Mode { .. } => self
+                .expr
+                .clone()
+                .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0))),
+
             // ListConcat and ArrayConcat take a single level of records and output a list containing exactly 1 element
             AggregateFunc::ListConcat { .. } | AggregateFunc::ArrayConcat { .. } => self
                 .expr
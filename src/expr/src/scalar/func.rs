@@ -12,9 +12,11 @@
 // which can be found in the LICENSE file at the root of this repository.
 
 use std::borrow::Cow;
+use std::cell::RefCell;
 use std::cmp::{self, Ordering};
 use std::convert::{TryFrom, TryInto};
 use std::ops::Deref;
+use std::rc::Rc;
 use std::str::FromStr;
 use std::{fmt, iter, str};
 
@@ -39,7 +41,8 @@ use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::date::Date;
 use mz_repr::adt::datetime::Timezone;
 use mz_repr::adt::interval::Interval;
-use mz_repr::adt::jsonb::JsonbRef;
+use mz_repr::adt::jsonb::{JsonbPacker, JsonbPathEdit, JsonbRef};
+use mz_repr::adt::jsonpath::{JsonPath, JsonPathError};
 use mz_repr::adt::mz_acl_item::{AclItem, AclMode, MzAclItem};
 use mz_repr::adt::numeric::{self, DecimalLike, Numeric, NumericMaxScale};
 use mz_repr::adt::range::{self, Range, RangeBound, RangeOps};
@@ -1664,6 +1667,12 @@ fn jsonb_concat<'a>(a: Datum<'a>, b: Datum<'a>, temp_storage: &'a RowArena) -> D
     }
 }
 
+fn jsonb_deep_merge<'a>(a: Datum<'a>, b: Datum<'a>, temp_storage: &'a RowArena) -> Datum<'a> {
+    temp_storage.make_datum(|packer| {
+        JsonbPacker::new(packer).pack_deep_merge(JsonbRef::from_datum(a), JsonbRef::from_datum(b))
+    })
+}
+
 fn jsonb_delete_int64<'a>(a: Datum<'a>, b: Datum<'a>, temp_storage: &'a RowArena) -> Datum<'a> {
     let i = b.unwrap_int64();
     match a {
@@ -1701,6 +1710,107 @@ fn jsonb_delete_string<'a>(a: Datum<'a>, b: Datum<'a>, temp_storage: &'a RowAren
     }
 }
 
+/// Parses the text of a SQL/JSON path expression.
+pub fn parse_jsonpath(path: &str) -> Result<JsonPath, EvalError> {
+    path.parse()
+        .map_err(|e: JsonPathError| EvalError::InvalidJsonPath(e.to_string()))
+}
+
+thread_local! {
+    /// The path most recently parsed by `jsonb_path_query`. The path is almost
+    /// always a literal, so this saves parsing it again for every row.
+    static LAST_JSONPATH: RefCell<Option<(String, Rc<JsonPath>)>> = RefCell::new(None);
+}
+
+/// Like [`parse_jsonpath`], but reuses the result of the previous call if it
+/// parsed the same path.
+fn parse_jsonpath_cached(path: &str) -> Result<Rc<JsonPath>, EvalError> {
+    LAST_JSONPATH.with(|last| {
+        let mut last = last.borrow_mut();
+        if let Some((text, parsed)) = &*last {
+            if text == path {
+                return Ok(Rc::clone(parsed));
+            }
+        }
+        let parsed = Rc::new(parse_jsonpath(path)?);
+        *last = Some((path.to_owned(), Rc::clone(&parsed)));
+        Ok(parsed)
+    })
+}
+
+/// Evaluates the SQL/JSON path `b` against the JSON datum `a`.
+///
+/// If `silent` is set, evaluation errors produce `None` rather than an error,
+/// like the `@?` and `@@` operators in PostgreSQL. Syntax errors in the path
+/// are always reported.
+pub fn jsonb_path_query<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    silent: bool,
+) -> Result<Option<Vec<Row>>, EvalError> {
+    let path = parse_jsonpath_cached(b.unwrap_str())?;
+    match path.query(a) {
+        Ok(items) => Ok(Some(
+            items
+                .into_iter()
+                .map(|item| Row::pack_slice(&[item]))
+                .collect(),
+        )),
+        Err(_) if silent => Ok(None),
+        Err(e) => Err(EvalError::JsonPath(e.to_string())),
+    }
+}
+
+fn jsonb_path_exists<'a>(a: Datum<'a>, b: Datum<'a>, silent: bool) -> Result<Datum<'a>, EvalError> {
+    Ok(match jsonb_path_query(a, b, silent)? {
+        Some(items) => (!items.is_empty()).into(),
+        None => Datum::Null,
+    })
+}
+
+fn jsonb_path_match<'a>(a: Datum<'a>, b: Datum<'a>, silent: bool) -> Result<Datum<'a>, EvalError> {
+    let Some(items) = jsonb_path_query(a, b, silent)? else {
+        return Ok(Datum::Null);
+    };
+    match items.as_slice() {
+        [item] => match item.unpack_first() {
+            Datum::True => Ok(Datum::True),
+            Datum::False => Ok(Datum::False),
+            Datum::JsonNull => Ok(Datum::Null),
+            _ if silent => Ok(Datum::Null),
+            _ => Err(EvalError::JsonPath(
+                "single boolean result is expected".into(),
+            )),
+        },
+        _ if silent => Ok(Datum::Null),
+        _ => Err(EvalError::JsonPath(
+            "single boolean result is expected".into(),
+        )),
+    }
+}
+
+fn jsonb_path_query_array<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let items = jsonb_path_query(a, b, false)?.expect("not silent");
+    Ok(temp_storage
+        .make_datum(|packer| packer.push_list(items.iter().map(|item| item.unpack_first()))))
+}
+
+fn jsonb_path_query_first<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let items = jsonb_path_query(a, b, false)?.expect("not silent");
+    Ok(match items.into_iter().next() {
+        Some(item) => temp_storage.push_unary_row(item),
+        None => Datum::Null,
+    })
+}
+
 fn date_part_interval<'a, D>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError>
 where
     D: DecimalLike + Into<Datum<'static>>,
@@ -2236,6 +2346,11 @@ pub enum BinaryFunc {
     JsonbContainsJsonb,
     JsonbDeleteInt64,
     JsonbDeleteString,
+    JsonbPathExists { silent: bool },
+    JsonbPathMatch { silent: bool },
+    JsonbPathQueryArray,
+    JsonbPathQueryFirst,
+    JsonbDeepMerge,
     MapContainsKey,
     MapGetValue,
     MapContainsAllKeys,
@@ -2483,6 +2598,11 @@ impl BinaryFunc {
             BinaryFunc::JsonbContainsJsonb => Ok(jsonb_contains_jsonb(a, b)),
             BinaryFunc::JsonbDeleteInt64 => Ok(jsonb_delete_int64(a, b, temp_storage)),
             BinaryFunc::JsonbDeleteString => Ok(jsonb_delete_string(a, b, temp_storage)),
+            BinaryFunc::JsonbPathExists { silent } => jsonb_path_exists(a, b, *silent),
+            BinaryFunc::JsonbPathMatch { silent } => jsonb_path_match(a, b, *silent),
+            BinaryFunc::JsonbPathQueryArray => jsonb_path_query_array(a, b, temp_storage),
+            BinaryFunc::JsonbPathQueryFirst => jsonb_path_query_first(a, b, temp_storage),
+            BinaryFunc::JsonbDeepMerge => Ok(jsonb_deep_merge(a, b, temp_storage)),
             BinaryFunc::MapContainsKey => Ok(map_contains_key(a, b)),
             BinaryFunc::MapGetValue => Ok(map_get_value(a, b)),
             BinaryFunc::MapContainsAllKeys => Ok(map_contains_all_keys(a, b)),
@@ -2666,6 +2786,14 @@ impl BinaryFunc {
             JsonbContainsString | JsonbContainsJsonb | MapContainsKey | MapContainsAllKeys
             | MapContainsAnyKeys | MapContainsMap => ScalarType::Bool.nullable(in_nullable),
 
+            JsonbPathExists { silent: false } => ScalarType::Bool.nullable(in_nullable),
+            JsonbPathExists { silent: true } | JsonbPathMatch { .. } => {
+                ScalarType::Bool.nullable(true)
+            }
+            JsonbPathQueryArray => ScalarType::Jsonb.nullable(in_nullable),
+            JsonbPathQueryFirst => ScalarType::Jsonb.nullable(true),
+            JsonbDeepMerge => ScalarType::Jsonb.nullable(in_nullable),
+
             MapGetValue => input1_type
                 .scalar_type
                 .unwrap_map_value_type()
@@ -2914,7 +3042,10 @@ impl BinaryFunc {
             | RangeDifference
             | UuidGenerateV5
             | MzAclItemContainsPrivilege
-            | ParseIdent => false,
+            | ParseIdent
+            | JsonbPathExists { silent: false }
+            | JsonbPathQueryArray
+            | JsonbDeepMerge => false,
 
             JsonbGetInt64 { .. }
             | JsonbGetString { .. }
@@ -2922,6 +3053,9 @@ impl BinaryFunc {
             | JsonbConcat
             | JsonbDeleteInt64
             | JsonbDeleteString
+            | JsonbPathExists { silent: true }
+            | JsonbPathMatch { .. }
+            | JsonbPathQueryFirst
             | MapGetValue
             | ListLengthMax { .. }
             | ArrayLength
@@ -3065,7 +3199,9 @@ impl BinaryFunc {
             | RangeAdjacent
             | RangeUnion
             | RangeIntersection
-            | RangeDifference => true,
+            | RangeDifference
+            | JsonbPathExists { silent: true }
+            | JsonbPathMatch { silent: true } => true,
             ToCharTimestamp
             | ToCharTimestampTz
             | AgeTimestamp
@@ -3114,7 +3250,12 @@ impl BinaryFunc {
             | UuidGenerateV5
             | GetByte
             | MzAclItemContainsPrivilege
-            | ParseIdent => false,
+            | ParseIdent
+            | JsonbPathExists { silent: false }
+            | JsonbPathMatch { silent: false }
+            | JsonbPathQueryArray
+            | JsonbPathQueryFirst
+            | JsonbDeepMerge => false,
         }
     }
 
@@ -3288,6 +3429,11 @@ impl BinaryFunc {
             | BinaryFunc::JsonbContainsJsonb
             | BinaryFunc::JsonbDeleteInt64
             | BinaryFunc::JsonbDeleteString
+            | BinaryFunc::JsonbPathExists { .. }
+            | BinaryFunc::JsonbPathMatch { .. }
+            | BinaryFunc::JsonbPathQueryArray
+            | BinaryFunc::JsonbPathQueryFirst
+            | BinaryFunc::JsonbDeepMerge
             | BinaryFunc::MapContainsKey
             | BinaryFunc::MapGetValue
             | BinaryFunc::MapContainsAllKeys
@@ -3488,6 +3634,13 @@ impl fmt::Display for BinaryFunc {
             BinaryFunc::JsonbContainsJsonb | BinaryFunc::MapContainsMap => f.write_str("@>"),
             BinaryFunc::JsonbDeleteInt64 => f.write_str("-"),
             BinaryFunc::JsonbDeleteString => f.write_str("-"),
+            BinaryFunc::JsonbPathExists { silent: true } => f.write_str("@?"),
+            BinaryFunc::JsonbPathExists { silent: false } => f.write_str("jsonb_path_exists"),
+            BinaryFunc::JsonbPathMatch { silent: true } => f.write_str("@@"),
+            BinaryFunc::JsonbPathMatch { silent: false } => f.write_str("jsonb_path_match"),
+            BinaryFunc::JsonbPathQueryArray => f.write_str("jsonb_path_query_array"),
+            BinaryFunc::JsonbPathQueryFirst => f.write_str("jsonb_path_query_first"),
+            BinaryFunc::JsonbDeepMerge => f.write_str("jsonb_deep_merge"),
             BinaryFunc::MapGetValue => f.write_str("->"),
             BinaryFunc::MapContainsAllKeys => f.write_str("?&"),
             BinaryFunc::MapContainsAnyKeys => f.write_str("?|"),
@@ -3699,6 +3852,15 @@ impl Arbitrary for BinaryFunc {
             Just(BinaryFunc::JsonbContainsJsonb).boxed(),
             Just(BinaryFunc::JsonbDeleteInt64).boxed(),
             Just(BinaryFunc::JsonbDeleteString).boxed(),
+            bool::arbitrary()
+                .prop_map(|silent| BinaryFunc::JsonbPathExists { silent })
+                .boxed(),
+            bool::arbitrary()
+                .prop_map(|silent| BinaryFunc::JsonbPathMatch { silent })
+                .boxed(),
+            Just(BinaryFunc::JsonbPathQueryArray).boxed(),
+            Just(BinaryFunc::JsonbPathQueryFirst).boxed(),
+            Just(BinaryFunc::JsonbDeepMerge).boxed(),
             Just(BinaryFunc::MapContainsKey).boxed(),
             Just(BinaryFunc::MapGetValue).boxed(),
             Just(BinaryFunc::MapContainsAllKeys).boxed(),
@@ -3892,6 +4054,11 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
             BinaryFunc::JsonbContainsJsonb => JsonbContainsJsonb(()),
             BinaryFunc::JsonbDeleteInt64 => JsonbDeleteInt64(()),
             BinaryFunc::JsonbDeleteString => JsonbDeleteString(()),
+            BinaryFunc::JsonbPathExists { silent } => JsonbPathExists(*silent),
+            BinaryFunc::JsonbPathMatch { silent } => JsonbPathMatch(*silent),
+            BinaryFunc::JsonbPathQueryArray => JsonbPathQueryArray(()),
+            BinaryFunc::JsonbPathQueryFirst => JsonbPathQueryFirst(()),
+            BinaryFunc::JsonbDeepMerge => JsonbDeepMerge(()),
             BinaryFunc::MapContainsKey => MapContainsKey(()),
             BinaryFunc::MapGetValue => MapGetValue(()),
             BinaryFunc::MapContainsAllKeys => MapContainsAllKeys(()),
@@ -4089,6 +4256,11 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                 JsonbContainsJsonb(()) => Ok(BinaryFunc::JsonbContainsJsonb),
                 JsonbDeleteInt64(()) => Ok(BinaryFunc::JsonbDeleteInt64),
                 JsonbDeleteString(()) => Ok(BinaryFunc::JsonbDeleteString),
+                JsonbPathExists(silent) => Ok(BinaryFunc::JsonbPathExists { silent }),
+                JsonbPathMatch(silent) => Ok(BinaryFunc::JsonbPathMatch { silent }),
+                JsonbPathQueryArray(()) => Ok(BinaryFunc::JsonbPathQueryArray),
+                JsonbPathQueryFirst(()) => Ok(BinaryFunc::JsonbPathQueryFirst),
+                JsonbDeepMerge(()) => Ok(BinaryFunc::JsonbDeepMerge),
                 MapContainsKey(()) => Ok(BinaryFunc::MapContainsKey),
                 MapGetValue(()) => Ok(BinaryFunc::MapGetValue),
                 MapContainsAllKeys(()) => Ok(BinaryFunc::MapContainsAllKeys),
@@ -4307,6 +4479,7 @@ derive_unary!(
     IsNull,
     IsTrue,
     IsFalse,
+    SecurityBarrier,
     BitNotInt16,
     BitNotInt32,
     BitNotInt64,
@@ -4651,6 +4824,7 @@ impl Arbitrary for UnaryFunc {
             IsNull::arbitrary().prop_map_into().boxed(),
             IsTrue::arbitrary().prop_map_into().boxed(),
             IsFalse::arbitrary().prop_map_into().boxed(),
+            SecurityBarrier::arbitrary().prop_map_into().boxed(),
             BitNotInt16::arbitrary().prop_map_into().boxed(),
             BitNotInt32::arbitrary().prop_map_into().boxed(),
             BitNotInt64::arbitrary().prop_map_into().boxed(),
@@ -5034,6 +5208,7 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::IsNull(_) => IsNull(()),
             UnaryFunc::IsTrue(_) => IsTrue(()),
             UnaryFunc::IsFalse(_) => IsFalse(()),
+            UnaryFunc::SecurityBarrier(_) => SecurityBarrier(()),
             UnaryFunc::BitNotInt16(_) => BitNotInt16(()),
             UnaryFunc::BitNotInt32(_) => BitNotInt32(()),
             UnaryFunc::BitNotInt64(_) => BitNotInt64(()),
@@ -5421,6 +5596,7 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 IsNull(()) => Ok(impls::IsNull.into()),
                 IsTrue(()) => Ok(impls::IsTrue.into()),
                 IsFalse(()) => Ok(impls::IsFalse.into()),
+                SecurityBarrier(()) => Ok(impls::SecurityBarrier.into()),
                 BitNotInt16(()) => Ok(impls::BitNotInt16.into()),
                 BitNotInt32(()) => Ok(impls::BitNotInt32.into()),
                 BitNotInt64(()) => Ok(impls::BitNotInt64.into()),
@@ -6330,6 +6506,87 @@ fn jsonb_build_object<'a>(datums: &[Datum<'a>], temp_storage: &'a RowArena) -> D
     }
 }
 
+fn jsonb_edit_path<'a>(
+    target: Datum<'a>,
+    path: Datum<'a>,
+    edit: JsonbPathEdit<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let path = path.unwrap_array().elements().iter().collect::<Vec<_>>();
+    temp_storage.try_make_datum(|packer| {
+        JsonbPacker::new(packer)
+            .pack_edit_path(JsonbRef::from_datum(target), &path, edit)
+            .map_err(|e| EvalError::InvalidParameterValue(e.to_string()))
+    })
+}
+
+fn jsonb_set<'a>(datums: &[Datum<'a>], temp_storage: &'a RowArena) -> Result<Datum<'a>, EvalError> {
+    let create_if_missing = datums.get(3).map_or(true, |d| d.unwrap_bool());
+    let edit = JsonbPathEdit::Set {
+        value: datums[2],
+        create_if_missing,
+    };
+    jsonb_edit_path(datums[0], datums[1], edit, temp_storage)
+}
+
+fn jsonb_set_lax<'a>(
+    datums: &[Datum<'a>],
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let (target, path, value) = (datums[0], datums[1], datums[2]);
+    let create_if_missing = datums.get(3).copied().unwrap_or(Datum::True);
+    let treatment = datums
+        .get(4)
+        .copied()
+        .unwrap_or(Datum::String("use_json_null"));
+    if !value.is_null() {
+        if target.is_null() || path.is_null() || create_if_missing.is_null() {
+            return Ok(Datum::Null);
+        }
+        return jsonb_set(&[target, path, value, create_if_missing], temp_storage);
+    }
+    let treatment = match treatment {
+        Datum::Null => None,
+        d => Some(d.unwrap_str()),
+    };
+    let treatment = match treatment {
+        Some(t @ ("raise_exception" | "use_json_null" | "delete_key" | "return_target")) => t,
+        _ => {
+            return Err(EvalError::InvalidParameterValue(
+                "null_value_treatment must be \"delete_key\", \"return_target\", \
+                 \"use_json_null\", or \"raise_exception\""
+                    .into(),
+            ))
+        }
+    };
+    if target.is_null() || path.is_null() || create_if_missing.is_null() {
+        return Ok(Datum::Null);
+    }
+    match treatment {
+        "raise_exception" => Err(EvalError::InvalidParameterValue(
+            "JSON value must not be null".into(),
+        )),
+        "use_json_null" => jsonb_set(
+            &[target, path, Datum::JsonNull, create_if_missing],
+            temp_storage,
+        ),
+        "delete_key" => jsonb_edit_path(target, path, JsonbPathEdit::Delete, temp_storage),
+        _ => Ok(target),
+    }
+}
+
+fn jsonb_insert<'a>(
+    datums: &[Datum<'a>],
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let after = datums.get(3).map_or(false, |d| d.unwrap_bool());
+    let edit = JsonbPathEdit::Insert {
+        value: datums[2],
+        after,
+    };
+    jsonb_edit_path(datums[0], datums[1], edit, temp_storage)
+}
+
 /// Constructs a new multidimensional array out of an arbitrary number of
 /// lower-dimensional arrays.
 ///
@@ -7317,6 +7574,9 @@ pub enum VariadicFunc {
     Replace,
     JsonbBuildArray,
     JsonbBuildObject,
+    JsonbSet,
+    JsonbSetLax,
+    JsonbInsert,
     ArrayCreate {
         // We need to know the element type to type empty arrays.
         elem_type: ScalarType,
@@ -7411,6 +7671,9 @@ impl VariadicFunc {
             VariadicFunc::Translate => Ok(translate(&ds, temp_storage)),
             VariadicFunc::JsonbBuildArray => Ok(jsonb_build_array(&ds, temp_storage)),
             VariadicFunc::JsonbBuildObject => Ok(jsonb_build_object(&ds, temp_storage)),
+            VariadicFunc::JsonbSet => jsonb_set(&ds, temp_storage),
+            VariadicFunc::JsonbSetLax => jsonb_set_lax(&ds, temp_storage),
+            VariadicFunc::JsonbInsert => jsonb_insert(&ds, temp_storage),
             VariadicFunc::ArrayCreate {
                 elem_type: ScalarType::Array(_),
             } => array_create_multidim(&ds, temp_storage),
@@ -7492,6 +7755,9 @@ impl VariadicFunc {
             | VariadicFunc::Translate
             | VariadicFunc::JsonbBuildArray
             | VariadicFunc::JsonbBuildObject
+            | VariadicFunc::JsonbSet
+            | VariadicFunc::JsonbSetLax
+            | VariadicFunc::JsonbInsert
             | VariadicFunc::ArrayCreate { elem_type: _ }
             | VariadicFunc::ArrayToString { elem_type: _ }
             | VariadicFunc::ArrayIndex { offset: _ }
@@ -7547,6 +7813,7 @@ impl VariadicFunc {
             Replace => ScalarType::String.nullable(in_nullable),
             Translate => ScalarType::String.nullable(in_nullable),
             JsonbBuildArray | JsonbBuildObject => ScalarType::Jsonb.nullable(true),
+            JsonbSet | JsonbSetLax | JsonbInsert => ScalarType::Jsonb.nullable(in_nullable),
             ArrayCreate { elem_type } => {
                 debug_assert!(
                     input_types.iter().all(|t| t.scalar_type.base_eq(elem_type)),
@@ -7631,6 +7898,7 @@ impl VariadicFunc {
                 | VariadicFunc::ConcatWs
                 | VariadicFunc::JsonbBuildArray
                 | VariadicFunc::JsonbBuildObject
+                | VariadicFunc::JsonbSetLax
                 | VariadicFunc::ListCreate { .. }
                 | VariadicFunc::RecordCreate { .. }
                 | VariadicFunc::ArrayCreate { .. }
@@ -7658,6 +7926,9 @@ impl VariadicFunc {
             | Translate
             | JsonbBuildArray
             | JsonbBuildObject
+            | JsonbSet
+            | JsonbSetLax
+            | JsonbInsert
             | ArrayCreate { .. }
             | ArrayToString { .. }
             | ListCreate { .. }
@@ -7761,6 +8032,9 @@ impl VariadicFunc {
             | VariadicFunc::Replace
             | VariadicFunc::JsonbBuildArray
             | VariadicFunc::JsonbBuildObject
+            | VariadicFunc::JsonbSet
+            | VariadicFunc::JsonbSetLax
+            | VariadicFunc::JsonbInsert
             | VariadicFunc::ArrayCreate { .. }
             | VariadicFunc::ArrayToString { .. }
             | VariadicFunc::ArrayIndex { .. }
@@ -7807,6 +8081,9 @@ impl fmt::Display for VariadicFunc {
             VariadicFunc::Translate => f.write_str("translate"),
             VariadicFunc::JsonbBuildArray => f.write_str("jsonb_build_array"),
             VariadicFunc::JsonbBuildObject => f.write_str("jsonb_build_object"),
+            VariadicFunc::JsonbSet => f.write_str("jsonb_set"),
+            VariadicFunc::JsonbSetLax => f.write_str("jsonb_set_lax"),
+            VariadicFunc::JsonbInsert => f.write_str("jsonb_insert"),
             VariadicFunc::ArrayCreate { .. } => f.write_str("array_create"),
             VariadicFunc::ArrayToString { .. } => f.write_str("array_to_string"),
             VariadicFunc::ArrayIndex { .. } => f.write_str("array_index"),
@@ -7872,6 +8149,9 @@ impl Arbitrary for VariadicFunc {
             Just(VariadicFunc::Replace).boxed(),
             Just(VariadicFunc::JsonbBuildArray).boxed(),
             Just(VariadicFunc::JsonbBuildObject).boxed(),
+            Just(VariadicFunc::JsonbSet).boxed(),
+            Just(VariadicFunc::JsonbSetLax).boxed(),
+            Just(VariadicFunc::JsonbInsert).boxed(),
             Just(VariadicFunc::MakeAclItem).boxed(),
             Just(VariadicFunc::MakeMzAclItem).boxed(),
             ScalarType::arbitrary()
@@ -7932,6 +8212,9 @@ impl RustType<ProtoVariadicFunc> for VariadicFunc {
             VariadicFunc::Translate => Translate(()),
             VariadicFunc::JsonbBuildArray => JsonbBuildArray(()),
             VariadicFunc::JsonbBuildObject => JsonbBuildObject(()),
+            VariadicFunc::JsonbSet => JsonbSet(()),
+            VariadicFunc::JsonbSetLax => JsonbSetLax(()),
+            VariadicFunc::JsonbInsert => JsonbInsert(()),
             VariadicFunc::ArrayCreate { elem_type } => ArrayCreate(elem_type.into_proto()),
             VariadicFunc::ArrayToString { elem_type } => ArrayToString(elem_type.into_proto()),
             VariadicFunc::ArrayIndex { offset } => ArrayIndex(offset.into_proto()),
@@ -7983,6 +8266,9 @@ impl RustType<ProtoVariadicFunc> for VariadicFunc {
                 Translate(()) => Ok(VariadicFunc::Translate),
                 JsonbBuildArray(()) => Ok(VariadicFunc::JsonbBuildArray),
                 JsonbBuildObject(()) => Ok(VariadicFunc::JsonbBuildObject),
+                JsonbSet(()) => Ok(VariadicFunc::JsonbSet),
+                JsonbSetLax(()) => Ok(VariadicFunc::JsonbSetLax),
+                JsonbInsert(()) => Ok(VariadicFunc::JsonbInsert),
                 ArrayCreate(elem_type) => Ok(VariadicFunc::ArrayCreate {
                     elem_type: elem_type.into_rust()?,
                 }),
@@ -19,6 +19,18 @@ sqlfunc!(
     }
 );
 
+// Marks the row-level security predicate of a relation. It evaluates to its
+// argument, but `MapFilterProject` evaluates predicates that contain it before
+// all other predicates, so that user predicates never observe rows that the
+// predicate hides.
+sqlfunc!(
+    #[sqlname = "security_barrier"]
+    #[is_monotone = true]
+    fn security_barrier(a: bool) -> bool {
+        a
+    }
+);
+
 sqlfunc!(
     #[sqlname = "boolean_to_text"]
     #[preserves_uniqueness = true]
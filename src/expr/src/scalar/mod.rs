@@ -662,6 +662,25 @@ impl MirScalarExpr {
         false
     }
 
+    /// Reports whether `self` contains a call to `security_barrier`, which
+    /// marks the row-level security predicate of a relation.
+    pub fn contains_security_barrier(&self) -> bool {
+        let mut worklist = vec![self];
+        while let Some(expr) = worklist.pop() {
+            if matches!(
+                expr,
+                MirScalarExpr::CallUnary {
+                    func: UnaryFunc::SecurityBarrier(_),
+                    ..
+                }
+            ) {
+                return true;
+            }
+            worklist.extend(expr.children());
+        }
+        false
+    }
+
     /// If self is a column, return the column index, otherwise `None`.
     pub fn as_column(&self) -> Option<usize> {
         if let MirScalarExpr::Column(c) = self {
@@ -2357,6 +2376,8 @@ pub enum EvalError {
         from: String,
         to: String,
     },
+    InvalidJsonPath(String),
+    JsonPath(String),
     InvalidRegex(String),
     InvalidRegexFlag(char),
     InvalidParameterValue(String),
@@ -2494,6 +2515,7 @@ impl fmt::Display for EvalError {
             EvalError::InvalidDatePart(part) => write!(f, "invalid datepart {}", part.quoted()),
             EvalError::NegSqrt => f.write_str("cannot take square root of a negative number"),
             EvalError::NullCharacterNotPermitted => f.write_str("null character not permitted"),
+            EvalError::InvalidJsonPath(e) | EvalError::JsonPath(e) => f.write_str(e),
             EvalError::InvalidRegex(e) => write!(f, "invalid regular expression: {}", e),
             EvalError::InvalidRegexFlag(c) => write!(f, "invalid regular expression flag: {}", c),
             EvalError::InvalidParameterValue(s) => f.write_str(s),
@@ -2780,6 +2802,8 @@ impl RustType<ProtoEvalError> for EvalError {
                 from: from.clone(),
                 to: to.clone(),
             }),
+            EvalError::InvalidJsonPath(v) => InvalidJsonPath(v.clone()),
+            EvalError::JsonPath(v) => JsonPath(v.clone()),
             EvalError::InvalidRegex(v) => InvalidRegex(v.clone()),
             EvalError::InvalidRegexFlag(v) => InvalidRegexFlag(v.into_proto()),
             EvalError::InvalidParameterValue(v) => InvalidParameterValue(v.clone()),
@@ -2907,6 +2931,8 @@ impl RustType<ProtoEvalError> for EvalError {
                     from: v.from,
                     to: v.to,
                 }),
+                InvalidJsonPath(v) => Ok(EvalError::InvalidJsonPath(v)),
+                JsonPath(v) => Ok(EvalError::JsonPath(v)),
                 InvalidRegex(v) => Ok(EvalError::InvalidRegex(v)),
                 InvalidRegexFlag(v) => Ok(EvalError::InvalidRegexFlag(char::from_proto(v)?)),
                 InvalidParameterValue(v) => Ok(EvalError::InvalidParameterValue(v)),
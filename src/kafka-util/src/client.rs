@@ -386,6 +386,36 @@ pub fn get_partitions<C: ClientContext>(
     Ok(partition_ids)
 }
 
+/// Retrieve the partitions of every topic whose name satisfies `matches` using the given
+/// `client`.
+///
+/// Topics whose metadata reports an error, e.g. because they are still being created, map to
+/// `None`; callers are expected to retry periodically.
+pub fn get_matching_topic_partitions<C: ClientContext>(
+    client: &Client<C>,
+    matches: impl Fn(&str) -> bool,
+    timeout: Duration,
+) -> Result<BTreeMap<String, Option<Vec<PartitionId>>>, anyhow::Error> {
+    let meta = client.fetch_metadata(None, timeout)?;
+
+    let mut topics = BTreeMap::new();
+    for meta_topic in meta.topics() {
+        if !matches(meta_topic.name()) {
+            continue;
+        }
+        let partition_ids: Vec<_> = meta_topic.partitions().iter().map(|p| p.id()).collect();
+        let available = meta_topic.error().is_none()
+            && meta_topic.partitions().iter().all(|p| p.error().is_none())
+            && !partition_ids.is_empty();
+        topics.insert(
+            meta_topic.name().to_string(),
+            available.then_some(partition_ids),
+        );
+    }
+
+    Ok(topics)
+}
+
 /// A simpler version of [`create_new_client_config`] that defaults
 /// the `log_level` to `INFO` and should only be used in tests.
 pub fn create_new_client_config_simple() -> ClientConfig {
@@ -87,13 +87,13 @@ use cloud_resource_controller::KubernetesResourceReader;
 use futures::stream::{BoxStream, StreamExt};
 use k8s_openapi::api::apps::v1::{StatefulSet, StatefulSetSpec};
 use k8s_openapi::api::core::v1::{
-    Affinity, Container, ContainerPort, ContainerState, EnvVar, EnvVarSource,
+    Affinity, Container, ContainerPort, ContainerState, EnvFromSource, EnvVar, EnvVarSource,
     EphemeralVolumeSource, NodeAffinity, NodeSelector, NodeSelectorRequirement, NodeSelectorTerm,
     ObjectFieldSelector, ObjectReference, PersistentVolumeClaim, PersistentVolumeClaimSpec,
     PersistentVolumeClaimTemplate, Pod, PodAffinity, PodAffinityTerm, PodAntiAffinity,
     PodSecurityContext, PodSpec, PodTemplateSpec, PreferredSchedulingTerm, ResourceRequirements,
-    Secret, Service as K8sService, ServicePort, ServiceSpec, Toleration, TopologySpreadConstraint,
-    Volume, VolumeMount, WeightedPodAffinityTerm,
+    Secret, SecretEnvSource, Service as K8sService, ServicePort, ServiceSpec, Toleration,
+    TopologySpreadConstraint, Volume, VolumeMount, WeightedPodAffinityTerm,
 };
 use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, LabelSelectorRequirement};
@@ -150,6 +150,12 @@ pub struct KubernetesOrchestratorConfig {
     pub ephemeral_volume_storage_class: Option<String>,
     /// The optional fs group for service's pods' `securityContext`.
     pub service_fs_group: Option<i64>,
+    /// The name of a Kubernetes secret whose keys are exposed to each service
+    /// as environment variables, if any.
+    ///
+    /// This is how services receive credentials that the orchestrator must
+    /// not pass on the command line, e.g. those of the Vault secrets reader.
+    pub service_env_secret: Option<String>,
 }
 
 /// Specifies whether Kubernetes should pull Docker images when creating pods.
@@ -1089,6 +1095,16 @@ impl NamespacedOrchestrator for NamespacedKubernetesOrchestrator {
             None
         };
 
+        let env_from = self.config.service_env_secret.as_ref().map(|secret| {
+            vec![EnvFromSource {
+                secret_ref: Some(SecretEnvSource {
+                    name: Some(secret.clone()),
+                    optional: Some(false),
+                }),
+                ..Default::default()
+            }]
+        });
+
         let mut volume_mounts = vec![];
 
         if self.config.coverage {
@@ -1235,6 +1251,7 @@ impl NamespacedOrchestrator for NamespacedKubernetesOrchestrator {
                         None
                     },
                     env,
+                    env_from,
                     ..Default::default()
                 }],
                 volumes,
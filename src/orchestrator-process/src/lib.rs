@@ -120,6 +120,8 @@ use tokio::time::{self, Duration};
 use tokio::{fs, io, select};
 use tracing::{debug, error, info, warn};
 
+use crate::secrets::SecretsKeyring;
+
 pub mod secrets;
 
 /// Configures a [`ProcessOrchestrator`].
@@ -134,6 +136,10 @@ pub struct ProcessOrchestratorConfig {
     pub environment_id: String,
     /// The directory in which to store secrets.
     pub secrets_dir: PathBuf,
+    /// The master keys with which to encrypt secrets at rest, if any.
+    ///
+    /// Child processes must be configured to read secrets with the same keys.
+    pub secrets_keyring: Option<SecretsKeyring>,
     /// A command to wrap the child command invocation
     pub command_wrapper: Vec<String>,
     /// Whether to crash this process if a child process crashes.
@@ -188,6 +194,7 @@ pub struct ProcessOrchestrator {
     namespaces: Mutex<BTreeMap<String, Arc<dyn NamespacedOrchestrator>>>,
     metadata_dir: PathBuf,
     secrets_dir: PathBuf,
+    secrets_keyring: Option<SecretsKeyring>,
     command_wrapper: Vec<String>,
     propagate_crashes: bool,
     tcp_proxy: Option<ProcessOrchestratorTcpProxyConfig>,
@@ -202,6 +209,7 @@ impl ProcessOrchestrator {
             suppress_output,
             environment_id,
             secrets_dir,
+            secrets_keyring,
             command_wrapper,
             propagate_crashes,
             tcp_proxy,
@@ -227,17 +235,25 @@ impl ProcessOrchestrator {
                 .context("creating prometheus directory")?;
         }
 
-        Ok(ProcessOrchestrator {
+        let orchestrator = ProcessOrchestrator {
             image_dir: fs::canonicalize(image_dir).await?,
             suppress_output,
             namespaces: Mutex::new(BTreeMap::new()),
             metadata_dir: fs::canonicalize(metadata_dir).await?,
             secrets_dir: fs::canonicalize(secrets_dir).await?,
+            secrets_keyring,
             command_wrapper,
             propagate_crashes,
             tcp_proxy,
             scratch_directory,
-        })
+        };
+        // Bring any secrets written in plaintext or under a retired master key
+        // up to date with the current keyring, if one is configured.
+        orchestrator
+            .rotate_secrets()
+            .await
+            .context("re-encrypting secrets")?;
+        Ok(orchestrator)
     }
 }
 
@@ -8,31 +8,372 @@
 // by the Apache License, Version 2.0.
 
 //! Management of user secrets via the local file system.
+//!
+//! Secrets are stored one per file in the secrets directory, named after the
+//! secret's ID. If a [`SecretsKeyring`] is configured, the files are envelope
+//! encrypted: each secret is encrypted with a fresh data key using AES-256-GCM,
+//! and the data key is in turn encrypted ("wrapped") with the keyring's
+//! primary master key. Encrypted files start with a magic number and a format
+//! byte that says how the rest of the file is to be read.
+//!
+//! Files written without a keyring are stored as plaintext, so that they stay
+//! readable by versions that predate encryption, and are still readable once a
+//! keyring is configured. The only plaintext secrets that are tagged with the
+//! magic number and a format byte are those that themselves start with the
+//! magic number, so that they are never mistaken for a tagged file. Plaintext
+//! files are encrypted on startup by [`ProcessOrchestrator::rotate_secrets`]
+//! once a keyring is configured, and are left alone otherwise.
 
-use std::path::PathBuf;
+use std::fmt;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use anyhow::{anyhow, bail, Context};
 use async_trait::async_trait;
 use mz_repr::GlobalId;
 use mz_secrets::{SecretsController, SecretsReader};
+use openssl::symm::{self, Cipher};
 use tokio::fs::{self, OpenOptions};
 use tokio::io::AsyncWriteExt;
+use tracing::info;
 
 use crate::ProcessOrchestrator;
 
+/// The environment variable from which [`SecretsKeyring::load`] reads master
+/// keys when no key file is specified.
+///
+/// Processes spawned by the [`ProcessOrchestrator`] inherit the variable, so
+/// the keys never need to appear on a command line.
+pub const SECRETS_MASTER_KEY_ENV: &str = "MZ_SECRETS_MASTER_KEY";
+
+/// Identifies a secret file that starts with a format byte.
+///
+/// Untagged files contain nothing but the plaintext secret.
+const MAGIC: &[u8; 4] = b"MZSE";
+/// The format of a secret file whose contents are stored as plaintext.
+const FORMAT_PLAINTEXT: u8 = 0;
+/// The format of a secret file whose contents are envelope encrypted with
+/// AES-256-GCM.
+const FORMAT_AES_256_GCM: u8 = 1;
+const KEY_LEN: usize = 32;
+const KEY_ID_LEN: usize = 8;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+/// The length of the header that precedes the ciphertext of an encrypted
+/// secret: magic, format, master key ID, and the nonce, wrapped data key and
+/// tag of the data key, followed by the nonce and tag of the contents.
+const HEADER_LEN: usize =
+    MAGIC.len() + 1 + KEY_ID_LEN + NONCE_LEN + KEY_LEN + TAG_LEN + NONCE_LEN + TAG_LEN;
+
+/// A 256-bit master key used to wrap the data keys of encrypted secrets.
+#[derive(Clone)]
+struct MasterKey {
+    id: [u8; KEY_ID_LEN],
+    key: [u8; KEY_LEN],
+}
+
+impl MasterKey {
+    fn new(key: [u8; KEY_LEN]) -> MasterKey {
+        // Identify keys by a prefix of their hash, so that the key ID stored
+        // alongside each secret reveals nothing about the key itself.
+        let digest = openssl::sha::sha256(&key);
+        let mut id = [0; KEY_ID_LEN];
+        id.copy_from_slice(&digest[..KEY_ID_LEN]);
+        MasterKey { id, key }
+    }
+}
+
+impl fmt::Debug for MasterKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MasterKey")
+            .field("id", &hex::encode(self.id))
+            .finish_non_exhaustive()
+    }
+}
+
+/// The master keys used to encrypt secrets at rest.
+///
+/// New secrets are always encrypted with the primary key. Retired keys are
+/// used only to decrypt secrets that were written before the primary key was
+/// rotated; see [`ProcessOrchestrator::rotate_secrets`].
+#[derive(Debug, Clone)]
+pub struct SecretsKeyring {
+    primary: MasterKey,
+    retired: Vec<MasterKey>,
+}
+
+impl SecretsKeyring {
+    /// Parses a keyring from a list of hex-encoded 256-bit keys separated by
+    /// commas or whitespace.
+    ///
+    /// The first key is the primary key. Any further keys are retired keys.
+    pub fn from_hex(keys: &str) -> Result<SecretsKeyring, anyhow::Error> {
+        let mut keys = keys
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|k| !k.is_empty())
+            .enumerate()
+            .map(|(i, k)| {
+                let mut key = [0; KEY_LEN];
+                hex::decode_to_slice(k, &mut key).with_context(|| {
+                    format!(
+                        "master key {} is not a hex-encoded {}-byte key",
+                        i + 1,
+                        KEY_LEN
+                    )
+                })?;
+                Ok(MasterKey::new(key))
+            })
+            .collect::<Result<Vec<_>, anyhow::Error>>()?;
+        if keys.is_empty() {
+            bail!("no master keys specified");
+        }
+        let primary = keys.remove(0);
+        Ok(SecretsKeyring {
+            primary,
+            retired: keys,
+        })
+    }
+
+    /// Loads the keyring from `key_file`, if specified, or else from the
+    /// [`SECRETS_MASTER_KEY_ENV`] environment variable.
+    ///
+    /// Returns `None` if neither is set, in which case secrets are stored
+    /// unencrypted.
+    pub fn load(key_file: Option<&Path>) -> Result<Option<SecretsKeyring>, anyhow::Error> {
+        let keys = match key_file {
+            Some(path) => std::fs::read_to_string(path)
+                .with_context(|| format!("reading master key file {}", path.display()))?,
+            None => match std::env::var(SECRETS_MASTER_KEY_ENV) {
+                Ok(keys) => keys,
+                Err(std::env::VarError::NotPresent) => return Ok(None),
+                Err(e) => return Err(e).context(SECRETS_MASTER_KEY_ENV),
+            },
+        };
+        Ok(Some(SecretsKeyring::from_hex(&keys)?))
+    }
+
+    fn key(&self, id: &[u8]) -> Option<&MasterKey> {
+        std::iter::once(&self.primary)
+            .chain(&self.retired)
+            .find(|k| k.id == id)
+    }
+
+    /// Encrypts the contents of the secret with the specified ID under the
+    /// primary key.
+    fn encrypt(&self, id: GlobalId, contents: &[u8]) -> Result<Vec<u8>, anyhow::Error> {
+        let cipher = Cipher::aes_256_gcm();
+        let mut data_key = [0; KEY_LEN];
+        let mut key_nonce = [0; NONCE_LEN];
+        let mut data_nonce = [0; NONCE_LEN];
+        openssl::rand::rand_bytes(&mut data_key)?;
+        openssl::rand::rand_bytes(&mut key_nonce)?;
+        openssl::rand::rand_bytes(&mut data_nonce)?;
+
+        let mut key_tag = [0; TAG_LEN];
+        let wrapped_key = symm::encrypt_aead(
+            cipher,
+            &self.primary.key,
+            Some(&key_nonce[..]),
+            &self.primary.id,
+            &data_key,
+            &mut key_tag,
+        )?;
+        // Bind the contents to the secret ID, so that encrypted files cannot
+        // be swapped for one another.
+        let mut data_tag = [0; TAG_LEN];
+        let ciphertext = symm::encrypt_aead(
+            cipher,
+            &data_key,
+            Some(&data_nonce[..]),
+            id.to_string().as_bytes(),
+            contents,
+            &mut data_tag,
+        )?;
+
+        let mut buf = Vec::with_capacity(HEADER_LEN + ciphertext.len());
+        buf.extend(MAGIC);
+        buf.push(FORMAT_AES_256_GCM);
+        buf.extend(self.primary.id);
+        buf.extend(key_nonce);
+        buf.extend(wrapped_key);
+        buf.extend(key_tag);
+        buf.extend(data_nonce);
+        buf.extend(data_tag);
+        buf.extend(ciphertext);
+        Ok(buf)
+    }
+
+    /// Decrypts the contents of an encrypted secret with the specified ID.
+    fn decrypt(&self, id: GlobalId, buf: &[u8]) -> Result<Vec<u8>, anyhow::Error> {
+        let header = EncryptedHeader::parse(buf)?;
+        let master_key = self.key(header.key_id).ok_or_else(|| {
+            anyhow!(
+                "secret {} is encrypted with unknown master key {}",
+                id,
+                hex::encode(header.key_id)
+            )
+        })?;
+        let cipher = Cipher::aes_256_gcm();
+        let data_key = symm::decrypt_aead(
+            cipher,
+            &master_key.key,
+            Some(header.key_nonce),
+            &master_key.id,
+            header.wrapped_key,
+            header.key_tag,
+        )
+        .with_context(|| format!("unwrapping data key for secret {}", id))?;
+        let contents = symm::decrypt_aead(
+            cipher,
+            &data_key,
+            Some(header.data_nonce),
+            id.to_string().as_bytes(),
+            &buf[HEADER_LEN..],
+            header.data_tag,
+        )
+        .with_context(|| format!("decrypting secret {}", id))?;
+        Ok(contents)
+    }
+}
+
+/// The header of an encrypted secret file.
+struct EncryptedHeader<'a> {
+    key_id: &'a [u8],
+    key_nonce: &'a [u8],
+    wrapped_key: &'a [u8],
+    key_tag: &'a [u8],
+    data_nonce: &'a [u8],
+    data_tag: &'a [u8],
+}
+
+impl<'a> EncryptedHeader<'a> {
+    fn parse(buf: &'a [u8]) -> Result<EncryptedHeader<'a>, anyhow::Error> {
+        if buf.len() < HEADER_LEN || format(buf) != Some(FORMAT_AES_256_GCM) {
+            bail!("malformed encrypted secret");
+        }
+        let mut rest = &buf[MAGIC.len() + 1..];
+        let mut take = |n: usize| {
+            let (head, tail) = rest.split_at(n);
+            rest = tail;
+            head
+        };
+        Ok(EncryptedHeader {
+            key_id: take(KEY_ID_LEN),
+            key_nonce: take(NONCE_LEN),
+            wrapped_key: take(KEY_LEN),
+            key_tag: take(TAG_LEN),
+            data_nonce: take(NONCE_LEN),
+            data_tag: take(TAG_LEN),
+        })
+    }
+}
+
+/// Returns the format byte of a secret file, or `None` if the file predates
+/// format bytes.
+///
+/// A file that consists of only the magic number has an invalid format.
+fn format(buf: &[u8]) -> Option<u8> {
+    buf.strip_prefix(MAGIC)
+        .map(|rest| rest.first().copied().unwrap_or(u8::MAX))
+}
+
+/// Encodes the contents of the secret with the specified ID for storage on
+/// disk.
+fn encode(
+    keyring: Option<&SecretsKeyring>,
+    id: GlobalId,
+    contents: &[u8],
+) -> Result<Vec<u8>, anyhow::Error> {
+    match keyring {
+        Some(keyring) => keyring.encrypt(id, contents),
+        // Leave plaintext untagged unless it could be mistaken for a tagged
+        // file, so that versions that predate format bytes can still read it.
+        None if !contents.starts_with(MAGIC) => Ok(contents.to_vec()),
+        None => {
+            let mut buf = Vec::with_capacity(MAGIC.len() + 1 + contents.len());
+            buf.extend(MAGIC);
+            buf.push(FORMAT_PLAINTEXT);
+            buf.extend(contents);
+            Ok(buf)
+        }
+    }
+}
+
+/// Decodes the on-disk contents of the secret with the specified ID.
+fn decode(
+    keyring: Option<&SecretsKeyring>,
+    id: GlobalId,
+    buf: Vec<u8>,
+) -> Result<Vec<u8>, anyhow::Error> {
+    match (format(&buf), keyring) {
+        // Untagged secrets are plaintext.
+        (None, _) => Ok(buf),
+        (Some(FORMAT_PLAINTEXT), _) => Ok(buf[MAGIC.len() + 1..].to_vec()),
+        (Some(FORMAT_AES_256_GCM), Some(keyring)) => keyring.decrypt(id, &buf),
+        (Some(FORMAT_AES_256_GCM), None) => {
+            bail!(
+                "secret {} is encrypted, but no master key is configured",
+                id
+            )
+        }
+        (Some(format), _) => bail!("secret {} has unsupported format {}", id, format),
+    }
+}
+
+impl ProcessOrchestrator {
+    /// Re-encrypts every secret that is not encrypted with the primary master
+    /// key, including any plaintext secrets.
+    ///
+    /// Once this completes, retired master keys are no longer needed and may
+    /// be removed from the keyring.
+    ///
+    /// Without a keyring, this does nothing: plaintext secrets are already in
+    /// the format that [`SecretsController::ensure`] would write, and
+    /// rewriting them would make them unreadable to older versions.
+    pub async fn rotate_secrets(&self) -> Result<(), anyhow::Error> {
+        let Some(keyring) = self.secrets_keyring.as_ref() else {
+            return Ok(());
+        };
+        let mut rotated = 0;
+        for id in self.list().await? {
+            let buf = fs::read(self.secrets_dir.join(id.to_string())).await?;
+            if format(&buf) == Some(FORMAT_AES_256_GCM)
+                && EncryptedHeader::parse(&buf)?.key_id == keyring.primary.id
+            {
+                continue;
+            }
+            let contents = decode(Some(keyring), id, buf)?;
+            self.ensure(id, &contents).await?;
+            rotated += 1;
+        }
+        if rotated > 0 {
+            info!(
+                "re-encrypted {rotated} secrets with master key {}",
+                hex::encode(keyring.primary.id)
+            );
+        }
+        Ok(())
+    }
+}
+
 #[async_trait]
 impl SecretsController for ProcessOrchestrator {
     async fn ensure(&self, id: GlobalId, contents: &[u8]) -> Result<(), anyhow::Error> {
+        let contents = encode(self.secrets_keyring.as_ref(), id, contents)?;
+        // Write to a temporary file and rename it into place, so that readers
+        // never observe a partially written secret.
         let file_path = self.secrets_dir.join(id.to_string());
+        let tmp_path = self.secrets_dir.join(format!(".{id}.tmp"));
         let mut file = OpenOptions::new()
             .mode(0o600)
             .create(true)
             .write(true)
             .truncate(true)
-            .open(file_path)
+            .open(&tmp_path)
             .await?;
-        file.write_all(contents).await?;
+        file.write_all(&contents).await?;
         file.sync_all().await?;
+        fs::rename(tmp_path, file_path).await?;
         Ok(())
     }
 
@@ -45,8 +386,9 @@ impl SecretsController for ProcessOrchestrator {
         let mut ids = Vec::new();
         let mut entries = fs::read_dir(&self.secrets_dir).await?;
         while let Some(dir) = entries.next_entry().await? {
-            let id: GlobalId = dir.file_name().to_string_lossy().parse()?;
-            ids.push(id);
+            if let Ok(id) = dir.file_name().to_string_lossy().parse() {
+                ids.push(id);
+            }
         }
         Ok(ids)
     }
@@ -54,6 +396,7 @@ impl SecretsController for ProcessOrchestrator {
     fn reader(&self) -> Arc<dyn SecretsReader> {
         Arc::new(ProcessSecretsReader {
             secrets_dir: self.secrets_dir.clone(),
+            keyring: self.secrets_keyring.clone(),
         })
     }
 }
@@ -62,13 +405,18 @@ impl SecretsController for ProcessOrchestrator {
 #[derive(Debug)]
 pub struct ProcessSecretsReader {
     secrets_dir: PathBuf,
+    keyring: Option<SecretsKeyring>,
 }
 
 impl ProcessSecretsReader {
     /// Constructs a new [`ProcessSecretsReader`] that reads secrets out of the
-    /// specified directory.
-    pub fn new(secrets_dir: PathBuf) -> ProcessSecretsReader {
-        ProcessSecretsReader { secrets_dir }
+    /// specified directory, decrypting them with `keyring` if they are
+    /// encrypted.
+    pub fn new(secrets_dir: PathBuf, keyring: Option<SecretsKeyring>) -> ProcessSecretsReader {
+        ProcessSecretsReader {
+            secrets_dir,
+            keyring,
+        }
     }
 }
 
@@ -76,6 +424,80 @@ impl ProcessSecretsReader {
 impl SecretsReader for ProcessSecretsReader {
     async fn read(&self, id: GlobalId) -> Result<Vec<u8>, anyhow::Error> {
         let contents = fs::read(self.secrets_dir.join(id.to_string())).await?;
-        Ok(contents)
+        decode(self.keyring.as_ref(), id, contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_repr::GlobalId;
+
+    use super::{decode, encode, SecretsKeyring, MAGIC};
+
+    const KEY_A: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+    const KEY_B: &str = "1f1e1d1c1b1a191817161514131211100f0e0d0c0b0a09080706050403020100";
+
+    #[mz_ore::test]
+    fn test_encrypt_decrypt() {
+        let keyring = SecretsKeyring::from_hex(KEY_A).unwrap();
+        let id = GlobalId::User(1);
+        let buf = keyring.encrypt(id, b"hunter2").unwrap();
+        assert!(!buf.windows(7).any(|w| w == b"hunter2"));
+        assert_eq!(decode(Some(&keyring), id, buf.clone()).unwrap(), b"hunter2");
+
+        // The contents are bound to the secret ID.
+        assert!(decode(Some(&keyring), GlobalId::User(2), buf.clone()).is_err());
+        // Encrypted secrets cannot be read without a keyring.
+        assert!(decode(None, id, buf).is_err());
+        // Plaintext secrets remain readable.
+        assert_eq!(
+            decode(Some(&keyring), id, b"legacy".to_vec()).unwrap(),
+            b"legacy"
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_plaintext() {
+        let keyring = SecretsKeyring::from_hex(KEY_A).unwrap();
+        let id = GlobalId::User(1);
+        // Plaintext that looks like an encrypted secret is still stored and
+        // read as plaintext.
+        let mut contents = MAGIC.to_vec();
+        contents.extend([1; 100]);
+        let buf = encode(None, id, &contents).unwrap();
+        assert_eq!(decode(None, id, buf.clone()).unwrap(), contents);
+        assert_eq!(decode(Some(&keyring), id, buf).unwrap(), contents);
+
+        let buf = encode(None, id, b"").unwrap();
+        assert_eq!(decode(None, id, buf).unwrap(), b"");
+
+        // Other plaintext is stored untagged, as older versions expect.
+        assert_eq!(encode(None, id, b"hunter2").unwrap(), b"hunter2");
+
+        // Unknown formats are rejected.
+        let mut buf = MAGIC.to_vec();
+        buf.push(42);
+        assert!(decode(Some(&keyring), id, buf).is_err());
+        assert!(decode(None, id, MAGIC.to_vec()).is_err());
+    }
+
+    #[mz_ore::test]
+    fn test_rotation() {
+        let old = SecretsKeyring::from_hex(KEY_A).unwrap();
+        let new = SecretsKeyring::from_hex(&format!("{KEY_B},{KEY_A}")).unwrap();
+        let id = GlobalId::User(1);
+        let buf = old.encrypt(id, b"hunter2").unwrap();
+        assert_eq!(decode(Some(&new), id, buf).unwrap(), b"hunter2");
+
+        let buf = new.encrypt(id, b"hunter2").unwrap();
+        assert!(decode(Some(&old), id, buf).is_err());
+    }
+
+    #[mz_ore::test]
+    fn test_parse_keyring() {
+        assert!(SecretsKeyring::from_hex("").is_err());
+        assert!(SecretsKeyring::from_hex("abcd").is_err());
+        let keyring = SecretsKeyring::from_hex(&format!("\n{KEY_A}\n{KEY_B}\n")).unwrap();
+        assert_eq!(keyring.retired.len(), 1);
     }
 }
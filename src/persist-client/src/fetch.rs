@@ -510,6 +510,39 @@ where
     type Item = ((Result<K, String>, Result<V, String>), T, D);
 
     fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_key_decoder(|k| Some(K::decode(k)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // We don't know in advance how restrictive the filter will be.
+        let max_len = self.part.part.updates.iter().map(|x| x.len()).sum();
+        (0, Some(max_len))
+    }
+}
+
+impl<K, V, T, D> FetchedPart<K, V, T, D>
+where
+    K: Debug + Codec,
+    V: Debug + Codec,
+    T: Timestamp + Lattice + Codec64,
+    D: Semigroup + Codec64 + Send + Sync,
+{
+    /// Returns the next update in this part, like [Iterator::next], but decodes
+    /// the key with `decode_key` instead of [Codec::decode].
+    ///
+    /// This allows callers that know more about how the key will be used (e.g.
+    /// which columns of a row they need) to skip some of the decoding work. The
+    /// value produced by `decode_key` only needs to be indistinguishable from
+    /// the one produced by [Codec::decode] for the caller's purposes. If
+    /// `decode_key` returns `None`, the update is skipped, e.g. because the
+    /// caller would filter it out anyway.
+    pub fn next_with_key_decoder<F>(
+        &mut self,
+        mut decode_key: F,
+    ) -> Option<((Result<K, String>, Result<V, String>), T, D)>
+    where
+        F: FnMut(&[u8]) -> Option<Result<K, String>>,
+    {
         while let Some((k, v, mut t, d)) = self.part_cursor.pop(&self.part) {
             if !self.ts_filter.filter_ts(&mut t) {
                 continue;
@@ -542,18 +575,14 @@ where
                 continue;
             }
 
-            let k = self.metrics.codecs.key.decode(|| K::decode(k));
+            let Some(k) = self.metrics.codecs.key.decode(|| decode_key(k)) else {
+                continue;
+            };
             let v = self.metrics.codecs.val.decode(|| V::decode(v));
             return Some(((k, v), t, d));
         }
         None
     }
-
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        // We don't know in advance how restrictive the filter will be.
-        let max_len = self.part.part.updates.iter().map(|x| x.len()).sum();
-        (0, Some(max_len))
-    }
 }
 
 impl<T> EncodedPart<T>
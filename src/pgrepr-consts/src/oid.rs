@@ -359,3 +359,15 @@ pub const FUNC_AVG_INTERNAL_V1_UINT16_OID: u32 = 16_640;
 pub const FUNC_AVG_INTERNAL_V1_FLOAT32_OID: u32 = 16_641;
 pub const FUNC_AVG_INTERNAL_V1_FLOAT64_OID: u32 = 16_642;
 pub const FUNC_AVG_INTERNAL_V1_INTERVAL_OID: u32 = 16_643;
+pub const FUNC_JSONB_PATH_EXISTS_OID: u32 = 16_644;
+pub const FUNC_JSONB_PATH_MATCH_OID: u32 = 16_645;
+pub const FUNC_JSONB_PATH_QUERY_OID: u32 = 16_646;
+pub const FUNC_JSONB_PATH_QUERY_ARRAY_OID: u32 = 16_647;
+pub const FUNC_JSONB_PATH_QUERY_FIRST_OID: u32 = 16_648;
+pub const OP_JSONB_PATH_EXISTS_OID: u32 = 16_649;
+pub const OP_JSONB_PATH_MATCH_OID: u32 = 16_650;
+pub const FUNC_JSONB_SET_3_OID: u32 = 16_651;
+pub const FUNC_JSONB_INSERT_3_OID: u32 = 16_652;
+pub const FUNC_JSONB_SET_LAX_3_OID: u32 = 16_653;
+pub const FUNC_JSONB_SET_LAX_4_OID: u32 = 16_654;
+pub const FUNC_JSONB_DEEP_MERGE_OID: u32 = 16_655;
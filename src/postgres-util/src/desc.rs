@@ -89,6 +89,20 @@ impl PostgresTableDesc {
                 "Error validating table in publication. Expected: {:?} Actual: {:?}",
                 &self, other
             );
+            let dropped_cols: Vec<_> = self
+                .columns
+                .iter()
+                .filter(|col| !other_cols.iter().any(|o| o.col_num == col.col_num))
+                .map(|col| col.name.as_str())
+                .collect();
+            if !dropped_cols.is_empty() {
+                bail!(
+                    "source table {} with oid {} has been altered: columns {} were dropped",
+                    self.name,
+                    self.oid,
+                    dropped_cols.join(", ")
+                )
+            }
             bail!(
                 "source table {} with oid {} has been altered",
                 self.name,
@@ -96,6 +110,18 @@ impl PostgresTableDesc {
             )
         }
     }
+
+    /// Returns the names of the columns of `other` that `self` does not describe, i.e. the columns
+    /// that were added to the table after `self` was captured. If `self` is compatible with
+    /// `other`, the values of these columns are ignored.
+    pub fn added_columns<'a>(&self, other: &'a PostgresTableDesc) -> Vec<&'a str> {
+        other
+            .columns
+            .iter()
+            .skip(self.columns.len())
+            .map(|col| col.name.as_str())
+            .collect()
+    }
 }
 
 impl RustType<ProtoPostgresTableDesc> for PostgresTableDesc {
@@ -23,6 +23,7 @@ pub mod date;
 pub mod datetime;
 pub mod interval;
 pub mod jsonb;
+pub mod jsonpath;
 pub mod mz_acl_item;
 pub mod numeric;
 pub mod pg_legacy_name;
@@ -80,6 +80,7 @@ use std::str::{self, FromStr};
 use std::{fmt, io};
 
 use dec::OrderedDecimal;
+use mz_ore::cast::CastFrom;
 use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
 use serde::ser::{Serialize, SerializeMap, SerializeSeq, SerializeStruct, Serializer};
 
@@ -272,6 +273,249 @@ impl<'a, 'row> JsonbPacker<'a, 'row> {
         pack(self.packer, &commands);
         Ok(())
     }
+
+    /// Packs a copy of `target` with the value at `path` modified as described
+    /// by `edit`.
+    ///
+    /// Each element of `path` must be a [`Datum::String`] or [`Datum::Null`].
+    /// Object members are addressed by key and array elements by (possibly
+    /// negative) integer index, as in PostgreSQL's `jsonb_set`,
+    /// `jsonb_insert` and `#-`. All steps but the last must already exist;
+    /// otherwise `target` is packed unchanged.
+    ///
+    /// The state of the packer is unspecified if an error is returned.
+    pub fn pack_edit_path(
+        self,
+        target: JsonbRef,
+        path: &[Datum],
+        edit: JsonbPathEdit,
+    ) -> Result<(), JsonbPathEditError> {
+        match target.datum {
+            Datum::Map(_) | Datum::List(_) => {}
+            _ if matches!(edit, JsonbPathEdit::Delete) => {
+                return Err(JsonbPathEditError::DeleteInScalar)
+            }
+            _ => return Err(JsonbPathEditError::SetInScalar),
+        }
+        if path.is_empty() {
+            self.packer.push(target.datum);
+            return Ok(());
+        }
+        edit_path(self.packer, target.datum, path, 0, &edit)
+    }
+
+    /// Packs the recursive merge of `a` and `b`.
+    ///
+    /// Objects are merged key by key, recursing into keys present in both.
+    /// In every other case, including JSON `null`s, the value from `b` wins.
+    pub fn pack_deep_merge(self, a: JsonbRef, b: JsonbRef) {
+        deep_merge(self.packer, a.datum, b.datum)
+    }
+}
+
+/// A modification applied by [`JsonbPacker::pack_edit_path`] to the value
+/// at the end of a path.
+#[derive(Debug, Clone, Copy)]
+pub enum JsonbPathEdit<'a> {
+    /// Replaces the value, adding it if it does not exist and
+    /// `create_if_missing` is set.
+    Set {
+        value: Datum<'a>,
+        create_if_missing: bool,
+    },
+    /// Inserts the value before (or after) the addressed array element, or
+    /// under the addressed object key, which must not already exist.
+    Insert { value: Datum<'a>, after: bool },
+    /// Removes the value.
+    Delete,
+}
+
+/// An error returned by [`JsonbPacker::pack_edit_path`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonbPathEditError {
+    /// The target of a set or insert is a scalar.
+    SetInScalar,
+    /// The target of a delete is a scalar.
+    DeleteInScalar,
+    /// The path element at the given one-based position is null.
+    NullPathElement(usize),
+    /// The path element at the given one-based position addresses an array
+    /// but is not an integer.
+    NonIntegerPathElement { position: usize, element: String },
+    /// An insert addresses an object key that already exists.
+    ExistingKey,
+}
+
+impl fmt::Display for JsonbPathEditError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JsonbPathEditError::SetInScalar => f.write_str("cannot set path in scalar"),
+            JsonbPathEditError::DeleteInScalar => f.write_str("cannot delete path in scalar"),
+            JsonbPathEditError::NullPathElement(position) => {
+                write!(f, "path element at position {} is null", position)
+            }
+            JsonbPathEditError::NonIntegerPathElement { position, element } => write!(
+                f,
+                "path element at position {} is not an integer: \"{}\"",
+                position, element
+            ),
+            JsonbPathEditError::ExistingKey => f.write_str("cannot replace existing key"),
+        }
+    }
+}
+
+impl std::error::Error for JsonbPathEditError {}
+
+fn edit_path(
+    packer: &mut RowPacker,
+    datum: Datum,
+    path: &[Datum],
+    level: usize,
+    edit: &JsonbPathEdit,
+) -> Result<(), JsonbPathEditError> {
+    let elem = match path[level] {
+        Datum::String(elem) => elem,
+        Datum::Null => return Err(JsonbPathEditError::NullPathElement(level + 1)),
+        d => panic!("jsonb path elements must be strings: {:?}", d),
+    };
+    let last = level == path.len() - 1;
+    let new_value = match edit {
+        JsonbPathEdit::Set {
+            value,
+            create_if_missing: true,
+        }
+        | JsonbPathEdit::Insert { value, .. } => Some(*value),
+        JsonbPathEdit::Set { .. } | JsonbPathEdit::Delete => None,
+    };
+    match datum {
+        Datum::Map(dict) => {
+            let exists = dict.iter().any(|(k, _)| k == elem);
+            if exists && last && matches!(edit, JsonbPathEdit::Insert { .. }) {
+                return Err(JsonbPathEditError::ExistingKey);
+            }
+            // A missing key is added in sorted position, and only when it is
+            // the last step of the path.
+            let mut pending = if !exists && last { new_value } else { None };
+            packer.push_dict_with(|packer| {
+                for (k, v) in dict.iter() {
+                    if let Some(value) = pending {
+                        if elem < k {
+                            packer.push(Datum::String(elem));
+                            packer.push(value);
+                            pending = None;
+                        }
+                    }
+                    if k != elem {
+                        packer.push(Datum::String(k));
+                        packer.push(v);
+                    } else if !last {
+                        packer.push(Datum::String(k));
+                        edit_path(packer, v, path, level + 1, edit)?;
+                    } else if let JsonbPathEdit::Set { value, .. } = edit {
+                        packer.push(Datum::String(k));
+                        packer.push(*value);
+                    }
+                }
+                if let Some(value) = pending {
+                    packer.push(Datum::String(elem));
+                    packer.push(value);
+                }
+                Ok(())
+            })
+        }
+        Datum::List(list) => {
+            let idx = strconv::parse_int32(elem).map_err(|_| {
+                JsonbPathEditError::NonIntegerPathElement {
+                    position: level + 1,
+                    element: elem.to_string(),
+                }
+            })?;
+            let len = list.iter().count();
+            // Negative indexes count back from the end of the array. Indexes
+            // that fall outside the array address the position just before
+            // its start or just past its end.
+            let idx = usize::try_from(idx).or_else(|_| {
+                len.checked_sub(usize::cast_from(idx.unsigned_abs()))
+                    .ok_or(())
+            });
+            let (prepend, append) = match (idx, new_value) {
+                (Ok(idx), Some(_)) if last => (len == 0, idx >= len && len > 0),
+                (Err(()), Some(_)) if last => (true, false),
+                _ => (false, false),
+            };
+            packer.push_list_with(|packer| {
+                if prepend {
+                    packer.push(new_value.expect("known to be present"));
+                }
+                for (i, e) in list.iter().enumerate() {
+                    if idx != Ok(i) {
+                        packer.push(e);
+                    } else if !last {
+                        edit_path(packer, e, path, level + 1, edit)?;
+                    } else {
+                        match edit {
+                            JsonbPathEdit::Set { value, .. } => packer.push(*value),
+                            JsonbPathEdit::Insert { value, after } => {
+                                if *after {
+                                    packer.push(e);
+                                    packer.push(*value);
+                                } else {
+                                    packer.push(*value);
+                                    packer.push(e);
+                                }
+                            }
+                            JsonbPathEdit::Delete => {}
+                        }
+                    }
+                }
+                if append {
+                    packer.push(new_value.expect("known to be present"));
+                }
+                Ok(())
+            })
+        }
+        // Steps into scalars leave the value unchanged.
+        _ => {
+            packer.push(datum);
+            Ok(())
+        }
+    }
+}
+
+fn deep_merge(packer: &mut RowPacker, a: Datum, b: Datum) {
+    match (a, b) {
+        (Datum::Map(dict_a), Datum::Map(dict_b)) => packer.push_dict_with(|packer| {
+            let mut iter_a = dict_a.iter().peekable();
+            let mut iter_b = dict_b.iter().peekable();
+            loop {
+                match (iter_a.peek(), iter_b.peek()) {
+                    (Some((ka, _)), Some((kb, _))) if ka == kb => {
+                        let (k, va) = iter_a.next().unwrap();
+                        let (_, vb) = iter_b.next().unwrap();
+                        packer.push(Datum::String(k));
+                        deep_merge(packer, va, vb);
+                    }
+                    (Some((ka, _)), Some((kb, _))) if ka < kb => {
+                        let (k, v) = iter_a.next().unwrap();
+                        packer.push(Datum::String(k));
+                        packer.push(v);
+                    }
+                    (_, Some(_)) => {
+                        let (k, v) = iter_b.next().unwrap();
+                        packer.push(Datum::String(k));
+                        packer.push(v);
+                    }
+                    (Some(_), None) => {
+                        let (k, v) = iter_a.next().unwrap();
+                        packer.push(Datum::String(k));
+                        packer.push(v);
+                    }
+                    (None, None) => break,
+                }
+            }
+        }),
+        (_, b) => packer.push(b),
+    }
 }
 
 // The magic internal key name that serde_json uses to indicate that an
@@ -0,0 +1,1093 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! SQL/JSON path language.
+//!
+//! This module implements a subset of the [SQL/JSON path language] over JSON
+//! [`Datum`]s, as produced by [`JsonbRef::into_datum`]. Paths are parsed with
+//! the [`FromStr`] implementation of [`JsonPath`] and evaluated with
+//! [`JsonPath::query`].
+//!
+//! The supported subset covers the `lax` and `strict` modes, member and
+//! element accessors (including wildcards, `last` and `to` ranges), filter
+//! expressions with comparison and boolean operators, `exists`,
+//! `starts with` and `is unknown`, and the `.size()` and `.type()` item
+//! methods. Variables, arithmetic, `like_regex` and the remaining item
+//! methods are not supported.
+//!
+//! [SQL/JSON path language]: https://www.postgresql.org/docs/current/functions-json.html#FUNCTIONS-SQLJSON-PATH
+//! [`JsonbRef::into_datum`]: crate::adt::jsonb::JsonbRef::into_datum
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Peekable;
+use std::str::{Chars, FromStr};
+
+use dec::OrderedDecimal;
+use mz_ore::cast::CastFrom;
+use mz_ore::stack::{CheckedRecursion, RecursionGuard, RecursionLimitError};
+
+use crate::adt::numeric::{self, Numeric};
+use crate::Datum;
+
+/// An error parsing or evaluating a [`JsonPath`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonPathError(String);
+
+impl fmt::Display for JsonPathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for JsonPathError {}
+
+impl From<RecursionLimitError> for JsonPathError {
+    fn from(_: RecursionLimitError) -> JsonPathError {
+        JsonPathError(format!(
+            "jsonpath exceeds nested expression limit of {}",
+            RECURSION_LIMIT
+        ))
+    }
+}
+
+/// The maximum nesting depth of the expressions of a path.
+///
+/// Evaluation recurses along the nesting of the expressions, so this bounds the
+/// stack usage of both parsing and evaluation.
+const RECURSION_LIMIT: usize = 128;
+
+/// A parsed SQL/JSON path expression.
+#[derive(Debug, Clone)]
+pub struct JsonPath {
+    /// Whether the path is evaluated in strict mode, rather than lax mode.
+    strict: bool,
+    expr: Expr,
+}
+
+impl JsonPath {
+    /// Evaluates the path against the JSON datum `root`, returning the
+    /// sequence of JSON items it selects.
+    ///
+    /// A path that is a predicate, like `$.a > 1`, returns a single boolean
+    /// item, or a JSON null if the result of the predicate is unknown.
+    pub fn query<'a>(&'a self, root: Datum<'a>) -> Result<Vec<Datum<'a>>, JsonPathError> {
+        let evaluator = Evaluator {
+            strict: self.strict,
+            root,
+        };
+        evaluator.eval(&self.expr, None)
+    }
+}
+
+impl FromStr for JsonPath {
+    type Err = JsonPathError;
+
+    fn from_str(s: &str) -> Result<JsonPath, JsonPathError> {
+        let mut parser = Parser {
+            tokens: lex(s)?,
+            pos: 0,
+            filter_depth: 0,
+            recursion_guard: RecursionGuard::with_limit(RECURSION_LIMIT),
+        };
+        let strict = if parser.consume_keyword("strict") {
+            true
+        } else {
+            parser.consume_keyword("lax");
+            false
+        };
+        let expr = parser.parse_or()?;
+        match parser.peek() {
+            None => Ok(JsonPath { strict, expr }),
+            Some(token) => Err(syntax_error(Some(token))),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Expr {
+    /// A path starting at `$` or `@`, followed by a chain of steps.
+    Path {
+        root: Root,
+        steps: Vec<Step>,
+    },
+    Literal(Literal),
+    Compare {
+        op: CmpOp,
+        left: Box<Expr>,
+        right: Box<Expr>,
+    },
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Exists(Box<Expr>),
+    StartsWith(Box<Expr>, String),
+    IsUnknown(Box<Expr>),
+}
+
+impl Expr {
+    fn is_predicate(&self) -> bool {
+        !matches!(self, Expr::Path { .. } | Expr::Literal(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Root {
+    /// `$`, the item the path is evaluated against.
+    Context,
+    /// `@`, the item being tested by the innermost filter.
+    Current,
+}
+
+#[derive(Debug, Clone)]
+enum Step {
+    /// `.key` or `."key"`.
+    Member(String),
+    /// `.*`.
+    AnyMember,
+    /// `[*]`.
+    AnyElement,
+    /// `[0, 2 to last]`.
+    Elements(Vec<Subscript>),
+    /// `? (predicate)`.
+    Filter(Box<Expr>),
+    /// `.size()`.
+    Size,
+    /// `.type()`.
+    Type,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Subscript {
+    from: Index,
+    to: Option<Index>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Index {
+    Number(i64),
+    /// `last - n`.
+    Last(i64),
+}
+
+impl Index {
+    fn resolve(&self, last: i64) -> i64 {
+        match self {
+            Index::Number(n) => *n,
+            Index::Last(n) => last.saturating_sub(*n),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Literal {
+    Null,
+    Bool(bool),
+    Number(OrderedDecimal<Numeric>),
+    String(String),
+}
+
+impl Literal {
+    fn datum(&self) -> Datum {
+        match self {
+            Literal::Null => Datum::JsonNull,
+            Literal::Bool(b) => Datum::from(*b),
+            Literal::Number(n) => Datum::Numeric(*n),
+            Literal::String(s) => Datum::String(s),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CmpOp {
+    Eq,
+    NotEq,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+}
+
+impl CmpOp {
+    fn test(&self, ordering: Ordering) -> bool {
+        match self {
+            CmpOp::Eq => ordering.is_eq(),
+            CmpOp::NotEq => ordering.is_ne(),
+            CmpOp::Lt => ordering.is_lt(),
+            CmpOp::Lte => ordering.is_le(),
+            CmpOp::Gt => ordering.is_gt(),
+            CmpOp::Gte => ordering.is_ge(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Dollar,
+    At,
+    Dot,
+    Star,
+    LBracket,
+    RBracket,
+    LParen,
+    RParen,
+    Comma,
+    Question,
+    Minus,
+    Not,
+    And,
+    Or,
+    Cmp(CmpOp),
+    Ident(String),
+    Variable(String),
+    String(String),
+    Number(String),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Token::Dollar => f.write_str("$"),
+            Token::At => f.write_str("@"),
+            Token::Dot => f.write_str("."),
+            Token::Star => f.write_str("*"),
+            Token::LBracket => f.write_str("["),
+            Token::RBracket => f.write_str("]"),
+            Token::LParen => f.write_str("("),
+            Token::RParen => f.write_str(")"),
+            Token::Comma => f.write_str(","),
+            Token::Question => f.write_str("?"),
+            Token::Minus => f.write_str("-"),
+            Token::Not => f.write_str("!"),
+            Token::And => f.write_str("&&"),
+            Token::Or => f.write_str("||"),
+            Token::Cmp(CmpOp::Eq) => f.write_str("=="),
+            Token::Cmp(CmpOp::NotEq) => f.write_str("!="),
+            Token::Cmp(CmpOp::Lt) => f.write_str("<"),
+            Token::Cmp(CmpOp::Lte) => f.write_str("<="),
+            Token::Cmp(CmpOp::Gt) => f.write_str(">"),
+            Token::Cmp(CmpOp::Gte) => f.write_str(">="),
+            Token::Ident(s) | Token::Number(s) => f.write_str(s),
+            Token::Variable(s) => write!(f, "${}", s),
+            Token::String(s) => write!(f, "\"{}\"", s),
+        }
+    }
+}
+
+fn syntax_error(token: Option<&Token>) -> JsonPathError {
+    match token {
+        Some(token) => JsonPathError(format!(
+            "syntax error at or near \"{}\" of jsonpath input",
+            token
+        )),
+        None => JsonPathError("syntax error at end of jsonpath input".into()),
+    }
+}
+
+fn lex(s: &str) -> Result<Vec<Token>, JsonPathError> {
+    fn lex_ident(first: char, chars: &mut Peekable<Chars>) -> String {
+        let mut ident = String::from(first);
+        while let Some(c) = chars.next_if(|c| c.is_alphanumeric() || *c == '_') {
+            ident.push(c);
+        }
+        ident
+    }
+
+    fn lex_digits(number: &mut String, chars: &mut Peekable<Chars>) {
+        while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+            number.push(c);
+        }
+    }
+
+    let mut tokens = vec![];
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '$' => match chars.next_if(|c| c.is_alphabetic() || *c == '_') {
+                Some(c) => Token::Variable(lex_ident(c, &mut chars)),
+                None => Token::Dollar,
+            },
+            '@' => Token::At,
+            '.' => Token::Dot,
+            '*' => Token::Star,
+            '[' => Token::LBracket,
+            ']' => Token::RBracket,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            ',' => Token::Comma,
+            '?' => Token::Question,
+            '-' => Token::Minus,
+            '!' if chars.next_if_eq(&'=').is_some() => Token::Cmp(CmpOp::NotEq),
+            '!' => Token::Not,
+            '=' if chars.next_if_eq(&'=').is_some() => Token::Cmp(CmpOp::Eq),
+            '<' if chars.next_if_eq(&'=').is_some() => Token::Cmp(CmpOp::Lte),
+            '<' if chars.next_if_eq(&'>').is_some() => Token::Cmp(CmpOp::NotEq),
+            '<' => Token::Cmp(CmpOp::Lt),
+            '>' if chars.next_if_eq(&'=').is_some() => Token::Cmp(CmpOp::Gte),
+            '>' => Token::Cmp(CmpOp::Gt),
+            '&' if chars.next_if_eq(&'&').is_some() => Token::And,
+            '|' if chars.next_if_eq(&'|').is_some() => Token::Or,
+            '"' => {
+                let mut string = String::new();
+                loop {
+                    match chars.next() {
+                        None => return Err(syntax_error(None)),
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some('b') => string.push('\u{8}'),
+                            Some('f') => string.push('\u{c}'),
+                            Some('n') => string.push('\n'),
+                            Some('r') => string.push('\r'),
+                            Some('t') => string.push('\t'),
+                            Some('u') => {
+                                let hex: String = chars.by_ref().take(4).collect();
+                                let c = u32::from_str_radix(&hex, 16)
+                                    .ok()
+                                    .and_then(char::from_u32)
+                                    .ok_or_else(|| {
+                                        JsonPathError(format!(
+                                            "invalid Unicode escape sequence \"\\u{}\" \
+                                             in jsonpath input",
+                                            hex
+                                        ))
+                                    })?;
+                                string.push(c);
+                            }
+                            Some(c) => string.push(c),
+                            None => return Err(syntax_error(None)),
+                        },
+                        Some(c) => string.push(c),
+                    }
+                }
+                Token::String(string)
+            }
+            c if c.is_ascii_digit() => {
+                let mut number = String::from(c);
+                lex_digits(&mut number, &mut chars);
+                // Only consume the dot if a fractional part follows, so that
+                // e.g. `$[1].a` still lexes as an accessor.
+                let mut lookahead = chars.clone();
+                if lookahead.next() == Some('.')
+                    && lookahead.next().map_or(false, |c| c.is_ascii_digit())
+                {
+                    number.push(chars.next().expect("peeked"));
+                    lex_digits(&mut number, &mut chars);
+                }
+                if let Some(e) = chars.next_if(|c| *c == 'e' || *c == 'E') {
+                    number.push(e);
+                    if let Some(sign) = chars.next_if(|c| *c == '+' || *c == '-') {
+                        number.push(sign);
+                    }
+                    lex_digits(&mut number, &mut chars);
+                }
+                Token::Number(number)
+            }
+            c if c.is_alphabetic() || c == '_' => Token::Ident(lex_ident(c, &mut chars)),
+            c => {
+                return Err(JsonPathError(format!(
+                    "syntax error at or near \"{}\" of jsonpath input",
+                    c
+                )))
+            }
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    /// The number of filter expressions enclosing the current position, which
+    /// determines whether `@` is allowed.
+    filter_depth: usize,
+    recursion_guard: RecursionGuard,
+}
+
+impl CheckedRecursion for Parser {
+    fn recursion_guard(&self) -> &RecursionGuard {
+        &self.recursion_guard
+    }
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + n)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn consume(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &Token) -> Result<(), JsonPathError> {
+        if self.consume(token) {
+            Ok(())
+        } else {
+            Err(syntax_error(self.peek()))
+        }
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Ident(ident)) if ident == keyword)
+    }
+
+    fn consume_keyword(&mut self, keyword: &str) -> bool {
+        if self.peek_keyword(keyword) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), JsonPathError> {
+        if self.consume_keyword(keyword) {
+            Ok(())
+        } else {
+            Err(syntax_error(self.peek()))
+        }
+    }
+
+    /// Checks that the operand of `token` is a predicate.
+    fn predicate(&self, expr: Expr, token: &Token) -> Result<Box<Expr>, JsonPathError> {
+        if expr.is_predicate() {
+            Ok(Box::new(expr))
+        } else {
+            Err(syntax_error(Some(token)))
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, JsonPathError> {
+        // All nested expressions are parsed through here or `parse_not`.
+        self.checked_recur_mut(|parser| {
+            let mut expr = parser.parse_and()?;
+            while parser.consume(&Token::Or) {
+                let right = parser.parse_and()?;
+                expr = Expr::Or(
+                    parser.predicate(expr, &Token::Or)?,
+                    parser.predicate(right, &Token::Or)?,
+                );
+            }
+            Ok(expr)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, JsonPathError> {
+        let mut expr = self.parse_not()?;
+        while self.consume(&Token::And) {
+            let right = self.parse_not()?;
+            expr = Expr::And(
+                self.predicate(expr, &Token::And)?,
+                self.predicate(right, &Token::And)?,
+            );
+        }
+        Ok(expr)
+    }
+
+    fn parse_not(&mut self) -> Result<Expr, JsonPathError> {
+        if self.consume(&Token::Not) {
+            let expr = self.checked_recur_mut(|parser| parser.parse_not())?;
+            return Ok(Expr::Not(self.predicate(expr, &Token::Not)?));
+        }
+        self.parse_predicate()
+    }
+
+    fn parse_predicate(&mut self) -> Result<Expr, JsonPathError> {
+        if self.peek_keyword("exists") && self.peek_nth(1) == Some(&Token::LParen) {
+            self.pos += 2;
+            let expr = self.parse_or()?;
+            self.expect(&Token::RParen)?;
+            return Ok(Expr::Exists(Box::new(expr)));
+        }
+        let left = self.parse_operand()?;
+        match self.peek() {
+            Some(Token::Cmp(op)) => {
+                let op = *op;
+                self.pos += 1;
+                let right = self.parse_operand()?;
+                Ok(Expr::Compare {
+                    op,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                })
+            }
+            Some(Token::Ident(ident)) if ident == "starts" => {
+                self.pos += 1;
+                self.expect_keyword("with")?;
+                match self.next() {
+                    Some(Token::String(prefix)) => Ok(Expr::StartsWith(Box::new(left), prefix)),
+                    token => Err(syntax_error(token.as_ref())),
+                }
+            }
+            Some(token @ Token::Ident(ident)) if ident == "is" => {
+                let token = token.clone();
+                self.pos += 1;
+                self.expect_keyword("unknown")?;
+                Ok(Expr::IsUnknown(self.predicate(left, &token)?))
+            }
+            Some(Token::Ident(ident)) if ident == "like_regex" => Err(JsonPathError(
+                "like_regex is not supported in jsonpath".into(),
+            )),
+            _ => Ok(left),
+        }
+    }
+
+    fn parse_operand(&mut self) -> Result<Expr, JsonPathError> {
+        let token = self.next();
+        let expr = match token {
+            Some(Token::LParen) => {
+                let expr = self.parse_or()?;
+                self.expect(&Token::RParen)?;
+                expr
+            }
+            Some(Token::Dollar) => Expr::Path {
+                root: Root::Context,
+                steps: self.parse_steps()?,
+            },
+            Some(Token::At) if self.filter_depth > 0 => Expr::Path {
+                root: Root::Current,
+                steps: self.parse_steps()?,
+            },
+            Some(Token::At) => {
+                return Err(JsonPathError("@ is not allowed in root expressions".into()))
+            }
+            Some(Token::Variable(name)) => {
+                return Err(JsonPathError(format!(
+                    "could not find jsonpath variable \"{}\"",
+                    name
+                )))
+            }
+            Some(Token::String(s)) => Expr::Literal(Literal::String(s)),
+            Some(Token::Number(n)) => Expr::Literal(Literal::Number(parse_number(&n)?)),
+            Some(Token::Minus) => match self.next() {
+                Some(Token::Number(n)) => {
+                    let mut n = parse_number(&n)?;
+                    numeric::cx_datum().neg(&mut n.0);
+                    Expr::Literal(Literal::Number(n))
+                }
+                token => return Err(syntax_error(token.as_ref())),
+            },
+            Some(Token::Ident(ident)) if ident == "null" => Expr::Literal(Literal::Null),
+            Some(Token::Ident(ident)) if ident == "true" => Expr::Literal(Literal::Bool(true)),
+            Some(Token::Ident(ident)) if ident == "false" => Expr::Literal(Literal::Bool(false)),
+            token => return Err(syntax_error(token.as_ref())),
+        };
+        Ok(expr)
+    }
+
+    fn parse_steps(&mut self) -> Result<Vec<Step>, JsonPathError> {
+        let mut steps = vec![];
+        loop {
+            let step = match self.peek() {
+                Some(Token::Dot) => {
+                    self.pos += 1;
+                    match self.next() {
+                        Some(Token::Star) => Step::AnyMember,
+                        Some(Token::String(key)) => Step::Member(key),
+                        Some(Token::Ident(name)) if self.consume(&Token::LParen) => {
+                            self.expect(&Token::RParen)?;
+                            match name.as_str() {
+                                "size" => Step::Size,
+                                "type" => Step::Type,
+                                _ => {
+                                    return Err(JsonPathError(format!(
+                                        "jsonpath item method .{}() is not supported",
+                                        name
+                                    )))
+                                }
+                            }
+                        }
+                        Some(Token::Ident(key)) => Step::Member(key),
+                        token => return Err(syntax_error(token.as_ref())),
+                    }
+                }
+                Some(Token::LBracket) => {
+                    self.pos += 1;
+                    if self.consume(&Token::Star) {
+                        self.expect(&Token::RBracket)?;
+                        Step::AnyElement
+                    } else {
+                        let mut subscripts = vec![];
+                        loop {
+                            let from = self.parse_index()?;
+                            let to = if self.consume_keyword("to") {
+                                Some(self.parse_index()?)
+                            } else {
+                                None
+                            };
+                            subscripts.push(Subscript { from, to });
+                            if !self.consume(&Token::Comma) {
+                                break;
+                            }
+                        }
+                        self.expect(&Token::RBracket)?;
+                        Step::Elements(subscripts)
+                    }
+                }
+                Some(Token::Question) => {
+                    self.pos += 1;
+                    self.expect(&Token::LParen)?;
+                    self.filter_depth += 1;
+                    let expr = self.parse_or()?;
+                    self.filter_depth -= 1;
+                    self.expect(&Token::RParen)?;
+                    Step::Filter(self.predicate(expr, &Token::Question)?)
+                }
+                _ => break,
+            };
+            steps.push(step);
+        }
+        Ok(steps)
+    }
+
+    fn parse_index(&mut self) -> Result<Index, JsonPathError> {
+        if self.consume_keyword("last") {
+            if self.consume(&Token::Minus) {
+                return Ok(Index::Last(self.parse_integer()?));
+            }
+            return Ok(Index::Last(0));
+        }
+        if self.consume(&Token::Minus) {
+            return Ok(Index::Number(-self.parse_integer()?));
+        }
+        Ok(Index::Number(self.parse_integer()?))
+    }
+
+    fn parse_integer(&mut self) -> Result<i64, JsonPathError> {
+        match self.next() {
+            Some(Token::Number(n)) => n.parse().map_err(|_| {
+                JsonPathError("jsonpath array subscript is not a single numeric value".into())
+            }),
+            token => Err(syntax_error(token.as_ref())),
+        }
+    }
+}
+
+fn parse_number(n: &str) -> Result<OrderedDecimal<Numeric>, JsonPathError> {
+    let mut cx = numeric::cx_datum();
+    let d = cx
+        .parse(n)
+        .map_err(|_| JsonPathError(format!("invalid numeric literal \"{}\" in jsonpath", n)))?;
+    if cx.status().any() {
+        return Err(JsonPathError(format!(
+            "invalid numeric literal \"{}\" in jsonpath",
+            n
+        )));
+    }
+    Ok(OrderedDecimal(d))
+}
+
+/// The result of a predicate, which follows SQL's three-valued logic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tri {
+    True,
+    False,
+    Unknown,
+}
+
+impl From<bool> for Tri {
+    fn from(b: bool) -> Tri {
+        if b {
+            Tri::True
+        } else {
+            Tri::False
+        }
+    }
+}
+
+struct Evaluator<'a> {
+    strict: bool,
+    root: Datum<'a>,
+}
+
+impl<'a> Evaluator<'a> {
+    fn eval(
+        &self,
+        expr: &'a Expr,
+        current: Option<Datum<'a>>,
+    ) -> Result<Vec<Datum<'a>>, JsonPathError> {
+        match expr {
+            Expr::Path { root, steps } => {
+                let root = match root {
+                    Root::Context => self.root,
+                    Root::Current => current.expect("@ is only parsed within filters"),
+                };
+                let mut items = vec![root];
+                for step in steps {
+                    let mut next = vec![];
+                    for item in items {
+                        self.step(step, item, true, &mut next)?;
+                    }
+                    items = next;
+                }
+                Ok(items)
+            }
+            Expr::Literal(literal) => Ok(vec![literal.datum()]),
+            _ => {
+                let datum = match self.test(expr, current) {
+                    Tri::True => Datum::True,
+                    Tri::False => Datum::False,
+                    Tri::Unknown => Datum::JsonNull,
+                };
+                Ok(vec![datum])
+            }
+        }
+    }
+
+    /// Applies `step` to `item`, pushing the selected items onto `out`.
+    ///
+    /// In lax mode, accessors that expect an object or a filter automatically
+    /// unwrap one level of arrays if `unwrap` is set.
+    fn step(
+        &self,
+        step: &'a Step,
+        item: Datum<'a>,
+        unwrap: bool,
+        out: &mut Vec<Datum<'a>>,
+    ) -> Result<(), JsonPathError> {
+        match (step, item) {
+            (Step::Member(_) | Step::AnyMember | Step::Filter(_), Datum::List(list))
+                if unwrap && !self.strict =>
+            {
+                for elem in list.iter() {
+                    self.step(step, elem, false, out)?;
+                }
+            }
+            (Step::Member(key), Datum::Map(map)) => match map.iter().find(|(k, _)| k == key) {
+                Some((_, value)) => out.push(value),
+                None if self.strict => {
+                    return Err(JsonPathError(format!(
+                        "JSON object does not contain key \"{}\"",
+                        key
+                    )))
+                }
+                None => {}
+            },
+            (Step::Member(_), _) if self.strict => {
+                return Err(JsonPathError(
+                    "jsonpath member accessor can only be applied to an object".into(),
+                ))
+            }
+            (Step::AnyMember, Datum::Map(map)) => out.extend(map.iter().map(|(_, v)| v)),
+            (Step::AnyMember, _) if self.strict => {
+                return Err(JsonPathError(
+                    "jsonpath wildcard member accessor can only be applied to an object".into(),
+                ))
+            }
+            (Step::Member(_) | Step::AnyMember, _) => {}
+            (Step::AnyElement, Datum::List(list)) => out.extend(list.iter()),
+            (Step::AnyElement, _) if self.strict => {
+                return Err(JsonPathError(
+                    "jsonpath wildcard array accessor can only be applied to an array".into(),
+                ))
+            }
+            (Step::AnyElement, item) => out.push(item),
+            (Step::Elements(subscripts), item) => {
+                let elems: Vec<_> = match item {
+                    Datum::List(list) => list.iter().collect(),
+                    _ if self.strict => {
+                        return Err(JsonPathError(
+                            "jsonpath array accessor can only be applied to an array".into(),
+                        ))
+                    }
+                    item => vec![item],
+                };
+                let last = i64::try_from(elems.len()).expect("array length fits in i64") - 1;
+                for subscript in subscripts {
+                    let from = subscript.from.resolve(last);
+                    let to = subscript.to.map_or(from, |to| to.resolve(last));
+                    if self.strict && (from < 0 || to > last || from > to) {
+                        return Err(JsonPathError(
+                            "jsonpath array subscript is out of bounds".into(),
+                        ));
+                    }
+                    for i in from.max(0)..=to.min(last) {
+                        out.push(elems[usize::try_from(i).expect("known non-negative")]);
+                    }
+                }
+            }
+            (Step::Filter(predicate), item) => {
+                if self.test(predicate, Some(item)) == Tri::True {
+                    out.push(item);
+                }
+            }
+            (Step::Size, Datum::List(list)) => {
+                let size = Numeric::from(u64::cast_from(list.iter().count()));
+                out.push(Datum::Numeric(OrderedDecimal(size)));
+            }
+            (Step::Size, _) if self.strict => {
+                return Err(JsonPathError(
+                    "jsonpath item method .size() can only be applied to an array".into(),
+                ))
+            }
+            (Step::Size, _) => out.push(Datum::Numeric(OrderedDecimal(Numeric::from(1)))),
+            (Step::Type, item) => out.push(Datum::String(json_type_name(item))),
+        }
+        Ok(())
+    }
+
+    /// Evaluates the operand of a comparison or `starts with`, which in lax
+    /// mode unwraps arrays.
+    fn operand(
+        &self,
+        expr: &'a Expr,
+        current: Option<Datum<'a>>,
+    ) -> Result<Vec<Datum<'a>>, JsonPathError> {
+        let items = self.eval(expr, current)?;
+        if self.strict {
+            return Ok(items);
+        }
+        let mut unwrapped = vec![];
+        for item in items {
+            match item {
+                Datum::List(list) => unwrapped.extend(list.iter()),
+                item => unwrapped.push(item),
+            }
+        }
+        Ok(unwrapped)
+    }
+
+    /// Evaluates a predicate. Errors within predicates are not raised, but
+    /// make the result unknown.
+    fn test(&self, expr: &'a Expr, current: Option<Datum<'a>>) -> Tri {
+        match expr {
+            Expr::Compare { op, left, right } => {
+                let (Ok(left), Ok(right)) =
+                    (self.operand(left, current), self.operand(right, current))
+                else {
+                    return Tri::Unknown;
+                };
+                self.any(
+                    left.iter()
+                        .flat_map(|l| right.iter().map(move |r| compare(*op, *l, *r))),
+                )
+            }
+            Expr::StartsWith(expr, prefix) => match self.operand(expr, current) {
+                Ok(items) => self.any(items.into_iter().map(|item| match item {
+                    Datum::String(s) => s.starts_with(prefix.as_str()).into(),
+                    _ => Tri::Unknown,
+                })),
+                Err(_) => Tri::Unknown,
+            },
+            Expr::Exists(expr) => match self.eval(expr, current) {
+                Ok(items) => (!items.is_empty()).into(),
+                Err(_) => Tri::Unknown,
+            },
+            Expr::And(left, right) => match (self.test(left, current), self.test(right, current)) {
+                (Tri::False, _) | (_, Tri::False) => Tri::False,
+                (Tri::True, Tri::True) => Tri::True,
+                _ => Tri::Unknown,
+            },
+            Expr::Or(left, right) => match (self.test(left, current), self.test(right, current)) {
+                (Tri::True, _) | (_, Tri::True) => Tri::True,
+                (Tri::False, Tri::False) => Tri::False,
+                _ => Tri::Unknown,
+            },
+            Expr::Not(expr) => match self.test(expr, current) {
+                Tri::True => Tri::False,
+                Tri::False => Tri::True,
+                Tri::Unknown => Tri::Unknown,
+            },
+            Expr::IsUnknown(expr) => (self.test(expr, current) == Tri::Unknown).into(),
+            Expr::Path { .. } | Expr::Literal(_) => {
+                unreachable!("parser only accepts predicates in predicate position")
+            }
+        }
+    }
+
+    /// Combines the results of testing each pair of items. In lax mode, any
+    /// true result wins; in strict mode, any unknown result wins.
+    fn any(&self, results: impl Iterator<Item = Tri>) -> Tri {
+        let mut found = false;
+        let mut unknown = false;
+        for result in results {
+            match result {
+                Tri::True if !self.strict => return Tri::True,
+                Tri::True => found = true,
+                Tri::Unknown if self.strict => return Tri::Unknown,
+                Tri::Unknown => unknown = true,
+                Tri::False => {}
+            }
+        }
+        if found {
+            Tri::True
+        } else if unknown {
+            Tri::Unknown
+        } else {
+            Tri::False
+        }
+    }
+}
+
+fn compare(op: CmpOp, left: Datum, right: Datum) -> Tri {
+    let ordering = match (left, right) {
+        (Datum::JsonNull, Datum::JsonNull) => Ordering::Equal,
+        // Null is only equal to null, but is comparable to any other item.
+        (Datum::JsonNull, _) | (_, Datum::JsonNull) => return (op == CmpOp::NotEq).into(),
+        (Datum::True | Datum::False, Datum::True | Datum::False) => {
+            (left == Datum::True).cmp(&(right == Datum::True))
+        }
+        (Datum::Numeric(l), Datum::Numeric(r)) => l.cmp(&r),
+        (Datum::String(l), Datum::String(r)) => l.cmp(r),
+        // Arrays, objects and items of different types are not comparable.
+        _ => return Tri::Unknown,
+    };
+    op.test(ordering).into()
+}
+
+fn json_type_name(item: Datum) -> &'static str {
+    match item {
+        Datum::JsonNull => "null",
+        Datum::True | Datum::False => "boolean",
+        Datum::Numeric(_) => "number",
+        Datum::String(_) => "string",
+        Datum::List(_) => "array",
+        Datum::Map(_) => "object",
+        _ => unreachable!("not a JSON datum: {:?}", item),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adt::jsonb::{Jsonb, JsonbRef};
+
+    use super::*;
+
+    fn query(json: &str, path: &str) -> Result<Vec<String>, JsonPathError> {
+        let jsonb: Jsonb = json.parse().unwrap();
+        let path: JsonPath = path.parse()?;
+        let items = path.query(jsonb.as_ref().into_datum())?;
+        Ok(items
+            .into_iter()
+            .map(|item| JsonbRef::from_datum(item).to_string())
+            .collect())
+    }
+
+    #[mz_ore::test]
+    fn test_jsonpath_accessors() {
+        let json = r#"{"a": {"b": [1, 2, 3]}, "c": [{"d": 1}, {"d": 2}, {"e": 3}]}"#;
+        assert_eq!(query(json, "$.a.b[0]").unwrap(), vec!["1"]);
+        assert_eq!(query(json, "$.a.b[last]").unwrap(), vec!["3"]);
+        assert_eq!(query(json, "$.a.b[1 to last]").unwrap(), vec!["2", "3"]);
+        assert_eq!(query(json, "$.a.b[*]").unwrap(), vec!["1", "2", "3"]);
+        assert_eq!(query(json, "$.a.\"b\".size()").unwrap(), vec!["3"]);
+        assert_eq!(query(json, "$.c.type()").unwrap(), vec![r#""array""#]);
+        // Lax mode unwraps arrays and ignores missing keys.
+        assert_eq!(query(json, "$.c.d").unwrap(), vec!["1", "2"]);
+        assert_eq!(query(json, "lax $.missing").unwrap(), Vec::<String>::new());
+        assert_eq!(
+            query(json, "strict $.missing").unwrap_err().to_string(),
+            r#"JSON object does not contain key "missing""#
+        );
+        assert_eq!(
+            query(json, "strict $.c.d").unwrap_err().to_string(),
+            "jsonpath member accessor can only be applied to an object"
+        );
+        assert_eq!(
+            query(json, "strict $.c[*].d").unwrap_err().to_string(),
+            r#"JSON object does not contain key "d""#
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_jsonpath_filters() {
+        let json =
+            r#"{"items": [{"price": 5, "name": "a"}, {"price": 15, "name": "b"}, {"name": "c"}]}"#;
+        assert_eq!(
+            query(json, "$.items[*] ? (@.price > 10).name").unwrap(),
+            vec![r#""b""#]
+        );
+        assert_eq!(
+            query(json, "$.items ? (@.price < 10 || !exists(@.price)).name").unwrap(),
+            vec![r#""a""#, r#""c""#]
+        );
+        assert_eq!(
+            query(json, r#"$.items ? (@.name starts with "b").price"#).unwrap(),
+            vec!["15"]
+        );
+        assert_eq!(
+            query(json, "$.items ? ((@.price > \"x\") is unknown).name").unwrap(),
+            vec![r#""a""#, r#""b""#]
+        );
+        assert_eq!(query(json, "$.items[*].price > 10").unwrap(), vec!["true"]);
+        assert_eq!(
+            query(json, "$.items[*].price > \"x\"").unwrap(),
+            vec!["null"]
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_jsonpath_syntax_errors() {
+        for (path, err) in [
+            ("$.", "syntax error at end of jsonpath input"),
+            ("$ $", r#"syntax error at or near "$" of jsonpath input"#),
+            ("@.a", "@ is not allowed in root expressions"),
+            (
+                "$ ? (@.a)",
+                r#"syntax error at or near "?" of jsonpath input"#,
+            ),
+            ("$.a ? (@ == $x)", r#"could not find jsonpath variable "x""#),
+            (
+                "$.a.double()",
+                "jsonpath item method .double() is not supported",
+            ),
+        ] {
+            assert_eq!(path.parse::<JsonPath>().unwrap_err().to_string(), err);
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_jsonpath_recursion_limit() {
+        let nested = |depth| format!("{}$ == 1{}", "(".repeat(depth), ")".repeat(depth));
+        assert_eq!(
+            query("1", &nested(RECURSION_LIMIT - 2)).unwrap(),
+            vec!["true"]
+        );
+        assert_eq!(
+            nested(RECURSION_LIMIT)
+                .parse::<JsonPath>()
+                .unwrap_err()
+                .to_string(),
+            "jsonpath exceeds nested expression limit of 128"
+        );
+        assert_eq!(
+            format!("{}$ == 1", "!".repeat(RECURSION_LIMIT + 1))
+                .parse::<JsonPath>()
+                .unwrap_err()
+                .to_string(),
+            "jsonpath exceeds nested expression limit of 128"
+        );
+        assert_eq!(
+            format!(
+                "${}",
+                " ? (@".repeat(RECURSION_LIMIT) + &")".repeat(RECURSION_LIMIT)
+            )
+            .parse::<JsonPath>()
+            .unwrap_err()
+            .to_string(),
+            "jsonpath exceeds nested expression limit of 128"
+        );
+    }
+}
@@ -24,6 +24,7 @@ use mz_persist_types::dyn_struct::{ColumnsMut, ColumnsRef, DynStructCfg, Validit
 use mz_persist_types::stats::{AtomicBytesStats, BytesStats, DynStats, OptionStats, StatsFn};
 use mz_persist_types::Codec;
 use mz_proto::{ProtoType, RustType, TryFromProtoError};
+use prost::encoding::{decode_key, decode_varint, WireType};
 use prost::Message;
 use uuid::Uuid;
 
@@ -1017,6 +1018,44 @@ impl TryFrom<&ProtoRow> for Row {
     }
 }
 
+impl Row {
+    /// Decodes a [Row] from an encoded [ProtoRow], only decoding the datums at
+    /// the positions set in `demand`.
+    ///
+    /// Datums that are not demanded (including any past the end of `demand`)
+    /// are skipped over in `buf` without being decoded, and replaced with
+    /// [Datum::Null]. This lets callers that only ever inspect a subset of the
+    /// columns skip the cost of decoding the rest.
+    pub fn decode_proto_projected(mut buf: &[u8], demand: &[bool]) -> Result<Self, String> {
+        let mut row = Row::default();
+        let mut packer = row.packer();
+        let mut idx = 0;
+        while !buf.is_empty() {
+            // Each datum is an occurrence of the `datums` field, which is the
+            // only field of a `ProtoRow`.
+            let (tag, wire_type) = decode_key(&mut buf).map_err(|err| err.to_string())?;
+            if tag != 1 || wire_type != WireType::LengthDelimited {
+                return Err(format!("unexpected field {tag} in ProtoRow"));
+            }
+            let len = decode_varint(&mut buf).map_err(|err| err.to_string())?;
+            let len = usize::cast_from(len);
+            if len > buf.len() {
+                return Err("truncated ProtoRow".into());
+            }
+            let (datum, rest) = buf.split_at(len);
+            buf = rest;
+            if demand.get(idx).copied().unwrap_or(false) {
+                let datum = ProtoDatum::decode(datum).map_err(|err| err.to_string())?;
+                packer.try_push_proto(&datum)?;
+            } else {
+                packer.push(Datum::Null);
+            }
+            idx += 1;
+        }
+        Ok(row)
+    }
+}
+
 impl RustType<ProtoRow> for Row {
     fn into_proto(&self) -> ProtoRow {
         let datums = self.iter().map(|x| x.into()).collect();
@@ -1208,6 +1247,46 @@ mod tests {
         );
     }
 
+    #[mz_ore::test]
+    fn proto_projected() {
+        let (_schema, row) = schema_and_row();
+        let mut buf = Vec::new();
+        row.encode(&mut buf);
+        let arity = row.iter().count();
+
+        // Demanding every column is the same as a full decode.
+        let all = vec![true; arity];
+        assert_eq!(Row::decode_proto_projected(&buf, &all), Ok(row.clone()));
+
+        // Undemanded columns, including those past the end of `demand`, are
+        // replaced with nulls.
+        let demand = [false, true];
+        let expected =
+            Row::pack(
+                row.iter()
+                    .enumerate()
+                    .map(|(idx, datum)| if idx == 1 { datum } else { Datum::Null }),
+            );
+        assert_eq!(Row::decode_proto_projected(&buf, &demand), Ok(expected));
+
+        // Undemanded columns are not decoded at all, so their encoding may be
+        // invalid.
+        let invalid = ProtoRow {
+            datums: vec![
+                ProtoDatum {
+                    datum_type: Some(DatumType::Other(ProtoDatumOther::Unknown.into())),
+                },
+                ProtoDatum::from(Datum::Int32(1)),
+            ],
+        };
+        let buf = invalid.encode_to_vec();
+        assert!(Row::decode_proto_projected(&buf, &[true, true]).is_err());
+        assert_eq!(
+            Row::decode_proto_projected(&buf, &[false, true]),
+            Ok(Row::pack([Datum::Null, Datum::Int32(1)]))
+        );
+    }
+
     fn scalar_type_columnar_roundtrip(scalar_type: ScalarType) {
         let skip_decode = is_no_stats_type(&scalar_type);
 
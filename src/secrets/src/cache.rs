@@ -173,6 +173,13 @@ impl SecretsReader for CachingSecretsReader {
 
         Ok(value)
     }
+
+    fn invalidate(&self, id: GlobalId) {
+        self.cache
+            .write()
+            .expect("CachingSecretsReader panicked!")
+            .remove(&id);
+    }
 }
 
 #[cfg(test)]
@@ -247,6 +254,30 @@ mod test {
         assert_eq!(reads[1], id);
     }
 
+    #[mz_ore::test(tokio::test)]
+    async fn test_invalidating_secret() {
+        let controller = InMemorySecretsController::new();
+        let testing_reader = TestingSecretsReader::new(controller.reader());
+        let caching_reader = CachingSecretsReader::new(Arc::new(testing_reader.clone()));
+
+        let id = GlobalId::User(1);
+
+        // Store our secret and read it once to populate the cache.
+        controller.ensure(id, &[42]).await.expect("success");
+        caching_reader.read(id).await.expect("success");
+
+        // Rotate the secret behind the cache's back.
+        controller.ensure(id, &[43]).await.expect("success");
+        assert_eq!(caching_reader.read(id).await.expect("success"), vec![42]);
+
+        // Once invalidated, we should observe the new value.
+        caching_reader.invalidate(id);
+        assert_eq!(caching_reader.read(id).await.expect("success"), vec![43]);
+
+        let reads = testing_reader.drain();
+        assert_eq!(reads, vec![id, id]);
+    }
+
     #[mz_ore::test(tokio::test)]
     async fn test_disabling_cache() {
         let controller = InMemorySecretsController::new();
@@ -129,6 +129,14 @@ pub trait SecretsReader: Debug + Send + Sync {
         let contents = self.read(id).await?;
         String::from_utf8(contents).context("converting secret value to string")
     }
+
+    /// Discards any cached copy of the specified secret, so that the next read
+    /// observes its current contents.
+    ///
+    /// Used when a secret is suspected to have been rotated, e.g. because a
+    /// credential it holds was rejected. Readers that do not cache need not do
+    /// anything.
+    fn invalidate(&self, _id: GlobalId) {}
 }
 
 #[derive(Debug)]
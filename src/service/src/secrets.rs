@@ -13,8 +13,10 @@ use std::sync::Arc;
 use clap::ArgEnum;
 use mz_aws_secrets_controller::AwsSecretsClient;
 use mz_orchestrator_kubernetes::secrets::KubernetesSecretsReader;
-use mz_orchestrator_process::secrets::ProcessSecretsReader;
+use mz_orchestrator_process::secrets::{ProcessSecretsReader, SecretsKeyring};
+use mz_secrets::cache::CachingSecretsReader;
 use mz_secrets::SecretsReader;
+use mz_vault_secrets_controller::{VaultAuth, VaultConfig, VaultSecretsClient};
 
 #[derive(clap::Parser, Clone, Debug)]
 pub struct SecretsReaderCliArgs {
@@ -29,6 +31,13 @@ pub struct SecretsReaderCliArgs {
         env = "SECRETS_READER_LOCAL_FILE_DIR"
     )]
     pub secrets_reader_local_file_dir: Option<PathBuf>,
+    /// When using the process secrets reader, a file containing the master
+    /// keys with which the secrets are encrypted.
+    ///
+    /// If unspecified, the keys are read from the `MZ_SECRETS_MASTER_KEY`
+    /// environment variable, if it is set.
+    #[structopt(long, env = "SECRETS_READER_LOCAL_FILE_MASTER_KEY_FILE")]
+    pub secrets_reader_local_file_master_key_file: Option<PathBuf>,
     /// When using the Kubernetes secrets reader, the Kubernetes context to
     /// load.
     #[structopt(
@@ -50,6 +59,44 @@ pub struct SecretsReaderCliArgs {
         env = "SECRETS_READER_AWS_PREFIX"
     )]
     pub secrets_reader_aws_prefix: Option<String>,
+    /// When using the Vault secrets reader, the address of the Vault server.
+    ///
+    /// Credentials are not accepted on the command line. They are read from
+    /// `--secrets-reader-vault-token-file`, or from the `VAULT_ROLE_ID` and
+    /// `VAULT_SECRET_ID` or `VAULT_TOKEN` environment variables.
+    #[structopt(
+        long,
+        required_if_eq("secrets-reader", "vault"),
+        env = "SECRETS_READER_VAULT_ADDRESS"
+    )]
+    pub secrets_reader_vault_address: Option<String>,
+    /// When using the Vault secrets reader, the mount path of the KV version 2
+    /// secrets engine.
+    #[structopt(
+        long,
+        required_if_eq("secrets-reader", "vault"),
+        env = "SECRETS_READER_VAULT_MOUNT"
+    )]
+    pub secrets_reader_vault_mount: Option<String>,
+    /// When using the Vault secrets reader, the path under the mount at which
+    /// secrets are stored.
+    #[structopt(
+        long,
+        required_if_eq("secrets-reader", "vault"),
+        env = "SECRETS_READER_VAULT_PREFIX"
+    )]
+    pub secrets_reader_vault_prefix: Option<String>,
+    /// When using the Vault secrets reader, the Vault Enterprise namespace.
+    #[structopt(long, env = "SECRETS_READER_VAULT_NAMESPACE")]
+    pub secrets_reader_vault_namespace: Option<String>,
+    /// When using the Vault secrets reader, a file containing the token with
+    /// which to authenticate, e.g. one maintained by a Vault agent.
+    #[structopt(long, env = "SECRETS_READER_VAULT_TOKEN_FILE")]
+    pub secrets_reader_vault_token_file: Option<PathBuf>,
+    /// When using the Vault secrets reader, the mount path of the AppRole auth
+    /// method.
+    #[structopt(long, env = "SECRETS_READER_VAULT_APPROLE_MOUNT")]
+    pub secrets_reader_vault_approle_mount: Option<String>,
 }
 
 #[derive(ArgEnum, Debug, Clone, Copy)]
@@ -57,6 +104,7 @@ pub enum SecretsControllerKind {
     LocalFile,
     Kubernetes,
     AwsSecretsManager,
+    Vault,
 }
 
 impl SecretsReaderCliArgs {
@@ -65,7 +113,10 @@ impl SecretsReaderCliArgs {
         match self.secrets_reader {
             SecretsControllerKind::LocalFile => {
                 let dir = self.secrets_reader_local_file_dir.expect("clap enforced");
-                Ok(Arc::new(ProcessSecretsReader::new(dir)))
+                let keyring = SecretsKeyring::load(
+                    self.secrets_reader_local_file_master_key_file.as_deref(),
+                )?;
+                Ok(Arc::new(ProcessSecretsReader::new(dir, keyring)))
             }
             SecretsControllerKind::Kubernetes => {
                 let context = self
@@ -78,6 +129,22 @@ impl SecretsReaderCliArgs {
                 let prefix = self.secrets_reader_aws_prefix.expect("clap enforced");
                 Ok(Arc::new(AwsSecretsClient::new(&region, &prefix).await))
             }
+            SecretsControllerKind::Vault => {
+                let auth = VaultAuth::from_env(
+                    self.secrets_reader_vault_token_file,
+                    self.secrets_reader_vault_approle_mount,
+                )?;
+                let client = VaultSecretsClient::new(VaultConfig {
+                    address: self.secrets_reader_vault_address.expect("clap enforced"),
+                    mount: self.secrets_reader_vault_mount.expect("clap enforced"),
+                    prefix: self.secrets_reader_vault_prefix.expect("clap enforced"),
+                    namespace: self.secrets_reader_vault_namespace,
+                    auth,
+                })?;
+                // Avoid a round trip to Vault, and potentially a login, on
+                // every read.
+                Ok(Arc::new(CachingSecretsReader::new(Arc::new(client))))
+            }
         }
     }
 
@@ -87,7 +154,7 @@ impl SecretsReaderCliArgs {
     pub fn to_flags(&self) -> Vec<String> {
         match self.secrets_reader {
             SecretsControllerKind::LocalFile => {
-                vec![
+                let mut flags = vec![
                     "--secrets-reader=local-file".to_string(),
                     format!(
                         "--secrets-reader-local-file-dir={}",
@@ -96,7 +163,14 @@ impl SecretsReaderCliArgs {
                             .expect("initialized correctly")
                             .display()
                     ),
-                ]
+                ];
+                if let Some(key_file) = &self.secrets_reader_local_file_master_key_file {
+                    flags.push(format!(
+                        "--secrets-reader-local-file-master-key-file={}",
+                        key_file.display()
+                    ));
+                }
+                flags
             }
             SecretsControllerKind::Kubernetes => {
                 vec![
@@ -126,6 +200,42 @@ impl SecretsReaderCliArgs {
                     ),
                 ]
             }
+            SecretsControllerKind::Vault => {
+                let mut flags = vec![
+                    "--secrets-reader=vault".to_string(),
+                    format!(
+                        "--secrets-reader-vault-address={}",
+                        self.secrets_reader_vault_address
+                            .as_ref()
+                            .expect("initialized correctly")
+                    ),
+                    format!(
+                        "--secrets-reader-vault-mount={}",
+                        self.secrets_reader_vault_mount
+                            .as_ref()
+                            .expect("initialized correctly")
+                    ),
+                    format!(
+                        "--secrets-reader-vault-prefix={}",
+                        self.secrets_reader_vault_prefix
+                            .as_ref()
+                            .expect("initialized correctly")
+                    ),
+                ];
+                if let Some(namespace) = &self.secrets_reader_vault_namespace {
+                    flags.push(format!("--secrets-reader-vault-namespace={}", namespace));
+                }
+                if let Some(token_file) = &self.secrets_reader_vault_token_file {
+                    flags.push(format!(
+                        "--secrets-reader-vault-token-file={}",
+                        token_file.display()
+                    ));
+                }
+                if let Some(mount) = &self.secrets_reader_vault_approle_mount {
+                    flags.push(format!("--secrets-reader-vault-approle-mount={}", mount));
+                }
+                flags
+            }
         }
     }
 }
@@ -376,6 +376,7 @@ pub enum SourceIncludeMetadataType {
     Partition,
     Offset,
     Headers,
+    Topic,
 }
 
 impl AstDisplay for SourceIncludeMetadataType {
@@ -386,6 +387,7 @@ impl AstDisplay for SourceIncludeMetadataType {
             SourceIncludeMetadataType::Partition => f.write_str("PARTITION"),
             SourceIncludeMetadataType::Offset => f.write_str("OFFSET"),
             SourceIncludeMetadataType::Headers => f.write_str("HEADERS"),
+            SourceIncludeMetadataType::Topic => f.write_str("TOPIC"),
         }
     }
 }
@@ -409,14 +411,14 @@ impl AstDisplay for SourceIncludeMetadata {
 impl_display!(SourceIncludeMetadata);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum Envelope {
+pub enum Envelope<T: AstInfo> {
     None,
-    Debezium(DbzMode),
+    Debezium(DbzMode<T>),
     Upsert,
     CdcV2,
 }
 
-impl Envelope {
+impl<T: AstInfo> Envelope<T> {
     /// `true` iff Materialize is expected to crash or exhibit UB
     /// when attempting to ingest data starting at an offset other than zero.
     pub fn requires_all_input(&self) -> bool {
@@ -424,14 +426,14 @@ impl Envelope {
             Envelope::None => false,
             // TODO[btv] - Adjust this if we change Dbz semantics
             // (why is this a parser-level concept, anyway? Should it be moved?)
-            Envelope::Debezium(DbzMode::Plain) => false,
+            Envelope::Debezium(_) => false,
             Envelope::Upsert => false,
             Envelope::CdcV2 => true,
         }
     }
 }
 
-impl AstDisplay for Envelope {
+impl<T: AstInfo> AstDisplay for Envelope<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
             Self::None => {
@@ -451,7 +453,7 @@ impl AstDisplay for Envelope {
         }
     }
 }
-impl_display!(Envelope);
+impl_display_t!(Envelope);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SubscribeOutput<T: AstInfo> {
@@ -519,23 +521,30 @@ impl<T: AstInfo> AstDisplay for Format<T> {
 impl_display_t!(Format);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum DbzMode {
-    /// There is now only one `DEBEZIUM` envelope,
-    /// which has upsert semantics in sources and classic
-    /// semantics in sinks.
+pub enum DbzMode<T: AstInfo> {
+    /// The bare `DEBEZIUM` envelope, which has upsert semantics in sources
+    /// and classic semantics in sinks.
     Plain,
+    /// `DEBEZIUM (TRANSACTION METADATA (...))`, which holds back records in
+    /// sources until the upstream transaction they belong to is complete.
+    TxMetadata(Vec<DbzTxMetadataOption<T>>),
 }
 
-impl AstDisplay for DbzMode {
-    fn fmt<W: fmt::Write>(&self, _f: &mut AstFormatter<W>) {
+impl<T: AstInfo> AstDisplay for DbzMode<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
             // We interpret the bare keyword `DEBEZIUM` as debezium upsert, so don't
             // display anything here.
             Self::Plain => {}
+            Self::TxMetadata(options) => {
+                f.write_str(" (TRANSACTION METADATA (");
+                f.write_node(&display::comma_separated(options));
+                f.write_str("))");
+            }
         }
     }
 }
-impl_display!(DbzMode);
+impl_display_t!(DbzMode);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum DbzTxMetadataOption<T: AstInfo> {
@@ -922,6 +931,7 @@ pub enum KafkaConfigOptionName {
     IsolationLevel,
     Topic,
     TopicMetadataRefreshIntervalMs,
+    TopicPattern,
     TransactionTimeoutMs,
     StartTimestamp,
     StartOffset,
@@ -944,6 +954,7 @@ impl AstDisplay for KafkaConfigOptionName {
             KafkaConfigOptionName::TopicMetadataRefreshIntervalMs => {
                 "TOPIC METADATA REFRESH INTERVAL MS"
             }
+            KafkaConfigOptionName::TopicPattern => "TOPIC PATTERN",
             KafkaConfigOptionName::TransactionTimeoutMs => "TRANSACTION TIMEOUT MS",
             KafkaConfigOptionName::StartOffset => "START OFFSET",
             KafkaConfigOptionName::StartTimestamp => "START TIMESTAMP",
@@ -1007,6 +1018,12 @@ pub enum PgConfigOptionName {
     Publication,
     /// Columns whose types you want to unconditionally format as text
     TextColumns,
+    /// Tables whose contents are retracted when they are truncated upstream
+    TruncateRetract,
+    /// Tables whose upstream truncations are ignored
+    TruncateIgnore,
+    /// Tables that are ingested in upsert mode, keyed by their primary key
+    UpsertTables,
 }
 
 impl AstDisplay for PgConfigOptionName {
@@ -1015,6 +1032,9 @@ impl AstDisplay for PgConfigOptionName {
             PgConfigOptionName::Details => "DETAILS",
             PgConfigOptionName::Publication => "PUBLICATION",
             PgConfigOptionName::TextColumns => "TEXT COLUMNS",
+            PgConfigOptionName::TruncateRetract => "TRUNCATE RETRACT",
+            PgConfigOptionName::TruncateIgnore => "TRUNCATE IGNORE",
+            PgConfigOptionName::UpsertTables => "UPSERT TABLES",
         })
     }
 }
@@ -1163,6 +1183,12 @@ pub enum CreateSinkConnection<T: AstInfo> {
         connection: KafkaConnection<T>,
         key: Option<KafkaSinkKey>,
     },
+    Postgres {
+        /// The postgres connection.
+        connection: T::ItemName,
+        options: Vec<PgSinkOption<T>>,
+        key: Option<KafkaSinkKey>,
+    },
 }
 
 impl<T: AstInfo> AstDisplay for CreateSinkConnection<T> {
@@ -1175,11 +1201,60 @@ impl<T: AstInfo> AstDisplay for CreateSinkConnection<T> {
                     f.write_node(key);
                 }
             }
+            CreateSinkConnection::Postgres {
+                connection,
+                options,
+                key,
+            } => {
+                f.write_str("POSTGRES CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+                if let Some(key) = key.as_ref() {
+                    f.write_node(key);
+                }
+            }
         }
     }
 }
 impl_display_t!(CreateSinkConnection);
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PgSinkOptionName {
+    /// The table the sink writes to, optionally qualified by its schema
+    Table,
+}
+
+impl AstDisplay for PgSinkOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            PgSinkOptionName::Table => "TABLE",
+        })
+    }
+}
+impl_display!(PgSinkOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An option in a `INTO POSTGRES CONNECTION ...` clause.
+pub struct PgSinkOption<T: AstInfo> {
+    pub name: PgSinkOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for PgSinkOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(PgSinkOption);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct KafkaSinkKey {
     pub key_columns: Vec<Ident>,
@@ -1299,18 +1374,22 @@ impl_display!(KeyConstraint);
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum CreateSourceOptionName {
     IgnoreKeys,
+    KeyTtl,
     Size,
     Timeline,
     TimestampInterval,
+    ValueDecodingErrors,
 }
 
 impl AstDisplay for CreateSourceOptionName {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         f.write_str(match self {
             CreateSourceOptionName::IgnoreKeys => "IGNORE KEYS",
+            CreateSourceOptionName::KeyTtl => "KEY TTL",
             CreateSourceOptionName::Size => "SIZE",
             CreateSourceOptionName::Timeline => "TIMELINE",
             CreateSourceOptionName::TimestampInterval => "TIMESTAMP INTERVAL",
+            CreateSourceOptionName::ValueDecodingErrors => "VALUE DECODING ERRORS",
         })
     }
 }
@@ -591,6 +591,7 @@ impl<T: AstInfo> Expr<T> {
             filter: None,
             over: None,
             distinct: false,
+            within_group: false,
         })
     }
 
@@ -815,6 +816,10 @@ pub struct Function<T: AstInfo> {
     pub over: Option<WindowSpec<T>>,
     // aggregate functions may specify eg `COUNT(DISTINCT x)`
     pub distinct: bool,
+    // ordered-set aggregate functions specify their ordering as e.g.
+    // `percentile_cont(0.5) WITHIN GROUP (ORDER BY x)`, which is stored in
+    // the `order_by` of `args`
+    pub within_group: bool,
 }
 
 impl<T: AstInfo> AstDisplay for Function<T> {
@@ -851,7 +856,14 @@ impl<T: AstInfo> AstDisplay for Function<T> {
         if self.distinct {
             f.write_str("DISTINCT ")
         }
-        f.write_node(&self.args);
+        match &self.args {
+            FunctionArgs::Args { args, order_by } if self.within_group => {
+                f.write_node(&display::comma_separated(args));
+                f.write_str(") WITHIN GROUP (ORDER BY ");
+                f.write_node(&display::comma_separated(order_by));
+            }
+            args => f.write_node(args),
+        }
         f.write_str(")");
         if let Some(filter) = &self.filter {
             f.write_str(" FILTER (WHERE ");
@@ -62,6 +62,7 @@ pub enum Statement<T: AstInfo> {
     CreateCluster(CreateClusterStatement<T>),
     CreateClusterReplica(CreateClusterReplicaStatement<T>),
     CreateSecret(CreateSecretStatement<T>),
+    CreatePolicy(CreatePolicyStatement<T>),
     AlterCluster(AlterClusterStatement<T>),
     AlterOwner(AlterOwnerStatement<T>),
     AlterObjectRename(AlterObjectRenameStatement),
@@ -78,6 +79,7 @@ pub enum Statement<T: AstInfo> {
     Discard(DiscardStatement),
     DropObjects(DropObjectsStatement),
     DropOwned(DropOwnedStatement<T>),
+    DropPolicy(DropPolicyStatement<T>),
     SetVariable(SetVariableStatement),
     ResetVariable(ResetVariableStatement),
     Show(ShowStatement<T>),
@@ -126,6 +128,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::CreateIndex(stmt) => f.write_node(stmt),
             Statement::CreateRole(stmt) => f.write_node(stmt),
             Statement::CreateSecret(stmt) => f.write_node(stmt),
+            Statement::CreatePolicy(stmt) => f.write_node(stmt),
             Statement::CreateType(stmt) => f.write_node(stmt),
             Statement::CreateCluster(stmt) => f.write_node(stmt),
             Statement::CreateClusterReplica(stmt) => f.write_node(stmt),
@@ -145,6 +148,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Discard(stmt) => f.write_node(stmt),
             Statement::DropObjects(stmt) => f.write_node(stmt),
             Statement::DropOwned(stmt) => f.write_node(stmt),
+            Statement::DropPolicy(stmt) => f.write_node(stmt),
             Statement::SetVariable(stmt) => f.write_node(stmt),
             Statement::ResetVariable(stmt) => f.write_node(stmt),
             Statement::Show(stmt) => f.write_node(stmt),
@@ -199,6 +203,7 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::CreateCluster => "create_cluster",
         StatementKind::CreateClusterReplica => "create_cluster_replica",
         StatementKind::CreateSecret => "create_secret",
+        StatementKind::CreatePolicy => "create_policy",
         StatementKind::AlterCluster => "alter_cluster",
         StatementKind::AlterObjectRename => "alter_object_rename",
         StatementKind::AlterIndex => "alter_index",
@@ -215,6 +220,7 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::Discard => "discard",
         StatementKind::DropObjects => "drop_objects",
         StatementKind::DropOwned => "drop_owned",
+        StatementKind::DropPolicy => "drop_policy",
         StatementKind::SetVariable => "set_variable",
         StatementKind::ResetVariable => "reset_variable",
         StatementKind::Show => "show",
@@ -911,7 +917,7 @@ pub struct CreateSourceStatement<T: AstInfo> {
     pub connection: CreateSourceConnection<T>,
     pub include_metadata: Vec<SourceIncludeMetadata>,
     pub format: CreateSourceFormat<T>,
-    pub envelope: Option<Envelope>,
+    pub envelope: Option<Envelope<T>>,
     pub if_not_exists: bool,
     pub key_constraint: Option<KeyConstraint>,
     pub with_options: Vec<CreateSourceOption<T>>,
@@ -1137,7 +1143,7 @@ pub struct CreateSinkStatement<T: AstInfo> {
     pub from: T::ItemName,
     pub connection: CreateSinkConnection<T>,
     pub format: Option<Format<T>>,
-    pub envelope: Option<Envelope>,
+    pub envelope: Option<Envelope<T>>,
     pub with_options: Vec<CreateSinkOption<T>>,
 }
 
@@ -1498,6 +1504,30 @@ impl<T: AstInfo> AstDisplay for CreateSecretStatement<T> {
 }
 impl_display_t!(CreateSecretStatement);
 
+/// `CREATE POLICY ..`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreatePolicyStatement<T: AstInfo> {
+    /// Name of the created policy.
+    pub name: Ident,
+    /// The table or view to which the policy applies.
+    pub object: T::ItemName,
+    /// The predicate that rows must satisfy to be visible.
+    pub using: Expr<T>,
+}
+
+impl<T: AstInfo> AstDisplay for CreatePolicyStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE POLICY ");
+        f.write_node(&self.name);
+        f.write_str(" ON ");
+        f.write_node(&self.object);
+        f.write_str(" USING (");
+        f.write_node(&self.using);
+        f.write_str(")");
+    }
+}
+impl_display_t!(CreatePolicyStatement);
+
 /// `CREATE TYPE ..`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateTypeStatement<T: AstInfo> {
@@ -1941,6 +1971,10 @@ impl_display_t!(AlterIndexStatement);
 pub enum AlterSinkAction<T: AstInfo> {
     SetOptions(Vec<CreateSinkOption<T>>),
     ResetOptions(Vec<CreateSinkOptionName>),
+    /// `RESET PROGRESS TO <timestamp>`
+    ResetProgress(u64),
+    /// `SET FROM <item>`
+    ChangeRelation(T::ItemName),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1970,6 +2004,14 @@ impl<T: AstInfo> AstDisplay for AlterSinkStatement<T> {
                 f.write_node(&display::comma_separated(options));
                 f.write_str(")");
             }
+            AlterSinkAction::ResetProgress(timestamp) => {
+                f.write_str("RESET PROGRESS TO ");
+                f.write_str(timestamp);
+            }
+            AlterSinkAction::ChangeRelation(from) => {
+                f.write_str("SET FROM ");
+                f.write_node(from);
+            }
         }
     }
 }
@@ -2236,6 +2278,30 @@ impl<T: AstInfo> AstDisplay for DropOwnedStatement<T> {
 }
 impl_display_t!(DropOwnedStatement);
 
+/// `DROP POLICY ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DropPolicyStatement<T: AstInfo> {
+    /// Whether `IF EXISTS` was specified.
+    pub if_exists: bool,
+    /// The name of the policy to drop.
+    pub name: Ident,
+    /// The table or view to which the policy applies.
+    pub object: T::ItemName,
+}
+
+impl<T: AstInfo> AstDisplay for DropPolicyStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("DROP POLICY ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str(" ON ");
+        f.write_node(&self.object);
+    }
+}
+impl_display_t!(DropPolicyStatement);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct QualifiedReplica {
     pub cluster: Ident,
@@ -3489,6 +3555,11 @@ impl_display!(Privilege);
 pub enum PrivilegeSpecification {
     All,
     Privileges(Vec<Privilege>),
+    /// A privilege on specific columns of a relation, e.g. `SELECT (a, b)`.
+    Columns {
+        privilege: Privilege,
+        columns: Vec<Ident>,
+    },
 }
 
 impl AstDisplay for PrivilegeSpecification {
@@ -3498,6 +3569,12 @@ impl AstDisplay for PrivilegeSpecification {
             PrivilegeSpecification::Privileges(privileges) => {
                 f.write_node(&display::comma_separated(privileges))
             }
+            PrivilegeSpecification::Columns { privilege, columns } => {
+                f.write_node(privilege);
+                f.write_str(" (");
+                f.write_node(&display::comma_separated(columns));
+                f.write_str(")");
+            }
         }
     }
 }
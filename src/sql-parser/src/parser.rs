@@ -765,7 +765,7 @@ impl<'a> Parser<'a> {
             self.parse_at_most_one_keyword(&[ALL, DISTINCT], &format!("function: {}", name))?,
             Some(DISTINCT),
         );
-        let args = self.parse_optional_args(true)?;
+        let mut args = self.parse_optional_args(true)?;
 
         if distinct && matches!(args, FunctionArgs::Star) {
             return Err(self.error(
@@ -774,6 +774,40 @@ impl<'a> Parser<'a> {
             ));
         }
 
+        // Ordered-set aggregates, like `percentile_cont(0.5) WITHIN GROUP (ORDER BY x)`, specify
+        // the ordering outside of the argument list. We store it alongside the arguments, like
+        // any other aggregate ORDER BY.
+        let within_group = if self.parse_keywords(&[WITHIN, GROUP]) {
+            let pos = self.peek_prev_pos();
+            self.expect_token(&Token::LParen)?;
+            self.expect_keywords(&[ORDER, BY])?;
+            let within_group_order_by = self.parse_comma_separated(Parser::parse_order_by_expr)?;
+            self.expect_token(&Token::RParen)?;
+            match &mut args {
+                FunctionArgs::Args { order_by, .. } if order_by.is_empty() => {
+                    *order_by = within_group_order_by;
+                }
+                FunctionArgs::Args { .. } => {
+                    return Err(self.error(
+                        pos,
+                        "cannot use multiple ORDER BY clauses with WITHIN GROUP".to_string(),
+                    ));
+                }
+                FunctionArgs::Star => {
+                    return Err(self.error(
+                        pos,
+                        "WITHIN GROUP not supported with * as function args".to_string(),
+                    ));
+                }
+            }
+            if distinct {
+                return Err(self.error(pos, "cannot use DISTINCT with WITHIN GROUP".to_string()));
+            }
+            true
+        } else {
+            false
+        };
+
         let filter = if self.parse_keyword(FILTER) {
             self.expect_token(&Token::LParen)?;
             self.expect_keyword(WHERE)?;
@@ -829,6 +863,7 @@ impl<'a> Parser<'a> {
             filter,
             over,
             distinct,
+            within_group,
         })
     }
 
@@ -963,6 +998,7 @@ impl<'a> Parser<'a> {
             filter: None,
             over: None,
             distinct: false,
+            within_group: false,
         }))
     }
 
@@ -1026,6 +1062,7 @@ impl<'a> Parser<'a> {
             filter: None,
             over: None,
             distinct: false,
+            within_group: false,
         }))
     }
 
@@ -1044,6 +1081,7 @@ impl<'a> Parser<'a> {
             filter: None,
             over: None,
             distinct: false,
+            within_group: false,
         }))
     }
 
@@ -1275,6 +1313,7 @@ impl<'a> Parser<'a> {
                         filter: None,
                         over: None,
                         distinct: false,
+                        within_group: false,
                     }))
                 }
                 COLLATE => Ok(Expr::Collate {
@@ -1399,6 +1438,7 @@ impl<'a> Parser<'a> {
             filter: None,
             over: None,
             distinct: false,
+            within_group: false,
         }))
     }
 
@@ -1854,6 +1894,9 @@ impl<'a> Parser<'a> {
         } else if self.peek_keyword(CONNECTION) {
             self.parse_create_connection()
                 .map_parser_err(StatementKind::CreateConnection)
+        } else if self.peek_keyword(POLICY) {
+            self.parse_create_policy()
+                .map_parser_err(StatementKind::CreatePolicy)
         } else if self.peek_keywords(&[MATERIALIZED, VIEW])
             || self.peek_keywords(&[OR, REPLACE, MATERIALIZED, VIEW])
         {
@@ -1865,6 +1908,10 @@ impl<'a> Parser<'a> {
                 self.peek_pos(),
                 "CREATE USER is not supported, for more information consult the documentation at https://materialize.com/docs/sql/create-role/#details"
             ).map_parser_err(StatementKind::CreateRole)
+        } else if self.peek_keyword(FUNCTION) || self.peek_keywords(&[OR, REPLACE, FUNCTION]) {
+            let _ = self.parse_keywords(&[OR, REPLACE]);
+            parser_err!(self, self.peek_pos(), "CREATE FUNCTION is not supported")
+                .map_no_statement_parser_err()
         } else {
             let index = self.index;
 
@@ -1879,7 +1926,7 @@ impl<'a> Parser<'a> {
             } else {
                 self.expected(
                     self.peek_pos(),
-                    "DATABASE, SCHEMA, ROLE, TYPE, INDEX, SINK, SOURCE, TABLE, SECRET, [OR REPLACE] [TEMPORARY] VIEW, or [OR REPLACE] MATERIALIZED VIEW after CREATE",
+                    "DATABASE, SCHEMA, ROLE, TYPE, INDEX, SINK, SOURCE, TABLE, SECRET, POLICY, [OR REPLACE] [TEMPORARY] VIEW, or [OR REPLACE] MATERIALIZED VIEW after CREATE",
                     self.peek_token(),
                 ).map_no_statement_parser_err()
             }
@@ -2141,13 +2188,20 @@ impl<'a> Parser<'a> {
         Ok(CsrConnectionProtobuf { connection, seed })
     }
 
-    fn parse_envelope(&mut self) -> Result<Envelope, ParserError> {
+    fn parse_envelope(&mut self) -> Result<Envelope<Raw>, ParserError> {
         let envelope = if self.parse_keyword(NONE) {
             Envelope::None
         } else if self.parse_keyword(DEBEZIUM) {
-            // In Platform, `DEBEZIUM UPSERT` is the only available option.
-            // Revisit this if we ever change that.
-            let debezium_mode = DbzMode::Plain;
+            let debezium_mode = if self.consume_token(&Token::LParen) {
+                self.expect_keywords(&[TRANSACTION, METADATA])?;
+                self.expect_token(&Token::LParen)?;
+                let options = self.parse_comma_separated(Parser::parse_dbz_tx_metadata_option)?;
+                self.expect_token(&Token::RParen)?;
+                self.expect_token(&Token::RParen)?;
+                DbzMode::TxMetadata(options)
+            } else {
+                DbzMode::Plain
+            };
             Envelope::Debezium(debezium_mode)
         } else if self.parse_keyword(UPSERT) {
             Envelope::Upsert
@@ -2163,6 +2217,14 @@ impl<'a> Parser<'a> {
         Ok(envelope)
     }
 
+    fn parse_dbz_tx_metadata_option(&mut self) -> Result<DbzTxMetadataOption<Raw>, ParserError> {
+        match self.expect_one_of_keywords(&[SOURCE, COLLECTION])? {
+            SOURCE => Ok(DbzTxMetadataOption::Source(self.parse_raw_name()?)),
+            COLLECTION => Ok(DbzTxMetadataOption::Collection(self.parse_option_value()?)),
+            _ => unreachable!(),
+        }
+    }
+
     /// Parse a `VALIDATE` statement
     fn parse_validate(&mut self) -> Result<Statement<Raw>, ParserError> {
         self.expect_keyword(CONNECTION)?;
@@ -2454,6 +2516,8 @@ impl<'a> Parser<'a> {
                 if self.parse_keyword(METADATA) {
                     self.expect_keywords(&[REFRESH, INTERVAL, MS])?;
                     KafkaConfigOptionName::TopicMetadataRefreshIntervalMs
+                } else if self.parse_keyword(PATTERN) {
+                    KafkaConfigOptionName::TopicPattern
                 } else {
                     KafkaConfigOptionName::Topic
                 }
@@ -2811,19 +2875,28 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_source_option_name(&mut self) -> Result<CreateSourceOptionName, ParserError> {
-        let name = match self.expect_one_of_keywords(&[IGNORE, SIZE, TIMELINE, TIMESTAMP])? {
-            IGNORE => {
-                self.expect_keyword(KEYS)?;
-                CreateSourceOptionName::IgnoreKeys
-            }
-            SIZE => CreateSourceOptionName::Size,
-            TIMELINE => CreateSourceOptionName::Timeline,
-            TIMESTAMP => {
-                self.expect_keyword(INTERVAL)?;
-                CreateSourceOptionName::TimestampInterval
-            }
-            _ => unreachable!(),
-        };
+        let name =
+            match self.expect_one_of_keywords(&[IGNORE, KEY, SIZE, TIMELINE, TIMESTAMP, VALUE])? {
+                IGNORE => {
+                    self.expect_keyword(KEYS)?;
+                    CreateSourceOptionName::IgnoreKeys
+                }
+                KEY => {
+                    self.expect_keyword(TTL)?;
+                    CreateSourceOptionName::KeyTtl
+                }
+                SIZE => CreateSourceOptionName::Size,
+                TIMELINE => CreateSourceOptionName::Timeline,
+                TIMESTAMP => {
+                    self.expect_keyword(INTERVAL)?;
+                    CreateSourceOptionName::TimestampInterval
+                }
+                VALUE => {
+                    self.expect_keywords(&[DECODING, ERRORS])?;
+                    CreateSourceOptionName::ValueDecodingErrors
+                }
+                _ => unreachable!(),
+            };
         Ok(name)
     }
 
@@ -3110,38 +3183,60 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_pg_connection_option(&mut self) -> Result<PgConfigOption<Raw>, ParserError> {
-        let name = match self.expect_one_of_keywords(&[DETAILS, PUBLICATION, TEXT])? {
-            DETAILS => PgConfigOptionName::Details,
-            PUBLICATION => PgConfigOptionName::Publication,
-            TEXT => {
-                self.expect_keyword(COLUMNS)?;
-
-                let _ = self.consume_token(&Token::Eq);
-
-                let value = self
-                    .parse_option_sequence(Parser::parse_item_name)?
-                    .map(|inner| {
-                        WithOptionValue::Sequence(
-                            inner
-                                .into_iter()
-                                .map(WithOptionValue::UnresolvedItemName)
-                                .collect_vec(),
-                        )
+        let name =
+            match self.expect_one_of_keywords(&[DETAILS, PUBLICATION, TEXT, TRUNCATE, UPSERT])? {
+                DETAILS => PgConfigOptionName::Details,
+                PUBLICATION => PgConfigOptionName::Publication,
+                TEXT => {
+                    self.expect_keyword(COLUMNS)?;
+                    return Ok(PgConfigOption {
+                        name: PgConfigOptionName::TextColumns,
+                        value: self.parse_pg_item_name_sequence()?,
                     });
-
-                return Ok(PgConfigOption {
-                    name: PgConfigOptionName::TextColumns,
-                    value,
-                });
-            }
-            _ => unreachable!(),
-        };
+                }
+                TRUNCATE => {
+                    let name = match self.expect_one_of_keywords(&[RETRACT, IGNORE])? {
+                        RETRACT => PgConfigOptionName::TruncateRetract,
+                        IGNORE => PgConfigOptionName::TruncateIgnore,
+                        _ => unreachable!(),
+                    };
+                    return Ok(PgConfigOption {
+                        name,
+                        value: self.parse_pg_item_name_sequence()?,
+                    });
+                }
+                UPSERT => {
+                    self.expect_keyword(TABLES)?;
+                    return Ok(PgConfigOption {
+                        name: PgConfigOptionName::UpsertTables,
+                        value: self.parse_pg_item_name_sequence()?,
+                    });
+                }
+                _ => unreachable!(),
+            };
         Ok(PgConfigOption {
             name,
             value: self.parse_optional_option_value()?,
         })
     }
 
+    /// Parses an optional `=` followed by a sequence of item names, as taken by the Postgres
+    /// options that refer to upstream tables or columns.
+    fn parse_pg_item_name_sequence(&mut self) -> Result<Option<WithOptionValue<Raw>>, ParserError> {
+        let _ = self.consume_token(&Token::Eq);
+
+        Ok(self
+            .parse_option_sequence(Parser::parse_item_name)?
+            .map(|inner| {
+                WithOptionValue::Sequence(
+                    inner
+                        .into_iter()
+                        .map(WithOptionValue::UnresolvedItemName)
+                        .collect_vec(),
+                )
+            }))
+    }
+
     fn parse_load_generator_option(&mut self) -> Result<LoadGeneratorOption<Raw>, ParserError> {
         let name = match self.expect_one_of_keywords(&[SCALE, TICK, MAX])? {
             SCALE => {
@@ -3167,11 +3262,45 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_create_sink_connection(&mut self) -> Result<CreateSinkConnection<Raw>, ParserError> {
-        self.expect_keyword(KAFKA)?;
-        self.expect_keyword(CONNECTION)?;
+        match self.expect_one_of_keywords(&[KAFKA, POSTGRES])? {
+            KAFKA => {
+                self.expect_keyword(CONNECTION)?;
 
-        let connection = self.parse_kafka_connection_reference()?;
+                let connection = self.parse_kafka_connection_reference()?;
+                let key = self.parse_sink_key()?;
+                Ok(CreateSinkConnection::Kafka { connection, key })
+            }
+            POSTGRES => {
+                self.expect_keyword(CONNECTION)?;
+                let connection = self.parse_raw_name()?;
 
+                let options = if self.consume_token(&Token::LParen) {
+                    let options = self.parse_comma_separated(Parser::parse_pg_sink_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
+                } else {
+                    vec![]
+                };
+                let key = self.parse_sink_key()?;
+                Ok(CreateSinkConnection::Postgres {
+                    connection,
+                    options,
+                    key,
+                })
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn parse_pg_sink_option(&mut self) -> Result<PgSinkOption<Raw>, ParserError> {
+        self.expect_keyword(TABLE)?;
+        Ok(PgSinkOption {
+            name: PgSinkOptionName::Table,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_sink_key(&mut self) -> Result<Option<KafkaSinkKey>, ParserError> {
         // one token of lookahead:
         // * `KEY (` means we're parsing a list of columns for the key
         // * `KEY FORMAT` means there is no key, we'll parse a KeyValueFormat later
@@ -3193,7 +3322,7 @@ impl<'a> Parser<'a> {
             } else {
                 None
             };
-        Ok(CreateSinkConnection::Kafka { connection, key })
+        Ok(key)
     }
 
     fn parse_create_view(&mut self) -> Result<Statement<Raw>, ParserError> {
@@ -3415,6 +3544,22 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn parse_create_policy(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(POLICY)?;
+        let name = self.parse_identifier()?;
+        self.expect_keyword(ON)?;
+        let object = self.parse_raw_name()?;
+        self.expect_keyword(USING)?;
+        self.expect_token(&Token::LParen)?;
+        let using = self.parse_expr()?;
+        self.expect_token(&Token::RParen)?;
+        Ok(Statement::CreatePolicy(CreatePolicyStatement {
+            name,
+            object,
+            using,
+        }))
+    }
+
     fn parse_create_type(&mut self) -> Result<Statement<Raw>, ParserError> {
         self.expect_keyword(TYPE)?;
         let name = self.parse_item_name()?;
@@ -3645,13 +3790,14 @@ impl<'a> Parser<'a> {
         if self.parse_keyword(INCLUDE) {
             self.parse_comma_separated(|parser| {
                 let ty = match parser
-                    .expect_one_of_keywords(&[KEY, TIMESTAMP, PARTITION, OFFSET, HEADERS])?
+                    .expect_one_of_keywords(&[KEY, TIMESTAMP, PARTITION, OFFSET, HEADERS, TOPIC])?
                 {
                     KEY => SourceIncludeMetadataType::Key,
                     TIMESTAMP => SourceIncludeMetadataType::Timestamp,
                     PARTITION => SourceIncludeMetadataType::Partition,
                     OFFSET => SourceIncludeMetadataType::Offset,
                     HEADERS => SourceIncludeMetadataType::Headers,
+                    TOPIC => SourceIncludeMetadataType::Topic,
                     _ => unreachable!("only explicitly allowed items can be parsed"),
                 };
                 let alias = parser
@@ -3680,6 +3826,9 @@ impl<'a> Parser<'a> {
         if self.parse_keyword(OWNED) {
             self.parse_drop_owned()
                 .map_parser_err(StatementKind::DropOwned)
+        } else if self.parse_keyword(POLICY) {
+            self.parse_drop_policy()
+                .map_parser_err(StatementKind::DropPolicy)
         } else {
             self.parse_drop_objects()
                 .map_parser_err(StatementKind::DropObjects)
@@ -3809,6 +3958,18 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn parse_drop_policy(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let if_exists = self.parse_if_exists()?;
+        let name = self.parse_identifier()?;
+        self.expect_keyword(ON)?;
+        let object = self.parse_raw_name()?;
+        Ok(Statement::DropPolicy(DropPolicyStatement {
+            if_exists,
+            name,
+            object,
+        }))
+    }
+
     fn parse_cluster_replica_name(&mut self) -> Result<QualifiedReplica, ParserError> {
         let cluster = self.parse_identifier()?;
         self.expect_token(&Token::Dot)?;
@@ -4583,6 +4744,18 @@ impl<'a> Parser<'a> {
                 .map_no_statement_parser_err()?
             {
                 RESET => {
+                    if self.parse_keyword(PROGRESS) {
+                        self.expect_keyword(TO)
+                            .map_parser_err(StatementKind::AlterSink)?;
+                        let timestamp = self
+                            .parse_literal_uint()
+                            .map_parser_err(StatementKind::AlterSink)?;
+                        return Ok(Statement::AlterSink(AlterSinkStatement {
+                            sink_name: name,
+                            if_exists,
+                            action: AlterSinkAction::ResetProgress(timestamp),
+                        }));
+                    }
                     self.expect_token(&Token::LParen)
                         .map_parser_err(StatementKind::AlterSink)?;
                     let reset_options = self
@@ -4603,6 +4776,16 @@ impl<'a> Parser<'a> {
                     {
                         return result;
                     }
+                    if self.parse_keyword(FROM) {
+                        let from = self
+                            .parse_raw_name()
+                            .map_parser_err(StatementKind::AlterSink)?;
+                        return Ok(Statement::AlterSink(AlterSinkStatement {
+                            sink_name: name,
+                            if_exists,
+                            action: AlterSinkAction::ChangeRelation(from),
+                        }));
+                    }
                     self.expect_token(&Token::LParen)
                         .map_parser_err(StatementKind::AlterSink)?;
                     let set_options = self
@@ -4767,7 +4950,7 @@ impl<'a> Parser<'a> {
             GrantTargetAllSpecification::All
         };
         let is_grant = self.expect_one_of_keywords(&[GRANT, REVOKE])? == GRANT;
-        let privileges = self.parse_privilege_specification().ok_or_else(|| {
+        let privileges = self.parse_privilege_specification()?.ok_or_else(|| {
             self.expected::<_, PrivilegeSpecification>(
                 self.peek_pos(),
                 "ALL or INSERT or SELECT or UPDATE or DELETE or USAGE or CREATE",
@@ -4775,6 +4958,13 @@ impl<'a> Parser<'a> {
             )
             .expect_err("only returns errors")
         })?;
+        if let PrivilegeSpecification::Columns { .. } = privileges {
+            return parser_err!(
+                self,
+                self.peek_prev_pos(),
+                "column privileges are not supported in ALTER DEFAULT PRIVILEGES"
+            );
+        }
         self.expect_keyword(ON)?;
         let object_type =
             self.expect_grant_revoke_plural_object_type(if is_grant { "GRANT" } else { "REVOKE" })?;
@@ -5948,7 +6138,7 @@ impl<'a> Parser<'a> {
 
     fn parse_set(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
         let modifier = self.parse_one_of_keywords(&[SESSION, LOCAL]);
-        let mut variable = self.parse_identifier().map_no_statement_parser_err()?;
+        let mut variable = self.parse_variable_name().map_no_statement_parser_err()?;
         let mut normal = self.consume_token(&Token::Eq) || self.parse_keyword(TO);
         if !normal {
             match variable.as_str().parse() {
@@ -6008,6 +6198,17 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses the name of a configuration parameter. Custom parameters, like
+    /// `app.tenant`, are named by dot-separated identifiers.
+    fn parse_variable_name(&mut self) -> Result<Ident, ParserError> {
+        let mut name = self.parse_identifier()?.into_string();
+        while self.consume_token(&Token::Dot) {
+            name.push('.');
+            name.push_str(self.parse_identifier()?.as_str());
+        }
+        Ok(Ident::new(name))
+    }
+
     fn parse_set_schema_to(&mut self) -> Result<SetVariableTo, ParserError> {
         if self.parse_keyword(DEFAULT) {
             Ok(SetVariableTo::Default)
@@ -6038,7 +6239,7 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_reset(&mut self) -> Result<Statement<Raw>, ParserError> {
-        let mut variable = self.parse_identifier()?;
+        let mut variable = self.parse_variable_name()?;
         if variable.as_str().parse() == Ok(SCHEMA) {
             variable = Ident::new("search_path");
         }
@@ -6324,6 +6525,7 @@ impl<'a> Parser<'a> {
                         filter: None,
                         over: None,
                         distinct: false,
+                        within_group: false,
                     },
                     alias,
                     with_ordinality,
@@ -6396,6 +6598,7 @@ impl<'a> Parser<'a> {
                         filter: None,
                         over: None,
                         distinct: false,
+                        within_group: false,
                     },
                     alias,
                     with_ordinality,
@@ -7050,7 +7253,10 @@ impl<'a> Parser<'a> {
     /// Parse a `GRANT` statement, assuming that the `GRANT` token
     /// has already been consumed.
     fn parse_grant(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
-        match self.parse_privilege_specification() {
+        match self
+            .parse_privilege_specification()
+            .map_parser_err(StatementKind::GrantPrivileges)?
+        {
             Some(privileges) => self
                 .parse_grant_privilege(privileges)
                 .map_parser_err(StatementKind::GrantPrivileges),
@@ -7092,7 +7298,10 @@ impl<'a> Parser<'a> {
     /// Parse a `REVOKE` statement, assuming that the `REVOKE` token
     /// has already been consumed.
     fn parse_revoke(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
-        match self.parse_privilege_specification() {
+        match self
+            .parse_privilege_specification()
+            .map_parser_err(StatementKind::RevokePrivileges)?
+        {
             Some(privileges) => self
                 .parse_revoke_privilege(privileges)
                 .map_parser_err(StatementKind::RevokePrivileges),
@@ -7490,14 +7699,20 @@ impl<'a> Parser<'a> {
     }
 
     /// Parse one or more privileges separated by a ','.
-    fn parse_privilege_specification(&mut self) -> Option<PrivilegeSpecification> {
+    fn parse_privilege_specification(
+        &mut self,
+    ) -> Result<Option<PrivilegeSpecification>, ParserError> {
         if self.parse_keyword(ALL) {
             let _ = self.parse_keyword(PRIVILEGES);
-            return Some(PrivilegeSpecification::All);
+            return Ok(Some(PrivilegeSpecification::All));
         }
 
         let mut privileges = Vec::new();
         while let Some(privilege) = self.parse_privilege() {
+            if privileges.is_empty() && self.peek_token() == Some(Token::LParen) {
+                let columns = self.parse_parenthesized_column_list(Mandatory)?;
+                return Ok(Some(PrivilegeSpecification::Columns { privilege, columns }));
+            }
             privileges.push(privilege);
             if !self.consume_token(&Token::Comma) {
                 break;
@@ -7505,9 +7720,9 @@ impl<'a> Parser<'a> {
         }
 
         if privileges.is_empty() {
-            None
+            Ok(None)
         } else {
-            Some(PrivilegeSpecification::Privileges(privileges))
+            Ok(Some(PrivilegeSpecification::Privileges(privileges)))
         }
     }
 
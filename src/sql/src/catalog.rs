@@ -294,6 +294,14 @@ pub trait SessionCatalog: fmt::Debug + ExprHumanizer + Send + Sync + ConnectionR
     /// Returns the [`PrivilegeMap`] of the object.
     fn get_privileges(&self, id: &SystemObjectId) -> Option<&PrivilegeMap>;
 
+    /// Returns the row-level security policies on the item `id`, keyed by
+    /// policy name, if any.
+    fn get_item_policies(&self, id: &GlobalId) -> Option<&BTreeMap<String, String>>;
+
+    /// Returns the privileges on the columns of the item `id`, keyed by
+    /// column name, if any.
+    fn get_column_privileges(&self, id: &GlobalId) -> Option<&BTreeMap<String, PrivilegeMap>>;
+
     /// Returns all the IDs of all objects that depend on `ids`, including `ids` themselves.
     ///
     /// The order is guaranteed to be in reverse dependency order, i.e. the leafs will appear
@@ -616,6 +624,10 @@ pub trait CatalogItem {
     /// catalog item is a type.
     fn type_details(&self) -> Option<&CatalogTypeDetails<IdReference>>;
 
+    /// Returns the columns that the catalog item reads from each relation, if
+    /// the catalog item is a view or materialized view.
+    fn view_column_reads(&self) -> Option<&BTreeMap<GlobalId, BTreeSet<usize>>>;
+
     /// Returns the ID of the owning role.
     fn owner_id(&self) -> RoleId;
 
@@ -1080,8 +1092,15 @@ pub enum CatalogError {
     DuplicateReplica(String, String),
     /// Unknown item.
     UnknownItem(String),
+    /// The item a name referred to at the requested time has since been
+    /// dropped.
+    DroppedItem(String),
     /// Item already exists.
     ItemAlreadyExists(GlobalId, String),
+    /// Unknown row-level security policy.
+    UnknownPolicy(String),
+    /// Row-level security policy already exists.
+    PolicyAlreadyExists(String),
     /// Unknown function.
     UnknownFunction {
         /// The identifier of the function we couldn't find
@@ -1131,7 +1150,14 @@ impl fmt::Display for CatalogError {
             }
             Self::DuplicateReplica(replica_name, cluster_name) => write!(f, "cannot create multiple replicas named '{replica_name}' on cluster '{cluster_name}'"),
             Self::UnknownItem(name) => write!(f, "unknown catalog item '{}'", name),
+            Self::DroppedItem(name) => write!(
+                f,
+                "catalog item '{}' referred to an item that has since been dropped",
+                name
+            ),
             Self::ItemAlreadyExists(_gid, name) => write!(f, "catalog item '{name}' already exists"),
+            Self::UnknownPolicy(name) => write!(f, "unknown policy '{}'", name),
+            Self::PolicyAlreadyExists(name) => write!(f, "policy '{name}' already exists"),
             Self::UnexpectedType {
                 name,
                 actual_type,
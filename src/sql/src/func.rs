@@ -1909,6 +1909,15 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         "convert_from" => Scalar {
             params!(Bytes, String) => BinaryFunc::ConvertFrom => String, 1714;
         },
+        "corr" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("corr")) => Float64, 2829;
+        },
+        "covar_pop" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("covar_pop")) => Float64, 2827;
+        },
+        "covar_samp" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("covar_samp")) => Float64, 2828;
+        },
         "cos" => Scalar {
             params!(Float64) => UnaryFunc::Cos(func::Cos) => Float64, 1605;
         },
@@ -2138,9 +2147,37 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
                 })
             }) => Jsonb, 3273;
         },
+        "jsonb_deep_merge" => Scalar {
+            params!(Jsonb, Jsonb) => BinaryFunc::JsonbDeepMerge => Jsonb, oid::FUNC_JSONB_DEEP_MERGE_OID;
+        },
+        "jsonb_insert" => Scalar {
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb) => VariadicFunc::JsonbInsert => Jsonb, oid::FUNC_JSONB_INSERT_3_OID;
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb, Bool) => VariadicFunc::JsonbInsert => Jsonb, 3579;
+        },
+        "jsonb_path_exists" => Scalar {
+            params!(Jsonb, String) => BinaryFunc::JsonbPathExists { silent: false } => Bool, oid::FUNC_JSONB_PATH_EXISTS_OID;
+        },
+        "jsonb_path_match" => Scalar {
+            params!(Jsonb, String) => BinaryFunc::JsonbPathMatch { silent: false } => Bool, oid::FUNC_JSONB_PATH_MATCH_OID;
+        },
+        "jsonb_path_query_array" => Scalar {
+            params!(Jsonb, String) => BinaryFunc::JsonbPathQueryArray => Jsonb, oid::FUNC_JSONB_PATH_QUERY_ARRAY_OID;
+        },
+        "jsonb_path_query_first" => Scalar {
+            params!(Jsonb, String) => BinaryFunc::JsonbPathQueryFirst => Jsonb, oid::FUNC_JSONB_PATH_QUERY_FIRST_OID;
+        },
         "jsonb_pretty" => Scalar {
             params!(Jsonb) => UnaryFunc::JsonbPretty(func::JsonbPretty) => String, 3306;
         },
+        "jsonb_set" => Scalar {
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb) => VariadicFunc::JsonbSet => Jsonb, oid::FUNC_JSONB_SET_3_OID;
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb, Bool) => VariadicFunc::JsonbSet => Jsonb, 3304;
+        },
+        "jsonb_set_lax" => Scalar {
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb) => VariadicFunc::JsonbSetLax => Jsonb, oid::FUNC_JSONB_SET_LAX_3_OID;
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb, Bool) => VariadicFunc::JsonbSetLax => Jsonb, oid::FUNC_JSONB_SET_LAX_4_OID;
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb, Bool, String) => VariadicFunc::JsonbSetLax => Jsonb, 6044;
+        },
         "jsonb_strip_nulls" => Scalar {
             params!(Jsonb) => UnaryFunc::JsonbStripNulls(func::JsonbStripNulls) => Jsonb, 3262;
         },
@@ -2499,6 +2536,33 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(String, String) => VariadicFunc::RegexpMatch => ScalarType::Array(Box::new(ScalarType::String)), 3396;
             params!(String, String, String) => VariadicFunc::RegexpMatch => ScalarType::Array(Box::new(ScalarType::String)), 3397;
         },
+        "regr_avgx" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("regr_avgx")) => Float64, 2822;
+        },
+        "regr_avgy" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("regr_avgy")) => Float64, 2823;
+        },
+        "regr_count" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("regr_count")) => Int64, 2818;
+        },
+        "regr_intercept" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("regr_intercept")) => Float64, 2826;
+        },
+        "regr_r2" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("regr_r2")) => Float64, 2824;
+        },
+        "regr_slope" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("regr_slope")) => Float64, 2825;
+        },
+        "regr_sxx" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("regr_sxx")) => Float64, 2819;
+        },
+        "regr_sxy" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("regr_sxy")) => Float64, 2821;
+        },
+        "regr_syy" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("regr_syy")) => Float64, 2820;
+        },
         "replace" => Scalar {
             params!(String, String, String) => VariadicFunc::Replace => String, 2087;
         },
@@ -2940,6 +3004,26 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
                 Ok((e, AggregateFunc::JsonbObjectAgg { order_by }))
             }) => Jsonb, 3270;
         },
+        "mode" => Aggregate {
+            params!(AnyElement) => Operation::unary_ordered(|_ecx, e, order_by| {
+                Ok((e, AggregateFunc::Mode { order_by }))
+            }) => AnyElement, 3984;
+        },
+        "percentile_cont" => Aggregate {
+            params!(Float64, Float64) => Operation::binary_ordered(|_ecx, fraction, value, order_by| {
+                let e = plan_percentile_input(fraction, value)?;
+                Ok((e, AggregateFunc::PercentileCont { order_by }))
+            }) => Float64, 3974;
+            params!(Float64, Interval) => Operation::binary(|_ecx, _fraction, _value| {
+                bail_unsupported!("percentile_cont(interval)")
+            }) => Interval, 3976;
+        },
+        "percentile_disc" => Aggregate {
+            params!(Float64, AnyElement) => Operation::binary_ordered(|_ecx, fraction, value, order_by| {
+                let e = plan_percentile_input(fraction, value)?;
+                Ok((e, AggregateFunc::PercentileDisc { order_by }))
+            }) => AnyElement, 3972;
+        },
         "string_agg" => Aggregate {
             params!(String, String) => Operation::binary_ordered(|_ecx, value, sep, order_by| {
                 let e = HirScalarExpr::CallVariadic {
@@ -3179,6 +3263,17 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
                 })
             }) => ReturnType::set_of(String.into()), 3931;
         },
+        "jsonb_path_query" => Table {
+            params!(Jsonb, String) => Operation::binary(move |_ecx, jsonb, path| {
+                Ok(TableFuncPlan {
+                    expr: HirRelationExpr::CallTable {
+                        func: TableFunc::JsonbPathQuery,
+                        exprs: vec![jsonb, path],
+                    },
+                    column_names: vec!["jsonb_path_query".into()],
+                })
+            }) => ReturnType::set_of(Jsonb.into()), oid::FUNC_JSONB_PATH_QUERY_OID;
+        },
         // Note that these implementations' input to `generate_series` is
         // contrived to match Flink's expected values. There are other,
         // equally valid windows we could generate.
@@ -3915,6 +4010,58 @@ pub static MZ_INTERNAL_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(
     }
 });
 
+/// Builds the input of `percentile_cont` and `percentile_disc`, which is a
+/// record of the requested fraction and the value to aggregate.
+fn plan_percentile_input(
+    fraction: HirScalarExpr,
+    value: HirScalarExpr,
+) -> Result<HirScalarExpr, PlanError> {
+    // The fraction is checked again during evaluation, but we can provide a
+    // better error for the common case of a constant fraction.
+    if let Some(fraction) = fraction.clone().into_literal_float64() {
+        if !(0.0..=1.0).contains(&fraction) {
+            sql_bail!("percentile value {} is not between 0 and 1", fraction);
+        }
+    }
+    // Like PostgreSQL, error on a fraction outside of [0, 1], while letting a
+    // null fraction through.
+    let out_of_range = fraction
+        .clone()
+        .call_binary(
+            HirScalarExpr::literal(Datum::from(0.0f64), ScalarType::Float64),
+            BinaryFunc::Lt,
+        )
+        .or(fraction.clone().call_binary(
+            HirScalarExpr::literal(Datum::from(1.0f64), ScalarType::Float64),
+            BinaryFunc::Gt,
+        ));
+    let message = HirScalarExpr::literal(Datum::String("percentile value "), ScalarType::String)
+        .call_binary(
+            fraction
+                .clone()
+                .call_unary(UnaryFunc::CastFloat64ToString(func::CastFloat64ToString)),
+            BinaryFunc::TextConcat,
+        )
+        .call_binary(
+            HirScalarExpr::literal(Datum::String(" is not between 0 and 1"), ScalarType::String),
+            BinaryFunc::TextConcat,
+        );
+    let fraction = HirScalarExpr::If {
+        cond: Box::new(out_of_range),
+        then: Box::new(HirScalarExpr::CallVariadic {
+            func: VariadicFunc::ErrorIfNull,
+            exprs: vec![HirScalarExpr::literal_null(ScalarType::Float64), message],
+        }),
+        els: Box::new(fraction),
+    };
+    Ok(HirScalarExpr::CallVariadic {
+        func: VariadicFunc::RecordCreate {
+            field_names: vec![ColumnName::from("fraction"), ColumnName::from("value")],
+        },
+        exprs: vec![fraction, value],
+    })
+}
+
 fn digest(algorithm: &'static str) -> Operation<HirScalarExpr> {
     Operation::unary(move |_ecx, input| {
         let algorithm = HirScalarExpr::literal(Datum::String(algorithm), ScalarType::String);
@@ -4325,6 +4472,12 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
                 Ok(rhs.call_binary(lhs, BinaryFunc::RangeContainsRange { rev: true }))
             }) => Bool, 3892;
         },
+        "@?" => Scalar {
+            params!(Jsonb, String) => BinaryFunc::JsonbPathExists { silent: true } => Bool, oid::OP_JSONB_PATH_EXISTS_OID;
+        },
+        "@@" => Scalar {
+            params!(Jsonb, String) => BinaryFunc::JsonbPathMatch { silent: true } => Bool, oid::OP_JSONB_PATH_MATCH_OID;
+        },
         "?" => Scalar {
             params!(Jsonb, String) => JsonbContainsString => Bool, 3247;
             params!(MapAny, String) => MapContainsKey => Bool, oid::OP_CONTAINS_KEY_MAP_OID;
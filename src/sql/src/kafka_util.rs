@@ -16,13 +16,17 @@ use anyhow::bail;
 use mz_kafka_util::client::DEFAULT_FETCH_METADATA_TIMEOUT;
 use mz_ore::task;
 use mz_sql_parser::ast::display::AstDisplay;
-use mz_sql_parser::ast::{AstInfo, KafkaConfigOption, KafkaConfigOptionName};
+use mz_sql_parser::ast::{
+    AstInfo, KafkaConfigOption, KafkaConfigOptionName, SourceIncludeMetadata,
+    SourceIncludeMetadataType,
+};
 use mz_storage_types::connections::StringOrSecret;
+use mz_storage_types::sources::KafkaMetadataKind;
 use rdkafka::consumer::{BaseConsumer, Consumer, ConsumerContext};
 use rdkafka::{Offset, TopicPartitionList};
 use tokio::time::Duration;
 
-use crate::names::Aug;
+use crate::names::{Aug, FullItemName, RawDatabaseSpecifier};
 use crate::normalize::generate_extracted_config;
 use crate::plan::with_options::TryFromValue;
 use crate::plan::PlanError;
@@ -51,6 +55,7 @@ pub fn validate_options_for_context<T: AstInfo>(
             IsolationLevel => None,
             Topic => None,
             TopicMetadataRefreshIntervalMs => None,
+            TopicPattern => Some(Source),
             TransactionTimeoutMs => None,
             StartTimestamp => Some(Source),
             StartOffset => Some(Source),
@@ -88,6 +93,7 @@ generate_extracted_config!(
     ),
     (Topic, String),
     (TopicMetadataRefreshIntervalMs, i32),
+    (TopicPattern, String),
     (TransactionTimeoutMs, i32),
     (StartTimestamp, i64),
     (StartOffset, Vec<i64>),
@@ -304,3 +310,41 @@ where
         .map_err(|e| sql_err!("{}", e))?;
     Ok(high)
 }
+
+/// Returns the names and kinds of the metadata columns requested by the
+/// `INCLUDE` clause of a Kafka source, in the order they were requested.
+///
+/// `INCLUDE KEY` is not a metadata column and is skipped.
+pub fn metadata_columns(
+    include_metadata: &[SourceIncludeMetadata],
+) -> Vec<(String, KafkaMetadataKind)> {
+    include_metadata
+        .iter()
+        .flat_map(|item| {
+            let (default_name, kind) = match item.ty {
+                SourceIncludeMetadataType::Timestamp => ("timestamp", KafkaMetadataKind::Timestamp),
+                SourceIncludeMetadataType::Partition => ("partition", KafkaMetadataKind::Partition),
+                SourceIncludeMetadataType::Offset => ("offset", KafkaMetadataKind::Offset),
+                SourceIncludeMetadataType::Headers => ("headers", KafkaMetadataKind::Headers),
+                SourceIncludeMetadataType::Topic => ("topic", KafkaMetadataKind::Topic),
+                SourceIncludeMetadataType::Key => return None,
+            };
+            let name = match item.alias.as_ref() {
+                Some(name) => name.to_string(),
+                None => default_name.to_owned(),
+            };
+            Some((name, kind))
+        })
+        .collect()
+}
+
+/// Returns the name by which a Kafka source refers to the companion relation
+/// that receives the records whose value failed to decode, when the source
+/// uses `VALUE DECODING ERRORS = INLINE`.
+pub fn value_decoding_errors_reference() -> FullItemName {
+    FullItemName {
+        database: RawDatabaseSpecifier::Ambient,
+        schema: "mz_kafka".into(),
+        item: "value_decoding_errors".into(),
+    }
+}
@@ -43,7 +43,7 @@ use mz_pgcopy::CopyFormatParams;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem};
 use mz_repr::explain::{ExplainConfig, ExplainFormat};
 use mz_repr::role_id::RoleId;
-use mz_repr::{ColumnName, Diff, GlobalId, RelationDesc, Row, ScalarType};
+use mz_repr::{ColumnName, Diff, GlobalId, RelationDesc, Row, ScalarType, Timestamp};
 use mz_sql_parser::ast::{
     AlterSourceAddSubsourceOption, CreateSourceSubsource, QualifiedReplica,
     TransactionIsolationLevel, TransactionMode, WithOptionValue,
@@ -91,7 +91,7 @@ pub use expr::{
 };
 pub use notice::PlanNotice;
 pub use optimize::OptimizerConfig;
-pub use query::{ExprContext, QueryContext, QueryLifetime};
+pub use query::{plan_as_of, ExprContext, QueryContext, QueryLifetime};
 pub use scope::Scope;
 pub use side_effecting_func::SideEffectingFunc;
 pub use statement::ddl::PlannedRoleAttributes;
@@ -116,11 +116,13 @@ pub enum Plan {
     CreateMaterializedView(CreateMaterializedViewPlan),
     CreateIndex(CreateIndexPlan),
     CreateType(CreateTypePlan),
+    CreatePolicy(CreatePolicyPlan),
     Comment(CommentPlan),
     DiscardTemp,
     DiscardAll,
     DropObjects(DropObjectsPlan),
     DropOwned(DropOwnedPlan),
+    DropPolicy(DropPolicyPlan),
     EmptyQuery,
     ShowAllVariables,
     ShowCreate(ShowCreatePlan),
@@ -231,6 +233,7 @@ impl Plan {
             StatementKind::CreateRole => vec![PlanKind::CreateRole],
             StatementKind::CreateSchema => vec![PlanKind::CreateSchema],
             StatementKind::CreateSecret => vec![PlanKind::CreateSecret],
+            StatementKind::CreatePolicy => vec![PlanKind::CreatePolicy],
             StatementKind::CreateSink => vec![PlanKind::CreateSink],
             StatementKind::CreateSource
             | StatementKind::CreateSubsource
@@ -246,6 +249,7 @@ impl Plan {
             StatementKind::Discard => vec![PlanKind::DiscardAll, PlanKind::DiscardTemp],
             StatementKind::DropObjects => vec![PlanKind::DropObjects],
             StatementKind::DropOwned => vec![PlanKind::DropOwned],
+            StatementKind::DropPolicy => vec![PlanKind::DropPolicy],
             StatementKind::Execute => vec![PlanKind::Execute],
             StatementKind::ExplainPlan => vec![PlanKind::ExplainPlan],
             StatementKind::ExplainTimestamp => vec![PlanKind::ExplainTimestamp],
@@ -296,6 +300,7 @@ impl Plan {
             Plan::CreateMaterializedView(_) => "create materialized view",
             Plan::CreateIndex(_) => "create index",
             Plan::CreateType(_) => "create type",
+            Plan::CreatePolicy(_) => "create policy",
             Plan::Comment(_) => "comment",
             Plan::DiscardTemp => "discard temp",
             Plan::DiscardAll => "discard all",
@@ -317,6 +322,7 @@ impl Plan {
                 ObjectType::Func => "drop function",
             },
             Plan::DropOwned(_) => "drop owned",
+            Plan::DropPolicy(_) => "drop policy",
             Plan::EmptyQuery => "do nothing",
             Plan::ShowAllVariables => "show all variables",
             Plan::ShowCreate(_) => "show create",
@@ -684,6 +690,8 @@ pub struct DropOwnedPlan {
     pub drop_ids: Vec<ObjectId>,
     /// The privileges to revoke.
     pub privilege_revokes: Vec<(SystemObjectId, MzAclItem)>,
+    /// The privileges on columns to revoke.
+    pub column_privilege_revokes: Vec<(GlobalId, ColumnName, MzAclItem)>,
     /// The default privileges to revoke.
     pub default_privilege_revokes: Vec<(DefaultPrivilegeObject, DefaultPrivilegeAclItem)>,
 }
@@ -728,6 +736,8 @@ pub struct SelectPlan {
     pub when: QueryWhen,
     pub finishing: RowSetFinishing,
     pub copy_to: Option<CopyFormat>,
+    /// The columns read from each relation, used to check column privileges.
+    pub column_reads: BTreeMap<GlobalId, BTreeSet<usize>>,
 }
 
 #[derive(Debug)]
@@ -756,6 +766,8 @@ pub struct SubscribePlan {
     pub copy_to: Option<CopyFormat>,
     pub emit_progress: bool,
     pub output: SubscribeOutput,
+    /// The columns read from each relation, used to check column privileges.
+    pub column_reads: BTreeMap<GlobalId, BTreeSet<usize>>,
 }
 
 #[derive(Debug)]
@@ -855,6 +867,8 @@ pub struct InsertPlan {
     pub id: GlobalId,
     pub values: mz_expr::MirRelationExpr,
     pub returning: Vec<mz_expr::MirScalarExpr>,
+    /// The columns read from each relation, used to check column privileges.
+    pub column_reads: BTreeMap<GlobalId, BTreeSet<usize>>,
 }
 
 #[derive(Debug)]
@@ -865,6 +879,8 @@ pub struct ReadThenWritePlan {
     pub assignments: BTreeMap<usize, mz_expr::MirScalarExpr>,
     pub kind: MutationKind,
     pub returning: Vec<mz_expr::MirScalarExpr>,
+    /// The columns read from each relation, used to check column privileges.
+    pub column_reads: BTreeMap<GlobalId, BTreeSet<usize>>,
 }
 
 /// Generated by `ALTER ... IF EXISTS` if the named object did not exist.
@@ -903,6 +919,10 @@ pub enum AlterOptionParameter<T = String> {
 pub struct AlterSinkPlan {
     pub id: GlobalId,
     pub size: AlterOptionParameter,
+    /// The timestamp from which the sink should resume publishing updates.
+    pub reset_progress: Option<Timestamp>,
+    /// The sink as it reads from the relation it should publish from now on.
+    pub set_from: Option<Sink>,
 }
 
 #[derive(Debug)]
@@ -1063,6 +1083,9 @@ pub struct UpdatePrivilege {
     pub acl_mode: AclMode,
     /// The ID of the object receiving privileges.
     pub target_id: SystemObjectId,
+    /// The columns of the object receiving privileges. If empty, the
+    /// privileges apply to the whole object.
+    pub columns: Vec<ColumnName>,
     /// The role that is granting the privileges.
     pub grantor: RoleId,
 }
@@ -1114,6 +1137,28 @@ pub struct CommentPlan {
     pub comment: Option<String>,
 }
 
+#[derive(Debug)]
+pub struct CreatePolicyPlan {
+    /// The name of the policy.
+    pub name: String,
+    /// The object that the policy restricts.
+    pub object_id: GlobalId,
+    /// The SQL text of the boolean expression that rows must satisfy to be
+    /// visible, with all names fully qualified.
+    pub predicate: String,
+}
+
+#[derive(Debug)]
+pub struct DropPolicyPlan {
+    /// The name of the policy.
+    pub name: String,
+    /// The object that the policy restricts.
+    pub object_id: GlobalId,
+    /// Whether a missing policy should be ignored rather than reported as an
+    /// error.
+    pub if_exists: bool,
+}
+
 #[derive(Clone, Debug)]
 pub struct Table {
     pub create_sql: String,
@@ -1226,6 +1271,8 @@ pub struct View {
     pub expr: mz_expr::MirRelationExpr,
     pub column_names: Vec<ColumnName>,
     pub temporary: bool,
+    /// The columns read from each relation, used to check column privileges.
+    pub column_reads: BTreeMap<GlobalId, BTreeSet<usize>>,
 }
 
 #[derive(Clone, Debug)]
@@ -1234,6 +1281,8 @@ pub struct MaterializedView {
     pub expr: mz_expr::MirRelationExpr,
     pub column_names: Vec<ColumnName>,
     pub cluster_id: ClusterId,
+    /// The columns read from each relation, used to check column privileges.
+    pub column_reads: BTreeMap<GlobalId, BTreeSet<usize>>,
 }
 
 #[derive(Clone, Debug)]
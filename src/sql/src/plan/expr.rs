@@ -183,6 +183,166 @@ pub enum HirRelationExpr {
     },
 }
 
+/// State for [`HirRelationExpr::referenced_columns`].
+#[derive(Default)]
+struct ColumnReferences {
+    /// The referenced columns of each global collection.
+    globals: BTreeMap<GlobalId, BTreeSet<usize>>,
+    /// The referenced columns of each `Let` binding whose body is being
+    /// analyzed.
+    locals: BTreeMap<mz_expr::LocalId, BTreeSet<usize>>,
+    /// The referenced columns of the enclosing scopes, innermost last.
+    outers: Vec<BTreeSet<usize>>,
+}
+
+impl ColumnReferences {
+    fn relation(&mut self, expr: &HirRelationExpr, mut demand: BTreeSet<usize>) {
+        match expr {
+            HirRelationExpr::Constant { .. } => {}
+            HirRelationExpr::Get { id, .. } => match id {
+                Id::Global(id) => self.globals.entry(*id).or_default().extend(demand),
+                Id::Local(id) => {
+                    if let Some(columns) = self.locals.get_mut(id) {
+                        columns.extend(demand);
+                    }
+                }
+            },
+            HirRelationExpr::Let {
+                id, value, body, ..
+            } => {
+                self.locals.insert(*id, BTreeSet::new());
+                self.relation(body, demand);
+                let demand = self.locals.remove(id).unwrap_or_default();
+                self.relation(value, demand);
+            }
+            HirRelationExpr::LetRec { bindings, body, .. } => {
+                for (_, _, value, _) in bindings {
+                    self.relation(value, (0..value.arity()).collect());
+                }
+                self.relation(body, demand);
+            }
+            HirRelationExpr::Project { input, outputs } => {
+                let demand = demand.into_iter().map(|c| outputs[c]).collect();
+                self.relation(input, demand);
+            }
+            HirRelationExpr::Map { input, scalars } => {
+                let arity = input.arity();
+                demand.retain(|c| *c < arity);
+                // Scalars may reference earlier scalars, so visit them last to
+                // first.
+                for scalar in scalars.iter().rev() {
+                    self.scalar(scalar, &mut demand);
+                }
+                demand.retain(|c| *c < arity);
+                self.relation(input, demand);
+            }
+            HirRelationExpr::CallTable { exprs, .. } => {
+                let mut local = BTreeSet::new();
+                for expr in exprs {
+                    self.scalar(expr, &mut local);
+                }
+            }
+            HirRelationExpr::Filter { input, predicates } => {
+                for predicate in predicates {
+                    self.scalar(predicate, &mut demand);
+                }
+                self.relation(input, demand);
+            }
+            HirRelationExpr::Join {
+                left, right, on, ..
+            } => {
+                self.scalar(on, &mut demand);
+                let left_arity = left.arity();
+                let (left_demand, right_demand): (BTreeSet<_>, BTreeSet<_>) =
+                    demand.into_iter().partition(|c| *c < left_arity);
+                let right_demand = right_demand.into_iter().map(|c| c - left_arity).collect();
+                // The right input may reference the columns of the left input
+                // as outer columns.
+                self.outers.push(left_demand);
+                self.relation(right, right_demand);
+                let left_demand = self.outers.pop().expect("pushed above");
+                self.relation(left, left_demand);
+            }
+            HirRelationExpr::Reduce {
+                input,
+                group_key,
+                aggregates,
+                ..
+            } => {
+                let mut demand = group_key.iter().copied().collect();
+                for aggregate in aggregates {
+                    self.scalar(&aggregate.expr, &mut demand);
+                }
+                self.relation(input, demand);
+            }
+            HirRelationExpr::Distinct { input } | HirRelationExpr::Threshold { input } => {
+                self.relation(input, (0..input.arity()).collect());
+            }
+            HirRelationExpr::TopK {
+                input,
+                group_key,
+                order_key,
+                ..
+            } => {
+                demand.extend(group_key.iter().copied());
+                demand.extend(order_key.iter().map(|o| o.column));
+                self.relation(input, demand);
+            }
+            HirRelationExpr::Negate { input } => self.relation(input, demand),
+            HirRelationExpr::Union { base, inputs } => {
+                self.relation(base, demand.clone());
+                for input in inputs {
+                    self.relation(input, demand.clone());
+                }
+            }
+        }
+    }
+
+    /// Records the columns that `expr` references, adding the columns of its
+    /// own scope to `local`.
+    fn scalar(&mut self, expr: &HirScalarExpr, local: &mut BTreeSet<usize>) {
+        match expr {
+            HirScalarExpr::Column(ColumnRef { level: 0, column }) => {
+                local.insert(*column);
+            }
+            HirScalarExpr::Column(ColumnRef { level, column }) => {
+                if let Some(index) = self.outers.len().checked_sub(*level) {
+                    self.outers[index].insert(*column);
+                }
+            }
+            HirScalarExpr::Parameter(_)
+            | HirScalarExpr::Literal(_, _)
+            | HirScalarExpr::CallUnmaterializable(_) => {}
+            HirScalarExpr::CallUnary { expr, .. } => self.scalar(expr, local),
+            HirScalarExpr::CallBinary { expr1, expr2, .. } => {
+                self.scalar(expr1, local);
+                self.scalar(expr2, local);
+            }
+            HirScalarExpr::CallVariadic { exprs, .. } => {
+                for expr in exprs {
+                    self.scalar(expr, local);
+                }
+            }
+            HirScalarExpr::If { cond, then, els } => {
+                self.scalar(cond, local);
+                self.scalar(then, local);
+                self.scalar(els, local);
+            }
+            HirScalarExpr::Exists(expr) | HirScalarExpr::Select(expr) => {
+                self.outers.push(mem::take(local));
+                self.relation(expr, (0..expr.arity()).collect());
+                *local = self.outers.pop().expect("pushed above");
+            }
+            HirScalarExpr::Windowing(expr) => {
+                let _ = expr.visit_expressions(&mut |e| -> Result<(), ()> {
+                    self.scalar(e, local);
+                    Ok(())
+                });
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 /// Just like [`mz_expr::MirScalarExpr`], except where otherwise noted below.
 pub enum HirScalarExpr {
@@ -926,6 +1086,23 @@ pub enum AggregateFunc {
     StringAgg {
         order_by: Vec<ColumnOrder>,
     },
+    /// Computes the continuous percentile of `Datum::List`s whose first
+    /// element is a record of the requested fraction and a
+    /// `Datum::Float64`. The other elements are columns used by `order_by`.
+    PercentileCont {
+        order_by: Vec<ColumnOrder>,
+    },
+    /// Computes the discrete percentile of `Datum::List`s whose first element
+    /// is a record of the requested fraction and a value. The other elements
+    /// are columns used by `order_by`.
+    PercentileDisc {
+        order_by: Vec<ColumnOrder>,
+    },
+    /// Computes the most frequent first element of `Datum::List`s. The other
+    /// elements are columns used by `order_by`.
+    Mode {
+        order_by: Vec<ColumnOrder>,
+    },
     /// Accumulates any number of `Datum::Dummy`s into `Datum::Dummy`.
     ///
     /// Useful for removing an expensive aggregation while maintaining the shape
@@ -990,6 +1167,13 @@ impl AggregateFunc {
                 mz_expr::AggregateFunc::ListConcat { order_by }
             }
             AggregateFunc::StringAgg { order_by } => mz_expr::AggregateFunc::StringAgg { order_by },
+            AggregateFunc::PercentileCont { order_by } => {
+                mz_expr::AggregateFunc::PercentileCont { order_by }
+            }
+            AggregateFunc::PercentileDisc { order_by } => {
+                mz_expr::AggregateFunc::PercentileDisc { order_by }
+            }
+            AggregateFunc::Mode { order_by } => mz_expr::AggregateFunc::Mode { order_by },
             AggregateFunc::Dummy => mz_expr::AggregateFunc::Dummy,
         }
     }
@@ -1020,6 +1204,7 @@ impl AggregateFunc {
             AggregateFunc::JsonbAgg { .. } => ScalarType::Jsonb,
             AggregateFunc::JsonbObjectAgg { .. } => ScalarType::Jsonb,
             AggregateFunc::StringAgg { .. } => ScalarType::String,
+            AggregateFunc::PercentileCont { .. } => ScalarType::Float64,
             AggregateFunc::SumInt16 | AggregateFunc::SumInt32 => ScalarType::Int64,
             AggregateFunc::SumInt64 => ScalarType::Numeric {
                 max_scale: Some(NumericMaxScale::ZERO),
@@ -1035,6 +1220,16 @@ impl AggregateFunc {
                     _ => unreachable!(),
                 }
             }
+            AggregateFunc::PercentileDisc { .. } => {
+                // The input is a Record of the ordered value and the ORDER BY
+                // keys, whose first field is a Record of the fraction and the
+                // value.
+                let fields = input_type.scalar_type.unwrap_record_element_type();
+                fields[0].unwrap_record_element_type()[1].clone()
+            }
+            AggregateFunc::Mode { .. } => {
+                input_type.scalar_type.unwrap_record_element_type()[0].clone()
+            }
             _ => input_type.scalar_type,
         };
         // max/min/sum return null on empty sets
@@ -1051,6 +1246,19 @@ impl AggregateFunc {
                 | ArrayConcat { .. }
                 | ListConcat { .. }
                 | StringAgg { .. }
+                | PercentileCont { .. }
+                | PercentileDisc { .. }
+                | Mode { .. }
+        )
+    }
+
+    /// Reports whether this is an ordered-set aggregate, which must be called
+    /// with a `WITHIN GROUP (ORDER BY ...)` clause.
+    pub fn is_ordered_set(&self) -> bool {
+        use AggregateFunc::*;
+        matches!(
+            self,
+            PercentileCont { .. } | PercentileDisc { .. } | Mode { .. }
         )
     }
 }
@@ -1181,6 +1389,22 @@ impl HirRelationExpr {
         correlated
     }
 
+    /// Returns, for each global collection read by this expression, the
+    /// columns of that collection that the expression references when the
+    /// columns in `demand` of its output are consumed.
+    ///
+    /// A column is referenced if its value can influence the output: it flows
+    /// into a demanded output column, or it is used by a predicate, a join
+    /// condition, a grouping or ordering key, or any scalar expression.
+    pub fn referenced_columns(
+        &self,
+        demand: BTreeSet<usize>,
+    ) -> BTreeMap<GlobalId, BTreeSet<usize>> {
+        let mut references = ColumnReferences::default();
+        references.relation(self, demand);
+        references.globals
+    }
+
     pub fn is_join_identity(&self) -> bool {
         match self {
             HirRelationExpr::Constant { rows, .. } => rows.len() == 1 && self.arity() == 0,
@@ -2856,6 +3080,25 @@ impl HirScalarExpr {
         })
     }
 
+    /// Attempts to simplify this expression to a literal 64-bit float.
+    ///
+    /// Returns `None` if this expression cannot be simplified, e.g. because it
+    /// contains non-literal values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this expression does not have type [`ScalarType::Float64`].
+    pub fn into_literal_float64(self) -> Option<f64> {
+        self.simplify_to_literal().and_then(|row| {
+            let datum = row.unpack_first();
+            if datum.is_null() {
+                None
+            } else {
+                Some(datum.unwrap_float64())
+            }
+        })
+    }
+
     /// Attempts to simplify this expression to a literal string.
     ///
     /// Returns `None` if this expression cannot be simplified, e.g. because it
@@ -33,6 +33,10 @@ pub enum PlanNotice {
         name: String,
         object_type: ObjectType,
     },
+    PolicyDoesNotExist {
+        name: String,
+        object: String,
+    },
     UpsertSinkKeyNotEnforced {
         key: Vec<ColumnName>,
         name: String,
@@ -80,6 +84,14 @@ impl fmt::Display for PlanNotice {
                     name.quoted()
                 )
             }
+            PlanNotice::PolicyDoesNotExist { name, object } => {
+                write!(
+                    f,
+                    "policy {} for relation {} does not exist, skipping",
+                    name.quoted(),
+                    object.quoted()
+                )
+            }
             PlanNotice::UpsertSinkKeyNotEnforced { .. } => {
                 write!(f, "upsert key not validated to be unique")
             }
@@ -67,7 +67,7 @@ use mz_sql_parser::ast::{
 };
 use uuid::Uuid;
 
-use crate::catalog::{CatalogItemType, CatalogType, SessionCatalog};
+use crate::catalog::{CatalogItem, CatalogItemType, CatalogType, SessionCatalog};
 use crate::func::{self, Func, FuncSpec};
 use crate::names::{Aug, FullItemName, PartialItemName, ResolvedDataType, ResolvedItemName};
 use crate::normalize;
@@ -2082,7 +2082,7 @@ fn plan_view_select(
         };
         let mut agg_exprs = vec![];
         for sql_function in aggregates {
-            agg_exprs.push(plan_aggregate(ecx, &sql_function)?);
+            agg_exprs.push(plan_aggregate(ecx, &sql_function, &group_hir_exprs)?);
             group_scope
                 .items
                 .push(ScopeItem::from_expr(Expr::Function(sql_function.clone())));
@@ -2861,6 +2861,7 @@ fn plan_table_function_internal(
         filter,
         over,
         distinct,
+        within_group,
     }: &Function<Aug>,
     with_ordinality: bool,
     table_name: Option<FullItemName>,
@@ -2868,6 +2869,10 @@ fn plan_table_function_internal(
     assert!(filter.is_none(), "cannot parse table function with FILTER");
     assert!(over.is_none(), "cannot parse table function with OVER");
     assert!(!*distinct, "cannot parse table function with DISTINCT");
+    assert!(
+        !*within_group,
+        "cannot parse table function with WITHIN GROUP"
+    );
 
     let ecx = &ExprContext {
         qcx,
@@ -4508,7 +4513,9 @@ fn plan_aggregate(
         filter,
         over,
         distinct,
+        within_group,
     }: &Function<Aug>,
+    group_exprs: &[HirScalarExpr],
 ) -> Result<AggregateExpr, PlanError> {
     // Normal aggregate functions, like `sum`, expect as input a single expression
     // which yields the datum to aggregate. Order sensitive aggregate functions,
@@ -4543,7 +4550,8 @@ fn plan_aggregate(
     let (args, order_by) = match &args {
         FunctionArgs::Star => (vec![], vec![]),
         FunctionArgs::Args { args, order_by } => {
-            if args.is_empty() {
+            // Ordered-set aggregates, like `mode`, may have no direct arguments.
+            if args.is_empty() && !*within_group {
                 sql_bail!(
                     "{}(*) must be used to call a parameterless aggregate function",
                     ecx.qcx
@@ -4552,7 +4560,29 @@ fn plan_aggregate(
                         .expect("name actually resolved")
                 );
             }
-            let args = plan_exprs(ecx, args)?;
+            let mut args = plan_exprs(ecx, args)?;
+            if *within_group {
+                // Like in PostgreSQL, the direct arguments of an ordered-set
+                // aggregate, like the fraction of `percentile_cont`, are
+                // evaluated once per group, so they must not vary within it.
+                for arg in &args {
+                    if references_ungrouped_columns(arg, group_exprs) {
+                        sql_bail!(
+                            "direct arguments of ordered-set aggregate {} must be constant \
+                            or use only grouped columns",
+                            ecx.qcx
+                                .scx
+                                .humanize_resolved_name(name)
+                                .expect("name actually resolved")
+                        );
+                    }
+                }
+                // The aggregated arguments of an ordered-set aggregate are the
+                // expressions in its WITHIN GROUP clause, which follow its
+                // direct arguments.
+                let within_group_exprs: Vec<_> = order_by.iter().map(|o| &o.expr).collect();
+                args.extend(plan_exprs(ecx, &within_group_exprs)?);
+            }
             (args, order_by.clone())
         }
     };
@@ -4560,6 +4590,28 @@ fn plan_aggregate(
     let (order_by_exprs, col_orders) = plan_function_order_by(ecx, &order_by)?;
 
     let (mut expr, func) = func::select_impl(ecx, FuncSpec::Func(name), impls, args, col_orders)?;
+    if func.is_ordered_set() != *within_group {
+        let name = ecx
+            .qcx
+            .scx
+            .humanize_resolved_name(name)
+            .expect("name actually resolved");
+        if *within_group {
+            sql_bail!(
+                "{} is not an ordered-set aggregate, so it cannot have WITHIN GROUP",
+                name
+            );
+        } else {
+            sql_bail!(
+                "WITHIN GROUP is required for ordered-set aggregate {}",
+                name
+            );
+        }
+    }
+    if *within_group && *distinct {
+        // Like PostgreSQL, we don't support DISTINCT for ordered-set aggregates.
+        sql_bail!("cannot use DISTINCT with WITHIN GROUP");
+    }
     if let Some(filter) = &filter {
         // If a filter is present, as in
         //
@@ -4617,6 +4669,38 @@ fn plan_aggregate(
     })
 }
 
+/// Reports whether `expr`, which is planned in the scope of the input of an
+/// aggregation, refers to columns of that input other than through one of the
+/// aggregation's `group_exprs`.
+fn references_ungrouped_columns(expr: &HirScalarExpr, group_exprs: &[HirScalarExpr]) -> bool {
+    let mut ungrouped = false;
+    #[allow(deprecated)]
+    expr.visit_pre_post(
+        &mut |e| {
+            if group_exprs.contains(e) {
+                // Grouped expressions are constant within a group, so there
+                // is no need to look at the columns they refer to.
+                Some(vec![])
+            } else {
+                None
+            }
+        },
+        &mut |e| match e {
+            _ if group_exprs.contains(e) => (),
+            HirScalarExpr::Column(col) if col.level == 0 => ungrouped = true,
+            HirScalarExpr::Exists(_) | HirScalarExpr::Select(_) => {
+                e.visit_columns(0, &mut |depth, col| {
+                    if col.level == depth {
+                        ungrouped = true;
+                    }
+                })
+            }
+            _ => (),
+        },
+    );
+    ungrouped
+}
+
 fn plan_identifier(ecx: &ExprContext, names: &[Ident]) -> Result<HirScalarExpr, PlanError> {
     let mut names = names.to_vec();
     let col_name = normalize::column_name(names.pop().unwrap());
@@ -4722,6 +4806,7 @@ fn plan_function<'a>(
         filter,
         over,
         distinct,
+        within_group,
     }: &'a Function<Aug>,
 ) -> Result<HirScalarExpr, PlanError> {
     let impls = match resolve_func(ecx, name, args)? {
@@ -4827,6 +4912,15 @@ fn plan_function<'a>(
                 .expect("already resolved")
         );
     }
+    if *within_group {
+        sql_bail!(
+            "WITHIN GROUP specified, but {} is not an aggregate function",
+            ecx.qcx
+                .scx
+                .humanize_resolved_name(name)
+                .expect("already resolved")
+        );
+    }
 
     let scalar_args = match &args {
         FunctionArgs::Star => {
@@ -5045,6 +5139,7 @@ fn validate_window_function_plan<'a>(
         filter,
         over,
         distinct,
+        within_group,
     }: &'a Function<Aug>,
 ) -> Result<
     (
@@ -5072,6 +5167,13 @@ fn validate_window_function_plan<'a>(
         );
     }
 
+    if *within_group {
+        sql_bail!(
+            "WITHIN GROUP specified, but {} is not an aggregate function",
+            name
+        );
+    }
+
     if filter.is_some() {
         bail_unsupported!("FILTER in non-aggregate window functions");
     }
@@ -5494,6 +5596,7 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
                     filter,
                     over: _,
                     distinct: _,
+                    within_group: _,
                 } = func;
                 if let Some(filter) = filter {
                     self.visit_expr_mut(filter);
@@ -5561,6 +5664,7 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
                 filter: None,
                 over: None,
                 distinct: false,
+                within_group: false,
             } = &func
             {
                 // Identical table functions can be de-duplicated.
@@ -5730,7 +5834,7 @@ impl<'a> QueryContext<'a> {
         }
     }
 
-    fn relation_type(&self, expr: &HirRelationExpr) -> RelationType {
+    pub fn relation_type(&self, expr: &HirRelationExpr) -> RelationType {
         expr.typ(&self.outer_relation_types, &self.scx.param_types.borrow())
     }
 
@@ -5773,10 +5877,14 @@ impl<'a> QueryContext<'a> {
                 let desc = item
                     .desc(&self.scx.catalog.resolve_full_name(item.name()))?
                     .clone();
-                let expr = HirRelationExpr::Get {
+                let mut expr = HirRelationExpr::Get {
                     id: Id::Global(item.id()),
                     typ: desc.typ().clone(),
                 };
+                if let Some(predicate) = self.plan_policy_filter(item, &desc)? {
+                    expr = apply_policy_barrier(expr, predicate, &desc);
+                }
+                check_policy_dependencies(self.scx, item)?;
 
                 let scope = Scope::from_source(Some(name), desc.iter_names().cloned());
 
@@ -5798,11 +5906,187 @@ impl<'a> QueryContext<'a> {
         }
     }
 
+    /// Plans the filter that the row-level security policies on `item` impose
+    /// on the active role, if any.
+    ///
+    /// Policies are permissive: a row is visible if it satisfies any of them.
+    fn plan_policy_filter(
+        &self,
+        item: &dyn CatalogItem,
+        desc: &RelationDesc,
+    ) -> Result<Option<HirScalarExpr>, PlanError> {
+        let Some(policies) = applicable_policies(self.scx, item) else {
+            return Ok(None);
+        };
+        // Maintained objects outlive the session that creates them, so there
+        // is no role to evaluate the policies on behalf of.
+        if !matches!(
+            self.lifetime,
+            QueryLifetime::OneShot | QueryLifetime::Subscribe
+        ) {
+            return Err(maintained_policy_error(self.scx, item));
+        }
+
+        let mut filter: Option<HirScalarExpr> = None;
+        for predicate in policies.values() {
+            let predicate = mz_sql_parser::parser::parse_expr(predicate)?;
+            let (predicate, _) = crate::names::resolve(self.scx.catalog, predicate)?;
+            let predicate = plan_policy_predicate(self.scx, self.lifetime, desc, predicate)?;
+            filter = Some(match filter {
+                None => predicate,
+                Some(filter) => filter.or(predicate),
+            });
+        }
+        Ok(filter)
+    }
+
     pub fn humanize_scalar_type(&self, typ: &ScalarType) -> String {
         self.scx.humanize_scalar_type(typ)
     }
 }
 
+/// Returns the row-level security policies on `item` that apply to the active
+/// role, if any.
+///
+/// Members of the role that owns `item` and system roles are not subject to
+/// policies.
+pub fn applicable_policies<'a>(
+    scx: &StatementContext<'a>,
+    item: &dyn CatalogItem,
+) -> Option<&'a BTreeMap<String, String>> {
+    let policies = match scx.catalog.get_item_policies(&item.id()) {
+        Some(policies) if !policies.is_empty() => policies,
+        _ => return None,
+    };
+    let active_role_id = scx.catalog.active_role_id();
+    if active_role_id.is_system()
+        || scx
+            .catalog
+            .collect_role_membership(active_role_id)
+            .contains(&item.owner_id())
+    {
+        return None;
+    }
+    Some(policies)
+}
+
+fn maintained_policy_error(scx: &StatementContext, item: &dyn CatalogItem) -> PlanError {
+    sql_err!(
+        "cannot reference {} in a maintained object: it has row-level security policies \
+         that apply to the current role",
+        scx.catalog
+            .resolve_full_name(item.name())
+            .to_string()
+            .quoted()
+    )
+}
+
+/// Ensures that `item` does not read, directly or through other objects, a
+/// relation with row-level security policies that apply to the active role.
+///
+/// Views and materialized views read their inputs with the privileges of
+/// their owner, so reading them would reveal the rows that the policies hide.
+pub fn check_policy_dependencies(
+    scx: &StatementContext,
+    item: &dyn CatalogItem,
+) -> Result<(), PlanError> {
+    let mut seen = BTreeSet::new();
+    let mut todo: Vec<_> = item.uses().0.iter().copied().collect();
+    while let Some(id) = todo.pop() {
+        if id == item.id() || !seen.insert(id) {
+            continue;
+        }
+        let dependency = scx.catalog.get_item(&id);
+        if applicable_policies(scx, dependency).is_some() {
+            sql_bail!(
+                "cannot read {}: it depends on {}, which has row-level security policies that \
+                 apply to the current role",
+                scx.catalog
+                    .resolve_full_name(item.name())
+                    .to_string()
+                    .quoted(),
+                scx.catalog
+                    .resolve_full_name(dependency.name())
+                    .to_string()
+                    .quoted()
+            );
+        }
+        todo.extend(dependency.uses().0.iter().copied());
+    }
+    Ok(())
+}
+
+/// Ensures that an object that reads the collection of `item` without planning
+/// a query, like a sink, does not bypass row-level security policies that
+/// apply to the active role.
+pub fn check_unfiltered_read(
+    scx: &StatementContext,
+    item: &dyn CatalogItem,
+) -> Result<(), PlanError> {
+    if applicable_policies(scx, item).is_some() {
+        return Err(maintained_policy_error(scx, item));
+    }
+    check_policy_dependencies(scx, item)
+}
+
+/// Restricts `expr`, which reads a relation described by `desc`, to the rows
+/// that satisfy the row-level security `predicate`, with the semantics of a
+/// PostgreSQL security barrier: no predicate of the enclosing query observes a
+/// row that `predicate` rejects.
+///
+/// The rows are filtered by `security_barrier(predicate)`, which
+/// `MapFilterProject` evaluates before the predicates that depend on the same
+/// columns. Each column is then replaced by
+/// `CASE WHEN predicate THEN column END`. The optimizer can neither push
+/// predicates on these columns below the replacement nor prove them equal to
+/// the original columns, so a predicate that is reordered with the filter
+/// still only observes the columns of visible rows.
+fn apply_policy_barrier(
+    expr: HirRelationExpr,
+    predicate: HirScalarExpr,
+    desc: &RelationDesc,
+) -> HirRelationExpr {
+    let arity = desc.arity();
+    let guarded_columns = desc
+        .iter_types()
+        .enumerate()
+        .map(|(i, typ)| HirScalarExpr::If {
+            cond: Box::new(predicate.clone()),
+            then: Box::new(HirScalarExpr::column(i)),
+            els: Box::new(HirScalarExpr::literal_null(typ.scalar_type.clone())),
+        })
+        .collect();
+    expr.filter(vec![
+        predicate.call_unary(UnaryFunc::SecurityBarrier(expr_func::SecurityBarrier))
+    ])
+    .map(guarded_columns)
+    .project((arity..2 * arity).collect())
+}
+
+/// Plans the `USING` predicate of a row-level security policy on a relation
+/// described by `desc`.
+pub fn plan_policy_predicate(
+    scx: &StatementContext,
+    lifetime: QueryLifetime,
+    desc: &RelationDesc,
+    mut predicate: Expr<Aug>,
+) -> Result<HirScalarExpr, PlanError> {
+    let scope = Scope::from_source(None, desc.iter_names());
+    let qcx = QueryContext::root(scx, lifetime);
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name: "USING clause",
+        scope: &scope,
+        relation_type: desc.typ(),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: false,
+        allow_windows: false,
+    };
+    transform_ast::transform(scx, &mut predicate)?;
+    plan_expr(ecx, &predicate)?.type_as(ecx, &ScalarType::Bool)
+}
+
 /// A bundle of unrelated things that we need for planning `Expr`s.
 #[derive(Debug, Clone)]
 pub struct ExprContext<'a> {
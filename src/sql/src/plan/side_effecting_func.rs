@@ -163,6 +163,7 @@ fn extract_sef_call(
                 filter: None,
                 over: None,
                 distinct: false,
+                within_group: false,
             }),
         alias: None,
     }] = &projection[..]
@@ -147,8 +147,10 @@ pub fn describe(
         Statement::CreateMaterializedView(stmt) => {
             ddl::describe_create_materialized_view(&scx, stmt)?
         }
+        Statement::CreatePolicy(stmt) => ddl::describe_create_policy(&scx, stmt)?,
         Statement::DropObjects(stmt) => ddl::describe_drop_objects(&scx, stmt)?,
         Statement::DropOwned(stmt) => ddl::describe_drop_owned(&scx, stmt)?,
+        Statement::DropPolicy(stmt) => ddl::describe_drop_policy(&scx, stmt)?,
 
         // `ACL` statements.
         Statement::AlterOwner(stmt) => acl::describe_alter_owner(&scx, stmt)?,
@@ -321,8 +323,10 @@ pub fn plan(
         Statement::CreateMaterializedView(stmt) => {
             ddl::plan_create_materialized_view(scx, stmt, params)
         }
+        Statement::CreatePolicy(stmt) => ddl::plan_create_policy(scx, stmt),
         Statement::DropObjects(stmt) => ddl::plan_drop_objects(scx, stmt),
         Statement::DropOwned(stmt) => ddl::plan_drop_owned(scx, stmt),
+        Statement::DropPolicy(stmt) => ddl::plan_drop_policy(scx, stmt),
 
         // `ACL` statements.
         Statement::AlterOwner(stmt) => acl::plan_alter_owner(scx, stmt),
@@ -25,6 +25,7 @@ use crate::catalog::{
 use crate::names::{
     Aug, ObjectId, ResolvedDatabaseSpecifier, ResolvedRoleName, SchemaSpecifier, SystemObjectId,
 };
+use crate::normalize;
 use crate::plan::error::PlanError;
 use crate::plan::statement::ddl::{
     ensure_cluster_is_not_linked, resolve_cluster, resolve_database, resolve_item, resolve_schema,
@@ -38,6 +39,7 @@ use crate::session::user::SYSTEM_USER;
 use mz_ore::str::StrExt;
 use mz_repr::adt::mz_acl_item::AclMode;
 use mz_repr::role_id::RoleId;
+use mz_repr::ColumnName;
 use mz_sql_parser::ast::{
     AbbreviatedGrantOrRevokeStatement, AlterDefaultPrivilegesStatement, AlterOwnerStatement,
     GrantPrivilegesStatement, GrantRoleStatement, GrantTargetAllSpecification,
@@ -397,6 +399,29 @@ fn plan_update_privilege(
     target: GrantTargetSpecification<Aug>,
     roles: Vec<ResolvedRoleName>,
 ) -> Result<UpdatePrivilegesPlan, PlanError> {
+    let columns: Vec<ColumnName> = match &privileges {
+        PrivilegeSpecification::Columns { privilege, columns } => {
+            if *privilege != Privilege::SELECT {
+                bail_unsupported!(format!("{privilege} privileges on columns"));
+            }
+            if !matches!(
+                &target,
+                GrantTargetSpecification::Object {
+                    object_type: mz_sql_parser::ast::ObjectType::Table,
+                    object_spec_inner: GrantTargetSpecificationInner::Objects { .. },
+                }
+            ) {
+                sql_bail!("column privileges can only be granted on individual relations");
+            }
+            columns
+                .iter()
+                .cloned()
+                .map(normalize::column_name)
+                .collect()
+        }
+        PrivilegeSpecification::All | PrivilegeSpecification::Privileges(_) => Vec::new(),
+    };
+
     let (object_type, target_ids) = match target {
         GrantTargetSpecification::Object {
             object_type,
@@ -521,6 +546,18 @@ fn plan_update_privilege(
                     object_name,
                 });
             }
+
+            if !columns.is_empty() {
+                let desc = item.desc(&scx.catalog.resolve_full_name(item.name()))?;
+                for column in &columns {
+                    if desc.get_by_name(column).is_none() {
+                        return Err(PlanError::UnknownColumn {
+                            table: Some(scx.catalog.minimal_qualification(item.name())),
+                            column: column.clone(),
+                        });
+                    }
+                }
+            }
         }
 
         let all_object_privileges = scx.catalog.all_object_privileges(reference_object_type);
@@ -551,6 +588,7 @@ fn plan_update_privilege(
         update_privileges.push(UpdatePrivilege {
             acl_mode,
             target_id,
+            columns: columns.clone(),
             grantor,
         });
     }
@@ -575,6 +613,9 @@ fn privilege_spec_to_acl_mode(
             .map(|privilege| privilege_to_acl_mode(privilege.clone()))
             // PostgreSQL doesn't care about duplicate privileges, so we don't either.
             .fold(AclMode::empty(), |accum, acl_mode| accum.union(acl_mode)),
+        PrivilegeSpecification::Columns { privilege, .. } => {
+            privilege_to_acl_mode(privilege.clone())
+        }
     }
 }
 
@@ -15,30 +15,33 @@
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
 use std::iter;
+use std::time::Duration;
 
 use itertools::Itertools;
 use mz_controller_types::{ClusterId, ReplicaId, DEFAULT_REPLICA_LOGGING_INTERVAL_MICROS};
 use mz_expr::CollectionPlan;
 use mz_interchange::avro::AvroSchemaGenerator;
 use mz_ore::cast::{self, CastFrom, TryCastFrom};
-use mz_ore::collections::HashSet;
+use mz_ore::collections::{CollectionExt, HashSet};
 use mz_ore::str::StrExt;
 use mz_proto::RustType;
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::mz_acl_item::{MzAclItem, PrivilegeMap};
 use mz_repr::adt::system::Oid;
 use mz_repr::role_id::RoleId;
-use mz_repr::{strconv, ColumnName, ColumnType, GlobalId, RelationDesc, RelationType, ScalarType};
+use mz_repr::{
+    strconv, ColumnName, ColumnType, GlobalId, RelationDesc, RelationType, ScalarType, Timestamp,
+};
 use mz_sql_parser::ast::display::comma_separated;
 use mz_sql_parser::ast::{
     AlterClusterAction, AlterClusterStatement, AlterRoleStatement, AlterSetClusterStatement,
     AlterSinkAction, AlterSinkStatement, AlterSourceAction, AlterSourceAddSubsourceOption,
     AlterSourceAddSubsourceOptionName, AlterSourceStatement, AlterSystemResetAllStatement,
     AlterSystemResetStatement, AlterSystemSetStatement, CommentObjectType, CommentStatement,
-    CreateConnectionOption, CreateConnectionOptionName, CreateTypeListOption,
-    CreateTypeListOptionName, CreateTypeMapOption, CreateTypeMapOptionName, DeferredItemName,
-    DropOwnedStatement, SshConnectionOption, UnresolvedItemName, UnresolvedObjectName,
-    UnresolvedSchemaName, Value,
+    CreateConnectionOption, CreateConnectionOptionName, CreatePolicyStatement,
+    CreateTypeListOption, CreateTypeListOptionName, CreateTypeMapOption, CreateTypeMapOptionName,
+    DeferredItemName, DropOwnedStatement, DropPolicyStatement, SshConnectionOption,
+    UnresolvedItemName, UnresolvedObjectName, UnresolvedSchemaName, Value,
 };
 use mz_storage_types::connections::aws::{AwsAssumeRole, AwsConfig, AwsCredentials};
 use mz_storage_types::connections::inline::ReferencedConnection;
@@ -48,17 +51,20 @@ use mz_storage_types::connections::{
 };
 use mz_storage_types::sinks::{
     KafkaConsistencyConfig, KafkaSinkConnectionBuilder, KafkaSinkConnectionRetention,
-    KafkaSinkFormat, SinkEnvelope, StorageSinkConnectionBuilder,
+    KafkaSinkFormat, PostgresSinkConnection, SinkEnvelope, StorageSinkConnectionBuilder,
 };
 use mz_storage_types::sources::encoding::{
     included_column_desc, AvroEncoding, ColumnSpec, CsvEncoding, DataEncoding, DataEncodingInner,
     ProtobufEncoding, RegexEncoding, SourceDataEncoding, SourceDataEncodingInner,
 };
 use mz_storage_types::sources::{
-    GenericSourceConnection, KafkaMetadataKind, KafkaSourceConnection, KeyEnvelope, LoadGenerator,
-    LoadGeneratorSourceConnection, PostgresSourceConnection, PostgresSourcePublicationDetails,
+    DebeziumDedupProjection, DebeziumEnvelope, DebeziumSourceProjection,
+    DebeziumTransactionMetadata, GenericSourceConnection, KafkaSourceConnection, KeyEnvelope,
+    LoadGenerator, LoadGeneratorSourceConnection, PostgresSourceConnection,
+    PostgresSourcePublicationDetails, PostgresTruncateBehavior,
     ProtoPostgresSourcePublicationDetails, SourceConnection, SourceDesc, SourceEnvelope,
     TestScriptSourceConnection, Timeline, UnplannedSourceEnvelope, UpsertStyle,
+    ValueDecodingErrors,
 };
 use prost::Message;
 
@@ -77,19 +83,19 @@ use crate::ast::{
     CreateTypeStatement, CreateViewStatement, CreateWebhookSourceStatement, CsrConfigOption,
     CsrConfigOptionName, CsrConnection, CsrConnectionAvro, CsrConnectionOption,
     CsrConnectionOptionName, CsrConnectionProtobuf, CsrSeedProtobuf, CsvColumns, DbzMode,
-    DropObjectsStatement, Envelope, Expr, Format, Ident, IfExistsBehavior, IndexOption,
-    IndexOptionName, KafkaBroker, KafkaBrokerAwsPrivatelinkOption,
+    DbzTxMetadataOption, DropObjectsStatement, Envelope, Expr, Format, Ident, IfExistsBehavior,
+    IndexOption, IndexOptionName, KafkaBroker, KafkaBrokerAwsPrivatelinkOption,
     KafkaBrokerAwsPrivatelinkOptionName, KafkaBrokerTunnel, KafkaConfigOptionName,
     KafkaConnectionOption, KafkaConnectionOptionName, KeyConstraint, LoadGeneratorOption,
-    LoadGeneratorOptionName, PgConfigOption, PgConfigOptionName, PostgresConnectionOption,
-    PostgresConnectionOptionName, ProtobufSchema, QualifiedReplica, ReferencedSubsources,
-    ReplicaDefinition, ReplicaOption, ReplicaOptionName, RoleAttribute, SourceIncludeMetadata,
-    SourceIncludeMetadataType, SshConnectionOptionName, Statement, TableConstraint,
-    UnresolvedDatabaseName, ViewDefinition,
+    LoadGeneratorOptionName, PgConfigOption, PgConfigOptionName, PgSinkOption, PgSinkOptionName,
+    PostgresConnectionOption, PostgresConnectionOptionName, ProtobufSchema, QualifiedReplica,
+    RawItemName, ReferencedSubsources, ReplicaDefinition, ReplicaOption, ReplicaOptionName,
+    RoleAttribute, SourceIncludeMetadata, SourceIncludeMetadataType, SshConnectionOptionName,
+    Statement, TableConstraint, UnresolvedDatabaseName, ViewDefinition,
 };
 use crate::catalog::{
-    CatalogCluster, CatalogDatabase, CatalogItem, CatalogItemType, CatalogType, CatalogTypeDetails,
-    ObjectType, SystemObjectType,
+    CatalogCluster, CatalogDatabase, CatalogError, CatalogItem, CatalogItemType, CatalogType,
+    CatalogTypeDetails, ObjectType, SystemObjectType,
 };
 use crate::kafka_util::{self, KafkaConfigOptionExtracted, KafkaStartOffsetType};
 use crate::names::{
@@ -114,12 +120,12 @@ use crate::plan::{
     ComputeReplicaIntrospectionConfig, CreateClusterManagedPlan, CreateClusterPlan,
     CreateClusterReplicaPlan, CreateClusterUnmanagedPlan, CreateClusterVariant,
     CreateConnectionPlan, CreateDatabasePlan, CreateIndexPlan, CreateMaterializedViewPlan,
-    CreateRolePlan, CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan, CreateSourcePlan,
-    CreateTablePlan, CreateTypePlan, CreateViewPlan, DataSourceDesc, DropObjectsPlan,
-    DropOwnedPlan, FullItemName, HirScalarExpr, Index, Ingestion, MaterializedView, Params, Plan,
-    PlanClusterOption, PlanNotice, QueryContext, ReplicaConfig, RotateKeysPlan, Secret, Sink,
-    Source, SourceSinkClusterConfig, Table, Type, View, WebhookHeaderFilters, WebhookHeaders,
-    WebhookValidation,
+    CreatePolicyPlan, CreateRolePlan, CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan,
+    CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan, DataSourceDesc,
+    DropObjectsPlan, DropOwnedPlan, DropPolicyPlan, FullItemName, HirScalarExpr, Index, Ingestion,
+    MaterializedView, Params, Plan, PlanClusterOption, PlanNotice, QueryContext, ReplicaConfig,
+    RotateKeysPlan, Secret, Sink, Source, SourceSinkClusterConfig, Table, Type, View,
+    WebhookHeaderFilters, WebhookHeaders, WebhookValidation,
 };
 use crate::session::vars;
 
@@ -380,16 +386,21 @@ pub fn describe_create_subsource(
 generate_extracted_config!(
     CreateSourceOption,
     (IgnoreKeys, bool),
+    (KeyTtl, Interval),
     (Size, String),
     (Timeline, String),
-    (TimestampInterval, Interval)
+    (TimestampInterval, Interval),
+    (ValueDecodingErrors, String)
 );
 
 generate_extracted_config!(
     PgConfigOption,
     (Details, String),
     (Publication, String),
-    (TextColumns, Vec::<UnresolvedItemName>, Default(vec![]))
+    (TextColumns, Vec::<UnresolvedItemName>, Default(vec![])),
+    (TruncateRetract, Vec::<UnresolvedItemName>, Default(vec![])),
+    (TruncateIgnore, Vec::<UnresolvedItemName>, Default(vec![])),
+    (UpsertTables, Vec::<UnresolvedItemName>, Default(vec![]))
 );
 
 pub fn plan_create_webhook_source(
@@ -568,8 +579,10 @@ pub fn plan_create_source(
     let envelope = envelope.clone().unwrap_or(Envelope::None);
 
     let allowed_with_options = vec![
+        CreateSourceOptionName::KeyTtl,
         CreateSourceOptionName::Size,
         CreateSourceOptionName::TimestampInterval,
+        CreateSourceOptionName::ValueDecodingErrors,
     ];
     if let Some(op) = with_options
         .iter()
@@ -597,6 +610,39 @@ pub fn plan_create_source(
         bail_unsupported!("INCLUDE metadata with non-Kafka sources");
     }
 
+    let CreateSourceOptionExtracted {
+        size,
+        timeline,
+        timestamp_interval,
+        ignore_keys,
+        key_ttl,
+        value_decoding_errors,
+        seen: _,
+    } = CreateSourceOptionExtracted::try_from(with_options.clone())?;
+
+    let value_decoding_errors = match value_decoding_errors {
+        Some(policy) => {
+            scx.require_feature_flag(&vars::ENABLE_VALUE_DECODING_ERRORS)?;
+            if !matches!(connection, CreateSourceConnection::Kafka { .. }) {
+                bail_unsupported!("VALUE DECODING ERRORS with non-Kafka sources");
+            }
+            if matches!(envelope, Envelope::CdcV2) {
+                bail_unsupported!("VALUE DECODING ERRORS with ENVELOPE MATERIALIZE");
+            }
+            match policy.to_lowercase().as_str() {
+                "error" => ValueDecodingErrors::Error,
+                "skip" => ValueDecodingErrors::Skip,
+                // The zero-th output is the main output
+                "inline" => ValueDecodingErrors::Inline { output_index: 1 },
+                _ => sql_bail!(
+                    "invalid VALUE DECODING ERRORS {}: must be one of ERROR, SKIP or INLINE",
+                    policy.quoted()
+                ),
+            }
+        }
+        None => ValueDecodingErrors::Error,
+    };
+
     let (mut external_connection, encoding, available_subsources) = match connection {
         CreateSourceConnection::Kafka(mz_sql_parser::ast::KafkaSourceConnection {
             connection:
@@ -621,6 +667,7 @@ pub fn plan_create_source(
                 KafkaConfigOptionName::StartOffset,
                 KafkaConfigOptionName::StartTimestamp,
                 KafkaConfigOptionName::Topic,
+                KafkaConfigOptionName::TopicPattern,
             ];
 
             if let Some(op) = options
@@ -648,9 +695,18 @@ pub fn plan_create_source(
                 kafka_connection.options.insert(k, v);
             }
 
-            let topic = extracted_options
-                .topic
-                .expect("validated exists during purification");
+            let (topic, topic_is_pattern) = match extracted_options.topic_pattern {
+                Some(pattern) => {
+                    scx.require_feature_flag(&vars::ENABLE_KAFKA_TOPIC_PATTERN)?;
+                    (pattern, true)
+                }
+                None => {
+                    let topic = extracted_options
+                        .topic
+                        .expect("validated exists during purification");
+                    (topic, false)
+                }
+            };
             let group_id_prefix = extracted_options.group_id_prefix;
 
             let mut start_offsets = BTreeMap::new();
@@ -678,55 +734,20 @@ pub fn plan_create_source(
             if !include_metadata.is_empty()
                 && !matches!(
                     envelope,
-                    Envelope::Upsert | Envelope::None | Envelope::Debezium(DbzMode::Plain)
+                    Envelope::Upsert | Envelope::None | Envelope::Debezium(_)
                 )
             {
                 // TODO(guswynn): should this be `bail_unsupported!`?
                 sql_bail!("INCLUDE <metadata> requires ENVELOPE (NONE|UPSERT|DEBEZIUM)");
             }
 
-            let metadata_columns = include_metadata
-                .into_iter()
-                .flat_map(|item| match item.ty {
-                    SourceIncludeMetadataType::Timestamp => {
-                        let name = match item.alias.as_ref() {
-                            Some(name) => name.to_string(),
-                            None => "timestamp".to_owned(),
-                        };
-                        Some((name, KafkaMetadataKind::Timestamp))
-                    }
-                    SourceIncludeMetadataType::Partition => {
-                        let name = match item.alias.as_ref() {
-                            Some(name) => name.to_string(),
-                            None => "partition".to_owned(),
-                        };
-                        Some((name, KafkaMetadataKind::Partition))
-                    }
-                    SourceIncludeMetadataType::Offset => {
-                        let name = match item.alias.as_ref() {
-                            Some(name) => name.to_string(),
-                            None => "offset".to_owned(),
-                        };
-                        Some((name, KafkaMetadataKind::Offset))
-                    }
-                    SourceIncludeMetadataType::Headers => {
-                        let name = match item.alias.as_ref() {
-                            Some(name) => name.to_string(),
-                            None => "headers".to_owned(),
-                        };
-                        Some((name, KafkaMetadataKind::Headers))
-                    }
-                    SourceIncludeMetadataType::Key => {
-                        // handled below
-                        None
-                    }
-                })
-                .collect();
+            let metadata_columns = kafka_util::metadata_columns(include_metadata);
 
             let connection = KafkaSourceConnection::<ReferencedConnection> {
                 connection: connection_item.id(),
                 connection_id: connection_item.id(),
                 topic,
+                topic_is_pattern,
                 start_offsets,
                 group_id_prefix,
                 environment_id: scx.catalog.config().environment_id.to_string(),
@@ -735,7 +756,11 @@ pub fn plan_create_source(
 
             let connection = GenericSourceConnection::Kafka(connection);
 
-            (connection, encoding, None)
+            let available_subsources = value_decoding_errors.output_index().map(|output_index| {
+                BTreeMap::from([(kafka_util::value_decoding_errors_reference(), output_index)])
+            });
+
+            (connection, encoding, available_subsources)
         }
         CreateSourceConnection::Postgres {
             connection,
@@ -753,6 +778,9 @@ pub fn plan_create_source(
                 details,
                 publication,
                 text_columns,
+                truncate_retract,
+                truncate_ignore,
+                upsert_tables,
                 seen: _,
             } = options.clone().try_into()?;
 
@@ -805,6 +833,29 @@ pub fn plan_create_source(
                     .insert(col);
             }
 
+            // Look up the tables referenced by the truncate options in the publication_catalog.
+            let mut truncate_oids = BTreeMap::new();
+            for (tables, behavior) in [
+                (truncate_retract, PostgresTruncateBehavior::Retract),
+                (truncate_ignore, PostgresTruncateBehavior::Ignore),
+            ] {
+                for name in tables {
+                    let (_name, table_desc) = publication_catalog
+                        .resolve(name)
+                        .expect("known to exist from purification");
+                    truncate_oids.insert(Oid(table_desc.oid), behavior);
+                }
+            }
+
+            // Look up the tables that are ingested in upsert mode in the publication_catalog.
+            let mut upsert_oids = BTreeSet::new();
+            for name in upsert_tables {
+                let (_name, table_desc) = publication_catalog
+                    .resolve(name)
+                    .expect("known to exist from purification");
+                upsert_oids.insert(Oid(table_desc.oid));
+            }
+
             // Register the available subsources
             let mut available_subsources = BTreeMap::new();
 
@@ -813,8 +864,39 @@ pub fn plan_create_source(
             // The postgres source reader will then eval each of those on the incoming rows based
             // on the target table
             let mut table_casts = BTreeMap::new();
+            let mut truncate_behaviors = BTreeMap::new();
+            let mut upsert_keys = BTreeMap::new();
 
             for (i, table) in details.tables.iter().enumerate() {
+                if let Some(behavior) = truncate_oids.get(&Oid(table.oid)) {
+                    truncate_behaviors.insert(i + 1, *behavior);
+                }
+
+                if upsert_oids.contains(&Oid(table.oid)) {
+                    let primary_key = table
+                        .keys
+                        .iter()
+                        .find(|key| key.is_primary)
+                        .expect("validated primary key exists during purification");
+                    let key_indices = primary_key
+                        .cols
+                        .iter()
+                        .map(|col_num| {
+                            table
+                                .columns
+                                .iter()
+                                .position(|column| column.col_num == Some(*col_num))
+                                .ok_or_else(|| {
+                                    sql_err!(
+                                        "internal error: primary key of {} refers to unknown column",
+                                        table.name
+                                    )
+                                })
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    upsert_keys.insert(i + 1, key_indices);
+                }
+
                 // First, construct an expression context where the expression is evaluated on an
                 // imaginary row which has the same number of columns as the upstream table but all
                 // of the types are text
@@ -959,6 +1041,8 @@ pub fn plan_create_source(
                     connection: connection_item.id(),
                     connection_id: connection_item.id(),
                     table_casts,
+                    truncate_behaviors,
+                    upsert_keys,
                     publication: publication.expect("validated exists during purification"),
                     publication_details,
                 });
@@ -1079,13 +1163,20 @@ pub fn plan_create_source(
         conn.table_casts.retain(|pos, _| used_pos.contains(pos));
     }
 
-    let CreateSourceOptionExtracted {
-        size,
-        timeline,
-        timestamp_interval,
-        ignore_keys,
-        seen: _,
-    } = CreateSourceOptionExtracted::try_from(with_options.clone())?;
+    let key_ttl = match key_ttl {
+        Some(key_ttl) => {
+            scx.require_feature_flag(&vars::ENABLE_UPSERT_KEY_TTL)?;
+            if !matches!(envelope, mz_sql_parser::ast::Envelope::Upsert) {
+                sql_bail!("KEY TTL requires ENVELOPE UPSERT");
+            }
+            let key_ttl = key_ttl.duration()?;
+            if key_ttl < Duration::from_millis(1) {
+                sql_bail!("KEY TTL must be at least 1 millisecond");
+            }
+            Some(key_ttl)
+        }
+        None => None,
+    };
 
     let (key_desc, value_desc) = encoding.desc()?;
 
@@ -1104,12 +1195,32 @@ pub fn plan_create_source(
         mz_sql_parser::ast::Envelope::None => UnplannedSourceEnvelope::None(key_envelope),
         mz_sql_parser::ast::Envelope::Debezium(mode) => {
             //TODO check that key envelope is not set
-            let (_before_idx, after_idx) = typecheck_debezium(&value_desc)?;
+            let (before_idx, after_idx) = typecheck_debezium(&value_desc)?;
 
             match mode {
                 DbzMode::Plain => UnplannedSourceEnvelope::Upsert {
                     style: UpsertStyle::Debezium { after_idx },
+                    key_ttl: None,
                 },
+                DbzMode::TxMetadata(options) => {
+                    scx.require_feature_flag(&vars::ENABLE_DEBEZIUM_TRANSACTION_METADATA)?;
+                    if !include_metadata.is_empty() {
+                        sql_bail!(
+                            "INCLUDE <metadata> is not supported with \
+                            ENVELOPE DEBEZIUM (TRANSACTION METADATA ...)"
+                        );
+                    }
+                    let before_idx = before_idx
+                        .ok_or_else(|| sql_err!("'before' column missing from debezium input"))?;
+                    let tx_metadata =
+                        plan_debezium_transaction_metadata(scx, options, &value_desc)?;
+                    let dedup = typecheck_debezium_dedup(&value_desc, tx_metadata)?;
+                    UnplannedSourceEnvelope::Debezium(DebeziumEnvelope {
+                        before_idx,
+                        after_idx,
+                        dedup,
+                    })
+                }
             }
         }
         mz_sql_parser::ast::Envelope::Upsert => {
@@ -1126,6 +1237,7 @@ pub fn plan_create_source(
             }
             UnplannedSourceEnvelope::Upsert {
                 style: UpsertStyle::Default(key_envelope),
+                key_ttl,
             }
         }
         mz_sql_parser::ast::Envelope::CdcV2 => {
@@ -1217,6 +1329,7 @@ pub fn plan_create_source(
         encoding,
         envelope: envelope.clone(),
         timestamp_interval,
+        value_decoding_errors,
     };
 
     let progress_subsource = match progress_subsource {
@@ -1267,12 +1380,25 @@ pub fn plan_create_source(
         Some(timeline) => Timeline::User(timeline),
     };
 
+    // The only source that reads from another source is a Debezium source
+    // with transaction metadata.
+    let source_imports = match &source_desc.envelope {
+        SourceEnvelope::Debezium(envelope) => envelope
+            .dedup
+            .tx_metadata
+            .iter()
+            .map(|tx_metadata| tx_metadata.tx_metadata_global_id)
+            .collect(),
+        SourceEnvelope::None(_) | SourceEnvelope::Upsert(_) | SourceEnvelope::CdcV2 => {
+            BTreeSet::new()
+        }
+    };
+
     let source = Source {
         create_sql,
         data_source: DataSourceDesc::Ingestion(Ingestion {
             desc: source_desc,
-            // Currently no source reads from another source
-            source_imports: BTreeSet::new(),
+            source_imports,
             subsource_exports,
             progress_subsource,
         }),
@@ -1559,10 +1685,193 @@ fn typecheck_debezium(value_desc: &RelationDesc) -> Result<(Option<usize>, usize
     Ok((before_idx, after_idx))
 }
 
+/// Computes where the `op` and `source` metadata live in a Debezium value, which
+/// the classic Debezium envelope uses to drop records it has already seen.
+fn typecheck_debezium_dedup(
+    value_desc: &RelationDesc,
+    tx_metadata: DebeziumTransactionMetadata,
+) -> Result<DebeziumDedupProjection, PlanError> {
+    let (op_idx, op_ty) = value_desc
+        .get_by_name(&"op".into())
+        .ok_or_else(|| sql_err!("'op' column missing from debezium input"))?;
+    if op_ty != &ScalarType::String.nullable(false) {
+        sql_bail!("'op' column must be of type non-nullable string");
+    }
+
+    let (source_idx, source_ty) = value_desc
+        .get_by_name(&"source".into())
+        .ok_or_else(|| sql_err!("'source' column missing from debezium input"))?;
+    let source_fields = match &source_ty.scalar_type {
+        ScalarType::Record { fields, .. } => fields,
+        _ => sql_bail!("'source' column must be of type record"),
+    };
+    let source_field = |name: &str, ty: ScalarType| -> Result<Option<usize>, PlanError> {
+        match source_fields.iter().position(|(n, _)| n.as_str() == name) {
+            Some(idx) if source_fields[idx].1.scalar_type == ty => Ok(Some(idx)),
+            Some(_) => sql_bail!("'source.{}' field must be of type {:?}", name, ty),
+            None => Ok(None),
+        }
+    };
+
+    let snapshot_idx = source_fields
+        .iter()
+        .position(|(n, _)| n.as_str() == "snapshot")
+        .ok_or_else(|| sql_err!("'source.snapshot' field missing from debezium input"))?;
+
+    let source_projection = if let (Some(file), Some(pos), Some(row)) = (
+        source_field("file", ScalarType::String)?,
+        source_field("pos", ScalarType::Int64)?,
+        source_field("row", ScalarType::Int32)?,
+    ) {
+        DebeziumSourceProjection::MySql { file, pos, row }
+    } else if let (Some(sequence), Some(lsn)) = (
+        source_field("sequence", ScalarType::String)?,
+        source_field("lsn", ScalarType::Int64)?,
+    ) {
+        DebeziumSourceProjection::Postgres { sequence, lsn }
+    } else if let (Some(change_lsn), Some(event_serial_no)) = (
+        source_field("change_lsn", ScalarType::String)?,
+        source_field("event_serial_no", ScalarType::Int64)?,
+    ) {
+        DebeziumSourceProjection::SqlServer {
+            change_lsn,
+            event_serial_no,
+        }
+    } else {
+        sql_bail!(
+            "'source' column must describe a MySQL, PostgreSQL or SQL Server debezium connector"
+        );
+    };
+
+    Ok(DebeziumDedupProjection {
+        op_idx,
+        source_idx,
+        snapshot_idx,
+        source_projection,
+        tx_metadata: Some(tx_metadata),
+    })
+}
+
+/// Plans the `TRANSACTION METADATA` of a Debezium envelope, checking the shape
+/// of the transaction metadata source and of the `transaction` column of the
+/// data it describes.
+fn plan_debezium_transaction_metadata(
+    scx: &StatementContext,
+    options: &[DbzTxMetadataOption<Aug>],
+    value_desc: &RelationDesc,
+) -> Result<DebeziumTransactionMetadata, PlanError> {
+    let mut tx_source = None;
+    let mut tx_data_collection_name = None;
+    for option in options {
+        match option {
+            DbzTxMetadataOption::Source(name) => {
+                if tx_source.replace(name).is_some() {
+                    sql_bail!("TRANSACTION METADATA SOURCE specified more than once");
+                }
+            }
+            DbzTxMetadataOption::Collection(value) => {
+                let collection = String::try_from_value(value.clone())?;
+                if tx_data_collection_name.replace(collection).is_some() {
+                    sql_bail!("TRANSACTION METADATA COLLECTION specified more than once");
+                }
+            }
+        }
+    }
+    let tx_source = tx_source.ok_or_else(|| sql_err!("TRANSACTION METADATA requires a SOURCE"))?;
+    let tx_data_collection_name = tx_data_collection_name
+        .ok_or_else(|| sql_err!("TRANSACTION METADATA requires a COLLECTION"))?;
+
+    let item = scx.get_item_by_resolved_name(tx_source)?;
+    if item.item_type() != CatalogItemType::Source {
+        sql_bail!(
+            "provided TRANSACTION METADATA SOURCE {} is not a source",
+            tx_source.full_name_str()
+        );
+    }
+    let tx_desc = item.desc(&scx.catalog.resolve_full_name(item.name()))?;
+
+    let non_nullable_string = |desc: &RelationDesc, name: &str| -> Result<usize, PlanError> {
+        match desc.get_by_name(&name.into()) {
+            Some((idx, ty)) if ty == &ScalarType::String.nullable(false) => Ok(idx),
+            Some(_) => sql_bail!("'{}' column must be of type non-nullable string", name),
+            None => sql_bail!(
+                "'{}' column missing from debezium transaction metadata",
+                name
+            ),
+        }
+    };
+    let tx_status_idx = non_nullable_string(&tx_desc, "status")?;
+    let tx_transaction_id_idx = non_nullable_string(&tx_desc, "id")?;
+
+    let (tx_data_collections_idx, data_collections_ty) = tx_desc
+        .get_by_name(&"data_collections".into())
+        .ok_or_else(|| {
+            sql_err!("'data_collections' column missing from debezium transaction metadata")
+        })?;
+    let data_collections_fields = match &data_collections_ty.scalar_type {
+        ScalarType::List { element_type, .. } | ScalarType::Array(element_type) => {
+            match &**element_type {
+                ScalarType::Record { fields, .. } => fields,
+                _ => sql_bail!("'data_collections' column must contain records"),
+            }
+        }
+        _ => sql_bail!("'data_collections' column must be of type list or array"),
+    };
+    let tx_data_collections_data_collection_idx = match data_collections_fields
+        .iter()
+        .position(|(n, _)| n.as_str() == "data_collection")
+    {
+        Some(idx) if data_collections_fields[idx].1 == ScalarType::String.nullable(false) => idx,
+        Some(_) => sql_bail!("'data_collection' field must be of type non-nullable string"),
+        None => sql_bail!("'data_collection' field missing from 'data_collections' column"),
+    };
+    let tx_data_collections_event_count_idx = match data_collections_fields
+        .iter()
+        .position(|(n, _)| n.as_str() == "event_count")
+    {
+        Some(idx)
+            if matches!(
+                data_collections_fields[idx].1.scalar_type,
+                ScalarType::Int16 | ScalarType::Int32 | ScalarType::Int64
+            ) =>
+        {
+            idx
+        }
+        Some(_) => sql_bail!("'event_count' field must be of an integer type"),
+        None => sql_bail!("'event_count' field missing from 'data_collections' column"),
+    };
+
+    let (data_transaction_idx, transaction_ty) = value_desc
+        .get_by_name(&"transaction".into())
+        .ok_or_else(|| sql_err!("'transaction' column missing from debezium input"))?;
+    let data_transaction_id_idx = match &transaction_ty.scalar_type {
+        ScalarType::Record { fields, .. } => {
+            match fields.iter().position(|(n, _)| n.as_str() == "id") {
+                Some(idx) if fields[idx].1 == ScalarType::String.nullable(false) => idx,
+                Some(_) => sql_bail!("'transaction.id' field must be of type non-nullable string"),
+                None => sql_bail!("'transaction.id' field missing from debezium input"),
+            }
+        }
+        _ => sql_bail!("'transaction' column must be of type record"),
+    };
+
+    Ok(DebeziumTransactionMetadata {
+        tx_metadata_global_id: item.id(),
+        tx_status_idx,
+        tx_transaction_id_idx,
+        tx_data_collections_idx,
+        tx_data_collections_data_collection_idx,
+        tx_data_collections_event_count_idx,
+        tx_data_collection_name,
+        data_transaction_idx,
+        data_transaction_id_idx,
+    })
+}
+
 fn get_encoding(
     scx: &StatementContext,
     format: &CreateSourceFormat<Aug>,
-    envelope: &Envelope,
+    envelope: &Envelope<Aug>,
     connection: Option<&CreateSourceConnection<Aug>>,
 ) -> Result<SourceDataEncoding<ReferencedConnection>, PlanError> {
     let encoding = match format {
@@ -1818,7 +2127,7 @@ fn get_encoding_inner(
 /// Extract the key envelope, if it is requested
 fn get_key_envelope(
     included_items: &[SourceIncludeMetadata],
-    envelope: &Envelope,
+    envelope: &Envelope<Aug>,
     encoding: &SourceDataEncoding<ReferencedConnection>,
 ) -> Result<KeyEnvelope, PlanError> {
     let key_definition = included_items
@@ -1918,6 +2227,7 @@ pub fn plan_view(
     assert!(finishing.is_trivial(expr.arity()));
 
     expr.bind_parameters(params)?;
+    let column_reads = expr.referenced_columns((0..expr.arity()).collect());
     let relation_expr = expr.optimize_and_lower(&scx.into())?;
 
     let name = if temporary {
@@ -1942,6 +2252,7 @@ pub fn plan_view(
         expr: relation_expr,
         column_names: names,
         temporary,
+        column_reads,
     };
 
     Ok((name, view))
@@ -2059,6 +2370,7 @@ pub fn plan_create_materialized_view(
     assert!(finishing.is_trivial(expr.arity()));
 
     expr.bind_parameters(params)?;
+    let column_reads = expr.referenced_columns((0..expr.arity()).collect());
     let expr = expr.optimize_and_lower(&scx.into())?;
 
     plan_utils::maybe_rename_columns(
@@ -2128,6 +2440,7 @@ pub fn plan_create_materialized_view(
             expr,
             column_names,
             cluster_id,
+            column_reads,
         },
         replace,
         drop_ids,
@@ -2180,7 +2493,10 @@ pub fn plan_create_sink(
 
     let envelope = match envelope {
         None => sql_bail!("ENVELOPE clause is required"),
-        Some(Envelope::Debezium(mz_sql_parser::ast::DbzMode::Plain)) => SinkEnvelope::Debezium,
+        Some(Envelope::Debezium(DbzMode::Plain)) => SinkEnvelope::Debezium,
+        Some(Envelope::Debezium(DbzMode::TxMetadata(_))) => {
+            sql_bail!("ENVELOPE DEBEZIUM (TRANSACTION METADATA ...) is not supported for sinks")
+        }
         Some(Envelope::Upsert) => SinkEnvelope::Upsert,
         Some(Envelope::CdcV2) => bail_unsupported!("CDCv2 sinks"),
         Some(Envelope::None) => bail_unsupported!("\"ENVELOPE NONE\" sinks"),
@@ -2199,10 +2515,11 @@ pub fn plan_create_sink(
 
     let from_name = &from;
     let from = scx.get_item_by_resolved_name(&from)?;
+    query::check_unfiltered_read(scx, from)?;
 
     let desc = from.desc(&scx.catalog.resolve_full_name(from.name()))?;
     let key_indices = match &connection {
-        CreateSinkConnection::Kafka { key, .. } => {
+        CreateSinkConnection::Kafka { key, .. } | CreateSinkConnection::Postgres { key, .. } => {
             if let Some(key) = key.clone() {
                 let key_columns = key
                     .key_columns
@@ -2292,6 +2609,19 @@ pub fn plan_create_sink(
             desc.into_owned(),
             envelope,
         )?,
+        CreateSinkConnection::Postgres {
+            connection,
+            options,
+            ..
+        } => postgres_sink_builder(
+            scx,
+            connection,
+            options,
+            format,
+            key_desc_and_indices,
+            desc.into_owned(),
+            envelope,
+        )?,
     };
 
     let CreateSinkOptionExtracted {
@@ -2347,6 +2677,71 @@ generate_extracted_config!(
     (AvroValueFullname, String)
 );
 
+generate_extracted_config!(PgSinkOption, (Table, String));
+
+fn postgres_sink_builder(
+    scx: &StatementContext,
+    connection: ResolvedItemName,
+    options: Vec<PgSinkOption<Aug>>,
+    format: Option<Format<Aug>>,
+    key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    value_desc: RelationDesc,
+    envelope: SinkEnvelope,
+) -> Result<StorageSinkConnectionBuilder<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_POSTGRES_SINK)?;
+
+    let item = scx.get_item_by_resolved_name(&connection)?;
+    let connection = match item.connection()? {
+        Connection::Postgres(connection) => connection.clone(),
+        _ => sql_bail!(
+            "{} is not a postgres connection",
+            scx.catalog.resolve_full_name(item.name())
+        ),
+    };
+
+    if format.is_some() {
+        sql_bail!("POSTGRES CONNECTION sinks do not support a FORMAT clause");
+    }
+    if envelope != SinkEnvelope::Upsert {
+        sql_bail!("POSTGRES CONNECTION sinks require ENVELOPE UPSERT");
+    }
+    // Ensured by the caller for ENVELOPE UPSERT.
+    let key_desc_and_indices =
+        key_desc_and_indices.expect("ENVELOPE UPSERT sinks must specify a KEY");
+    for (name, typ) in key_desc_and_indices.0.iter() {
+        if typ.nullable {
+            sql_bail!(
+                "KEY column {} of a POSTGRES CONNECTION sink must not be nullable",
+                name.as_str().quoted()
+            );
+        }
+    }
+
+    let PgSinkOptionExtracted { table, seen: _ } = options.try_into()?;
+    let table = table.ok_or_else(|| sql_err!("POSTGRES CONNECTION must specify TABLE"))?;
+    let (schema, table) = match table.split('.').collect::<Vec<_>>()[..] {
+        [table] => ("public".to_string(), table.to_string()),
+        [schema, table] => (schema.to_string(), table.to_string()),
+        _ => sql_bail!(
+            "invalid TABLE {}: must be of the form [schema.]table",
+            table.quoted()
+        ),
+    };
+
+    Ok(StorageSinkConnectionBuilder::Postgres(
+        PostgresSinkConnection {
+            connection,
+            connection_id: item.id(),
+            schema,
+            table,
+            key_desc_and_indices,
+            value_desc,
+            progress_table: "mz_sink_progress".to_string(),
+            environment_id: scx.catalog.config().environment_id.to_string(),
+        },
+    ))
+}
+
 fn kafka_sink_builder(
     scx: &StatementContext,
     mz_sql_parser::ast::KafkaConnection {
@@ -4087,6 +4482,7 @@ pub fn plan_drop_owned(
     let role_ids: BTreeSet<_> = role_names.into_iter().map(|role| role.id).collect();
     let mut drop_ids = Vec::new();
     let mut privilege_revokes = Vec::new();
+    let mut column_privilege_revokes = Vec::new();
     let mut default_privilege_revokes = Vec::new();
 
     fn update_privilege_revokes(
@@ -4191,6 +4587,25 @@ pub fn plan_drop_owned(
             &role_ids,
             &mut privilege_revokes,
         );
+        for (column, privileges) in scx
+            .catalog
+            .get_column_privileges(&item.id())
+            .into_iter()
+            .flatten()
+        {
+            column_privilege_revokes.extend(
+                privileges
+                    .all_values()
+                    .filter(|privilege| role_ids.contains(&privilege.grantee))
+                    .map(|privilege| {
+                        (
+                            item.id(),
+                            ColumnName::from(column.as_str()),
+                            privilege.clone(),
+                        )
+                    }),
+            );
+        }
     }
 
     // Schemas
@@ -4293,6 +4708,7 @@ pub fn plan_drop_owned(
         role_ids: role_ids.into_iter().collect(),
         drop_ids,
         privilege_revokes,
+        column_privilege_revokes,
         default_privilege_revokes,
     }))
 }
@@ -4834,6 +5250,8 @@ pub fn plan_alter_sink(
     let id = entry.id();
 
     let mut size = AlterOptionParameter::Unchanged;
+    let mut reset_progress = None;
+    let mut set_from = None;
     match action {
         AlterSinkAction::SetOptions(options) => {
             let CreateSinkOptionExtracted {
@@ -4861,9 +5279,43 @@ pub fn plan_alter_sink(
                 }
             }
         }
+        AlterSinkAction::ResetProgress(timestamp) => {
+            scx.require_feature_flag(&vars::ENABLE_ALTER_SINK_RESET_PROGRESS)?;
+            reset_progress = Some(Timestamp::from(timestamp));
+        }
+        AlterSinkAction::ChangeRelation(from) => {
+            scx.require_feature_flag(&vars::ENABLE_ALTER_SINK_SET_FROM)?;
+            let from = scx.get_item_by_resolved_name(&from)?;
+
+            // Plan the sink as if it had been created on the new relation,
+            // which derives its key, schemas and creation statement the same
+            // way `CREATE SINK` does.
+            let mut stmt = match crate::parse::parse(entry.create_sql())?.into_element().ast {
+                Statement::CreateSink(stmt) => stmt,
+                _ => sql_bail!(
+                    "{} was not created with CREATE SINK",
+                    scx.catalog.resolve_full_name(entry.name())
+                ),
+            };
+            stmt.from = RawItemName::Id(
+                from.id().to_string(),
+                normalize::unresolve(scx.catalog.resolve_full_name(from.name())),
+            );
+            stmt.if_not_exists = true;
+            let (stmt, _) = crate::names::resolve(scx.catalog, stmt)?;
+            match plan_create_sink(scx, stmt)? {
+                Plan::CreateSink(CreateSinkPlan { sink, .. }) => set_from = Some(sink),
+                _ => unreachable!("CREATE SINK plans as a sink"),
+            }
+        }
     };
 
-    Ok(Plan::AlterSink(AlterSinkPlan { id, size }))
+    Ok(Plan::AlterSink(AlterSinkPlan {
+        id,
+        size,
+        reset_progress,
+        set_from,
+    }))
 }
 
 pub fn describe_alter_source(
@@ -5279,6 +5731,141 @@ pub fn plan_comment(
     }))
 }
 
+pub fn describe_create_policy(
+    _: &StatementContext,
+    _: CreatePolicyStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_policy(
+    scx: &StatementContext,
+    CreatePolicyStatement {
+        name,
+        object,
+        using,
+    }: CreatePolicyStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_ROW_LEVEL_SECURITY)?;
+
+    let name = normalize::ident(name);
+    let item = resolve_policy_object(scx, &object)?;
+    let full_name = scx.catalog.resolve_full_name(item.name());
+    if scx
+        .catalog
+        .get_item_policies(&item.id())
+        .map_or(false, |policies| policies.contains_key(&name))
+    {
+        return Err(CatalogError::PolicyAlreadyExists(name).into());
+    }
+
+    // Policies cannot be evaluated within maintained objects that other roles
+    // have already built on top of this object.
+    let owner_id = item.owner_id();
+    for dependent_id in item.used_by() {
+        let dependent = scx.catalog.get_item(dependent_id);
+        if !dependent.owner_id().is_system()
+            && !scx
+                .catalog
+                .collect_role_membership(&dependent.owner_id())
+                .contains(&owner_id)
+        {
+            sql_bail!(
+                "cannot create policy on {}: {} {} is owned by a role that would be subject to it",
+                full_name.to_string().quoted(),
+                dependent.item_type(),
+                scx.catalog
+                    .resolve_full_name(dependent.name())
+                    .to_string()
+                    .quoted(),
+            );
+        }
+    }
+
+    // Validate the predicate now so that planning queries against the object
+    // later cannot fail.
+    let desc = item.desc(&full_name)?;
+    query::plan_policy_predicate(scx, QueryLifetime::OneShot, &desc, using.clone())?;
+
+    Ok(Plan::CreatePolicy(CreatePolicyPlan {
+        name,
+        object_id: item.id(),
+        predicate: using.to_ast_string_stable(),
+    }))
+}
+
+pub fn describe_drop_policy(
+    _: &StatementContext,
+    _: DropPolicyStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_drop_policy(
+    scx: &StatementContext,
+    DropPolicyStatement {
+        if_exists,
+        name,
+        object,
+    }: DropPolicyStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let name = normalize::ident(name);
+    let item = resolve_policy_object(scx, &object)?;
+    if !scx
+        .catalog
+        .get_item_policies(&item.id())
+        .map_or(false, |policies| policies.contains_key(&name))
+    {
+        if !if_exists {
+            return Err(CatalogError::UnknownPolicy(name).into());
+        }
+        scx.catalog.add_notice(PlanNotice::PolicyDoesNotExist {
+            name: name.clone(),
+            object: scx.catalog.resolve_full_name(item.name()).to_string(),
+        });
+    }
+
+    Ok(Plan::DropPolicy(DropPolicyPlan {
+        name,
+        object_id: item.id(),
+        if_exists,
+    }))
+}
+
+/// Resolves the object that a policy applies to, which must be a relation
+/// that can be read from.
+fn resolve_policy_object<'a>(
+    scx: &'a StatementContext,
+    object: &ResolvedItemName,
+) -> Result<&'a dyn CatalogItem, PlanError> {
+    let item = scx.get_item_by_resolved_name(object)?;
+    match item.item_type() {
+        CatalogItemType::Table
+        | CatalogItemType::Source
+        | CatalogItemType::View
+        | CatalogItemType::MaterializedView => {}
+        ty => sql_bail!(
+            "{} is a {}, but policies can only be created on tables, sources, views and \
+             materialized views",
+            scx.catalog
+                .resolve_full_name(item.name())
+                .to_string()
+                .quoted(),
+            ty,
+        ),
+    }
+    if item.id().is_system() {
+        sql_bail!(
+            "cannot create policy on system object {}",
+            scx.catalog
+                .resolve_full_name(item.name())
+                .to_string()
+                .quoted()
+        );
+    }
+    Ok(item)
+}
+
 pub(crate) fn resolve_cluster<'a>(
     scx: &'a StatementContext,
     name: &'a Ident,
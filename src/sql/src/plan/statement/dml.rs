@@ -20,7 +20,7 @@ use mz_expr::MirRelationExpr;
 use mz_pgcopy::{CopyCsvFormatParams, CopyFormatParams, CopyTextFormatParams};
 use mz_repr::adt::numeric::NumericMaxScale;
 use mz_repr::explain::{ExplainConfig, ExplainFormat};
-use mz_repr::{RelationDesc, ScalarType};
+use mz_repr::{GlobalId, RelationDesc, ScalarType};
 use mz_sql_parser::ast::{ExplainTimestampStatement, Expr, OrderByExpr, SubscribeOutput};
 
 use crate::ast::display::AstDisplay;
@@ -81,6 +81,7 @@ pub fn plan_insert(
     let (id, mut expr, returning) =
         query::plan_insert_query(scx, table_name, columns, source, returning)?;
     expr.bind_parameters(params)?;
+    let column_reads = expr.referenced_columns((0..expr.arity()).collect());
     let expr = expr.optimize_and_lower(&scx.into())?;
     let returning = returning
         .expr
@@ -92,6 +93,7 @@ pub fn plan_insert(
         id,
         values: expr,
         returning,
+        column_reads,
     }))
 }
 
@@ -141,6 +143,13 @@ pub fn plan_read_then_write(
     }: query::ReadThenWritePlan,
 ) -> Result<Plan, PlanError> {
     selection.bind_parameters(params)?;
+    // The selection reads every column of the table to produce retractions, but only the
+    // columns that the `WHERE` clause and the `SET` expressions reference are read by the user.
+    let arity = selection.arity();
+    let column_reads = selection
+        .clone()
+        .map(assignments.values().cloned().collect())
+        .referenced_columns((arity..arity + assignments.len()).collect());
     let selection = selection.optimize_and_lower(&scx.into())?;
     let mut assignments_outer = BTreeMap::new();
     for (idx, mut set) in assignments {
@@ -156,6 +165,7 @@ pub fn plan_read_then_write(
         assignments: assignments_outer,
         kind,
         returning: Vec::new(),
+        column_reads,
     }))
 }
 
@@ -182,15 +192,19 @@ pub fn plan_select(
         return Ok(Plan::SideEffectingFunc(f));
     }
 
-    let query::PlannedQuery {
-        expr, finishing, ..
-    } = plan_query(scx, select.query, params, QueryLifetime::OneShot)?;
+    let (
+        query::PlannedQuery {
+            expr, finishing, ..
+        },
+        column_reads,
+    ) = plan_query(scx, select.query, params, QueryLifetime::OneShot)?;
     let when = query::plan_as_of(scx, select.as_of)?;
     Ok(Plan::Select(SelectPlan {
         source: expr,
         when,
         finishing,
         copy_to,
+        column_reads,
     }))
 }
 
@@ -373,13 +387,20 @@ pub fn plan_explain_timestamp(
 }
 
 /// Plans and decorrelates a `Query`. Like `query::plan_root_query`, but returns
-/// an `mz_expr::MirRelationExpr`, which cannot include correlated expressions.
+/// an `mz_expr::MirRelationExpr`, which cannot include correlated expressions,
+/// along with the columns the query reads from each relation.
 pub fn plan_query(
     scx: &StatementContext,
     query: Query<Aug>,
     params: &Params,
     lifetime: QueryLifetime,
-) -> Result<query::PlannedQuery<MirRelationExpr>, PlanError> {
+) -> Result<
+    (
+        query::PlannedQuery<MirRelationExpr>,
+        BTreeMap<GlobalId, BTreeSet<usize>>,
+    ),
+    PlanError,
+> {
     let query::PlannedQuery {
         mut expr,
         desc,
@@ -387,12 +408,20 @@ pub fn plan_query(
         scope,
     } = query::plan_root_query(scx, query, lifetime)?;
     expr.bind_parameters(params)?;
-    Ok(query::PlannedQuery {
+    let demand = finishing
+        .project
+        .iter()
+        .copied()
+        .chain(finishing.order_by.iter().map(|order| order.column))
+        .collect();
+    let column_reads = expr.referenced_columns(demand);
+    let query = query::PlannedQuery {
         expr: expr.optimize_and_lower(&scx.into())?,
         desc,
         finishing,
         scope,
-    })
+    };
+    Ok((query, column_reads))
 }
 
 generate_extracted_config!(SubscribeOption, (Snapshot, bool), (Progress, bool));
@@ -483,7 +512,7 @@ pub fn plan_subscribe(
     params: &Params,
     copy_to: Option<CopyFormat>,
 ) -> Result<Plan, PlanError> {
-    let (from, desc, scope) = match relation {
+    let (from, desc, scope, column_reads) = match relation {
         SubscribeRelation::Name(name) => {
             let entry = scx.get_item_by_resolved_name(&name)?;
             let desc = match entry.desc(&scx.catalog.resolve_full_name(entry.name())) {
@@ -494,15 +523,36 @@ pub fn plan_subscribe(
                     entry.item_type(),
                 ),
             };
-            let item_name = match name {
-                ResolvedItemName::Item { full_name, .. } => Some(full_name.into()),
-                _ => None,
-            };
-            let scope = Scope::from_source(item_name, desc.iter().map(|(name, _type)| name));
-            (SubscribeFrom::Id(entry.id()), desc.into_owned(), scope)
+            if query::applicable_policies(scx, entry).is_some() {
+                // Reading the collection directly would bypass the row-level
+                // security policies that apply to the current role.
+                let qcx = QueryContext::root(scx, QueryLifetime::Subscribe);
+                let (expr, scope) = qcx.resolve_table_name(name)?;
+                let desc = RelationDesc::new(qcx.relation_type(&expr), desc.iter_names().cloned());
+                let column_reads = expr.referenced_columns((0..desc.arity()).collect());
+                let from = SubscribeFrom::Query {
+                    expr: expr.optimize_and_lower(&scx.into())?,
+                    desc: desc.clone(),
+                };
+                (from, desc, scope, column_reads)
+            } else {
+                query::check_policy_dependencies(scx, entry)?;
+                let item_name = match name {
+                    ResolvedItemName::Item { full_name, .. } => Some(full_name.into()),
+                    _ => None,
+                };
+                let scope = Scope::from_source(item_name, desc.iter().map(|(name, _type)| name));
+                let column_reads = BTreeMap::from([(entry.id(), (0..desc.arity()).collect())]);
+                (
+                    SubscribeFrom::Id(entry.id()),
+                    desc.into_owned(),
+                    scope,
+                    column_reads,
+                )
+            }
         }
         SubscribeRelation::Query(query) => {
-            let query = plan_query(scx, query, params, QueryLifetime::Subscribe)?;
+            let (query, column_reads) = plan_query(scx, query, params, QueryLifetime::Subscribe)?;
             // There's no way to apply finishing operations to a `SUBSCRIBE` directly, so the
             // finishing should have already been turned into a `TopK` by
             // `plan_query` / `plan_root_query`, upon seeing the `QueryLifetime::Subscribe`.
@@ -515,6 +565,7 @@ pub fn plan_subscribe(
                 },
                 desc,
                 query.scope,
+                column_reads,
             )
         }
     };
@@ -631,6 +682,7 @@ pub fn plan_subscribe(
         copy_to,
         emit_progress: progress.unwrap_or(false),
         output,
+        column_reads,
     }))
 }
 
@@ -17,8 +17,8 @@ use mz_ore::stack::{CheckedRecursion, RecursionGuard};
 use mz_repr::namespaces::{MZ_CATALOG_SCHEMA, MZ_INTERNAL_SCHEMA, PG_CATALOG_SCHEMA};
 use mz_sql_parser::ast::visit_mut::{self, VisitMut, VisitMutNode};
 use mz_sql_parser::ast::{
-    Expr, Function, FunctionArgs, Ident, Op, OrderByExpr, Query, Select, SelectItem, TableAlias,
-    TableFactor, TableWithJoins, Value,
+    Expr, Function, FunctionArgs, Ident, IsExprConstruct, Op, OrderByExpr, Query, Select,
+    SelectItem, TableAlias, TableFactor, TableWithJoins, Value,
 };
 use uuid::Uuid;
 
@@ -58,6 +58,9 @@ where
 //   * Rewrites the suite of standard deviation and variance functions in a
 //     manner similar to `avg`.
 //
+//   * Rewrites the two-argument statistical aggregates (`corr`, `covar_*` and
+//     `regr_*`) in terms of `sum` and `count`, in the same manner as variance.
+//
 // TODO(sploiselle): rewrite these in terms of func::sql_op!
 struct FuncRewriter<'a> {
     scx: &'a StatementContext<'a>,
@@ -98,6 +101,14 @@ impl<'a> FuncRewriter<'a> {
         })
     }
 
+    fn float64_data_type(&self) -> ResolvedDataType {
+        self.resolve_known_valid_data_type(&PartialItemName {
+            database: None,
+            schema: Some(PG_CATALOG_SCHEMA.into()),
+            item: "float8".into(),
+        })
+    }
+
     // Divides `lhs` by `rhs` but replaces division-by-zero errors with NULL;
     // note that this is semantically equivalent to `NULLIF(rhs, 0)`.
     fn plan_divide(lhs: Expr<Aug>, rhs: Expr<Aug>) -> Expr<Aug> {
@@ -131,6 +142,7 @@ impl<'a> FuncRewriter<'a> {
             filter,
             over: None,
             distinct,
+            within_group: false,
         })
     }
 
@@ -272,6 +284,143 @@ impl<'a> FuncRewriter<'a> {
             )
     }
 
+    fn plan_regr(
+        &mut self,
+        name: &str,
+        y: Expr<Aug>,
+        x: Expr<Aug>,
+        filter: Option<Box<Expr<Aug>>>,
+        distinct: bool,
+    ) -> Expr<Aug> {
+        // N.B. like `plan_variance`, this uses the "textbook" formulas, which
+        // are built entirely out of `sum` and `count` and so plan as
+        // accumulable reductions, at the cost of numerical stability. With
+        // `N` the number of rows where both `y` and `x` are non-null, the
+        // building blocks are
+        //
+        //     regr_sxx(y, x) = sum(x²) - sum(x)² / N
+        //     regr_syy(y, x) = sum(y²) - sum(y)² / N
+        //     regr_sxy(y, x) = sum(x * y) - sum(x) * sum(y) / N
+        //
+        // and everything else is derived from those as in PostgreSQL.
+        if distinct && self.status.is_ok() {
+            self.status = Err(PlanError::Unsupported {
+                feature: format!("{}(DISTINCT ...)", name),
+                issue_no: None,
+            });
+        }
+
+        // Only rows where both arguments are non-null contribute, so fold that
+        // condition into the aggregate filter.
+        let is_not_null = |expr: &Expr<Aug>| Expr::IsExpr {
+            expr: Box::new(expr.clone()),
+            construct: IsExprConstruct::Null,
+            negated: true,
+        };
+        let pair_filter = is_not_null(&y).and(is_not_null(&x));
+        let filter = Some(Box::new(match filter {
+            Some(filter) => pair_filter.and(*filter),
+            None => pair_filter,
+        }));
+
+        let count = self.plan_agg(
+            self.scx
+                .dangerous_resolve_name(vec![PG_CATALOG_SCHEMA, "count"]),
+            x.clone(),
+            vec![],
+            filter.clone(),
+            false,
+        );
+        if name == "regr_count" {
+            return count;
+        }
+
+        // The arguments are float8 in PostgreSQL. Multiplying by a float8 one
+        // implicitly casts them, whereas `x::float8` would perform an explicit
+        // cast and accept too many types.
+        let one = Expr::number("1").cast(self.float64_data_type());
+        let y = y.multiply(one.clone());
+        let x = x.multiply(one);
+        let mut sum = |expr: Expr<Aug>| {
+            self.plan_agg(
+                self.scx
+                    .dangerous_resolve_name(vec![PG_CATALOG_SCHEMA, "sum"]),
+                expr,
+                vec![],
+                filter.clone(),
+                false,
+            )
+        };
+        let sum_x = sum(x.clone());
+        let sum_y = sum(y.clone());
+        let sum_xx = sum(x.clone().multiply(x.clone()));
+        let sum_yy = sum(y.clone().multiply(y.clone()));
+        let sum_xy = sum(x.multiply(y));
+
+        let sxx = sum_xx.minus(Self::plan_divide(
+            sum_x.clone().multiply(sum_x.clone()),
+            count.clone(),
+        ));
+        let syy = sum_yy.minus(Self::plan_divide(
+            sum_y.clone().multiply(sum_y.clone()),
+            count.clone(),
+        ));
+        let sxy = sum_xy.minus(Self::plan_divide(
+            sum_x.clone().multiply(sum_y.clone()),
+            count.clone(),
+        ));
+
+        match name {
+            "regr_sxx" => sxx,
+            "regr_syy" => syy,
+            "regr_sxy" => sxy,
+            "regr_avgx" => Self::plan_divide(sum_x, count),
+            "regr_avgy" => Self::plan_divide(sum_y, count),
+            "covar_pop" => Self::plan_divide(sxy, count),
+            "covar_samp" => Self::plan_divide(sxy, count.minus(Expr::number("1"))),
+            // Rounding can leave a sum of squares that should be zero slightly
+            // negative, which must not reach `sqrt`.
+            "corr" => Expr::Case {
+                operand: None,
+                conditions: vec![sxx
+                    .clone()
+                    .lt_eq(Expr::number("0"))
+                    .or(syy.clone().lt_eq(Expr::number("0")))],
+                results: vec![Expr::null()],
+                else_result: Some(Box::new(
+                    sxy.divide(
+                        sxx.multiply(syy).call_unary(
+                            self.scx
+                                .dangerous_resolve_name(vec![PG_CATALOG_SCHEMA, "sqrt"]),
+                        ),
+                    ),
+                )),
+            },
+            "regr_slope" => Self::plan_divide(sxy, sxx),
+            "regr_intercept" => {
+                // (sum(y) - sum(x) * regr_slope) / N
+                let slope = Self::plan_divide(sxy, sxx);
+                Self::plan_divide(sum_y.minus(sum_x.multiply(slope)), count)
+            }
+            "regr_r2" => Expr::Case {
+                operand: None,
+                conditions: vec![
+                    sxx.clone().equals(Expr::number("0")),
+                    syy.clone().equals(Expr::number("0")),
+                ],
+                results: vec![
+                    Expr::null(),
+                    Expr::number("1").cast(self.float64_data_type()),
+                ],
+                else_result: Some(Box::new(Self::plan_divide(
+                    sxy.clone().multiply(sxy),
+                    sxx.multiply(syy),
+                ))),
+            },
+            _ => unreachable!("unknown regression aggregate {}", name),
+        }
+    }
+
     fn plan_bool_and(
         &mut self,
         expr: Expr<Aug>,
@@ -336,6 +485,7 @@ impl<'a> FuncRewriter<'a> {
             filter,
             distinct,
             over: None,
+            within_group: false,
         } = func
         {
             let pg_catalog_id = self
@@ -383,6 +533,11 @@ impl<'a> FuncRewriter<'a> {
                 let (lhs, rhs) = (args[0].clone(), args[1].clone());
                 match name.as_str() {
                     "mod" => lhs.modulo(rhs),
+                    "corr" | "covar_pop" | "covar_samp" | "regr_avgx" | "regr_avgy"
+                    | "regr_count" | "regr_intercept" | "regr_r2" | "regr_slope" | "regr_sxx"
+                    | "regr_sxy" | "regr_syy" => {
+                        self.plan_regr(name.as_str(), lhs, rhs, filter, distinct)
+                    }
                     "pow" => Expr::call(
                         self.scx
                             .dangerous_resolve_name(vec![PG_CATALOG_SCHEMA, "power"]),
@@ -610,6 +765,7 @@ impl<'a> Desugarer<'a> {
                                 filter: None,
                                 over: None,
                                 distinct: false,
+                                within_group: false,
                             },
                             alias: Some(TableAlias {
                                 name: Ident::new("_"),
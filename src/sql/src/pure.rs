@@ -35,10 +35,12 @@ use mz_storage_types::connections::inline::IntoInlineConnection;
 use mz_storage_types::connections::{Connection, ConnectionContext};
 use mz_storage_types::sources::{
     GenericSourceConnection, PostgresSourcePublicationDetails, SourceConnection,
+    ValueDecodingErrors,
 };
 use prost::Message;
 use protobuf_native::compiler::{SourceTreeDescriptorDatabase, VirtualSourceTree};
 use protobuf_native::MessageLite;
+use regex::Regex;
 use tracing::info;
 use uuid::Uuid;
 
@@ -51,7 +53,7 @@ use crate::catalog::{ErsatzCatalog, SessionCatalog};
 use crate::kafka_util::KafkaConfigOptionExtracted;
 use crate::names::Aug;
 use crate::plan::error::PlanError;
-use crate::plan::statement::ddl::load_generator_ast_to_generator;
+use crate::plan::statement::ddl::{load_generator_ast_to_generator, CreateSourceOptionExtracted};
 use crate::plan::StatementContext;
 use crate::{kafka_util, normalize};
 
@@ -173,7 +175,8 @@ async fn purify_create_source(
         connection,
         format,
         envelope,
-        include_metadata: _,
+        include_metadata,
+        with_options,
         referenced_subsources,
         progress_subsource,
         ..
@@ -250,9 +253,24 @@ async fn purify_create_source(
                 connection.options.insert(k, v);
             }
 
-            let topic = extracted_options
-                .topic
-                .ok_or(KafkaSourcePurificationError::ConnectionMissingTopic)?;
+            let topic = match (extracted_options.topic, extracted_options.topic_pattern) {
+                (Some(topic), None) => topic,
+                (None, Some(pattern)) => {
+                    if offset_type.is_some() {
+                        Err(KafkaSourcePurificationError::TopicPatternUnsupported(
+                            "START OFFSET or START TIMESTAMP",
+                        ))?;
+                    }
+                    if let Err(e) = Regex::new(&pattern) {
+                        Err(KafkaSourcePurificationError::InvalidTopicPattern(
+                            e.to_string(),
+                        ))?;
+                    }
+                    pattern
+                }
+                (Some(_), Some(_)) => Err(KafkaSourcePurificationError::TopicAndTopicPattern)?,
+                (None, None) => Err(KafkaSourcePurificationError::ConnectionMissingTopic)?,
+            };
 
             let consumer = connection
                 .create_with_context(
@@ -304,6 +322,61 @@ async fn purify_create_source(
                     None => {}
                 }
             }
+
+            let source_options: CreateSourceOptionExtracted = with_options.clone().try_into()?;
+            if matches!(
+                source_options.value_decoding_errors.as_deref(),
+                Some(policy) if policy.eq_ignore_ascii_case("inline")
+            ) {
+                // Generate the companion subsource that receives the records
+                // whose value fails to decode.
+                let transient_id = GlobalId::Transient(get_transient_subsource_id());
+
+                let (item, prefix) = source_name.0.split_last().unwrap();
+                let mut suggested_name = prefix.to_vec();
+                suggested_name.push(format!("{}_errors", item).into());
+
+                let partial = normalize::unresolved_item_name(UnresolvedItemName(suggested_name))?;
+                let qualified = scx.allocate_qualified_name(partial)?;
+                let found_name = scx.catalog.find_available_name(qualified);
+                let full_name = scx.catalog.resolve_full_name(&found_name);
+
+                let metadata_columns = kafka_util::metadata_columns(include_metadata);
+                let desc = ValueDecodingErrors::relation_desc(
+                    metadata_columns
+                        .iter()
+                        .map(|(name, kind)| (name.as_str(), kind.scalar_type().nullable(false))),
+                );
+                let (columns, constraints) = scx.relation_desc_into_table_defs(&desc)?;
+
+                *referenced_subsources = Some(ReferencedSubsources::SubsetTables(vec![
+                    CreateSourceSubsource {
+                        reference: UnresolvedItemName::from(
+                            kafka_util::value_decoding_errors_reference(),
+                        ),
+                        subsource: Some(DeferredItemName::Named(
+                            crate::names::ResolvedItemName::Item {
+                                id: transient_id,
+                                qualifiers: found_name.qualifiers,
+                                full_name: full_name.clone(),
+                                print_id: true,
+                            },
+                        )),
+                    },
+                ]));
+
+                let subsource = CreateSubsourceStatement {
+                    name: UnresolvedItemName::from(full_name),
+                    columns,
+                    constraints,
+                    if_not_exists: false,
+                    with_options: vec![CreateSubsourceOption {
+                        name: CreateSubsourceOptionName::References,
+                        value: Some(WithOptionValue::Value(Value::Boolean(true))),
+                    }],
+                };
+                subsources.push((transient_id, subsource));
+            }
         }
         CreateSourceConnection::TestScript { desc_json: _ } => {
             if let Some(referenced_subsources) = referenced_subsources {
@@ -332,6 +405,9 @@ async fn purify_create_source(
             let crate::plan::statement::PgConfigOptionExtracted {
                 publication,
                 mut text_columns,
+                mut truncate_retract,
+                mut truncate_ignore,
+                mut upsert_tables,
                 details,
                 ..
             } = options.clone().try_into()?;
@@ -431,8 +507,30 @@ async fn purify_create_source(
                 );
             }
 
-            postgres::validate_requested_subsources(&config, &validated_requested_subsources)
-                .await?;
+            // Fully qualify the tables that are ingested in upsert mode.
+            let upsert_oids = postgres::generate_upsert_tables(
+                &publication_catalog,
+                &mut upsert_tables,
+                &PgConfigOptionName::UpsertTables.to_ast_string(),
+            )?;
+            if let Some(option) = options
+                .iter_mut()
+                .find(|option| option.name == PgConfigOptionName::UpsertTables)
+            {
+                let mut seq: Vec<_> = upsert_tables
+                    .into_iter()
+                    .map(WithOptionValue::UnresolvedItemName)
+                    .collect();
+                seq.sort();
+                option.value = Some(WithOptionValue::Sequence(seq));
+            }
+
+            postgres::validate_requested_subsources(
+                &config,
+                &validated_requested_subsources,
+                &upsert_oids,
+            )
+            .await?;
 
             let text_cols_dict = postgres::generate_text_columns(
                 &publication_catalog,
@@ -456,6 +554,29 @@ async fn purify_create_source(
                 text_cols_option.value = Some(WithOptionValue::Sequence(seq));
             }
 
+            // Fully qualify the tables whose truncate behavior was configured.
+            let mut truncate_tables = BTreeSet::new();
+            for (option_name, tables) in [
+                (PgConfigOptionName::TruncateRetract, &mut truncate_retract),
+                (PgConfigOptionName::TruncateIgnore, &mut truncate_ignore),
+            ] {
+                postgres::generate_table_references(
+                    &publication_catalog,
+                    tables,
+                    &option_name.to_ast_string(),
+                    &mut truncate_tables,
+                )?;
+
+                if let Some(option) = options.iter_mut().find(|option| option.name == option_name) {
+                    let mut seq: Vec<_> = tables
+                        .drain(..)
+                        .map(WithOptionValue::UnresolvedItemName)
+                        .collect();
+                    seq.sort();
+                    option.value = Some(WithOptionValue::Sequence(seq));
+                }
+            }
+
             let (targeted_subsources, new_subsources) = postgres::generate_targeted_subsources(
                 &scx,
                 validated_requested_subsources,
@@ -755,7 +876,13 @@ async fn purify_alter_source(
         }
     }
 
-    postgres::validate_requested_subsources(&config, &validated_requested_subsources).await?;
+    // Subsources added to an existing source are never ingested in upsert mode.
+    postgres::validate_requested_subsources(
+        &config,
+        &validated_requested_subsources,
+        &BTreeSet::new(),
+    )
+    .await?;
     let mut subsource_id_counter = 0;
     let get_transient_subsource_id = move || {
         subsource_id_counter += 1;
@@ -843,7 +970,7 @@ async fn purify_source_format(
     catalog: &dyn SessionCatalog,
     format: &mut CreateSourceFormat<Aug>,
     connection: &mut CreateSourceConnection<Aug>,
-    envelope: &Option<Envelope>,
+    envelope: &Option<Envelope<Aug>>,
     connection_context: &ConnectionContext,
 ) -> Result<(), PlanError> {
     if matches!(format, CreateSourceFormat::KeyValue { .. })
@@ -877,7 +1004,7 @@ async fn purify_source_format_single(
     catalog: &dyn SessionCatalog,
     format: &mut Format<Aug>,
     connection: &mut CreateSourceConnection<Aug>,
-    envelope: &Option<Envelope>,
+    envelope: &Option<Envelope<Aug>>,
     connection_context: &ConnectionContext,
 ) -> Result<(), PlanError> {
     match format {
@@ -916,7 +1043,7 @@ async fn purify_csr_connection_proto(
     catalog: &dyn SessionCatalog,
     connection: &mut CreateSourceConnection<Aug>,
     csr_connection: &mut CsrConnectionProtobuf<Aug>,
-    envelope: &Option<Envelope>,
+    envelope: &Option<Envelope<Aug>>,
     connection_context: &ConnectionContext,
 ) -> Result<(), PlanError> {
     let topic = if let CreateSourceConnection::Kafka(KafkaSourceConnection {
@@ -928,7 +1055,12 @@ async fn purify_csr_connection_proto(
             .clone()
             .try_into()
             .expect("already verified options valid provided");
-        topic.expect("already validated topic provided")
+        match topic {
+            Some(topic) => topic,
+            None => Err(KafkaSourcePurificationError::TopicPatternUnsupported(
+                "CONFLUENT SCHEMA REGISTRY",
+            ))?,
+        }
     } else {
         sql_bail!("Confluent Schema Registry is only supported with Kafka sources")
     };
@@ -972,7 +1104,7 @@ async fn purify_csr_connection_avro(
     catalog: &dyn SessionCatalog,
     connection: &mut CreateSourceConnection<Aug>,
     csr_connection: &mut CsrConnectionAvro<Aug>,
-    envelope: &Option<Envelope>,
+    envelope: &Option<Envelope<Aug>>,
     connection_context: &ConnectionContext,
 ) -> Result<(), PlanError> {
     let topic = if let CreateSourceConnection::Kafka(KafkaSourceConnection {
@@ -984,7 +1116,12 @@ async fn purify_csr_connection_avro(
             .clone()
             .try_into()
             .expect("already verified options valid provided");
-        topic.expect("already validated topic provided")
+        match topic {
+            Some(topic) => topic,
+            None => Err(KafkaSourcePurificationError::TopicPatternUnsupported(
+                "CONFLUENT SCHEMA REGISTRY",
+            ))?,
+        }
     } else {
         sql_bail!("Confluent Schema Registry is only supported with Kafka sources")
     };
@@ -47,6 +47,10 @@ pub enum PgSourcePurificationError {
     UserLacksSelectOnTables { user: String, tables: Vec<String> },
     #[error("referenced items not tables with REPLICA IDENTITY FULL")]
     NotTablesWReplicaIdentityFull { items: Vec<String> },
+    #[error("UPSERT TABLES refers to items not tables with REPLICA IDENTITY DEFAULT or FULL")]
+    NotTablesWReplicaIdentityDefault { items: Vec<String> },
+    #[error("UPSERT TABLES refers to table {name} without a primary key")]
+    UpsertTableWithoutPrimaryKey { name: UnresolvedItemName },
     #[error("TEXT COLUMNS refers to table not currently being added")]
     DanglingTextColumns { items: Vec<PartialItemName> },
     #[error("referenced tables use unsupported types")]
@@ -95,7 +99,8 @@ impl PgSourcePurificationError {
                 user,
                 tables.join(", ")
             )),
-            Self::NotTablesWReplicaIdentityFull { items } => {
+            Self::NotTablesWReplicaIdentityFull { items }
+            | Self::NotTablesWReplicaIdentityDefault { items } => {
                 Some(format!("referenced items: {}", items.join(", ")))
             }
             Self::UnrecognizedTypes { cols } => Some(format!(
@@ -141,6 +146,12 @@ pub enum KafkaSourcePurificationError {
     ReferencedSubsources(ReferencedSubsources<Aug>),
     #[error("KAFKA CONNECTION without TOPIC")]
     ConnectionMissingTopic,
+    #[error("cannot specify both TOPIC and TOPIC PATTERN")]
+    TopicAndTopicPattern,
+    #[error("invalid TOPIC PATTERN")]
+    InvalidTopicPattern(String),
+    #[error("{0} is not supported with TOPIC PATTERN")]
+    TopicPatternUnsupported(&'static str),
     #[error("{0} is not a KAFKA CONNECTION")]
     NotKafkaConnection(FullItemName),
     #[error("failed to create and connect Kafka consumer")]
@@ -151,6 +162,7 @@ impl KafkaSourcePurificationError {
     pub fn detail(&self) -> Option<String> {
         match self {
             Self::KafkaConsumerError(e) => Some(e.clone()),
+            Self::InvalidTopicPattern(e) => Some(e.clone()),
             _ => None,
         }
     }
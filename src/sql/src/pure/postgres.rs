@@ -49,9 +49,12 @@ pub(super) fn derive_catalog_from_publication_tables<'a>(
     Ok(ErsatzCatalog(tables_by_name))
 }
 
+/// Validates the subsources requested for a Postgres source. The tables whose OIDs are in
+/// `upsert_tables` are ingested in upsert mode and so only need `REPLICA IDENTITY DEFAULT`.
 pub(super) async fn validate_requested_subsources(
     config: &Config,
     requested_subsources: &[(UnresolvedItemName, UnresolvedItemName, &PostgresTableDesc)],
+    upsert_tables: &BTreeSet<u32>,
 ) -> Result<(), PlanError> {
     // This condition would get caught during the catalog transaction, but produces a
     // vague, non-contextual error. Instead, error here so we can suggest to the user
@@ -105,12 +108,13 @@ pub(super) async fn validate_requested_subsources(
 
     privileges::check_table_privileges(config, tables_to_check_permissions).await?;
 
-    let oids: Vec<_> = requested_subsources
+    let (upsert_oids, oids): (Vec<_>, Vec<_>) = requested_subsources
         .iter()
         .map(|(_, _, table_desc)| table_desc.oid)
-        .collect();
+        .partition(|oid| upsert_tables.contains(oid));
 
     replica_identity::check_replica_identity_full(config, oids).await?;
+    replica_identity::check_replica_identity_default(config, upsert_oids).await?;
 
     Ok(())
 }
@@ -178,6 +182,58 @@ pub(super) fn generate_text_columns(
     Ok(text_cols_dict)
 }
 
+/// Resolves the upstream tables named in `tables` into their fully qualified names, returning an
+/// error for any table that was already seen in `seen`.
+pub(super) fn generate_table_references(
+    publication_catalog: &ErsatzCatalog<'_, PostgresTableDesc>,
+    tables: &mut [UnresolvedItemName],
+    option_name: &str,
+    seen: &mut BTreeSet<u32>,
+) -> Result<(), PlanError> {
+    for name in tables {
+        let (fully_qualified_name, desc) =
+            publication_catalog.resolve(name.clone()).map_err(|e| {
+                PlanError::InvalidOptionValue {
+                    option_name: option_name.to_string(),
+                    err: Box::new(e),
+                }
+            })?;
+        *name = fully_qualified_name;
+
+        if !seen.insert(desc.oid) {
+            return Err(PlanError::InvalidOptionValue {
+                option_name: option_name.to_string(),
+                err: Box::new(PlanError::UnexpectedDuplicateReference { name: name.clone() }),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolves the upstream tables named in the `UPSERT TABLES` option into their fully qualified
+/// names, returning the OIDs of the tables. Every table must have a primary key, which is the key
+/// of its upsert state.
+pub(super) fn generate_upsert_tables(
+    publication_catalog: &ErsatzCatalog<'_, PostgresTableDesc>,
+    tables: &mut [UnresolvedItemName],
+    option_name: &str,
+) -> Result<BTreeSet<u32>, PlanError> {
+    let mut oids = BTreeSet::new();
+    generate_table_references(publication_catalog, tables, option_name, &mut oids)?;
+
+    for name in tables.iter() {
+        let (_, desc) = publication_catalog
+            .resolve(name.clone())
+            .expect("resolved above");
+        if !desc.keys.iter().any(|key| key.is_primary) {
+            Err(PgSourcePurificationError::UpsertTableWithoutPrimaryKey { name: name.clone() })?;
+        }
+    }
+
+    Ok(oids)
+}
+
 pub(crate) fn generate_targeted_subsources<F>(
     scx: &StatementContext,
     validated_requested_subsources: Vec<(
@@ -502,7 +558,52 @@ mod replica_identity {
         config: &Config,
         oids: Vec<Oid>,
     ) -> Result<(), PlanError> {
-        let client = config.connect("check_replica_identity_full").await?;
+        let mut invalid_replica_identity = invalid_replica_identities(config, oids, &["f"]).await?;
+
+        if invalid_replica_identity.is_empty() {
+            Ok(())
+        } else {
+            invalid_replica_identity.sort();
+            Err(PgSourcePurificationError::NotTablesWReplicaIdentityFull {
+                items: invalid_replica_identity,
+            })?
+        }
+    }
+
+    /// Ensures that all provided OIDs are tables with `REPLICA IDENTITY DEFAULT` or `REPLICA
+    /// IDENTITY FULL`, either of which includes the primary key of deleted rows in the
+    /// replication stream.
+    pub async fn check_replica_identity_default(
+        config: &Config,
+        oids: Vec<Oid>,
+    ) -> Result<(), PlanError> {
+        if oids.is_empty() {
+            return Ok(());
+        }
+
+        let mut invalid_replica_identity =
+            invalid_replica_identities(config, oids, &["d", "f"]).await?;
+
+        if invalid_replica_identity.is_empty() {
+            Ok(())
+        } else {
+            invalid_replica_identity.sort();
+            Err(
+                PgSourcePurificationError::NotTablesWReplicaIdentityDefault {
+                    items: invalid_replica_identity,
+                },
+            )?
+        }
+    }
+
+    /// Returns the names of the provided OIDs that are not tables whose replica identity is one
+    /// of `identities`.
+    async fn invalid_replica_identities(
+        config: &Config,
+        oids: Vec<Oid>,
+        identities: &[&str],
+    ) -> Result<Vec<String>, PlanError> {
+        let client = config.connect("check_replica_identity").await?;
 
         let oids_len = oids.len();
 
@@ -514,7 +615,7 @@ mod replica_identity {
             }],
         );
 
-        let mut invalid_replica_identity = client
+        let invalid_replica_identity = client
             .query(
                 "
             SELECT
@@ -523,8 +624,8 @@ mod replica_identity {
                 (SELECT unnest($1::OID[]) AS oid) AS input
                 LEFT JOIN pg_class ON input.oid = pg_class.oid
             WHERE
-                relreplident != 'f' OR relreplident IS NULL;",
-                &[&oids],
+                relreplident::TEXT != ALL($2::TEXT[]) OR relreplident IS NULL;",
+                &[&oids, &identities],
             )
             .await
             .map_err(PostgresError::from)?
@@ -532,13 +633,6 @@ mod replica_identity {
             .map(|row| row.get("name"))
             .collect::<Vec<String>>();
 
-        if invalid_replica_identity.is_empty() {
-            Ok(())
-        } else {
-            invalid_replica_identity.sort();
-            Err(PgSourcePurificationError::NotTablesWReplicaIdentityFull {
-                items: invalid_replica_identity,
-            })?
-        }
+        Ok(invalid_replica_identity)
     }
 }
@@ -14,7 +14,7 @@ use itertools::Itertools;
 use mz_controller_types::ClusterId;
 use mz_expr::{CollectionPlan, MirRelationExpr};
 use mz_ore::str::StrExt;
-use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem};
+use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem, PrivilegeMap};
 use mz_repr::role_id::RoleId;
 use mz_repr::GlobalId;
 use mz_sql_parser::ast::QualifiedReplica;
@@ -96,6 +96,16 @@ pub enum UnauthorizedError {
     Privilege {
         object_description: ErrorMessageObjectDescription,
     },
+    /// The action reads columns that the role has no privileges on.
+    #[error(
+        "permission denied for {} {} of {object_description}",
+        if columns.len() == 1 { "column" } else { "columns" },
+        columns.iter().map(|column| column.quoted()).join(", ")
+    )]
+    ColumnPrivilege {
+        columns: Vec<String>,
+        object_description: ErrorMessageObjectDescription,
+    },
     // TODO(jkosh44) When we implement parameter privileges, this can be replaced with a regular
     //  privilege error.
     /// The action can only be performed by the mz_system role.
@@ -127,7 +137,8 @@ impl UnauthorizedError {
             }
             UnauthorizedError::Ownership { .. }
             | UnauthorizedError::RoleMembership { .. }
-            | UnauthorizedError::Privilege { .. } => None,
+            | UnauthorizedError::Privilege { .. }
+            | UnauthorizedError::ColumnPrivilege { .. } => None,
         }
     }
 }
@@ -142,6 +153,10 @@ struct RbacRequirements {
     /// The privileges required. The tuples are of the form:
     /// (What object the privilege is on, What privilege is required, Who must possess the privilege).
     privileges: Vec<(SystemObjectId, AclMode, RoleId)>,
+    /// The columns read from each relation, keyed by the relation and the role reading it. A
+    /// `SELECT` privilege on a relation required of a role is also satisfied by that role's
+    /// `SELECT` privileges on all of the columns it reads from the relation.
+    column_reads: BTreeMap<(GlobalId, RoleId), BTreeSet<usize>>,
     /// true if the plan requires USAGE privileges on all applicable items, false otherwise.
     ///
     /// Most plans will be true but some plans, like SHOW CREATE, can reference an item without
@@ -192,9 +207,16 @@ impl RbacRequirements {
             .collect();
         ownership_err(unheld_ownership, catalog)?;
 
-        check_object_privileges(
+        let privileges = check_column_privileges(
             catalog,
             self.privileges,
+            self.column_reads,
+            &role_membership,
+            role_metadata.current_role,
+        )?;
+        check_object_privileges(
+            catalog,
+            privileges,
             role_membership,
             role_metadata.current_role,
         )?;
@@ -213,6 +235,7 @@ impl Default for RbacRequirements {
             role_membership: BTreeSet::new(),
             ownership: Vec::new(),
             privileges: Vec::new(),
+            column_reads: BTreeMap::new(),
             item_usage: true,
             superuser_action: None,
         }
@@ -551,6 +574,27 @@ fn generate_rbac_requirements(
                 ..Default::default()
             }
         }
+        Plan::CreatePolicy(plan::CreatePolicyPlan {
+            name: _,
+            object_id,
+            predicate: _,
+        })
+        | Plan::DropPolicy(plan::DropPolicyPlan {
+            name: _,
+            object_id,
+            if_exists: _,
+        }) => {
+            let item = catalog.get_item(object_id);
+            RbacRequirements {
+                ownership: vec![ObjectId::Item(*object_id)],
+                privileges: vec![(
+                    SystemObjectId::Object(item.name().qualifiers.clone().into()),
+                    AclMode::USAGE,
+                    role_id,
+                )],
+                ..Default::default()
+            }
+        }
         Plan::DropObjects(plan::DropObjectsPlan {
             referenced_ids,
             drop_ids: _,
@@ -598,6 +642,7 @@ fn generate_rbac_requirements(
             role_ids,
             drop_ids: _,
             privilege_revokes: _,
+            column_privilege_revokes: _,
             default_privilege_revokes: _,
         }) => RbacRequirements {
             role_membership: role_ids.into_iter().cloned().collect(),
@@ -645,6 +690,7 @@ fn generate_rbac_requirements(
             when: _,
             finishing: _,
             copy_to: _,
+            column_reads,
         }) => {
             let mut privileges =
                 generate_read_privileges(catalog, resolved_ids.0.iter().cloned(), role_id);
@@ -655,6 +701,7 @@ fn generate_rbac_requirements(
             }
             RbacRequirements {
                 privileges,
+                column_reads: generate_column_reads(catalog, column_reads, role_id),
                 ..Default::default()
             }
         }
@@ -666,6 +713,7 @@ fn generate_rbac_requirements(
             copy_to: _,
             emit_progress: _,
             output: _,
+            column_reads,
         }) => {
             let mut privileges =
                 generate_read_privileges(catalog, resolved_ids.0.iter().cloned(), role_id);
@@ -678,6 +726,7 @@ fn generate_rbac_requirements(
             }
             RbacRequirements {
                 privileges,
+                column_reads: generate_column_reads(catalog, column_reads, role_id),
                 ..Default::default()
             }
         }
@@ -743,6 +792,7 @@ fn generate_rbac_requirements(
             id,
             values,
             returning,
+            column_reads,
         }) => {
             let schema_id: ObjectId = catalog.get_item(id).name().qualifiers.clone().into();
             let mut privileges = vec![
@@ -789,6 +839,7 @@ fn generate_rbac_requirements(
             }
             RbacRequirements {
                 privileges,
+                column_reads: generate_column_reads(catalog, column_reads, role_id),
                 ..Default::default()
             }
         }
@@ -821,8 +872,18 @@ fn generate_rbac_requirements(
             )],
             ..Default::default()
         },
-        Plan::AlterSink(plan::AlterSinkPlan { id, size: _ }) => RbacRequirements {
+        Plan::AlterSink(plan::AlterSinkPlan {
+            id,
+            size: _,
+            reset_progress: _,
+            set_from,
+        }) => RbacRequirements {
             ownership: vec![ObjectId::Item(*id)],
+            privileges: generate_read_privileges(
+                catalog,
+                set_from.iter().map(|sink| sink.from),
+                role_id,
+            ),
             ..Default::default()
         },
         Plan::AlterSource(plan::AlterSourcePlan { id, action: _ }) => RbacRequirements {
@@ -948,6 +1009,7 @@ fn generate_rbac_requirements(
             assignments,
             kind,
             returning,
+            column_reads,
         }) => {
             let acl_mode = match kind {
                 MutationKind::Insert => AclMode::INSERT,
@@ -996,6 +1058,7 @@ fn generate_rbac_requirements(
             }
             RbacRequirements {
                 privileges,
+                column_reads: generate_column_reads(catalog, column_reads, role_id),
                 ..Default::default()
             }
         }
@@ -1325,7 +1388,16 @@ fn generate_read_privileges_inner(
             match item.item_type() {
                 CatalogItemType::View | CatalogItemType::MaterializedView => {
                     privileges.push((SystemObjectId::Object(id.into()), AclMode::SELECT, role_id));
-                    views.push((item.uses().0.iter().cloned(), item.owner_id()));
+                    // The inputs of a dropped materialized view that is retained for `AS OF`
+                    // reads may no longer exist.
+                    let view_ids: Vec<_> = item
+                        .uses()
+                        .0
+                        .iter()
+                        .filter(|id| catalog.try_get_item(id).is_some())
+                        .cloned()
+                        .collect();
+                    views.push((view_ids, item.owner_id()));
                 }
                 CatalogItemType::Table | CatalogItemType::Source => {
                     privileges.push((SystemObjectId::Object(id.into()), AclMode::SELECT, role_id));
@@ -1340,13 +1412,48 @@ fn generate_read_privileges_inner(
 
     for (view_ids, view_owner) in views {
         privileges.extend_from_slice(&generate_read_privileges_inner(
-            catalog, view_ids, view_owner, seen,
+            catalog,
+            view_ids.into_iter(),
+            view_owner,
+            seen,
         ));
     }
 
     privileges
 }
 
+/// Attributes the columns in `column_reads` to `role_id`. Views and materialized views read their
+/// inputs with the privileges of their owners, so the columns they read are attributed to their
+/// owners.
+fn generate_column_reads(
+    catalog: &impl SessionCatalog,
+    column_reads: &BTreeMap<GlobalId, BTreeSet<usize>>,
+    role_id: RoleId,
+) -> BTreeMap<(GlobalId, RoleId), BTreeSet<usize>> {
+    let mut reads: BTreeMap<_, BTreeSet<_>> = BTreeMap::new();
+    let mut seen = BTreeSet::new();
+    let mut pending = vec![(column_reads, role_id)];
+    while let Some((column_reads, role_id)) = pending.pop() {
+        for (id, columns) in column_reads {
+            // The inputs of a dropped materialized view that is retained for `AS OF` reads may no
+            // longer exist.
+            let Some(item) = catalog.try_get_item(id) else {
+                continue;
+            };
+            reads
+                .entry((*id, role_id))
+                .or_default()
+                .extend(columns.iter().copied());
+            if let Some(view_reads) = item.view_column_reads() {
+                if seen.insert(*id) {
+                    pending.push((view_reads, item.owner_id()));
+                }
+            }
+        }
+    }
+    reads
+}
+
 fn generate_item_usage_privileges(
     catalog: &impl SessionCatalog,
     ids: &ResolvedIds,
@@ -1398,6 +1505,74 @@ fn generate_cluster_usage_privileges(
     None
 }
 
+/// Checks the `SELECT` privileges of each role on the columns it reads in `column_reads`.
+///
+/// For each relation that a role can't read in full, but holds column privileges on, returns an
+/// error naming any columns in `column_reads` it can't read. Otherwise, the returned privileges
+/// omit the `SELECT` privilege on the relation required of the role, as the column privileges
+/// satisfy it.
+fn check_column_privileges(
+    catalog: &impl SessionCatalog,
+    privileges: Vec<(SystemObjectId, AclMode, RoleId)>,
+    column_reads: BTreeMap<(GlobalId, RoleId), BTreeSet<usize>>,
+    role_membership: &BTreeSet<RoleId>,
+    current_role_id: RoleId,
+) -> Result<Vec<(SystemObjectId, AclMode, RoleId)>, UnauthorizedError> {
+    let mut role_memberships: BTreeMap<RoleId, BTreeSet<RoleId>> = BTreeMap::new();
+    role_memberships.insert(current_role_id, role_membership.clone());
+
+    let mut satisfied = BTreeSet::new();
+    for ((id, role_id), columns) in column_reads {
+        let role_membership = role_memberships
+            .entry(role_id)
+            .or_insert_with_key(|role_id| catalog.collect_role_membership(role_id));
+        let can_select = |privileges: &PrivilegeMap| {
+            role_membership
+                .iter()
+                .flat_map(|role_id| privileges.get_acl_items_for_grantee(role_id))
+                .any(|mz_acl_item| mz_acl_item.acl_mode.contains(AclMode::SELECT))
+        };
+
+        let Some(column_privileges) = catalog.get_column_privileges(&id) else {
+            continue;
+        };
+        if !column_privileges.values().any(&can_select) {
+            continue;
+        }
+        let object_id = SystemObjectId::Object(id.into());
+        let object_privileges = catalog
+            .get_privileges(&object_id)
+            .expect("relations have privileges");
+        if can_select(object_privileges) {
+            continue;
+        }
+
+        let item = catalog.get_item(&id);
+        let desc = item
+            .desc(&catalog.resolve_full_name(item.name()))
+            .expect("column privileges only exist on relations");
+        let forbidden_columns: Vec<_> = columns
+            .into_iter()
+            .map(|column| desc.get_name(column).as_str().to_string())
+            .filter(|column| !column_privileges.get(column).map_or(false, &can_select))
+            .collect();
+        if !forbidden_columns.is_empty() {
+            return Err(UnauthorizedError::ColumnPrivilege {
+                columns: forbidden_columns,
+                object_description: ErrorMessageObjectDescription::from_id(&object_id, catalog),
+            });
+        }
+        satisfied.insert((object_id, role_id));
+    }
+
+    Ok(privileges
+        .into_iter()
+        .filter(|(object_id, acl_mode, role_id)| {
+            !(*acl_mode == AclMode::SELECT && satisfied.contains(&(object_id.clone(), *role_id)))
+        })
+        .collect())
+}
+
 fn check_object_privileges(
     catalog: &impl SessionCatalog,
     privileges: Vec<(SystemObjectId, AclMode, RoleId)>,
@@ -242,6 +242,29 @@ pub static APPLICATION_NAME: Lazy<ServerVar<String>> = Lazy::new(|| ServerVar {
     internal: false,
 });
 
+/// The default value of custom configuration parameters.
+static DEFAULT_CUSTOM_VALUE: String = String::new();
+
+/// Reports whether `name` names a custom configuration parameter.
+///
+/// Like in PostgreSQL, custom parameters, which applications can use to pass
+/// values like `app.tenant` to `current_setting`, are named by two or more
+/// dot-separated identifiers.
+fn is_custom_var_name(name: &str) -> bool {
+    name.contains('.') && name.split('.').all(|part| !part.is_empty())
+}
+
+/// The definition that all custom configuration parameters share.
+///
+/// Custom parameters are stored by each session under their own name, so the
+/// name of this definition never appears in output.
+static CUSTOM_VAR: ServerVar<String> = ServerVar {
+    name: UncasedStr::new("custom"),
+    value: &DEFAULT_CUSTOM_VALUE,
+    description: "A custom configuration parameter.",
+    internal: false,
+};
+
 pub static CLIENT_ENCODING: ServerVar<ClientEncoding> = ServerVar {
     name: UncasedStr::new("client_encoding"),
     value: &ClientEncoding::Utf8,
@@ -561,6 +584,16 @@ pub const METRICS_RETENTION: ServerVar<Duration> = ServerVar {
     internal: true,
 };
 
+/// The time for which the storage collections of dropped tables and
+/// materialized views remain readable by queries with an explicit `AS OF`.
+pub const DROPPED_RELATION_RETENTION: ServerVar<Duration> = ServerVar {
+    name: UncasedStr::new("dropped_relation_retention"),
+    value: &Duration::from_secs(5 * 60),
+    description: "The time for which the data of dropped tables and materialized views remains \
+                  readable by queries with an explicit AS OF (Materialize).",
+    internal: true,
+};
+
 static DEFAULT_ALLOWED_CLUSTER_REPLICA_SIZES: Lazy<Vec<Ident>> = Lazy::new(Vec::new);
 static ALLOWED_CLUSTER_REPLICA_SIZES: Lazy<ServerVar<Vec<Ident>>> = Lazy::new(|| ServerVar {
     name: UncasedStr::new("allowed_cluster_replica_sizes"),
@@ -962,6 +995,26 @@ const PG_SOURCE_SNAPSHOT_STATEMENT_TIMEOUT: ServerVar<Duration> = ServerVar {
     internal: true,
 };
 
+/// Sets the maximum time a PG source waits between attempts to reconnect with
+/// freshly read credentials after the upstream rejected them.
+const PG_SOURCE_AUTH_RETRY_MAX_BACKOFF: ServerVar<Duration> = ServerVar {
+    name: UncasedStr::new("pg_source_auth_retry_max_backoff"),
+    value: &mz_storage_types::parameters::DEFAULT_PG_SOURCE_AUTH_RETRY_MAX_BACKOFF,
+    description: "Sets the maximum time a PG source waits between attempts to reconnect with \
+    re-read credentials after an authentication failure (Materialize).",
+    internal: true,
+};
+
+/// Sets how long a PG source keeps reconnecting with freshly read credentials
+/// before reporting an authentication failure as an error.
+const PG_SOURCE_AUTH_RETRY_TIMEOUT: ServerVar<Duration> = ServerVar {
+    name: UncasedStr::new("pg_source_auth_retry_timeout"),
+    value: &mz_storage_types::parameters::DEFAULT_PG_SOURCE_AUTH_RETRY_TIMEOUT,
+    description: "Sets how long a PG source keeps reconnecting with re-read credentials after \
+    an authentication failure before reporting an error (Materialize).",
+    internal: true,
+};
+
 /// Controls the connection timeout to Cockroach.
 ///
 /// Used by persist as [`mz_persist_client::cfg::DynamicConfig::consensus_connect_timeout`].
@@ -1312,6 +1365,16 @@ pub const STATEMENT_LOGGING_RETENTION: ServerVar<Duration> = ServerVar {
     internal: true,
 };
 
+/// Controls how long events are retained in the audit log. Older events are
+/// pruned when the catalog is opened. Zero retains events forever.
+pub const AUDIT_LOG_RETENTION: ServerVar<Duration> = ServerVar {
+    name: UncasedStr::new("audit_log_retention"),
+    value: &Duration::ZERO,
+    description: "The time to retain audit log events, applied at startup; zero retains them \
+    forever (Materialize).",
+    internal: true,
+};
+
 pub const AUTO_ROUTE_INTROSPECTION_QUERIES: ServerVar<bool> = ServerVar {
     name: UncasedStr::new("auto_route_introspection_queries"),
     value: &true,
@@ -1657,6 +1720,35 @@ feature_flags!(
         "the try_parse_monotonic_iso8601_timestamp function"
     ),
     (enable_alter_set_cluster, "ALTER ... SET CLUSTER syntax"),
+    (
+        enable_alter_sink_reset_progress,
+        "ALTER SINK ... RESET PROGRESS syntax"
+    ),
+    (enable_alter_sink_set_from, "ALTER SINK ... SET FROM syntax"),
+    (
+        enable_kafka_topic_pattern,
+        "CREATE SOURCE ... (TOPIC PATTERN ...)"
+    ),
+    (
+        enable_upsert_key_ttl,
+        "CREATE SOURCE ... WITH (KEY TTL ...)"
+    ),
+    (
+        enable_value_decoding_errors,
+        "CREATE SOURCE ... WITH (VALUE DECODING ERRORS ...)"
+    ),
+    (
+        enable_debezium_transaction_metadata,
+        "ENVELOPE DEBEZIUM (TRANSACTION METADATA ...)"
+    ),
+    (
+        enable_postgres_sink,
+        "CREATE SINK ... INTO POSTGRES CONNECTION"
+    ),
+    (
+        enable_row_level_security,
+        "CREATE POLICY for row-level security"
+    ),
     (
         enable_dangerous_functions,
         "executing potentially dangerous functions"
@@ -1736,6 +1828,9 @@ impl OwnedVarInput {
 #[derive(Debug)]
 pub struct SessionVars {
     vars: BTreeMap<&'static UncasedStr, Box<dyn SessionVarMut>>,
+    /// The custom configuration parameters this session has set, keyed by
+    /// their lowercased name.
+    custom_vars: BTreeMap<String, SessionVar<String>>,
     // Inputs to computed variables.
     build_info: &'static BuildInfo,
     user: User,
@@ -1745,6 +1840,7 @@ impl SessionVars {
     pub fn new(build_info: &'static BuildInfo, user: User) -> SessionVars {
         let s = SessionVars {
             vars: BTreeMap::new(),
+            custom_vars: BTreeMap::new(),
             build_info,
             user,
         };
@@ -1946,6 +2042,11 @@ impl SessionVars {
             Err(VarError::ReadOnlyParameter(
                 MAX_IDENTIFIER_LENGTH.name.as_str(),
             ))
+        } else if !self.vars.contains_key(name) && is_custom_var_name(name.as_str()) {
+            self.custom_vars
+                .entry(name.as_str().to_lowercase())
+                .or_insert_with(|| SessionVar::new(&CUSTOM_VAR))
+                .set(input, local)
         } else {
             self.vars
                 .get_mut(name)
@@ -1982,6 +2083,12 @@ impl SessionVars {
             Err(VarError::ReadOnlyParameter(MZ_VERSION_NAME.as_str()))
         } else if name == IS_SUPERUSER_NAME {
             Err(VarError::ReadOnlyParameter(IS_SUPERUSER_NAME.as_str()))
+        } else if !self.vars.contains_key(name) && is_custom_var_name(name.as_str()) {
+            // A custom parameter that was never set already has its default value.
+            if let Some(var) = self.custom_vars.get_mut(&name.as_str().to_lowercase()) {
+                var.reset(local);
+            }
+            Ok(())
         } else {
             self.vars
                 .get_mut(name)
@@ -2014,9 +2121,31 @@ impl SessionVars {
                 changed.insert(var.name(), after);
             }
         }
+        // Like in PostgreSQL, changes to custom parameters are not reported.
+        for var in self.custom_vars.values_mut() {
+            var.end_transaction(action);
+        }
         changed
     }
 
+    /// Returns the value of the custom configuration parameter `name`, if this
+    /// session has set it.
+    ///
+    /// Custom parameters are matched case insensitively.
+    pub fn custom(&self, name: &str) -> Option<&str> {
+        self.custom_vars
+            .get(&name.to_lowercase())
+            .map(|var| SessionVar::value(var).as_str())
+    }
+
+    /// Returns an iterator over the custom configuration parameters this
+    /// session has set, by their lowercased name, and their current values.
+    pub fn iter_custom(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.custom_vars
+            .iter()
+            .map(|(name, var)| (name.as_str(), SessionVar::value(var).as_str()))
+    }
+
     /// Returns the value of the `application_name` configuration parameter.
     pub fn application_name(&self) -> &str {
         self.expect_value(&*APPLICATION_NAME).as_str()
@@ -2347,6 +2476,7 @@ impl SystemVars {
             .with_var(&PERSIST_PUBSUB_PUSH_DIFF_ENABLED)
             .with_var(&PERSIST_ROLLUP_THRESHOLD)
             .with_var(&METRICS_RETENTION)
+            .with_var(&DROPPED_RELATION_RETENTION)
             .with_var(&UNSAFE_MOCK_AUDIT_EVENT_TIMESTAMP)
             .with_var(&ENABLE_LD_RBAC_CHECKS)
             .with_var(&ENABLE_RBAC_CHECKS)
@@ -2356,6 +2486,8 @@ impl SystemVars {
             .with_var(&PG_SOURCE_KEEPALIVES_RETRIES)
             .with_var(&PG_SOURCE_TCP_USER_TIMEOUT)
             .with_var(&PG_SOURCE_SNAPSHOT_STATEMENT_TIMEOUT)
+            .with_var(&PG_SOURCE_AUTH_RETRY_MAX_BACKOFF)
+            .with_var(&PG_SOURCE_AUTH_RETRY_TIMEOUT)
             .with_var(&ENABLE_LAUNCHDARKLY)
             .with_var(&MAX_CONNECTIONS)
             .with_var(&KEEP_N_SOURCE_STATUS_HISTORY_ENTRIES)
@@ -2395,6 +2527,7 @@ impl SystemVars {
             )
             .with_var(&TRUNCATE_STATEMENT_LOG)
             .with_var(&STATEMENT_LOGGING_RETENTION)
+            .with_var(&AUDIT_LOG_RETENTION)
             .with_var(&OPTIMIZER_STATS_TIMEOUT)
             .with_var(&OPTIMIZER_ONESHOT_STATS_TIMEOUT);
 
@@ -2873,6 +3006,16 @@ impl SystemVars {
         *self.expect_value(&PG_SOURCE_SNAPSHOT_STATEMENT_TIMEOUT)
     }
 
+    /// Returns the `pg_source_auth_retry_max_backoff` configuration parameter.
+    pub fn pg_source_auth_retry_max_backoff(&self) -> Duration {
+        *self.expect_value(&PG_SOURCE_AUTH_RETRY_MAX_BACKOFF)
+    }
+
+    /// Returns the `pg_source_auth_retry_timeout` configuration parameter.
+    pub fn pg_source_auth_retry_timeout(&self) -> Duration {
+        *self.expect_value(&PG_SOURCE_AUTH_RETRY_TIMEOUT)
+    }
+
     /// Returns the `crdb_connect_timeout` configuration parameter.
     pub fn crdb_connect_timeout(&self) -> Duration {
         *self.expect_value(&CRDB_CONNECT_TIMEOUT)
@@ -2976,6 +3119,11 @@ impl SystemVars {
         *self.expect_value(&METRICS_RETENTION)
     }
 
+    /// Returns the `dropped_relation_retention` configuration parameter.
+    pub fn dropped_relation_retention(&self) -> Duration {
+        *self.expect_value(&DROPPED_RELATION_RETENTION)
+    }
+
     /// Returns the `unsafe_mock_audit_event_timestamp` configuration parameter.
     pub fn unsafe_mock_audit_event_timestamp(&self) -> Option<mz_repr::Timestamp> {
         *self.expect_value(&UNSAFE_MOCK_AUDIT_EVENT_TIMESTAMP)
@@ -3132,6 +3280,13 @@ impl SystemVars {
         *self.expect_value(&STATEMENT_LOGGING_RETENTION)
     }
 
+    /// Returns the `audit_log_retention` configuration parameter, or `None` if
+    /// audit log events are retained forever.
+    pub fn audit_log_retention(&self) -> Option<Duration> {
+        let retention = *self.expect_value(&AUDIT_LOG_RETENTION);
+        (!retention.is_zero()).then_some(retention)
+    }
+
     /// Returns the `optimizer_stats_timeout` configuration parameter.
     pub fn optimizer_stats_timeout(&self) -> Duration {
         *self.expect_value(&OPTIMIZER_STATS_TIMEOUT)
@@ -4603,6 +4758,8 @@ pub fn is_storage_config_var(name: &str) -> bool {
         || name == PG_SOURCE_KEEPALIVES_RETRIES.name()
         || name == PG_SOURCE_TCP_USER_TIMEOUT.name()
         || name == PG_SOURCE_SNAPSHOT_STATEMENT_TIMEOUT.name()
+        || name == PG_SOURCE_AUTH_RETRY_MAX_BACKOFF.name()
+        || name == PG_SOURCE_AUTH_RETRY_TIMEOUT.name()
         || name == STORAGE_DATAFLOW_MAX_INFLIGHT_BYTES.name()
         || name == STORAGE_DATAFLOW_MAX_INFLIGHT_BYTES_TO_CLUSTER_SIZE_FRACTION.name()
         || name == STORAGE_DATAFLOW_MAX_INFLIGHT_BYTES_DISK_ONLY.name()
@@ -941,6 +941,7 @@ impl<'a> RunnerInner<'a> {
                 suppress_output: false,
                 environment_id: environment_id.to_string(),
                 secrets_dir: secrets_dir.clone(),
+                secrets_keyring: None,
                 command_wrapper: config
                     .orchestrator_process_wrapper
                     .as_ref()
@@ -987,9 +988,16 @@ impl<'a> RunnerInner<'a> {
                 secrets_args: mz_service::secrets::SecretsReaderCliArgs {
                     secrets_reader: mz_service::secrets::SecretsControllerKind::LocalFile,
                     secrets_reader_local_file_dir: Some(secrets_dir),
+                    secrets_reader_local_file_master_key_file: None,
                     secrets_reader_kubernetes_context: None,
                     secrets_reader_aws_region: None,
                     secrets_reader_aws_prefix: None,
+                    secrets_reader_vault_address: None,
+                    secrets_reader_vault_mount: None,
+                    secrets_reader_vault_prefix: None,
+                    secrets_reader_vault_namespace: None,
+                    secrets_reader_vault_token_file: None,
+                    secrets_reader_vault_approle_mount: None,
                 },
             },
             secrets_controller,
@@ -1014,6 +1022,7 @@ impl<'a> RunnerInner<'a> {
             storage_usage_collection_interval: Duration::from_secs(3600),
             storage_usage_retention_period: None,
             segment_api_key: None,
+            audit_log_export: None,
             egress_ips: vec![],
             aws_account_id: None,
             aws_privatelink_availability_zones: None,
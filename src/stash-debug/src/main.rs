@@ -254,12 +254,14 @@ macro_rules! for_collections {
                 $macro!(catalog::CLUSTER_COLLECTION);
                 $macro!(catalog::CLUSTER_INTROSPECTION_SOURCE_INDEX_COLLECTION);
                 $macro!(catalog::CLUSTER_REPLICA_COLLECTION);
+                $macro!(catalog::COLUMN_PRIVILEGES_COLLECTION);
                 $macro!(catalog::CONFIG_COLLECTION);
                 $macro!(catalog::CONFIG_COLLECTION);
                 $macro!(catalog::DATABASES_COLLECTION);
                 $macro!(catalog::DEFAULT_PRIVILEGES_COLLECTION);
                 $macro!(catalog::ID_ALLOCATOR_COLLECTION);
                 $macro!(catalog::ITEM_COLLECTION);
+                $macro!(catalog::POLICIES_COLLECTION);
                 $macro!(catalog::ROLES_COLLECTION);
                 $macro!(catalog::SCHEMAS_COLLECTION);
                 $macro!(catalog::SETTING_COLLECTION);
@@ -500,6 +502,7 @@ impl Usage {
             aws_privatelink_availability_zones: None,
             system_parameter_sync_config: None,
             storage_usage_retention_period: None,
+            audit_log_retain_from: None,
             http_host_name: None,
             connection_context: None,
             active_connection_count: Arc::new(Mutex::new(ConnectionCounter::new(0))),
@@ -61,6 +61,10 @@ pub(crate) mod v34_to_v35;
 pub(crate) mod v35_to_v36;
 pub(crate) mod v36_to_v37;
 pub(crate) mod v37_to_v38;
+pub(crate) mod v38_to_v39;
+pub(crate) mod v39_to_v40;
+pub(crate) mod v40_to_v41;
+pub(crate) mod v41_to_v42;
 
 macro_rules! objects {
     ( $( $x:ident ),* ) => {
@@ -74,7 +78,7 @@ macro_rules! objects {
     }
 }
 
-objects!(v27, v28, v29, v31, v32, v33, v34, v35, v36, v37, v38);
+objects!(v27, v28, v29, v31, v32, v33, v34, v35, v36, v37, v38, v39, v40);
 
 pub(crate) enum MigrationAction<K1, K2, V2> {
     /// Deletes the provided key.
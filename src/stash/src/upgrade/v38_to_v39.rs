@@ -0,0 +1,21 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use crate::upgrade::objects_v39;
+use crate::{StashError, Transaction, TypedCollection};
+
+/// Migration to initialize the row-level security policies collection (e.g. `CREATE POLICY`).
+pub async fn upgrade(tx: &'_ mut Transaction<'_>) -> Result<(), StashError> {
+    const POLICIES_COLLECTION: TypedCollection<objects_v39::PolicyKey, objects_v39::PolicyValue> =
+        TypedCollection::new("policies");
+
+    POLICIES_COLLECTION.initialize(tx, vec![]).await?;
+
+    Ok(())
+}
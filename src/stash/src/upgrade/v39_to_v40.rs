@@ -0,0 +1,23 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use crate::upgrade::objects_v40;
+use crate::{StashError, Transaction, TypedCollection};
+
+/// Migration to initialize the column privileges collection (e.g. `GRANT SELECT (a) ON t`).
+pub async fn upgrade(tx: &'_ mut Transaction<'_>) -> Result<(), StashError> {
+    const COLUMN_PRIVILEGES_COLLECTION: TypedCollection<
+        objects_v40::ColumnPrivilegeKey,
+        objects_v40::ColumnPrivilegeValue,
+    > = TypedCollection::new("column_privileges");
+
+    COLUMN_PRIVILEGES_COLLECTION.initialize(tx, vec![]).await?;
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+/// No-op migration for recording whether sinks have made progress.
+///
+/// Sinks created before this version do not have `has_progress` backfilled, so
+/// they never recover their progress from their data topic. Backfilling it
+/// would not help: records they published before this version lack the sink ID
+/// header that recovery requires to tell their records apart from those of
+/// other sinks. If the progress topic of such a sink loses its progress, its
+/// progress must be reset explicitly.
+pub fn upgrade() {}
@@ -319,6 +319,8 @@ pub struct SourceStatisticsUpdate {
     pub envelope_state_bytes: u64,
     pub envelope_state_count: u64,
     pub rehydration_latency_ms: Option<u64>,
+    pub keys_expired: u64,
+    pub records_skipped: u64,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
@@ -350,6 +352,8 @@ impl PackableStats for SourceStatisticsUpdate {
         packer.push(Datum::from(self.envelope_state_bytes));
         packer.push(Datum::from(self.envelope_state_count));
         packer.push(Datum::from(self.rehydration_latency_ms));
+        packer.push(Datum::from(self.keys_expired));
+        packer.push(Datum::from(self.records_skipped));
     }
 }
 impl PackableStats for SinkStatisticsUpdate {
@@ -407,6 +411,8 @@ impl RustType<ProtoStorageResponse> for StorageResponse<mz_repr::Timestamp> {
                                 envelope_state_bytes: update.envelope_state_bytes,
                                 envelope_state_count: update.envelope_state_count,
                                 rehydration_latency_ms: update.rehydration_latency_ms,
+                                keys_expired: update.keys_expired,
+                                records_skipped: update.records_skipped,
                             })
                             .collect(),
                         sink_updates: sink_stats
@@ -454,6 +460,8 @@ impl RustType<ProtoStorageResponse> for StorageResponse<mz_repr::Timestamp> {
                             envelope_state_bytes: update.envelope_state_bytes,
                             envelope_state_count: update.envelope_state_count,
                             rehydration_latency_ms: update.rehydration_latency_ms,
+                            keys_expired: update.keys_expired,
+                            records_skipped: update.records_skipped,
                         })
                     })
                     .collect::<Result<Vec<_>, TryFromProtoError>>()?,
@@ -18,7 +18,7 @@
 //! Eventually, the source is dropped with either `drop_sources()` or by allowing compaction to the
 //! empty frontier.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Debug;
 use std::sync::Arc;
 
@@ -308,6 +308,33 @@ pub trait StorageController: Debug + Send {
         )>,
     ) -> Result<(), StorageError>;
 
+    /// Check that the export associated with `id` can be repointed at the
+    /// input of `description`.
+    ///
+    /// Note that this check is optimistic and its return of `Ok(())` does not guarantee that
+    /// subsequent calls to `alter_export` are guaranteed to succeed.
+    fn check_alter_export(
+        &mut self,
+        id: GlobalId,
+        description: &ExportDescription<Self::Timestamp>,
+    ) -> Result<(), StorageError>;
+
+    /// Repoints the export `id` at the input of `description`, which must
+    /// otherwise match the export's current description.
+    ///
+    /// The export keeps writing out its previous input up to a switch frontier
+    /// that it has not yet written, and from there on writes out its new input.
+    /// At the switch frontier it writes out the difference between the two.
+    async fn alter_export(
+        &mut self,
+        id: GlobalId,
+        description: ExportDescription<Self::Timestamp>,
+    ) -> Result<(), StorageError>;
+
+    /// Restarts the export `id` with its current description, e.g. so that it
+    /// picks up progress that was written out on its behalf.
+    fn restart_export(&mut self, id: GlobalId) -> Result<(), StorageError>;
+
     /// Notify the storage controller to prepare for an export to be created
     fn prepare_export(
         &mut self,
@@ -348,6 +375,14 @@ pub trait StorageController: Debug + Send {
     ///     `drop_sources`.
     fn drop_sources_unvalidated(&mut self, identifiers: Vec<GlobalId>);
 
+    /// Allows the resources of collections that were dropped by the adapter,
+    /// but whose drop never reached the controller, e.g. because the process
+    /// restarted in between, to be reclaimed.
+    ///
+    /// Identifiers of collections that are currently installed, or that the
+    /// controller has no durable state for, are ignored.
+    async fn drop_orphaned_collections(&mut self, identifiers: BTreeSet<GlobalId>);
+
     /// Append `updates` into the local input named `id` and advance its upper to `upper`.
     ///
     /// The method returns a oneshot that can be awaited to indicate completion of the write.
@@ -17,6 +17,20 @@ pub fn pack_status_row(
     error: Option<&str>,
     ts: u64,
     hint: Option<&str>,
+) -> Row {
+    pack_source_status_row(collection_id, status_name, error, ts, hint, &[])
+}
+
+/// Like [`pack_status_row`], but additionally records the upstream columns that a source ignores
+/// because they were added to its upstream table after the source was created. They are reported
+/// as the `ignored_columns` entry of the row's details.
+pub fn pack_source_status_row(
+    collection_id: GlobalId,
+    status_name: &str,
+    error: Option<&str>,
+    ts: u64,
+    hint: Option<&str>,
+    ignored_columns: &[String],
 ) -> Row {
     let timestamp = NaiveDateTime::from_timestamp_opt(
         (ts / 1000)
@@ -41,13 +55,20 @@ pub fn pack_status_row(
     let mut packer = row.packer();
     packer.extend([timestamp, collection_id, status, error]);
 
-    match hint {
-        Some(hint) => {
-            let metadata = vec![("hint", Datum::String(hint))];
-            packer.push_dict(metadata);
-        }
-        None => packer.push(Datum::Null),
-    };
+    if hint.is_none() && ignored_columns.is_empty() {
+        packer.push(Datum::Null);
+    } else {
+        packer.push_dict_with(|packer| {
+            if let Some(hint) = hint {
+                packer.push(Datum::String("hint"));
+                packer.push(Datum::String(hint));
+            }
+            if !ignored_columns.is_empty() {
+                packer.push(Datum::String("ignored_columns"));
+                packer.push_list(ignored_columns.iter().map(|c| Datum::String(c)));
+            }
+        });
+    }
     row
 }
 
@@ -205,4 +226,25 @@ mod tests {
             vec![("hint", Datum::String(hint))]
         );
     }
+
+    #[mz_ore::test]
+    fn test_row_with_ignored_columns() {
+        let id = GlobalId::User(1);
+        let status = "running";
+        let ignored_columns = vec!["a".to_string(), "b".to_string()];
+        let row = pack_source_status_row(id, status, None, 1000, None, &ignored_columns);
+
+        for (datum, column_type) in row.iter().zip(MZ_SOURCE_STATUS_HISTORY_DESC.iter_types()) {
+            assert!(datum.is_instance_of(column_type));
+        }
+
+        let details = row.iter().nth(4).unwrap().unwrap_map();
+        let details = details.iter().collect::<Vec<_>>();
+        assert_eq!(details.len(), 1);
+        assert_eq!(details[0].0, "ignored_columns");
+        assert_eq!(
+            details[0].1.unwrap_list().iter().collect::<Vec<_>>(),
+            vec![Datum::String("a"), Datum::String("b")]
+        );
+    }
 }
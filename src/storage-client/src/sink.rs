@@ -7,21 +7,29 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::{anyhow, bail, Context};
+use itertools::Itertools;
 use mz_kafka_util::client::{MzClientContext, DEFAULT_FETCH_METADATA_TIMEOUT};
 use mz_ore::collections::CollectionExt;
+use mz_ore::task;
+use mz_repr::{GlobalId, Timestamp};
 use mz_storage_types::connections::inline::ReferencedConnection;
 use mz_storage_types::connections::ConnectionContext;
 use mz_storage_types::sinks::{
     KafkaConsistencyConfig, KafkaSinkConnection, KafkaSinkConnectionBuilder,
     KafkaSinkConnectionRetention, KafkaSinkFormat, KafkaSinkProgressConnection,
-    PublishedSchemaInfo, StorageSinkConnection, StorageSinkConnectionBuilder,
+    PostgresSinkConnection, PublishedSchemaInfo, StorageSinkConnection,
+    StorageSinkConnectionBuilder,
 };
 use rdkafka::admin::{AdminClient, AdminOptions, NewTopic, ResourceSpecifier, TopicReplication};
+use rdkafka::error::RDKafkaErrorCode;
+use rdkafka::producer::{BaseRecord, Producer, ThreadedProducer};
 use rdkafka::ClientContext;
+use serde::Serialize;
 use tracing::warn;
 
 /// Build a sink connection.
@@ -38,7 +46,161 @@ pub async fn build_sink_connection(
         (StorageSinkConnectionBuilder::Kafka(k), StorageSinkConnectionBuilder::Kafka(rk)) => {
             build_kafka(k, rk, connection_context).await
         }
+        (StorageSinkConnectionBuilder::Postgres(p), StorageSinkConnectionBuilder::Postgres(rp)) => {
+            validate_postgres(p, connection_context).await?;
+            Ok(StorageSinkConnection::Postgres(rp))
+        }
+        _ => unreachable!("builder and referenced builder must be the same kind"),
+    }
+}
+
+/// Builds the connection of a sink that is repointed at a new input, for which
+/// `builder` was planned.
+///
+/// A sink that publishes Avro schemas only accepts the new input if the
+/// schemas derived from it are compatible with the schemas the sink has
+/// published, according to the compatibility level of their subjects in the
+/// schema registry.
+pub async fn build_altered_sink_connection(
+    builder: StorageSinkConnectionBuilder,
+    referenced_builder: StorageSinkConnectionBuilder<ReferencedConnection>,
+    connection_context: ConnectionContext,
+) -> Result<StorageSinkConnection<ReferencedConnection>, anyhow::Error> {
+    if let StorageSinkConnectionBuilder::Kafka(KafkaSinkConnectionBuilder {
+        topic_name,
+        format:
+            KafkaSinkFormat::Avro {
+                key_schema,
+                value_schema,
+                csr_connection,
+            },
+        ..
+    }) = &builder
+    {
+        let ccsr = csr_connection.connect(&connection_context).await?;
+        let schemas = [
+            (format!("{}-key", topic_name), key_schema.as_deref()),
+            (format!("{}-value", topic_name), Some(value_schema.as_str())),
+        ];
+        for (subject, schema) in schemas {
+            let Some(schema) = schema else {
+                continue;
+            };
+            let compatible = ccsr
+                .is_compatible(&subject, schema, mz_ccsr::SchemaType::Avro, &[])
+                .await
+                .with_context(|| {
+                    format!("unable to check compatibility of schema for {subject}")
+                })?;
+            if !compatible {
+                bail!(
+                    "schema of the new input is incompatible with the latest schema of \
+                    subject {subject}"
+                );
+            }
+        }
     }
+    build_sink_connection(builder, referenced_builder, connection_context).await
+}
+
+/// Ensures that the table a Postgres sink writes to exists, has a column for
+/// every column of the sink, and has a unique constraint on the key of the sink.
+async fn validate_postgres(
+    connection: PostgresSinkConnection,
+    connection_context: ConnectionContext,
+) -> Result<(), anyhow::Error> {
+    let client = connection
+        .connection
+        .config(&*connection_context.secrets_reader)
+        .await?
+        .connect("postgres_sink_validation")
+        .await?;
+
+    let columns = client
+        .query(
+            "SELECT a.attname::text AS name, a.atttypid AS oid,
+                format_type(a.atttypid, NULL) AS type_name
+            FROM pg_attribute a
+            JOIN pg_class c ON c.oid = a.attrelid
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE n.nspname = $1 AND c.relname = $2 AND a.attnum > 0 AND NOT a.attisdropped",
+            &[&connection.schema, &connection.table],
+        )
+        .await?
+        .into_iter()
+        .map(|row| {
+            (
+                row.get::<_, String>("name"),
+                (row.get::<_, u32>("oid"), row.get::<_, String>("type_name")),
+            )
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    if columns.is_empty() {
+        bail!(
+            "table {}.{} does not exist",
+            connection.schema,
+            connection.table
+        );
+    }
+    for (name, typ) in connection.value_desc.iter() {
+        let Some((oid, type_name)) = columns.get(name.as_str()) else {
+            bail!(
+                "table {}.{} has no column named {}",
+                connection.schema,
+                connection.table,
+                name
+            );
+        };
+        // The sink writes the text encoding of each value, so the upstream
+        // column must have the type of the sinked column.
+        let expected = mz_pgrepr::Type::from(&typ.scalar_type);
+        if *oid != expected.oid() {
+            bail!(
+                "column {} of table {}.{} has type {}, but the sink writes values of type {}",
+                name,
+                connection.schema,
+                connection.table,
+                type_name,
+                expected.name()
+            );
+        }
+    }
+
+    // `ON CONFLICT` requires a unique index on exactly the key columns.
+    let unique_keys = client
+        .query(
+            "SELECT array_agg(a.attname::text) AS columns
+            FROM pg_index i
+            JOIN pg_attribute a ON a.attrelid = i.indrelid AND a.attnum = ANY(i.indkey)
+            WHERE i.indrelid = format('%I.%I', $1::text, $2::text)::regclass
+                AND i.indisunique AND i.indpred IS NULL
+            GROUP BY i.indexrelid",
+            &[&connection.schema, &connection.table],
+        )
+        .await?
+        .into_iter()
+        .map(|row| {
+            row.get::<_, Vec<String>>("columns")
+                .into_iter()
+                .collect::<BTreeSet<_>>()
+        })
+        .collect::<Vec<_>>();
+    let key = connection
+        .key_desc_and_indices
+        .0
+        .iter_names()
+        .map(|name| name.as_str().to_string())
+        .collect::<BTreeSet<_>>();
+    if !unique_keys.contains(&key) {
+        bail!(
+            "table {}.{} has no unique constraint on the sink key ({})",
+            connection.schema,
+            connection.table,
+            key.iter().join(", ")
+        );
+    }
+    Ok(())
 }
 
 struct TopicConfigs {
@@ -304,3 +466,130 @@ async fn build_kafka(
         fuel: builder.fuel,
     }))
 }
+
+/// Recreates the progress topic of the Kafka sink `connection` if it does not exist, e.g. because
+/// it was deleted after the sink was created.
+///
+/// Unlike unconditionally creating the topic, this does not require permission to create topics if
+/// the progress topic exists.
+pub async fn ensure_kafka_progress_topic(
+    connection: &KafkaSinkConnection,
+    connection_context: &ConnectionContext,
+) -> Result<(), anyhow::Error> {
+    let topic = connection.progress.topic.as_str();
+    let client: AdminClient<_> = connection
+        .connection
+        .create_with_context(
+            connection_context,
+            MzClientContext::default(),
+            &BTreeMap::new(),
+        )
+        .await
+        .context("creating admin client failed")?;
+    // Fetching metadata blocks, and this may be called from a timely worker.
+    let client = Arc::new(client);
+    let metadata = task::spawn_blocking(|| format!("fetch_progress_topic_metadata:{topic}"), {
+        let client = Arc::clone(&client);
+        let topic = topic.to_string();
+        move || {
+            client
+                .inner()
+                .fetch_metadata(Some(&topic), DEFAULT_FETCH_METADATA_TIMEOUT)
+        }
+    })
+    .await?
+    .with_context(|| format!("error fetching metadata for progress topic {}", topic))?;
+    let exists = match metadata.topics().iter().find(|t| t.name() == topic) {
+        None => false,
+        Some(meta_topic) => match meta_topic.error().map(RDKafkaErrorCode::from) {
+            None => !meta_topic.partitions().is_empty(),
+            Some(RDKafkaErrorCode::UnknownTopicOrPartition) => false,
+            Some(e) => bail!(
+                "error fetching metadata for progress topic {}: {}",
+                topic,
+                e
+            ),
+        },
+    };
+    if !exists {
+        warn!("progress topic {} does not exist, recreating it", topic);
+        ensure_kafka_topic(
+            &*client,
+            topic,
+            1,
+            -1,
+            KafkaSinkConnectionRetention::default(),
+        )
+        .await
+        .context("error registering kafka progress topic for sink")?;
+    }
+    Ok(())
+}
+
+/// The progress record that `ALTER SINK ... RESET PROGRESS` writes to a Kafka sink's progress
+/// topic. This must stay compatible with the progress record that the sink itself writes.
+#[derive(Serialize)]
+struct ResetProgressRecord {
+    timestamp: Timestamp,
+    reset: bool,
+}
+
+/// Makes the Kafka sink `sink_id` resume from `timestamp` once it restarts, i.e. the sink will only
+/// publish updates at times greater than `timestamp`. The sink runs on a replica with `peers`
+/// workers in total.
+///
+/// The progress record is written in a transaction of the sink's own transactional producer, which
+/// fences out the running sink, so that it cannot write out progress that overrides the reset. The
+/// caller must restart the sink afterwards.
+///
+/// The progress topic is recreated if it does not exist, which allows recovering sinks whose
+/// progress topic was deleted.
+pub async fn reset_kafka_sink_progress(
+    connection: &KafkaSinkConnection,
+    sink_id: GlobalId,
+    peers: usize,
+    timestamp: Timestamp,
+    connection_context: &ConnectionContext,
+) -> Result<(), anyhow::Error> {
+    ensure_kafka_progress_topic(connection, connection_context).await?;
+
+    let worker_id = KafkaSinkConnection::active_worker(sink_id, peers);
+    let producer: ThreadedProducer<_> = connection
+        .connection
+        .create_with_context(
+            connection_context,
+            MzClientContext::default(),
+            &BTreeMap::from([(
+                "transactional.id",
+                KafkaSinkConnection::transactional_id(sink_id, worker_id),
+            )]),
+        )
+        .await
+        .context("creating producer failed")?;
+    let topic = connection.progress.topic.clone();
+    let key = KafkaSinkConnection::progress_key(sink_id);
+    let payload = serde_json::to_vec(&ResetProgressRecord {
+        timestamp,
+        reset: true,
+    })
+    .expect("serialization to vec cannot fail");
+    task::spawn_blocking(
+        || format!("reset_kafka_sink_progress:{sink_id}"),
+        move || {
+            let timeout = DEFAULT_FETCH_METADATA_TIMEOUT;
+            producer
+                .init_transactions(timeout)
+                .context("error fencing out the sink")?;
+            producer.begin_transaction()?;
+            producer
+                .send(BaseRecord::to(&topic).key(&key).payload(&payload))
+                .map_err(|(e, _)| e)
+                .context("error writing progress record")?;
+            producer
+                .commit_transaction(timeout)
+                .context("error committing progress record")?;
+            Ok::<_, anyhow::Error>(())
+        },
+    )
+    .await?
+}
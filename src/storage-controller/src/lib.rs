@@ -125,7 +125,10 @@ use mz_storage_client::metrics::StorageControllerMetrics;
 use mz_storage_types::controller::{CollectionMetadata, DurableCollectionMetadata, StorageError};
 use mz_storage_types::instances::StorageInstanceId;
 use mz_storage_types::parameters::StorageParameters;
-use mz_storage_types::sinks::{ProtoDurableExportMetadata, SinkAsOf, StorageSinkDesc};
+use mz_storage_types::sinks::{
+    ProtoDurableExportMetadata, ProtoDurableSinkPreviousFrom, SinkAsOf, SinkPreviousFrom,
+    StorageSinkDesc,
+};
 use mz_storage_types::sources::{IngestionDescription, SourceData, SourceExport};
 use proptest::prelude::{any, Arbitrary, BoxedStrategy, Strategy};
 use prost::Message;
@@ -133,7 +136,7 @@ use serde::{Deserialize, Serialize};
 use timely::order::{PartialOrder, TotalOrder};
 use timely::progress::{Antichain, ChangeBatch, Timestamp};
 use tokio_stream::StreamMap;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 use crate::command_wals::ProtoShardId;
 use crate::rehydration::RehydratingStorageClient;
@@ -177,6 +180,23 @@ impl MetadataExport<mz_repr::Timestamp> for MetadataExportFetcher {
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DurableExportMetadata<T> {
     pub initial_as_of: SinkAsOf<T>,
+    /// The input the export read from before it was repointed at its current
+    /// input, until the export has switched over to its current input.
+    pub previous_from: Option<DurableSinkPreviousFrom<T>>,
+    /// Whether the export's write frontier has ever advanced, i.e. whether it
+    /// may have published data.
+    ///
+    /// This is `false` for exports created before it was introduced, even if
+    /// they have published data; see the v41 to v42 stash migration.
+    pub has_progress: bool,
+}
+
+/// The durable part of a [`SinkPreviousFrom`], which allows resuming the switch
+/// of an export to a new input after a restart.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DurableSinkPreviousFrom<T> {
+    pub id: GlobalId,
+    pub switch_frontier: Antichain<T>,
 }
 
 impl PartialOrd for DurableExportMetadata<mz_repr::Timestamp> {
@@ -199,6 +219,8 @@ impl RustType<ProtoDurableExportMetadata> for DurableExportMetadata<mz_repr::Tim
     fn into_proto(&self) -> ProtoDurableExportMetadata {
         ProtoDurableExportMetadata {
             initial_as_of: Some(self.initial_as_of.into_proto()),
+            previous_from: self.previous_from.into_proto(),
+            has_progress: self.has_progress,
         }
     }
 
@@ -207,6 +229,28 @@ impl RustType<ProtoDurableExportMetadata> for DurableExportMetadata<mz_repr::Tim
             initial_as_of: proto
                 .initial_as_of
                 .into_rust_if_some("ProtoDurableExportMetadata::initial_as_of")?,
+            previous_from: proto.previous_from.into_rust()?,
+            has_progress: proto.has_progress,
+        })
+    }
+}
+
+impl RustType<ProtoDurableSinkPreviousFrom> for DurableSinkPreviousFrom<mz_repr::Timestamp> {
+    fn into_proto(&self) -> ProtoDurableSinkPreviousFrom {
+        ProtoDurableSinkPreviousFrom {
+            id: Some(self.id.into_proto()),
+            switch_frontier: Some(self.switch_frontier.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoDurableSinkPreviousFrom) -> Result<Self, TryFromProtoError> {
+        Ok(DurableSinkPreviousFrom {
+            id: proto
+                .id
+                .into_rust_if_some("ProtoDurableSinkPreviousFrom::id")?,
+            switch_frontier: proto
+                .switch_frontier
+                .into_rust_if_some("ProtoDurableSinkPreviousFrom::switch_frontier")?,
         })
     }
 }
@@ -217,6 +261,8 @@ impl RustType<mz_stash::objects::proto::DurableExportMetadata>
     fn into_proto(&self) -> mz_stash::objects::proto::DurableExportMetadata {
         mz_stash::objects::proto::DurableExportMetadata {
             initial_as_of: Some(self.initial_as_of.into_proto()),
+            previous_from: self.previous_from.into_proto(),
+            has_progress: self.has_progress,
         }
     }
 
@@ -227,6 +273,30 @@ impl RustType<mz_stash::objects::proto::DurableExportMetadata>
             initial_as_of: proto
                 .initial_as_of
                 .into_rust_if_some("DurableExportMetadata::initial_as_of")?,
+            previous_from: proto.previous_from.into_rust()?,
+            has_progress: proto.has_progress,
+        })
+    }
+}
+
+impl RustType<mz_stash::objects::proto::SinkPreviousFrom>
+    for DurableSinkPreviousFrom<mz_repr::Timestamp>
+{
+    fn into_proto(&self) -> mz_stash::objects::proto::SinkPreviousFrom {
+        mz_stash::objects::proto::SinkPreviousFrom {
+            id: Some(self.id.into_proto()),
+            switch_frontier: Some(self.switch_frontier.into_proto()),
+        }
+    }
+
+    fn from_proto(
+        proto: mz_stash::objects::proto::SinkPreviousFrom,
+    ) -> Result<Self, TryFromProtoError> {
+        Ok(DurableSinkPreviousFrom {
+            id: proto.id.into_rust_if_some("SinkPreviousFrom::id")?,
+            switch_frontier: proto
+                .switch_frontier
+                .into_rust_if_some("SinkPreviousFrom::switch_frontier")?,
         })
     }
 }
@@ -250,8 +320,22 @@ impl Arbitrary for DurableExportMetadata<mz_repr::Timestamp> {
     type Parameters = ();
 
     fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
-        (any::<SinkAsOf<mz_repr::Timestamp>>(),)
-            .prop_map(|(initial_as_of,)| Self { initial_as_of })
+        (
+            any::<SinkAsOf<mz_repr::Timestamp>>(),
+            proptest::option::of((
+                any::<GlobalId>(),
+                proptest::collection::vec(any::<mz_repr::Timestamp>(), 1..4),
+            )),
+            any::<bool>(),
+        )
+            .prop_map(|(initial_as_of, previous_from, has_progress)| Self {
+                initial_as_of,
+                previous_from: previous_from.map(|(id, switch_frontier)| DurableSinkPreviousFrom {
+                    id,
+                    switch_frontier: Antichain::from(switch_frontier),
+                }),
+                has_progress,
+            })
             .boxed()
     }
 }
@@ -284,6 +368,12 @@ pub struct Controller<T: Timestamp + Lattice + Codec64 + From<EpochMillis> + Tim
     /// without blocking the storage controller.
     persist_read_handles: persist_handles::PersistReadWorker<T>,
     stashed_response: Option<StorageResponse<T>>,
+    /// Exports that have switched over to the input they were repointed at,
+    /// whose durable metadata still records their previous input.
+    completed_export_switches: Vec<GlobalId>,
+    /// Exports whose write frontier has advanced for the first time, whose
+    /// durable metadata does not yet record that they have made progress.
+    exports_with_new_progress: Vec<GlobalId>,
     /// Compaction commands to send during the next call to
     /// `StorageController::process`.
     pending_compaction_commands: Vec<(GlobalId, Antichain<T>, Option<StorageInstanceId>)>,
@@ -349,6 +439,7 @@ where
     StorageResponse<T>: RustType<ProtoStorageResponse>,
     MetadataExportFetcher: MetadataExport<T>,
     DurableExportMetadata<T>: RustType<proto::DurableExportMetadata>,
+    DurableSinkPreviousFrom<T>: RustType<proto::SinkPreviousFrom>,
 {
     type Timestamp = T;
 
@@ -1013,7 +1104,7 @@ where
             let from_collection = self.collection(from_id)?;
             let from_storage_metadata = from_collection.collection_metadata.clone();
 
-            let storage_dependencies = vec![from_id];
+            let mut storage_dependencies = vec![from_id];
 
             let value = MetadataExportFetcher::get_stash_collection()
                 .insert_key_without_overwrite(
@@ -1021,6 +1112,8 @@ where
                     id.into_proto(),
                     DurableExportMetadata {
                         initial_as_of: description.sink.as_of.clone(),
+                        previous_from: None,
+                        has_progress: false,
                     }
                     .into_proto(),
                 )
@@ -1030,6 +1123,56 @@ where
 
             durable_export_data.initial_as_of.downgrade(&acquired_since);
 
+            // If the export was repointed at `from_id` but we restarted before
+            // it wrote out the switch, resume the switch from its previous
+            // input. Like `from_id`, the previous input is held back at the
+            // since we can acquire for it.
+            let mut read_capability = acquired_since.clone();
+            let mut previous_from = None;
+            if let Some(previous) = durable_export_data.previous_from.take() {
+                // We cannot switch before the new input can be read.
+                let mut switch_frontier = previous.switch_frontier;
+                switch_frontier.join_assign(&acquired_since);
+                let mut capability = read_capability.clone();
+                match self.determine_collection_since_joins(&[previous.id]) {
+                    Ok(since) => capability.join_assign(&since),
+                    Err(_) => {
+                        warn!(
+                            sink_id = id.to_string(),
+                            previous_id = previous.id.to_string(),
+                            "create_exports: previous input of sink no longer exists, \
+                            not resuming its switch"
+                        );
+                        capability = switch_frontier.clone();
+                    }
+                }
+                let mut as_of = durable_export_data.initial_as_of.clone();
+                as_of.downgrade(&capability);
+                if previous.id != from_id
+                    && PartialOrder::less_than(&as_of.frontier, &switch_frontier)
+                {
+                    self.install_read_capabilities(id, &[previous.id], capability.clone())?;
+                    self.install_read_capabilities(id, &[from_id], switch_frontier.clone())?;
+                    self.remove_read_capabilities(acquired_since.clone(), &[from_id]);
+
+                    let previous_collection = self.collection(previous.id)?;
+                    previous_from = Some((
+                        SinkPreviousFrom {
+                            id: previous.id,
+                            desc: previous_collection.description.desc.clone(),
+                            storage_metadata: (),
+                            switch_frontier,
+                        },
+                        previous_collection.collection_metadata.clone(),
+                    ));
+                    durable_export_data.initial_as_of = as_of;
+                    read_capability = capability;
+                    storage_dependencies = vec![previous.id];
+                } else {
+                    self.record_export_previous_from(id, None).await?;
+                }
+            }
+
             info!(
                 sink_id = id.to_string(),
                 from_id = from_id.to_string(),
@@ -1038,11 +1181,15 @@ where
                 "create_exports: creating sink"
             );
 
+            let mut export_description = description.clone();
+            export_description.sink.previous_from =
+                previous_from.as_ref().map(|(previous, _)| previous.clone());
+            export_description.sink.resuming = durable_export_data.has_progress;
             self.exports.insert(
                 id,
                 ExportState::new(
-                    description.clone(),
-                    acquired_since,
+                    export_description,
+                    read_capability,
                     read_policy,
                     storage_dependencies,
                 ),
@@ -1068,6 +1215,15 @@ where
                     as_of: durable_export_data.initial_as_of,
                     status_id,
                     from_storage_metadata,
+                    previous_from: previous_from.map(|(previous, storage_metadata)| {
+                        SinkPreviousFrom {
+                            id: previous.id,
+                            desc: previous.desc,
+                            storage_metadata,
+                            switch_frontier: previous.switch_frontier,
+                        }
+                    }),
+                    resuming: durable_export_data.has_progress,
                 },
             };
 
@@ -1090,6 +1246,203 @@ where
         Ok(())
     }
 
+    fn check_alter_export(
+        &mut self,
+        id: GlobalId,
+        description: &ExportDescription<Self::Timestamp>,
+    ) -> Result<(), StorageError> {
+        let current = &self.export(id)?.description;
+
+        if current.sink.previous_from.is_some() {
+            return Err(StorageError::InvalidUsage(format!(
+                "sink {id} has not yet switched over to its current input"
+            )));
+        }
+        if current.instance_id != description.instance_id
+            || !current
+                .sink
+                .connection
+                .is_input_alteration_of(&description.sink.connection)
+            || current.sink.envelope != description.sink.envelope
+            || current.sink.status_id != description.sink.status_id
+        {
+            return Err(StorageError::InvalidUsage(format!(
+                "sink {id} can only be altered to read from a new input"
+            )));
+        }
+        self.collection(description.sink.from)?;
+
+        Ok(())
+    }
+
+    async fn alter_export(
+        &mut self,
+        id: GlobalId,
+        description: ExportDescription<Self::Timestamp>,
+    ) -> Result<(), StorageError> {
+        self.check_alter_export(id, &description)?;
+
+        let export = self.export(id)?;
+        let current = &export.description;
+        let previous_id = current.sink.from;
+        let new_id = description.sink.from;
+        if previous_id == new_id {
+            return Ok(());
+        }
+
+        // The sink must not have written out any time at or beyond the switch.
+        // It reports the last time that it has written out as its write
+        // frontier, and it cannot have written out anything its previous input
+        // has not yet produced. We also cannot switch before the new input can
+        // be read.
+        let mut switch_frontier: Antichain<T> = export
+            .write_frontier
+            .iter()
+            .map(|t| t.step_forward())
+            .collect();
+        switch_frontier.join_assign(&self.collection(previous_id)?.write_frontier);
+        switch_frontier.join_assign(&self.determine_collection_since_joins(&[new_id])?);
+
+        let mut as_of = current.sink.as_of.clone();
+        as_of.downgrade(&export.read_capability);
+
+        let status_id = match description.sink.status_id {
+            Some(status_collection_id) => Some(
+                self.collection(status_collection_id)?
+                    .collection_metadata
+                    .data_shard,
+            ),
+            None => None,
+        };
+        let from_storage_metadata = self.collection(new_id)?.collection_metadata.clone();
+        let previous_storage_metadata = self.collection(previous_id)?.collection_metadata.clone();
+        let previous_desc = current.sink.from_desc.clone();
+
+        info!(
+            sink_id = id.to_string(),
+            previous_id = previous_id.to_string(),
+            new_id = new_id.to_string(),
+            switch_frontier = ?switch_frontier,
+            "alter_export: switching sink input"
+        );
+
+        // Record the switch before the sink starts it, so that we resume it
+        // from the previous input if we restart before the sink wrote it out.
+        let durable_previous_from = DurableSinkPreviousFrom {
+            id: previous_id,
+            switch_frontier: switch_frontier.clone(),
+        };
+        self.record_export_previous_from(id, Some(durable_previous_from.into_proto()))
+            .await?;
+
+        // Hold back the new input at the switch until the sink has written it
+        // out. The hold on the previous input is moved over to the new input
+        // once that happens, see `complete_export_switches`.
+        self.install_read_capabilities(id, &[new_id], switch_frontier.clone())?;
+
+        let export = self.export_mut(id)?;
+        export.description.sink.from = new_id;
+        export.description.sink.from_desc = description.sink.from_desc.clone();
+        export.description.sink.connection = description.sink.connection.clone();
+        export.description.sink.previous_from = Some(SinkPreviousFrom {
+            id: previous_id,
+            desc: previous_desc.clone(),
+            storage_metadata: (),
+            switch_frontier: switch_frontier.clone(),
+        });
+
+        let cmd = CreateSinkCommand {
+            id,
+            description: StorageSinkDesc {
+                from: new_id,
+                from_desc: description.sink.from_desc,
+                connection: description.sink.connection,
+                envelope: description.sink.envelope,
+                as_of,
+                status_id,
+                from_storage_metadata,
+                previous_from: Some(SinkPreviousFrom {
+                    id: previous_id,
+                    desc: previous_desc,
+                    storage_metadata: previous_storage_metadata,
+                    switch_frontier,
+                }),
+                resuming: export.description.sink.resuming,
+            },
+        };
+
+        let client = self
+            .clients
+            .get_mut(&description.instance_id)
+            .ok_or_else(|| StorageError::ExportInstanceMissing {
+                storage_instance_id: description.instance_id,
+                export_id: id,
+            })?;
+        client.send(StorageCommand::CreateSinks(vec![cmd]));
+
+        Ok(())
+    }
+
+    fn restart_export(&mut self, id: GlobalId) -> Result<(), StorageError> {
+        let export = self.export(id)?;
+        let description = export.description.clone();
+
+        let mut as_of = description.sink.as_of.clone();
+        as_of.downgrade(&export.read_capability);
+
+        let status_id = match description.sink.status_id {
+            Some(status_collection_id) => Some(
+                self.collection(status_collection_id)?
+                    .collection_metadata
+                    .data_shard,
+            ),
+            None => None,
+        };
+        let from_storage_metadata = self
+            .collection(description.sink.from)?
+            .collection_metadata
+            .clone();
+        let previous_from = match description.sink.previous_from {
+            Some(previous) => Some(SinkPreviousFrom {
+                id: previous.id,
+                desc: previous.desc,
+                storage_metadata: self.collection(previous.id)?.collection_metadata.clone(),
+                switch_frontier: previous.switch_frontier,
+            }),
+            None => None,
+        };
+
+        info!(sink_id = id.to_string(), "restart_export: restarting sink");
+
+        // Storage workers replace the running sink dataflow when they are sent
+        // the description of a sink that they already know about.
+        let cmd = CreateSinkCommand {
+            id,
+            description: StorageSinkDesc {
+                from: description.sink.from,
+                from_desc: description.sink.from_desc,
+                connection: description.sink.connection,
+                envelope: description.sink.envelope,
+                as_of,
+                status_id,
+                from_storage_metadata,
+                previous_from,
+                resuming: description.sink.resuming,
+            },
+        };
+
+        let client = self
+            .clients
+            .get_mut(&description.instance_id)
+            .ok_or_else(|| StorageError::ExportInstanceMissing {
+                storage_instance_id: description.instance_id,
+                export_id: id,
+            })?;
+        client.send(StorageCommand::CreateSinks(vec![cmd]));
+
+        Ok(())
+    }
+
     fn drop_sources(&mut self, identifiers: Vec<GlobalId>) -> Result<(), StorageError> {
         self.validate_collection_ids(identifiers.iter().cloned())?;
         self.drop_sources_unvalidated(identifiers);
@@ -1108,6 +1461,54 @@ where
         self.set_read_policy(policies);
     }
 
+    async fn drop_orphaned_collections(&mut self, identifiers: BTreeSet<GlobalId>) {
+        let identifiers: BTreeSet<_> = identifiers
+            .into_iter()
+            .filter(|id| self.collection(*id).is_err())
+            .collect();
+        if identifiers.is_empty() {
+            return;
+        }
+
+        let mut orphaned: BTreeMap<GlobalId, DurableCollectionMetadata> = METADATA_COLLECTION
+            .peek_one(&mut self.stash)
+            .await
+            .expect("stash operation must succeed")
+            .into_iter()
+            .map(RustType::from_proto)
+            .collect::<Result<_, _>>()
+            .expect("invalid durable collection metadata");
+        orphaned.retain(|id, _| identifiers.contains(id));
+        if orphaned.is_empty() {
+            return;
+        }
+        info!(
+            "dropping orphaned storage collections {:?}",
+            orphaned.keys().collect::<Vec<_>>()
+        );
+
+        // Ensure we don't leak any shards by tracking all of them we intend to
+        // finalize.
+        self.register_shards_for_finalization(
+            orphaned
+                .values()
+                .map(|DurableCollectionMetadata { data_shard }| *data_shard),
+        )
+        .await;
+
+        METADATA_COLLECTION
+            .delete_keys(
+                &mut self.stash,
+                orphaned.keys().map(RustType::into_proto).collect(),
+            )
+            .await
+            .expect("stash operation must succeed");
+
+        if self.config.finalize_shards {
+            self.finalize_shards().await;
+        }
+    }
+
     /// Drops the read capability for the sinks and allows their resources to be reclaimed.
     fn drop_sinks(&mut self, identifiers: Vec<GlobalId>) -> Result<(), StorageError> {
         self.validate_export_ids(identifiers.iter().cloned())?;
@@ -1264,6 +1665,17 @@ where
                         read_capability_changes.insert(*id, update);
                     }
                 }
+
+                // The write frontier of a sink only advances once it has
+                // published data. Closed frontiers identify sinks being
+                // dropped, which no longer need to recover their progress.
+                if !export.description.sink.resuming
+                    && !export.write_frontier.is_empty()
+                    && !export.write_frontier.less_equal(&T::minimum())
+                {
+                    export.description.sink.resuming = true;
+                    self.exports_with_new_progress.push(*id);
+                }
             } else {
                 panic!("Reference to absent collection {id}");
             }
@@ -1272,6 +1684,8 @@ where
         if !read_capability_changes.is_empty() {
             self.update_read_capabilities(&mut read_capability_changes);
         }
+
+        self.complete_export_switches(updates.iter().map(|(id, _)| *id));
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
@@ -1418,6 +1832,17 @@ where
             None => (),
             Some(StorageResponse::FrontierUppers(updates)) => {
                 self.update_write_frontiers(&updates);
+
+                // Exports that have switched over to their new input no longer
+                // need to resume the switch after a restart.
+                for id in std::mem::take(&mut self.completed_export_switches) {
+                    self.record_export_previous_from(id, None).await?;
+                }
+                // Exports that have made progress may have published data, and
+                // so recover their progress from it if they have to.
+                for id in std::mem::take(&mut self.exports_with_new_progress) {
+                    self.record_export_has_progress(id).await?;
+                }
             }
             Some(StorageResponse::DroppedIds(ids)) => {
                 let shards_to_finalize: Vec<_> = ids
@@ -1870,6 +2295,8 @@ where
             persist_monotonic_worker,
             persist_read_handles: persist_handles::PersistReadWorker::new(),
             stashed_response: None,
+            completed_export_switches: Vec::new(),
+            exports_with_new_progress: Vec::new(),
             pending_compaction_commands: vec![],
             collection_manager,
             introspection_ids: BTreeMap::new(),
@@ -1938,6 +2365,90 @@ where
         Ok(joined_since)
     }
 
+    /// Completes the switch of the given exports over to the input they were
+    /// repointed at, for those that no longer need to read their previous
+    /// input.
+    ///
+    /// This moves the export's read hold from the previous input over to the
+    /// new one, and releases the hold on the new input at the switch frontier.
+    fn complete_export_switches(&mut self, ids: impl Iterator<Item = GlobalId>) {
+        let mut read_capability_changes = BTreeMap::default();
+
+        for id in ids {
+            let Ok(export) = self.export_mut(id) else {
+                continue;
+            };
+            let Some(previous) = &export.description.sink.previous_from else {
+                continue;
+            };
+            if !PartialOrder::less_equal(&previous.switch_frontier, &export.read_capability) {
+                continue;
+            }
+
+            let previous = export
+                .description
+                .sink
+                .previous_from
+                .take()
+                .expect("checked above");
+            let new_id = export.description.sink.from;
+            export.storage_dependencies = vec![new_id];
+            self.completed_export_switches.push(id);
+
+            let mut previous_changes = ChangeBatch::new();
+            previous_changes.extend(export.read_capability.iter().map(|t| (t.clone(), -1)));
+            let mut new_changes = ChangeBatch::new();
+            new_changes.extend(export.read_capability.iter().map(|t| (t.clone(), 1)));
+            new_changes.extend(previous.switch_frontier.iter().map(|t| (t.clone(), -1)));
+
+            for (id, changes) in [(previous.id, previous_changes), (new_id, new_changes)] {
+                read_capability_changes
+                    .entry(id)
+                    .or_insert_with(ChangeBatch::new)
+                    .extend(changes.into_inner());
+            }
+        }
+
+        if !read_capability_changes.is_empty() {
+            self.update_read_capabilities(&mut read_capability_changes);
+        }
+    }
+
+    /// Durably records the previous input of the export `id`, or that it has
+    /// none, so that a switch of the export over to a new input resumes after
+    /// a restart.
+    async fn record_export_previous_from(
+        &mut self,
+        id: GlobalId,
+        previous_from: Option<proto::SinkPreviousFrom>,
+    ) -> Result<(), StorageError> {
+        METADATA_EXPORT
+            .upsert_key(&mut self.stash, id.into_proto(), move |prev| match prev {
+                Some(prev) => Ok(proto::DurableExportMetadata {
+                    previous_from,
+                    ..prev.clone()
+                }),
+                None => Err(StorageError::IdentifierMissing(id)),
+            })
+            .await??;
+        Ok(())
+    }
+
+    /// Durably records that the export `id` has made progress, so that it
+    /// recovers its progress from the data it published after a restart.
+    async fn record_export_has_progress(&mut self, id: GlobalId) -> Result<(), StorageError> {
+        METADATA_EXPORT
+            .upsert_key(&mut self.stash, id.into_proto(), move |prev| match prev {
+                Some(prev) => Ok(proto::DurableExportMetadata {
+                    has_progress: true,
+                    ..prev.clone()
+                }),
+                None => Err(StorageError::IdentifierMissing(id)),
+            })
+            .await??;
+        Ok(())
+    }
+
     /// Install read capabilities on the given `storage_dependencies`.
     #[tracing::instrument(level = "info", skip(self))]
     fn install_read_capabilities(
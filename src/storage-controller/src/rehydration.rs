@@ -471,6 +471,14 @@ where
                         None => panic!("Reference to absent collection: {id}"),
                     };
                     if PartialOrder::less_than(reported, &new_upper) {
+                        // A sink whose upper advanced may have published data,
+                        // so when it is recreated it must recover its progress
+                        // from that data if it has to.
+                        if let Some(export) = self.sinks.get_mut(&id) {
+                            if !new_upper.less_equal(&T::minimum()) {
+                                export.description.resuming = true;
+                            }
+                        }
                         reported.clone_from(&new_upper);
                         new_uppers.push((id, new_upper));
                     }
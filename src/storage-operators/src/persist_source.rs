@@ -17,7 +17,7 @@ use std::time::Instant;
 
 use differential_dataflow::lattice::Lattice;
 use futures::{future::Either, StreamExt};
-use mz_expr::{ColumnSpecs, Interpreter, MfpPlan, ResultSpec, UnmaterializableFunc};
+use mz_expr::{ColumnSpecs, Interpreter, MfpPlan, MirScalarExpr, ResultSpec, UnmaterializableFunc};
 use mz_ore::cast::CastFrom;
 use mz_ore::collections::CollectionExt;
 use mz_ore::vec::VecExt;
@@ -264,6 +264,9 @@ where
 
     // Extract the MFP if it exists; leave behind an identity MFP in that case.
     let map_filter_project = map_filter_project.as_mut().map(|mfp| mfp.take());
+    // Only decode the columns that the MFP reads, and skip the rows that its
+    // leading predicates reject.
+    let mut decoder = map_filter_project.as_ref().map(MfpDecoder::new);
 
     builder.build(move |_caps| {
         let name = name.to_owned();
@@ -298,6 +301,7 @@ where
                     &yield_fn,
                     &until,
                     map_filter_project.as_ref(),
+                    decoder.as_mut(),
                     &mut datum_vec,
                     &mut row_builder,
                     &mut handle,
@@ -334,6 +338,7 @@ impl PendingWork {
         yield_fn: YFn,
         until: &Antichain<Timestamp>,
         map_filter_project: Option<&MfpPlan>,
+        mut decoder: Option<&mut MfpDecoder>,
         datum_vec: &mut DatumVec,
         row_builder: &mut Row,
         output: &mut ConsolidateBuffer<
@@ -353,7 +358,12 @@ impl PendingWork {
         YFn: Fn(Instant, usize) -> bool,
     {
         let is_filter_pushdown_audit = self.fetched_part.is_filter_pushdown_audit();
-        while let Some(((key, val), time, diff)) = self.fetched_part.next() {
+        while let Some(((key, val), time, diff)) = match decoder.as_deref_mut() {
+            Some(decoder) => self
+                .fetched_part
+                .next_with_key_decoder(|buf| decoder.decode(buf)),
+            None => self.fetched_part.next(),
+        } {
             if until.less_equal(&time) {
                 continue;
             }
@@ -422,6 +432,70 @@ impl PendingWork {
     }
 }
 
+/// Decodes the rows of a collection for an [`MfpPlan`], only decoding the columns that the plan
+/// reads.
+///
+/// Each row is first decoded with just the columns read by the
+/// [leading predicates](MfpPlan::leading_predicates) of the plan. Rows that these predicates
+/// reject are skipped without decoding their remaining columns, as the plan would filter them out
+/// anyway.
+struct MfpDecoder {
+    /// The columns read by the plan.
+    demand: Vec<bool>,
+    /// The columns read by `predicates`.
+    predicate_demand: Vec<bool>,
+    /// The leading predicates of the plan.
+    predicates: Vec<MirScalarExpr>,
+    datum_vec: DatumVec,
+}
+
+impl MfpDecoder {
+    fn new(mfp: &MfpPlan) -> Self {
+        let predicates: Vec<_> = mfp.leading_predicates().cloned().collect();
+        let predicate_support = predicates.iter().flat_map(|predicate| predicate.support());
+        MfpDecoder {
+            demand: Self::column_demand(mfp.demand()),
+            predicate_demand: Self::column_demand(predicate_support),
+            predicates,
+            datum_vec: DatumVec::new(),
+        }
+    }
+
+    /// Returns a mask of the `columns`. Columns past its end are not set.
+    fn column_demand(columns: impl IntoIterator<Item = usize>) -> Vec<bool> {
+        let mut demand = vec![];
+        for col in columns {
+            if demand.len() <= col {
+                demand.resize(col + 1, false);
+            }
+            demand[col] = true;
+        }
+        demand
+    }
+
+    /// Decodes the encoded [`SourceData`] in `buf`, or returns `None` if the plan would filter it
+    /// out. Columns of the decoded row that the plan doesn't read are set to [`Datum::Null`].
+    fn decode(&mut self, buf: &[u8]) -> Option<Result<SourceData, String>> {
+        if !self.predicates.is_empty() {
+            if let Ok(SourceData(Ok(row))) =
+                SourceData::decode_projected(buf, &self.predicate_demand)
+            {
+                let arena = RowArena::new();
+                let datums = self.datum_vec.borrow_with(&row);
+                for predicate in &self.predicates {
+                    match predicate.eval(&datums, &arena) {
+                        Ok(Datum::True) => {}
+                        Ok(_) => return None,
+                        // Leave it to the plan to report the error.
+                        Err(_) => break,
+                    }
+                }
+            }
+        }
+        Some(SourceData::decode_projected(buf, &self.demand))
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct PersistSourceDataStats<'a> {
     pub(crate) desc: &'a RelationDesc,
@@ -1037,6 +1037,18 @@ impl PostgresConnection<InlinedConnection> {
         Ok(mz_postgres_util::Config::new(config, tunnel)?)
     }
 
+    /// Returns the IDs of the secrets holding the credentials with which this
+    /// connection authenticates to the server.
+    pub fn credential_secrets(&self) -> impl Iterator<Item = GlobalId> + '_ {
+        let user = match &self.user {
+            StringOrSecret::Secret(id) => Some(*id),
+            StringOrSecret::String(_) => None,
+        };
+        user.into_iter()
+            .chain(self.password)
+            .chain(self.tls_identity.as_ref().map(|identity| identity.key))
+    }
+
     async fn validate(
         &self,
         _id: GlobalId,
@@ -587,6 +587,10 @@ mod columnation {
                             from: self.string_region.copy(from),
                             to: self.string_region.copy(to),
                         },
+                        EvalError::InvalidJsonPath(x) => {
+                            EvalError::InvalidJsonPath(self.string_region.copy(x))
+                        }
+                        EvalError::JsonPath(x) => EvalError::JsonPath(self.string_region.copy(x)),
                         EvalError::InvalidRegex(x) => {
                             EvalError::InvalidRegex(self.string_region.copy(x))
                         }
@@ -30,6 +30,8 @@ pub struct StorageParameters {
     pub persist: PersistParameters,
     pub pg_source_tcp_timeouts: mz_postgres_util::TcpTimeoutConfig,
     pub pg_source_snapshot_statement_timeout: Duration,
+    /// How PG sources retry when the upstream rejects their credentials.
+    pub pg_source_auth_retry_config: PgSourceAuthRetryConfig,
     pub keep_n_source_status_history_entries: usize,
     pub keep_n_sink_status_history_entries: usize,
     /// A set of parameters used to tune RocksDB when used with `UPSERT` sources.
@@ -97,6 +99,53 @@ impl RustType<ProtoStorageMaxInflightBytesConfig> for StorageMaxInflightBytesCon
     }
 }
 
+/// The default for [`PgSourceAuthRetryConfig::max_backoff`].
+pub const DEFAULT_PG_SOURCE_AUTH_RETRY_MAX_BACKOFF: Duration = Duration::from_secs(30);
+/// The default for [`PgSourceAuthRetryConfig::timeout`].
+pub const DEFAULT_PG_SOURCE_AUTH_RETRY_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Configures how PG sources react to the upstream rejecting their credentials,
+/// e.g. because a password was rotated by an external secrets manager.
+///
+/// The credentials are read again from the secrets reader and the connection
+/// is retried with exponential backoff, up to `max_backoff` between attempts,
+/// until `timeout` has elapsed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PgSourceAuthRetryConfig {
+    /// The maximum time to wait between two attempts.
+    pub max_backoff: Duration,
+    /// How long to keep retrying before reporting the failure as an error.
+    pub timeout: Duration,
+}
+
+impl Default for PgSourceAuthRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_backoff: DEFAULT_PG_SOURCE_AUTH_RETRY_MAX_BACKOFF,
+            timeout: DEFAULT_PG_SOURCE_AUTH_RETRY_TIMEOUT,
+        }
+    }
+}
+
+impl RustType<ProtoPgSourceAuthRetryConfig> for PgSourceAuthRetryConfig {
+    fn into_proto(&self) -> ProtoPgSourceAuthRetryConfig {
+        ProtoPgSourceAuthRetryConfig {
+            max_backoff: Some(self.max_backoff.into_proto()),
+            timeout: Some(self.timeout.into_proto()),
+        }
+    }
+    fn from_proto(proto: ProtoPgSourceAuthRetryConfig) -> Result<Self, TryFromProtoError> {
+        Ok(Self {
+            max_backoff: proto
+                .max_backoff
+                .into_rust_if_some("ProtoPgSourceAuthRetryConfig::max_backoff")?,
+            timeout: proto
+                .timeout
+                .into_rust_if_some("ProtoPgSourceAuthRetryConfig::timeout")?,
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
 pub struct UpsertAutoSpillConfig {
     /// A flag to whether allow automatically spilling to disk or not
@@ -129,6 +178,7 @@ impl StorageParameters {
             persist,
             pg_source_tcp_timeouts,
             pg_source_snapshot_statement_timeout,
+            pg_source_auth_retry_config,
             keep_n_source_status_history_entries,
             keep_n_sink_status_history_entries,
             upsert_rocksdb_tuning_config,
@@ -146,6 +196,7 @@ impl StorageParameters {
         self.persist.update(persist);
         self.pg_source_tcp_timeouts = pg_source_tcp_timeouts;
         self.pg_source_snapshot_statement_timeout = pg_source_snapshot_statement_timeout;
+        self.pg_source_auth_retry_config = pg_source_auth_retry_config;
         self.keep_n_source_status_history_entries = keep_n_source_status_history_entries;
         self.keep_n_sink_status_history_entries = keep_n_sink_status_history_entries;
         self.upsert_rocksdb_tuning_config = upsert_rocksdb_tuning_config;
@@ -171,6 +222,7 @@ impl RustType<ProtoStorageParameters> for StorageParameters {
             pg_source_snapshot_statement_timeout: Some(
                 self.pg_source_snapshot_statement_timeout.into_proto(),
             ),
+            pg_source_auth_retry_config: Some(self.pg_source_auth_retry_config.into_proto()),
             keep_n_source_status_history_entries: u64::cast_from(
                 self.keep_n_source_status_history_entries,
             ),
@@ -207,6 +259,9 @@ impl RustType<ProtoStorageParameters> for StorageParameters {
                 .into_rust_if_some(
                     "ProtoStorageParameters::pg_source_snapshot_statement_timeout",
                 )?,
+            pg_source_auth_retry_config: proto
+                .pg_source_auth_retry_config
+                .into_rust_if_some("ProtoStorageParameters::pg_source_auth_retry_config")?,
             keep_n_source_status_history_entries: usize::cast_from(
                 proto.keep_n_source_status_history_entries,
             ),
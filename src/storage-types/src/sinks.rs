@@ -11,6 +11,8 @@
 
 use std::fmt::Debug;
 
+use differential_dataflow::Hashable;
+use mz_ore::cast::CastFrom;
 use mz_persist_client::ShardId;
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::{GlobalId, RelationDesc};
@@ -21,7 +23,7 @@ use serde::{Deserialize, Serialize};
 use timely::progress::frontier::Antichain;
 use timely::PartialOrder;
 
-use crate::connections::{CsrConnection, KafkaConnection};
+use crate::connections::{CsrConnection, KafkaConnection, PostgresConnection};
 use crate::controller::CollectionMetadata;
 
 use crate::connections::inline::{
@@ -41,6 +43,81 @@ pub struct StorageSinkDesc<S: StorageSinkDescFillState, T = mz_repr::Timestamp>
     pub as_of: SinkAsOf<T>,
     pub status_id: Option<<S as StorageSinkDescFillState>::StatusId>,
     pub from_storage_metadata: <S as StorageSinkDescFillState>::StorageMetadata,
+    /// The input the sink read from before `from` was changed by `ALTER SINK
+    /// ... SET FROM`, if the sink might not yet have written the switch over
+    /// to `from`.
+    pub previous_from: Option<SinkPreviousFrom<S, T>>,
+    /// Whether the sink is known to have published data before, i.e. its
+    /// write frontier has advanced at some point. Only such sinks recover
+    /// their progress from the data they published if their progress records
+    /// are missing.
+    pub resuming: bool,
+}
+
+/// The input a sink read from before it was repointed at a new relation.
+///
+/// The sink emits the updates of the previous input at times not beyond
+/// `switch_frontier`, and the updates of its current input from there on. At
+/// the switch frontier it emits the difference between the two.
+///
+/// The columns of the previous input are matched up with those of the current
+/// input by name. Columns of the current input that the previous input lacks
+/// are `NULL` in the updates of the previous input.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SinkPreviousFrom<S: StorageSinkDescFillState, T = mz_repr::Timestamp> {
+    pub id: GlobalId,
+    pub desc: RelationDesc,
+    pub storage_metadata: <S as StorageSinkDescFillState>::StorageMetadata,
+    pub switch_frontier: Antichain<T>,
+}
+
+impl Arbitrary for SinkPreviousFrom<MetadataFilled, mz_repr::Timestamp> {
+    type Strategy = BoxedStrategy<Self>;
+    type Parameters = ();
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<GlobalId>(),
+            any::<RelationDesc>(),
+            any::<CollectionMetadata>(),
+            proptest::collection::vec(any::<mz_repr::Timestamp>(), 1..4),
+        )
+            .prop_map(
+                |(id, desc, storage_metadata, switch_frontier)| SinkPreviousFrom {
+                    id,
+                    desc,
+                    storage_metadata,
+                    switch_frontier: Antichain::from(switch_frontier),
+                },
+            )
+            .boxed()
+    }
+}
+
+impl RustType<ProtoSinkPreviousFrom> for SinkPreviousFrom<MetadataFilled, mz_repr::Timestamp> {
+    fn into_proto(&self) -> ProtoSinkPreviousFrom {
+        ProtoSinkPreviousFrom {
+            id: Some(self.id.into_proto()),
+            storage_metadata: Some(self.storage_metadata.into_proto()),
+            switch_frontier: Some(self.switch_frontier.into_proto()),
+            desc: Some(self.desc.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoSinkPreviousFrom) -> Result<Self, TryFromProtoError> {
+        Ok(SinkPreviousFrom {
+            id: proto.id.into_rust_if_some("ProtoSinkPreviousFrom::id")?,
+            desc: proto
+                .desc
+                .into_rust_if_some("ProtoSinkPreviousFrom::desc")?,
+            storage_metadata: proto
+                .storage_metadata
+                .into_rust_if_some("ProtoSinkPreviousFrom::storage_metadata")?,
+            switch_frontier: proto
+                .switch_frontier
+                .into_rust_if_some("ProtoSinkPreviousFrom::switch_frontier")?,
+        })
+    }
 }
 
 pub trait StorageSinkDescFillState {
@@ -75,6 +152,8 @@ impl Arbitrary for StorageSinkDesc<MetadataFilled, mz_repr::Timestamp> {
             any::<SinkAsOf<mz_repr::Timestamp>>(),
             any::<Option<ShardId>>(),
             any::<CollectionMetadata>(),
+            any::<Option<SinkPreviousFrom<MetadataFilled, mz_repr::Timestamp>>>(),
+            any::<bool>(),
         )
             .prop_map(
                 |(
@@ -85,6 +164,8 @@ impl Arbitrary for StorageSinkDesc<MetadataFilled, mz_repr::Timestamp> {
                     as_of,
                     status_id,
                     from_storage_metadata,
+                    previous_from,
+                    resuming,
                 )| {
                     StorageSinkDesc {
                         from,
@@ -94,6 +175,8 @@ impl Arbitrary for StorageSinkDesc<MetadataFilled, mz_repr::Timestamp> {
                         as_of,
                         status_id,
                         from_storage_metadata,
+                        previous_from,
+                        resuming,
                     }
                 },
             )
@@ -111,6 +194,8 @@ impl RustType<ProtoStorageSinkDesc> for StorageSinkDesc<MetadataFilled, mz_repr:
             as_of: Some(self.as_of.into_proto()),
             status_id: self.status_id.into_proto(),
             from_storage_metadata: Some(self.from_storage_metadata.into_proto()),
+            previous_from: self.previous_from.into_proto(),
+            resuming: self.resuming,
         }
     }
 
@@ -131,6 +216,8 @@ impl RustType<ProtoStorageSinkDesc> for StorageSinkDesc<MetadataFilled, mz_repr:
             from_storage_metadata: proto
                 .from_storage_metadata
                 .into_rust_if_some("ProtoStorageSinkDesc::from_storage_metadata")?,
+            previous_from: proto.previous_from.into_rust()?,
+            resuming: proto.resuming,
         })
     }
 }
@@ -238,6 +325,7 @@ impl RustType<proto::SinkAsOf> for SinkAsOf<mz_repr::Timestamp> {
 #[derive(Arbitrary, Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub enum StorageSinkConnection<C: ConnectionAccess = InlinedConnection> {
     Kafka(KafkaSinkConnection<C>),
+    Postgres(PostgresSinkConnection<C>),
 }
 
 impl<R: ConnectionResolver> IntoInlineConnection<StorageSinkConnection, R>
@@ -246,6 +334,7 @@ impl<R: ConnectionResolver> IntoInlineConnection<StorageSinkConnection, R>
     fn into_inline_connection(self, r: R) -> StorageSinkConnection {
         match self {
             Self::Kafka(conn) => StorageSinkConnection::Kafka(conn.into_inline_connection(r)),
+            Self::Postgres(conn) => StorageSinkConnection::Postgres(conn.into_inline_connection(r)),
         }
     }
 }
@@ -256,6 +345,7 @@ impl<C: ConnectionAccess> StorageSinkConnection<C> {
         use StorageSinkConnection::*;
         match self {
             Kafka(KafkaSinkConnection { connection_id, .. }) => Some(*connection_id),
+            Postgres(PostgresSinkConnection { connection_id, .. }) => Some(*connection_id),
         }
     }
 
@@ -263,8 +353,33 @@ impl<C: ConnectionAccess> StorageSinkConnection<C> {
     pub fn name(&self) -> &'static str {
         match self {
             StorageSinkConnection::Kafka(_) => "kafka",
+            StorageSinkConnection::Postgres(_) => "postgres",
         }
     }
+
+    /// Reports whether `other` writes to the same place as `self`, and differs
+    /// from `self` at most in the parts that depend on the input of the sink,
+    /// as is the case when the sink is repointed at a new input.
+    pub fn is_input_alteration_of(&self, other: &Self) -> bool
+    where
+        C: Clone + PartialEq,
+    {
+        let mut other = other.clone();
+        match (self, &mut other) {
+            (StorageSinkConnection::Kafka(this), StorageSinkConnection::Kafka(other)) => {
+                other.key_desc_and_indices = this.key_desc_and_indices.clone();
+                other.relation_key_indices = this.relation_key_indices.clone();
+                other.value_desc = this.value_desc.clone();
+                other.published_schema_info = this.published_schema_info.clone();
+            }
+            (StorageSinkConnection::Postgres(this), StorageSinkConnection::Postgres(other)) => {
+                other.key_desc_and_indices = this.key_desc_and_indices.clone();
+                other.value_desc = this.value_desc.clone();
+            }
+            _ => return false,
+        }
+        *self == other
+    }
 }
 
 impl RustType<ProtoStorageSinkConnection> for StorageSinkConnection {
@@ -273,6 +388,7 @@ impl RustType<ProtoStorageSinkConnection> for StorageSinkConnection {
         ProtoStorageSinkConnection {
             kind: Some(match self {
                 StorageSinkConnection::Kafka(kafka) => Kind::Kafka(kafka.into_proto()),
+                StorageSinkConnection::Postgres(postgres) => Kind::Postgres(postgres.into_proto()),
             }),
         }
     }
@@ -284,6 +400,7 @@ impl RustType<ProtoStorageSinkConnection> for StorageSinkConnection {
             .ok_or_else(|| TryFromProtoError::missing_field("ProtoStorageSinkConnection::kind"))?;
         Ok(match kind {
             Kind::Kafka(kafka) => StorageSinkConnection::Kafka(kafka.into_rust()?),
+            Kind::Postgres(postgres) => StorageSinkConnection::Postgres(postgres.into_rust()?),
         })
     }
 }
@@ -350,6 +467,30 @@ impl<R: ConnectionResolver> IntoInlineConnection<KafkaSinkConnection, R>
     }
 }
 
+impl KafkaSinkConnection {
+    /// Returns the index of the worker that writes out the sink `sink_id` on a
+    /// replica with `peers` workers in total.
+    ///
+    /// This relies on the sink exchanging all of its updates to the worker
+    /// that the hash of its ID maps to.
+    pub fn active_worker(sink_id: GlobalId, peers: usize) -> usize {
+        usize::cast_from(sink_id.hashed()) % peers
+    }
+
+    /// Returns the transactional ID of the producer with which the worker
+    /// `worker_id` writes out the sink `sink_id`.
+    ///
+    /// Initializing transactions with this ID fences out the running sink.
+    pub fn transactional_id(sink_id: GlobalId, worker_id: usize) -> String {
+        format!("mz-producer-{sink_id}-{worker_id}")
+    }
+
+    /// Returns the key of the progress records of the sink `sink_id`.
+    pub fn progress_key(sink_id: GlobalId) -> String {
+        format!("mz-sink-{sink_id}")
+    }
+}
+
 impl RustType<proto_kafka_sink_connection::ProtoKeyDescAndIndices> for (RelationDesc, Vec<usize>) {
     fn into_proto(&self) -> proto_kafka_sink_connection::ProtoKeyDescAndIndices {
         proto_kafka_sink_connection::ProtoKeyDescAndIndices {
@@ -422,6 +563,93 @@ impl RustType<ProtoKafkaSinkConnection> for KafkaSinkConnection {
     }
 }
 
+/// A sink that upserts the updates of each timestamp into a PostgreSQL table in
+/// a single transaction.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PostgresSinkConnection<C: ConnectionAccess = InlinedConnection> {
+    pub connection: PostgresConnection<C>,
+    pub connection_id: GlobalId,
+    /// The schema of the table the sink writes to.
+    pub schema: String,
+    /// The table the sink writes to.
+    pub table: String,
+    /// The columns that identify a row of the table. The table must have a
+    /// unique constraint on them.
+    pub key_desc_and_indices: (RelationDesc, Vec<usize>),
+    pub value_desc: RelationDesc,
+    /// The table, in `schema`, that holds the timestamp up to which each sink
+    /// has written its updates.
+    pub progress_table: String,
+    /// The environment the sink belongs to. Its progress is recorded under a
+    /// key that includes it, so that sinks of different environments can share
+    /// a progress table.
+    pub environment_id: String,
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<PostgresSinkConnection, R>
+    for PostgresSinkConnection<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> PostgresSinkConnection {
+        let PostgresSinkConnection {
+            connection,
+            connection_id,
+            schema,
+            table,
+            key_desc_and_indices,
+            value_desc,
+            progress_table,
+            environment_id,
+        } = self;
+
+        PostgresSinkConnection {
+            connection: connection.into_inline_connection(r),
+            connection_id,
+            schema,
+            table,
+            key_desc_and_indices,
+            value_desc,
+            progress_table,
+            environment_id,
+        }
+    }
+}
+
+impl RustType<ProtoPostgresSinkConnection> for PostgresSinkConnection {
+    fn into_proto(&self) -> ProtoPostgresSinkConnection {
+        ProtoPostgresSinkConnection {
+            connection: Some(self.connection.into_proto()),
+            connection_id: Some(self.connection_id.into_proto()),
+            schema: self.schema.clone(),
+            table: self.table.clone(),
+            key_desc_and_indices: Some(self.key_desc_and_indices.into_proto()),
+            value_desc: Some(self.value_desc.into_proto()),
+            progress_table: self.progress_table.clone(),
+            environment_id: self.environment_id.clone(),
+        }
+    }
+
+    fn from_proto(proto: ProtoPostgresSinkConnection) -> Result<Self, TryFromProtoError> {
+        Ok(PostgresSinkConnection {
+            connection: proto
+                .connection
+                .into_rust_if_some("ProtoPostgresSinkConnection::connection")?,
+            connection_id: proto
+                .connection_id
+                .into_rust_if_some("ProtoPostgresSinkConnection::connection_id")?,
+            schema: proto.schema,
+            table: proto.table,
+            key_desc_and_indices: proto
+                .key_desc_and_indices
+                .into_rust_if_some("ProtoPostgresSinkConnection::key_desc_and_indices")?,
+            value_desc: proto
+                .value_desc
+                .into_rust_if_some("ProtoPostgresSinkConnection::value_desc")?,
+            progress_table: proto.progress_table,
+            environment_id: proto.environment_id,
+        })
+    }
+}
+
 /// TODO(JLDLaughlin): Documentation.
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct PublishedSchemaInfo {
@@ -448,6 +676,8 @@ impl RustType<ProtoPublishedSchemaInfo> for PublishedSchemaInfo {
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum StorageSinkConnectionBuilder<C: ConnectionAccess = InlinedConnection> {
     Kafka(KafkaSinkConnectionBuilder<C>),
+    /// Postgres sinks need no setup before they are created.
+    Postgres(PostgresSinkConnection<C>),
 }
 
 impl<R: ConnectionResolver> IntoInlineConnection<StorageSinkConnectionBuilder, R>
@@ -458,6 +688,9 @@ impl<R: ConnectionResolver> IntoInlineConnection<StorageSinkConnectionBuilder, R
             Self::Kafka(conn) => {
                 StorageSinkConnectionBuilder::Kafka(conn.into_inline_connection(r))
             }
+            Self::Postgres(conn) => {
+                StorageSinkConnectionBuilder::Postgres(conn.into_inline_connection(r))
+            }
         }
     }
 }
@@ -468,6 +701,7 @@ impl<C: ConnectionAccess> StorageSinkConnectionBuilder<C> {
         use StorageSinkConnectionBuilder::*;
         match self {
             Kafka(KafkaSinkConnectionBuilder { connection_id, .. }) => Some(*connection_id),
+            Postgres(PostgresSinkConnection { connection_id, .. }) => Some(*connection_id),
         }
     }
 
@@ -476,6 +710,7 @@ impl<C: ConnectionAccess> StorageSinkConnectionBuilder<C> {
         use StorageSinkConnectionBuilder::*;
         match self {
             Kafka(_) => "kafka",
+            Postgres(_) => "postgres",
         }
     }
 }
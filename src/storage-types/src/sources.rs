@@ -22,6 +22,7 @@ use dec::OrderedDecimal;
 use itertools::EitherOrBoth::Both;
 use itertools::Itertools;
 use mz_expr::{MirScalarExpr, PartitionId};
+use mz_ore::cast::CastFrom;
 use mz_ore::now::NowFn;
 use mz_persist_types::columnar::{
     ColumnFormat, ColumnGet, ColumnPush, Data, DataType, PartDecoder, PartEncoder, Schema,
@@ -346,6 +347,15 @@ pub trait SourceTimestamp: timely::progress::Timestamp + Refines<()> + std::fmt:
     fn try_into_compat_ts(&self) -> Option<(PartitionId, MzOffset)>;
     fn encode_row(&self) -> Row;
     fn decode_row(row: &Row) -> Self;
+
+    /// Encodes this timestamp into a row of the remap collection described by `desc`.
+    ///
+    /// Timestamps whose remap collection can take more than one shape use `desc` to pick the
+    /// right one; all others encode the same row as [`SourceTimestamp::encode_row`].
+    fn encode_row_for_desc(&self, desc: &RelationDesc) -> Row {
+        let _ = desc;
+        self.encode_row()
+    }
 }
 
 impl SourceTimestamp for MzOffset {
@@ -459,6 +469,151 @@ impl SourceTimestamp for Partitioned<i32, MzOffset> {
     }
 }
 
+/// A partition of a Kafka source.
+///
+/// Sources that read a single topic leave `topic` empty, which keeps their remap collection in
+/// the `(partition, offset)` shape described by [`KAFKA_PROGRESS_DESC`]. Sources created with
+/// `TOPIC PATTERN` record the topic of every partition and use
+/// [`KAFKA_TOPIC_PATTERN_PROGRESS_DESC`] instead.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct KafkaPartition {
+    pub topic: String,
+    pub partition: i32,
+}
+
+impl KafkaPartition {
+    pub fn new(topic: String, partition: i32) -> Self {
+        KafkaPartition { topic, partition }
+    }
+}
+
+impl std::fmt::Display for KafkaPartition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.topic.is_empty() {
+            write!(f, "{}", self.partition)
+        } else {
+            write!(f, "{}:{}", self.topic, self.partition)
+        }
+    }
+}
+
+impl Partitioned<KafkaPartition, MzOffset> {
+    /// Reports whether any bound of this timestamp names a topic, in which case it can only be
+    /// recorded in a remap collection described by [`KAFKA_TOPIC_PATTERN_PROGRESS_DESC`].
+    fn has_topic(&self) -> bool {
+        match self.interval() {
+            Interval::Point(p) => !p.topic.is_empty(),
+            Interval::Range(l, u) => [l, u]
+                .into_iter()
+                .any(|b| matches!(b, RangeBound::Elem(p) if !p.topic.is_empty())),
+        }
+    }
+
+    fn encode_topic_pattern_row(&self) -> Row {
+        let bound = |b: &RangeBound<KafkaPartition>| match b {
+            RangeBound::Elem(p) => (Datum::String(&p.topic), Datum::Int32(p.partition)),
+            RangeBound::Bottom | RangeBound::Top => (Datum::Null, Datum::Null),
+        };
+        let (lower, upper) = match self.interval() {
+            Interval::Range(l, u) => (bound(l), bound(u)),
+            Interval::Point(p) => {
+                let p = (Datum::String(&p.topic), Datum::Int32(p.partition));
+                (p, p)
+            }
+        };
+        Row::pack([
+            lower.0,
+            lower.1,
+            upper.0,
+            upper.1,
+            Datum::UInt64(self.timestamp().offset),
+        ])
+    }
+
+    fn decode_topic_pattern_row(row: &Row) -> Self {
+        let datums: Vec<_> = row.iter().collect();
+        let bound = |topic: Datum, partition: Datum| match (topic, partition) {
+            (Datum::String(topic), Datum::Int32(partition)) => {
+                Some(KafkaPartition::new(topic.to_owned(), partition))
+            }
+            (Datum::Null, Datum::Null) => None,
+            invalid => panic!("invalid partition bound {invalid:?}"),
+        };
+        match &datums[..] {
+            [lower_topic, lower_pid, upper_topic, upper_pid, Datum::UInt64(offset)] => {
+                let lower = bound(*lower_topic, *lower_pid);
+                let upper = bound(*upper_topic, *upper_pid);
+                let offset = MzOffset::from(*offset);
+                match (lower, upper) {
+                    (Some(lower), Some(upper)) if lower == upper => {
+                        Partitioned::with_partition(lower, offset)
+                    }
+                    (lower, upper) => Partitioned::with_range(lower, upper, offset),
+                }
+            }
+            _ => panic!("invalid row {row:?}"),
+        }
+    }
+}
+
+impl SourceTimestamp for Partitioned<KafkaPartition, MzOffset> {
+    fn from_compat_ts(pid: PartitionId, offset: MzOffset) -> Self {
+        match pid {
+            PartitionId::Kafka(pid) => {
+                Partitioned::with_partition(KafkaPartition::new(String::new(), pid), offset)
+            }
+            PartitionId::None => panic!("invalid partitioned partition {pid}"),
+        }
+    }
+
+    fn try_into_compat_ts(&self) -> Option<(PartitionId, MzOffset)> {
+        let pid = self.partition()?;
+        Some((PartitionId::Kafka(pid.partition), *self.timestamp()))
+    }
+
+    fn encode_row(&self) -> Row {
+        if self.has_topic() {
+            return self.encode_topic_pattern_row();
+        }
+        let pid = |b: &RangeBound<KafkaPartition>| match b {
+            RangeBound::Elem(p) => Some(p.partition),
+            RangeBound::Bottom | RangeBound::Top => None,
+        };
+        let ts = match self.interval() {
+            Interval::Range(l, u) => Partitioned::with_range(pid(l), pid(u), *self.timestamp()),
+            Interval::Point(p) => Partitioned::with_partition(p.partition, *self.timestamp()),
+        };
+        ts.encode_row()
+    }
+
+    fn decode_row(row: &Row) -> Self {
+        if row.iter().count() == KAFKA_TOPIC_PATTERN_PROGRESS_DESC.arity() {
+            return Self::decode_topic_pattern_row(row);
+        }
+        let ts = Partitioned::<i32, MzOffset>::decode_row(row);
+        let partition = |p: &i32| KafkaPartition::new(String::new(), *p);
+        match ts.interval() {
+            Interval::Range(l, u) => {
+                let bound = |b: &RangeBound<i32>| match b {
+                    RangeBound::Elem(p) => Some(partition(p)),
+                    RangeBound::Bottom | RangeBound::Top => None,
+                };
+                Partitioned::with_range(bound(l), bound(u), *ts.timestamp())
+            }
+            Interval::Point(p) => Partitioned::with_partition(partition(p), *ts.timestamp()),
+        }
+    }
+
+    fn encode_row_for_desc(&self, desc: &RelationDesc) -> Row {
+        if desc.arity() == KAFKA_TOPIC_PATTERN_PROGRESS_DESC.arity() {
+            self.encode_topic_pattern_row()
+        } else {
+            assert!(!self.has_topic(), "topic in single topic timestamp {self}");
+            self.encode_row()
+        }
+    }
+}
+
 /// Universal language for describing message positions in Materialize, in a source independent
 /// way. Individual sources like Kafka or File sources should explicitly implement their own offset
 /// type that converts to/From MzOffsets. A 0-MzOffset denotes an empty stream.
@@ -625,6 +780,41 @@ pub enum KafkaMetadataKind {
     Offset,
     Timestamp,
     Headers,
+    Topic,
+}
+
+impl KafkaMetadataKind {
+    /// The type of the column holding this piece of metadata.
+    pub fn scalar_type(&self) -> ScalarType {
+        match self {
+            KafkaMetadataKind::Partition => ScalarType::Int32,
+            KafkaMetadataKind::Offset => ScalarType::UInt64,
+            KafkaMetadataKind::Timestamp => ScalarType::Timestamp { precision: None },
+            KafkaMetadataKind::Topic => ScalarType::String,
+            KafkaMetadataKind::Headers => ScalarType::List {
+                element_type: Box::new(ScalarType::Record {
+                    fields: vec![
+                        (
+                            "key".into(),
+                            ColumnType {
+                                nullable: false,
+                                scalar_type: ScalarType::String,
+                            },
+                        ),
+                        (
+                            "value".into(),
+                            ColumnType {
+                                nullable: false,
+                                scalar_type: ScalarType::Bytes,
+                            },
+                        ),
+                    ],
+                    custom_id: None,
+                }),
+                custom_id: None,
+            },
+        }
+    }
 }
 
 impl RustType<ProtoKafkaMetadataKind> for KafkaMetadataKind {
@@ -636,6 +826,7 @@ impl RustType<ProtoKafkaMetadataKind> for KafkaMetadataKind {
                 KafkaMetadataKind::Offset => Kind::Offset(()),
                 KafkaMetadataKind::Timestamp => Kind::Timestamp(()),
                 KafkaMetadataKind::Headers => Kind::Headers(()),
+                KafkaMetadataKind::Topic => Kind::Topic(()),
             }),
         }
     }
@@ -650,6 +841,7 @@ impl RustType<ProtoKafkaMetadataKind> for KafkaMetadataKind {
             Kind::Offset(()) => KafkaMetadataKind::Offset,
             Kind::Timestamp(()) => KafkaMetadataKind::Timestamp,
             Kind::Headers(()) => KafkaMetadataKind::Headers,
+            Kind::Topic(()) => KafkaMetadataKind::Topic,
         })
     }
 }
@@ -855,7 +1047,10 @@ impl RustType<ProtoSourceEnvelope> for SourceEnvelope {
 pub enum UnplannedSourceEnvelope {
     None(KeyEnvelope),
     Debezium(DebeziumEnvelope),
-    Upsert { style: UpsertStyle },
+    Upsert {
+        style: UpsertStyle,
+        key_ttl: Option<Duration>,
+    },
     CdcV2,
 }
 
@@ -892,6 +1087,9 @@ pub struct UpsertEnvelope {
     /// The indices of the keys in the full value row, used
     /// to deduplicate data in `upsert_core`
     pub key_indices: Vec<usize>,
+    /// If set, keys that are not updated for this long, as measured in
+    /// reclocked time, are retracted from the output.
+    pub key_ttl: Option<Duration>,
 }
 
 impl Arbitrary for UpsertEnvelope {
@@ -903,11 +1101,13 @@ impl Arbitrary for UpsertEnvelope {
             any::<usize>(),
             any::<UpsertStyle>(),
             proptest::collection::vec(any::<usize>(), 1..4),
+            any::<Option<Duration>>(),
         )
-            .prop_map(|(source_arity, style, key_indices)| Self {
+            .prop_map(|(source_arity, style, key_indices, key_ttl)| Self {
                 source_arity,
                 style,
                 key_indices,
+                key_ttl,
             })
             .boxed()
     }
@@ -919,6 +1119,7 @@ impl RustType<ProtoUpsertEnvelope> for UpsertEnvelope {
             source_arity: self.source_arity.into_proto(),
             style: Some(self.style.into_proto()),
             key_indices: self.key_indices.into_proto(),
+            key_ttl: self.key_ttl.into_proto(),
         }
     }
 
@@ -929,6 +1130,7 @@ impl RustType<ProtoUpsertEnvelope> for UpsertEnvelope {
                 .style
                 .into_rust_if_some("ProtoUpsertEnvelope::style")?,
             key_indices: proto.key_indices.into_rust()?,
+            key_ttl: proto.key_ttl.into_rust()?,
         })
     }
 }
@@ -1208,6 +1410,7 @@ impl UnplannedSourceEnvelope {
         match self {
             UnplannedSourceEnvelope::Upsert {
                 style: upsert_style,
+                key_ttl,
             } => SourceEnvelope::Upsert(UpsertEnvelope {
                 style: upsert_style,
                 key_indices: key.expect(
@@ -1218,6 +1421,7 @@ impl UnplannedSourceEnvelope {
                     "into_source_envelope to be passed \
                     correct parameters for UnplannedSourceEnvelope::Upsert",
                 ),
+                key_ttl,
             }),
             UnplannedSourceEnvelope::Debezium(inner) => SourceEnvelope::Debezium(inner),
             UnplannedSourceEnvelope::None(key_envelope) => SourceEnvelope::None(NoneEnvelope {
@@ -1401,6 +1605,9 @@ pub struct KafkaSourceConnection<C: ConnectionAccess = InlinedConnection> {
     pub connection: C::Kafka,
     pub connection_id: GlobalId,
     pub topic: String,
+    /// Whether `topic` is a regular expression selecting every topic to read from, rather than
+    /// the name of a single topic.
+    pub topic_is_pattern: bool,
     // Map from partition -> starting offset
     pub start_offsets: BTreeMap<i32, i64>,
     pub group_id_prefix: Option<String>,
@@ -1416,6 +1623,7 @@ impl<R: ConnectionResolver> IntoInlineConnection<KafkaSourceConnection, R>
             connection,
             connection_id,
             topic,
+            topic_is_pattern,
             start_offsets,
             group_id_prefix,
             environment_id,
@@ -1426,6 +1634,7 @@ impl<R: ConnectionResolver> IntoInlineConnection<KafkaSourceConnection, R>
             connection: r.resolve_connection(connection).unwrap_kafka(),
             connection_id,
             topic,
+            topic_is_pattern,
             start_offsets,
             group_id_prefix,
             environment_id,
@@ -1446,6 +1655,20 @@ pub static KAFKA_PROGRESS_DESC: Lazy<RelationDesc> = Lazy::new(|| {
         .with_column("offset", ScalarType::UInt64.nullable(true))
 });
 
+/// The progress collection of a Kafka source created with `TOPIC PATTERN`.
+///
+/// Every row describes either a single partition, in which case the lower and upper bounds are
+/// equal, or the exclusive range of partitions between its bounds, where `NULL` bounds are
+/// unbounded.
+pub static KAFKA_TOPIC_PATTERN_PROGRESS_DESC: Lazy<RelationDesc> = Lazy::new(|| {
+    RelationDesc::empty()
+        .with_column("lower_topic", ScalarType::String.nullable(true))
+        .with_column("lower_partition", ScalarType::Int32.nullable(true))
+        .with_column("upper_topic", ScalarType::String.nullable(true))
+        .with_column("upper_partition", ScalarType::Int32.nullable(true))
+        .with_column("offset", ScalarType::UInt64.nullable(true))
+});
+
 impl<C: ConnectionAccess> KafkaSourceConnection<C> {
     /// Returns the id for the consumer group the configured source will use.
     ///
@@ -1472,7 +1695,11 @@ impl<C: ConnectionAccess> SourceConnection for KafkaSourceConnection<C> {
     }
 
     fn timestamp_desc(&self) -> RelationDesc {
-        KAFKA_PROGRESS_DESC.clone()
+        if self.topic_is_pattern {
+            KAFKA_TOPIC_PATTERN_PROGRESS_DESC.clone()
+        } else {
+            KAFKA_PROGRESS_DESC.clone()
+        }
     }
 
     fn connection_id(&self) -> Option<GlobalId> {
@@ -1482,36 +1709,7 @@ impl<C: ConnectionAccess> SourceConnection for KafkaSourceConnection<C> {
     fn metadata_columns(&self) -> Vec<(&str, ColumnType)> {
         self.metadata_columns
             .iter()
-            .map(|(name, kind)| {
-                let typ = match kind {
-                    KafkaMetadataKind::Partition => ScalarType::Int32,
-                    KafkaMetadataKind::Offset => ScalarType::UInt64,
-                    KafkaMetadataKind::Timestamp => ScalarType::Timestamp { precision: None },
-                    KafkaMetadataKind::Headers => ScalarType::List {
-                        element_type: Box::new(ScalarType::Record {
-                            fields: vec![
-                                (
-                                    "key".into(),
-                                    ColumnType {
-                                        nullable: false,
-                                        scalar_type: ScalarType::String,
-                                    },
-                                ),
-                                (
-                                    "value".into(),
-                                    ColumnType {
-                                        nullable: false,
-                                        scalar_type: ScalarType::Bytes,
-                                    },
-                                ),
-                            ],
-                            custom_id: None,
-                        }),
-                        custom_id: None,
-                    },
-                };
-                (&**name, typ.nullable(false))
-            })
+            .map(|(name, kind)| (&**name, kind.scalar_type().nullable(false)))
             .collect()
     }
 }
@@ -1528,6 +1726,7 @@ where
             any::<C::Kafka>(),
             any::<GlobalId>(),
             any::<String>(),
+            any::<bool>(),
             proptest::collection::btree_map(any::<i32>(), any::<i64>(), 1..4),
             any::<Option<String>>(),
             any::<String>(),
@@ -1538,6 +1737,7 @@ where
                     connection,
                     connection_id,
                     topic,
+                    topic_is_pattern,
                     start_offsets,
                     group_id_prefix,
                     environment_id,
@@ -1546,6 +1746,7 @@ where
                     connection,
                     connection_id,
                     topic,
+                    topic_is_pattern,
                     start_offsets,
                     group_id_prefix,
                     environment_id,
@@ -1562,6 +1763,7 @@ impl RustType<ProtoKafkaSourceConnection> for KafkaSourceConnection<InlinedConne
             connection: Some(self.connection.into_proto()),
             connection_id: Some(self.connection_id.into_proto()),
             topic: self.topic.clone(),
+            topic_is_pattern: self.topic_is_pattern,
             start_offsets: self.start_offsets.clone(),
             group_id_prefix: self.group_id_prefix.clone(),
             environment_id: None,
@@ -1592,6 +1794,7 @@ impl RustType<ProtoKafkaSourceConnection> for KafkaSourceConnection<InlinedConne
                 .connection_id
                 .into_rust_if_some("ProtoKafkaSourceConnection::connection_id")?,
             topic: proto.topic,
+            topic_is_pattern: proto.topic_is_pattern,
             start_offsets: proto.start_offsets,
             group_id_prefix: proto.group_id_prefix,
             environment_id: match (proto.environment_id, proto.environment_name) {
@@ -1638,6 +1841,73 @@ impl RustType<ProtoCompression> for Compression {
     }
 }
 
+/// What to do with records whose value fails to decode.
+#[derive(Arbitrary, Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ValueDecodingErrors {
+    /// Emit the decoding error into the source's error collection.
+    Error,
+    /// Drop the record, only counting it in the source's statistics.
+    Skip,
+    /// Drop the record from the source and emit its raw bytes and decoding
+    /// error into the companion relation rendered at `output_index`.
+    Inline { output_index: usize },
+}
+
+impl ValueDecodingErrors {
+    /// The columns of the companion relation of a source with the given
+    /// metadata columns.
+    pub fn relation_desc<'a>(
+        metadata_columns: impl IntoIterator<Item = (&'a str, ColumnType)>,
+    ) -> RelationDesc {
+        let mut desc = RelationDesc::empty()
+            .with_column("error", ScalarType::String.nullable(false))
+            .with_column("raw", ScalarType::Bytes.nullable(false));
+        for (name, typ) in metadata_columns {
+            desc = desc.with_column(name, typ);
+        }
+        desc
+    }
+
+    /// The output index of the companion relation, if any.
+    pub fn output_index(&self) -> Option<usize> {
+        match self {
+            ValueDecodingErrors::Inline { output_index } => Some(*output_index),
+            ValueDecodingErrors::Error | ValueDecodingErrors::Skip => None,
+        }
+    }
+}
+
+impl RustType<ProtoValueDecodingErrors> for ValueDecodingErrors {
+    fn into_proto(&self) -> ProtoValueDecodingErrors {
+        use proto_value_decoding_errors::Kind;
+        ProtoValueDecodingErrors {
+            kind: Some(match self {
+                ValueDecodingErrors::Error => Kind::Error(()),
+                ValueDecodingErrors::Skip => Kind::Skip(()),
+                ValueDecodingErrors::Inline { output_index } => {
+                    Kind::InlineOutputIndex(output_index.into_proto())
+                }
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoValueDecodingErrors) -> Result<Self, TryFromProtoError> {
+        use proto_value_decoding_errors::Kind;
+        Ok(match proto.kind {
+            Some(Kind::Error(())) => ValueDecodingErrors::Error,
+            Some(Kind::Skip(())) => ValueDecodingErrors::Skip,
+            Some(Kind::InlineOutputIndex(output_index)) => ValueDecodingErrors::Inline {
+                output_index: output_index.into_rust()?,
+            },
+            None => {
+                return Err(TryFromProtoError::missing_field(
+                    "ProtoValueDecodingErrors::kind",
+                ))
+            }
+        })
+    }
+}
+
 /// An external source of updates for a relational collection.
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub struct SourceDesc<C: ConnectionAccess = InlinedConnection> {
@@ -1645,6 +1915,7 @@ pub struct SourceDesc<C: ConnectionAccess = InlinedConnection> {
     pub encoding: encoding::SourceDataEncoding<C>,
     pub envelope: SourceEnvelope,
     pub timestamp_interval: Duration,
+    pub value_decoding_errors: ValueDecodingErrors,
 }
 
 impl<R: ConnectionResolver> IntoInlineConnection<SourceDesc, R>
@@ -1656,6 +1927,7 @@ impl<R: ConnectionResolver> IntoInlineConnection<SourceDesc, R>
             encoding,
             envelope,
             timestamp_interval,
+            value_decoding_errors,
         } = self;
 
         SourceDesc {
@@ -1663,6 +1935,7 @@ impl<R: ConnectionResolver> IntoInlineConnection<SourceDesc, R>
             encoding: encoding.into_inline_connection(r),
             envelope,
             timestamp_interval,
+            value_decoding_errors,
         }
     }
 }
@@ -1677,13 +1950,17 @@ impl Arbitrary for SourceDesc {
             any::<encoding::SourceDataEncoding>(),
             any::<SourceEnvelope>(),
             any::<Duration>(),
+            any::<ValueDecodingErrors>(),
         )
             .prop_map(
-                |(connection, encoding, envelope, timestamp_interval)| Self {
-                    connection,
-                    encoding,
-                    envelope,
-                    timestamp_interval,
+                |(connection, encoding, envelope, timestamp_interval, value_decoding_errors)| {
+                    Self {
+                        connection,
+                        encoding,
+                        envelope,
+                        timestamp_interval,
+                        value_decoding_errors,
+                    }
                 },
             )
             .boxed()
@@ -1697,6 +1974,7 @@ impl RustType<ProtoSourceDesc> for SourceDesc {
             encoding: Some(self.encoding.into_proto()),
             envelope: Some(self.envelope.into_proto()),
             timestamp_interval: Some(self.timestamp_interval.into_proto()),
+            value_decoding_errors: Some(self.value_decoding_errors.into_proto()),
         }
     }
 
@@ -1714,6 +1992,9 @@ impl RustType<ProtoSourceDesc> for SourceDesc {
             timestamp_interval: proto
                 .timestamp_interval
                 .into_rust_if_some("ProtoSourceDesc::timestamp_interval")?,
+            value_decoding_errors: proto
+                .value_decoding_errors
+                .into_rust_if_some("ProtoSourceDesc::value_decoding_errors")?,
         })
     }
 }
@@ -1769,6 +2050,7 @@ impl<C: ConnectionAccess> SourceDesc<C> {
             encoding,
             envelope,
             timestamp_interval,
+            value_decoding_errors,
         } = &self;
         connection.alter_compatible(id, &other.connection)?;
 
@@ -1777,6 +2059,7 @@ impl<C: ConnectionAccess> SourceDesc<C> {
             encoding == &other.encoding,
             envelope == &other.envelope,
             timestamp_interval == &other.timestamp_interval,
+            value_decoding_errors == &other.value_decoding_errors,
         ];
 
         for compatible in compatibility_checks {
@@ -1950,6 +2233,12 @@ pub struct PostgresSourceConnection<C: ConnectionAccess = InlinedConnection> {
     /// The cast expressions to convert the incoming string encoded rows to
     /// their target types, keyed by their position in the source.
     pub table_casts: BTreeMap<usize, Vec<MirScalarExpr>>,
+    /// How to handle an upstream `TRUNCATE` of each table, keyed by their
+    /// position in the source. Tables that are not present produce an error.
+    pub truncate_behaviors: BTreeMap<usize, PostgresTruncateBehavior>,
+    /// The indices of the primary key columns of the tables that are ingested
+    /// in upsert mode, keyed by their position in the source.
+    pub upsert_keys: BTreeMap<usize, Vec<usize>>,
     pub publication: String,
     pub publication_details: PostgresSourcePublicationDetails,
 }
@@ -1962,6 +2251,8 @@ impl<R: ConnectionResolver> IntoInlineConnection<PostgresSourceConnection, R>
             connection_id,
             connection,
             table_casts,
+            truncate_behaviors,
+            upsert_keys,
             publication,
             publication_details,
         } = self;
@@ -1970,6 +2261,8 @@ impl<R: ConnectionResolver> IntoInlineConnection<PostgresSourceConnection, R>
             connection_id,
             connection: r.resolve_connection(connection).unwrap_pg(),
             table_casts,
+            truncate_behaviors,
+            upsert_keys,
             publication,
             publication_details,
         }
@@ -1989,14 +2282,34 @@ impl<C: ConnectionAccess> Arbitrary for PostgresSourceConnection<C> {
                 proptest::collection::vec(any::<MirScalarExpr>(), 1..4),
                 1..4,
             ),
+            proptest::collection::btree_map(
+                any::<usize>(),
+                any::<PostgresTruncateBehavior>(),
+                0..4,
+            ),
+            proptest::collection::btree_map(
+                any::<usize>(),
+                proptest::collection::vec(any::<usize>(), 1..4),
+                0..4,
+            ),
             any::<String>(),
             any::<PostgresSourcePublicationDetails>(),
         )
             .prop_map(
-                |(connection, connection_id, table_casts, publication, details)| Self {
+                |(
+                    connection,
+                    connection_id,
+                    table_casts,
+                    truncate_behaviors,
+                    upsert_keys,
+                    publication,
+                    details,
+                )| Self {
                     connection,
                     connection_id,
                     table_casts,
+                    truncate_behaviors,
+                    upsert_keys,
                     publication,
                     publication_details: details,
                 },
@@ -2038,6 +2351,8 @@ impl<C: ConnectionAccess> SourceConnection for PostgresSourceConnection<C> {
             connection_id,
             connection,
             table_casts,
+            truncate_behaviors,
+            upsert_keys,
             publication,
             publication_details,
         } = self;
@@ -2054,6 +2369,24 @@ impl<C: ConnectionAccess> SourceConnection for PostgresSourceConnection<C> {
                     Both((_, l_val), (_, r_val)) => l_val == r_val,
                     _ => true,
                 }),
+            truncate_behaviors
+                .iter()
+                .merge_join_by(&other.truncate_behaviors, |(l_key, _), (r_key, _)| {
+                    l_key.cmp(r_key)
+                })
+                .all(|r| match r {
+                    Both((_, l_val), (_, r_val)) => l_val == r_val,
+                    _ => true,
+                }),
+            upsert_keys
+                .iter()
+                .merge_join_by(&other.upsert_keys, |(l_key, _), (r_key, _)| {
+                    l_key.cmp(r_key)
+                })
+                .all(|r| match r {
+                    Both((_, l_val), (_, r_val)) => l_val == r_val,
+                    _ => true,
+                }),
             publication == &other.publication,
             publication_details == &other.publication_details,
         ];
@@ -2097,6 +2430,23 @@ impl RustType<ProtoPostgresSourceConnection> for PostgresSourceConnection {
             details: Some(self.publication_details.into_proto()),
             table_casts,
             table_cast_pos,
+            truncate_behaviors: self
+                .truncate_behaviors
+                .iter()
+                .map(|(pos, behavior)| behavior.into_proto(*pos))
+                .collect(),
+            upsert_keys: self
+                .upsert_keys
+                .iter()
+                .map(|(pos, key_indices)| ProtoPostgresUpsertKey {
+                    pos: mz_ore::cast::usize_to_u64(*pos),
+                    key_indices: key_indices
+                        .iter()
+                        .copied()
+                        .map(mz_ore::cast::usize_to_u64)
+                        .collect(),
+                })
+                .collect(),
         }
     }
 
@@ -2126,6 +2476,22 @@ impl RustType<ProtoPostgresSourceConnection> for PostgresSourceConnection {
             table_casts.insert(mz_ore::cast::u64_to_usize(pos), column_casts);
         }
 
+        let mut truncate_behaviors = BTreeMap::new();
+        for proto in proto.truncate_behaviors {
+            let pos = mz_ore::cast::u64_to_usize(proto.pos);
+            truncate_behaviors.insert(pos, PostgresTruncateBehavior::from_proto(proto)?);
+        }
+
+        let mut upsert_keys = BTreeMap::new();
+        for proto in proto.upsert_keys {
+            let key_indices = proto
+                .key_indices
+                .into_iter()
+                .map(mz_ore::cast::u64_to_usize)
+                .collect();
+            upsert_keys.insert(mz_ore::cast::u64_to_usize(proto.pos), key_indices);
+        }
+
         Ok(PostgresSourceConnection {
             connection: proto
                 .connection
@@ -2138,6 +2504,47 @@ impl RustType<ProtoPostgresSourceConnection> for PostgresSourceConnection {
                 .details
                 .into_rust_if_some("ProtoPostgresSourceConnection::details")?,
             table_casts,
+            truncate_behaviors,
+            upsert_keys,
+        })
+    }
+}
+
+/// What a Postgres source does when a table it ingests is truncated upstream.
+#[derive(Arbitrary, Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PostgresTruncateBehavior {
+    /// Permanently error the subsource.
+    Error,
+    /// Ignore the truncation, leaving the contents of the subsource untouched.
+    Ignore,
+    /// Retract the contents of the subsource at the LSN of the truncation.
+    Retract,
+}
+
+impl PostgresTruncateBehavior {
+    fn into_proto(&self, pos: usize) -> ProtoPostgresTruncateBehavior {
+        use proto_postgres_truncate_behavior::Kind;
+        ProtoPostgresTruncateBehavior {
+            pos: mz_ore::cast::usize_to_u64(pos),
+            kind: Some(match self {
+                PostgresTruncateBehavior::Error => Kind::Error(()),
+                PostgresTruncateBehavior::Ignore => Kind::Ignore(()),
+                PostgresTruncateBehavior::Retract => Kind::Retract(()),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoPostgresTruncateBehavior) -> Result<Self, TryFromProtoError> {
+        use proto_postgres_truncate_behavior::Kind;
+        Ok(match proto.kind {
+            Some(Kind::Error(())) => PostgresTruncateBehavior::Error,
+            Some(Kind::Ignore(())) => PostgresTruncateBehavior::Ignore,
+            Some(Kind::Retract(())) => PostgresTruncateBehavior::Retract,
+            None => {
+                return Err(TryFromProtoError::MissingField(
+                    "ProtoPostgresTruncateBehavior::kind".into(),
+                ))
+            }
         })
     }
 }
@@ -2653,6 +3060,40 @@ impl DerefMut for SourceData {
     }
 }
 
+impl SourceData {
+    /// Decodes a [SourceData] previously encoded with [Codec::encode], only
+    /// materializing the datums of an `Ok` row at the positions set in
+    /// `demand`.
+    ///
+    /// Datums that are not demanded are skipped without being decoded and
+    /// replaced with [Datum::Null], so the result must only be handed to
+    /// consumers that never look at them, e.g. an [mz_expr::MfpPlan] whose
+    /// demand is a subset of `demand`. Errors are always decoded in full.
+    pub fn decode_projected(buf: &[u8], demand: &[bool]) -> Result<Self, String> {
+        if let Some(row) = Self::ok_row_bytes(buf) {
+            if let Ok(row) = Row::decode_proto_projected(row, demand) {
+                return Ok(SourceData(Ok(row)));
+            }
+        }
+        // Errors, and anything we failed to decode above, take the regular path,
+        // which also knows how to fall back to the legacy encoding.
+        Self::decode(buf)
+    }
+
+    /// Returns the encoded `ProtoRow` of a `ProtoSourceData` that consists of
+    /// just an `ok` row, without decoding it.
+    fn ok_row_bytes(mut buf: &[u8]) -> Option<&[u8]> {
+        let (tag, wire_type) = prost::encoding::decode_key(&mut buf).ok()?;
+        if tag != 1 || wire_type != prost::encoding::WireType::LengthDelimited {
+            return None;
+        }
+        let len = usize::cast_from(prost::encoding::decode_varint(&mut buf).ok()?);
+        // Anything following the row, like another occurrence of the oneof,
+        // needs the regular decoding logic.
+        (len == buf.len()).then_some(buf)
+    }
+}
+
 impl RustType<ProtoSourceData> for SourceData {
     fn into_proto(&self) -> ProtoSourceData {
         use proto_source_data::Kind;
@@ -2853,6 +3294,38 @@ mod tests {
         assert!("".parse::<Timeline>().is_err());
     }
 
+    #[mz_ore::test]
+    fn test_source_data_decode_projected() {
+        let encode = |data: &SourceData| {
+            let mut buf = Vec::new();
+            data.encode(&mut buf);
+            buf
+        };
+
+        let row = SourceData(Ok(Row::pack([
+            Datum::Int64(1),
+            Datum::String("a"),
+            Datum::True,
+        ])));
+        let buf = encode(&row);
+        assert_eq!(
+            SourceData::decode_projected(&buf, &[true, true, true]),
+            Ok(row)
+        );
+        assert_eq!(
+            SourceData::decode_projected(&buf, &[false, true]),
+            Ok(SourceData(Ok(Row::pack([
+                Datum::Null,
+                Datum::String("a"),
+                Datum::Null,
+            ]))))
+        );
+
+        // Errors are always decoded in full.
+        let err = SourceData(Err(EnvelopeError::Debezium("foo".into()).into()));
+        assert_eq!(SourceData::decode_projected(&encode(&err), &[]), Ok(err));
+    }
+
     fn scalar_type_columnar_roundtrip(scalar_type: ScalarType) {
         let skip_decode = is_no_stats_type(&scalar_type);
 
@@ -505,6 +505,7 @@ where
                 hint: None,
             },
             should_halt: true,
+            ignored_columns: None,
         };
         (0, halt_status)
     });
@@ -28,14 +28,16 @@ pub async fn write_to_persist(
     status_shard: ShardId,
     relation_desc: &RelationDesc,
     hint: Option<&str>,
+    ignored_columns: &[String],
 ) {
     let now_ms = now();
-    let row = mz_storage_client::healthcheck::pack_status_row(
+    let row = mz_storage_client::healthcheck::pack_source_status_row(
         collection_id,
         new_status,
         new_error,
         now_ms,
         hint,
+        ignored_columns,
     );
 
     let mut handle = client
@@ -29,6 +29,8 @@ pub struct DataflowParameters {
     pub pg_source_tcp_timeouts: mz_postgres_util::TcpTimeoutConfig,
     /// Configured PG `statement_timeout` value to use during snapshotting.
     pub pg_source_snapshot_statement_timeout: Duration,
+    /// Configured PG retry behavior when the upstream rejects the credentials.
+    pub pg_source_auth_retry_config: mz_storage_types::parameters::PgSourceAuthRetryConfig,
     /// Configuration/tuning for RocksDB
     pub upsert_rocksdb_tuning_config: mz_rocksdb::RocksDBConfig,
     /// A set of parameters to configure auto spill to disk behaviour for a `DISK`
@@ -55,6 +57,7 @@ impl DataflowParameters {
         Self {
             pg_source_tcp_timeouts: Default::default(),
             pg_source_snapshot_statement_timeout: Default::default(),
+            pg_source_auth_retry_config: Default::default(),
             upsert_rocksdb_tuning_config: mz_rocksdb::RocksDBConfig::new(
                 shared_rocksdb_write_buffer_manager,
                 cluster_memory_limit,
@@ -70,6 +73,7 @@ impl DataflowParameters {
         &mut self,
         pg_source_tcp_timeouts: mz_postgres_util::TcpTimeoutConfig,
         pg_source_snapshot_statement_timeout: Duration,
+        pg_source_auth_retry_config: mz_storage_types::parameters::PgSourceAuthRetryConfig,
         rocksdb_params: mz_rocksdb::RocksDBTuningParameters,
         auto_spill_config: mz_storage_types::parameters::UpsertAutoSpillConfig,
         storage_dataflow_max_inflight_bytes_config: mz_storage_types::parameters::StorageMaxInflightBytesConfig,
@@ -78,6 +82,7 @@ impl DataflowParameters {
     ) {
         self.pg_source_tcp_timeouts = pg_source_tcp_timeouts;
         self.pg_source_snapshot_statement_timeout = pg_source_snapshot_statement_timeout;
+        self.pg_source_auth_retry_config = pg_source_auth_retry_config;
         self.upsert_rocksdb_tuning_config.apply(rocksdb_params);
         self.auto_spill_config = auto_spill_config;
         self.storage_dataflow_max_inflight_bytes_config =
@@ -129,6 +134,8 @@ pub enum InternalStorageCommand {
         pg_source_tcp_timeouts: mz_postgres_util::TcpTimeoutConfig,
         /// PG snapshot `statement_timeout` config
         pg_source_snapshot_statement_timeout: Duration,
+        /// PG authentication retry config
+        pg_source_auth_retry_config: mz_storage_types::parameters::PgSourceAuthRetryConfig,
         /// RocksDB configuration.
         rocksdb: mz_rocksdb::RocksDBTuningParameters,
         /// Backpressure configuration.
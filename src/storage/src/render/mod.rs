@@ -303,6 +303,7 @@ pub fn build_ingestion_dataflow<A: Allocate>(
                             hint: None,
                         },
                         should_halt: true,
+                        ignored_columns: None,
                     };
                     (0, halt_status)
                 });
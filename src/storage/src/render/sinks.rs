@@ -15,6 +15,7 @@ use std::collections::BTreeSet;
 use std::rc::Rc;
 use std::sync::Arc;
 
+use differential_dataflow::lattice::Lattice;
 use differential_dataflow::operators::arrange::Arrange;
 use differential_dataflow::trace::implementations::ord::ColValSpine;
 use differential_dataflow::{AsCollection, Collection, Hashable};
@@ -29,6 +30,8 @@ use mz_storage_types::sinks::{
     MetadataFilled, SinkEnvelope, StorageSinkConnection, StorageSinkDesc,
 };
 use timely::dataflow::Scope;
+use timely::progress::Antichain;
+use timely::PartialOrder;
 use tracing::warn;
 
 use crate::storage_state::{SinkToken, StorageState};
@@ -54,13 +57,24 @@ pub(crate) fn render_sink<G: Scope<Timestamp = Timestamp>>(
         }
     }
 
+    // If the sink was repointed at `sink.from` and might not have written out the switch yet,
+    // we read the previous input up to the switch and `sink.from` from the switch on.
+    let previous_from = sink.previous_from.as_ref().filter(|previous| {
+        PartialOrder::less_than(&sink.as_of.frontier, &previous.switch_frontier)
+    });
+
+    let mut as_of = sink.as_of.frontier.clone();
+    if let Some(previous) = previous_from {
+        as_of.join_assign(&previous.switch_frontier);
+    }
+
     let (ok_collection, err_collection, source_token) = persist_source::persist_source(
         scope,
         sink.from,
         Arc::clone(&storage_state.persist_clients),
         sink.from_storage_metadata.clone(),
-        Some(sink.as_of.frontier.clone()),
-        timely::progress::Antichain::new(),
+        Some(as_of),
+        Antichain::new(),
         None,
         None,
         // Copy the logic in DeltaJoin/Get/Join to start.
@@ -68,8 +82,63 @@ pub(crate) fn render_sink<G: Scope<Timestamp = Timestamp>>(
     );
     needed_tokens.push(source_token);
 
-    let ok_collection =
-        apply_sink_envelope(sink_id, sink, &sink_render, ok_collection.as_collection());
+    let mut ok_collection = ok_collection.as_collection();
+    let mut err_collection = err_collection.as_collection();
+
+    if let Some(previous) = previous_from {
+        let (previous_ok, previous_err, previous_token) = persist_source::persist_source(
+            scope,
+            previous.id,
+            Arc::clone(&storage_state.persist_clients),
+            previous.storage_metadata.clone(),
+            Some(sink.as_of.frontier.clone()),
+            previous.switch_frontier.clone(),
+            None,
+            None,
+            |_timer, count| count > 1_000_000,
+        );
+        needed_tokens.push(previous_token);
+
+        // Retract the contents of the previous input at the switch, which leaves exactly the
+        // difference between the two inputs to be written out at that time.
+        let switch_ts = *previous
+            .switch_frontier
+            .as_option()
+            .expect("switch frontier is not empty");
+        let mut previous_ok = previous_ok.as_collection();
+        let previous_err = previous_err.as_collection();
+
+        // Line the columns of the previous input up with those of the new one.
+        let projection: Vec<_> = sink
+            .from_desc
+            .iter_names()
+            .map(|name| previous.desc.get_by_name(name).map(|(i, _)| i))
+            .collect();
+        if projection
+            .iter()
+            .copied()
+            .ne((0..previous.desc.arity()).map(Some))
+        {
+            let mut row_buf = Row::default();
+            previous_ok = previous_ok.map(move |row| {
+                let datums = row.unpack();
+                row_buf.packer().extend(
+                    projection
+                        .iter()
+                        .map(|i| i.map_or(Datum::Null, |i| datums[i])),
+                );
+                row_buf.clone()
+            });
+        }
+        ok_collection = ok_collection
+            .concat(&previous_ok)
+            .concat(&previous_ok.delay(move |_| switch_ts).negate());
+        err_collection = err_collection
+            .concat(&previous_err)
+            .concat(&previous_err.delay(move |_| switch_ts).negate());
+    }
+
+    let ok_collection = apply_sink_envelope(sink_id, sink, &sink_render, ok_collection);
 
     let healthchecker_args = HealthcheckerArgs {
         persist_clients: Arc::clone(&storage_state.persist_clients),
@@ -83,7 +152,7 @@ pub(crate) fn render_sink<G: Scope<Timestamp = Timestamp>>(
         sink,
         sink_id,
         ok_collection,
-        err_collection.as_collection(),
+        err_collection,
         healthchecker_args,
     );
 
@@ -274,5 +343,6 @@ where
 {
     match connection {
         StorageSinkConnection::Kafka(connection) => Box::new(connection.clone()),
+        StorageSinkConnection::Postgres(connection) => Box::new(connection.clone()),
     }
 }
@@ -15,6 +15,7 @@ use std::any::Any;
 use std::collections::BTreeMap;
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::Duration;
 
 use differential_dataflow::{collection, AsCollection, Collection, Hashable};
 use mz_ore::cast::CastLossy;
@@ -39,9 +40,9 @@ use timely::dataflow::Stream;
 use timely::progress::{Antichain, Timestamp as _};
 
 use crate::decode::{render_decode_cdcv2, render_decode_delimited};
-use crate::render::upsert::UpsertKey;
+use crate::render::upsert::{UpsertKey, UpsertValue};
 use crate::source::types::{DecodeResult, HealthStatusUpdate, SourceOutput};
-use crate::source::{self, RawSourceCreationConfig, SourceCreationParams};
+use crate::source::{self, RawSourceCreationConfig, SourceCreationParams, SourceStatistics};
 
 /// A type-level enum that holds one of two types of sources depending on their message type
 ///
@@ -99,6 +100,12 @@ pub fn render_source<'g, G: Scope<Timestamp = ()>>(
     let connection = description.desc.connection.clone();
     let source_name = format!("{}-{}", connection.name(), id);
 
+    // The outputs of Postgres sources that are ingested in upsert mode, along with their keys.
+    let upsert_keys = match &connection {
+        GenericSourceConnection::Postgres(connection) => connection.upsert_keys.clone(),
+        _ => BTreeMap::new(),
+    };
+
     let params = SourceCreationParams {
         pg_source_tcp_timeouts: storage_state
             .dataflow_parameters
@@ -107,6 +114,10 @@ pub fn render_source<'g, G: Scope<Timestamp = ()>>(
         pg_source_snapshot_statement_timeout: storage_state
             .dataflow_parameters
             .pg_source_snapshot_statement_timeout,
+        pg_source_auth_retry_config: storage_state
+            .dataflow_parameters
+            .pg_source_auth_retry_config
+            .clone(),
     };
 
     let base_source_config = RawSourceCreationConfig {
@@ -217,17 +228,29 @@ pub fn render_source<'g, G: Scope<Timestamp = ()>>(
 
     needed_tokens.push(source_token);
 
+    // The companion relation of sources with `VALUE DECODING ERRORS = INLINE` is not read from
+    // the upstream system but made up of the records of the main output that failed to decode.
+    let dead_letters_index = description.desc.value_decoding_errors.output_index();
+    let mut dead_letters = None;
+
     let mut outputs = vec![];
-    for (ok_source, err_source) in streams {
+    for (output_index, (ok_source, err_source)) in streams.into_iter().enumerate() {
+        if Some(output_index) == dead_letters_index {
+            outputs.push((None, err_source.map(DataflowError::from)));
+            continue;
+        }
+
         // All sources should push their various error streams into this vector,
         // whose contents will be concatenated and inserted along the collection.
         // All subsources include the non-definite errors of the ingestion
         let error_collections = vec![err_source.map(DataflowError::from)];
 
-        let (ok, err, extra_tokens, health_stream) = render_source_stream(
+        let (ok, err, extra_tokens, health_stream, output_dead_letters) = render_source_stream(
             scope,
             dataflow_debug_name,
             id,
+            output_index,
+            upsert_keys.get(&output_index).cloned(),
             ok_source,
             description.clone(),
             as_of.clone(),
@@ -237,19 +260,44 @@ pub fn render_source<'g, G: Scope<Timestamp = ()>>(
             starter.clone(),
         );
         needed_tokens.extend(extra_tokens);
-        outputs.push((ok, err));
+        outputs.push((Some(ok), err));
+        if output_dead_letters.is_some() {
+            dead_letters = output_dead_letters;
+        }
 
         health = health.concat(&health_stream.leave());
     }
+
+    let outputs = outputs
+        .into_iter()
+        .map(|(ok, err)| match ok {
+            Some(ok) => (ok, err),
+            None => (
+                dead_letters
+                    .take()
+                    .expect("main output renders dead letters"),
+                err,
+            ),
+        })
+        .collect();
     (outputs, health, Rc::new(needed_tokens))
 }
 
 /// Completes the rendering of a particular source stream by applying decoding and envelope
 /// processing as necessary
+///
+/// If `upsert_key` is set, the stream consists of upsert commands for the table with that key,
+/// which is how Postgres sources ingest tables in upsert mode.
+///
+/// If the source uses `VALUE DECODING ERRORS = INLINE`, also returns the rows of its companion
+/// relation.
+#[allow(clippy::too_many_arguments)]
 fn render_source_stream<G>(
     scope: &mut G,
     dataflow_debug_name: &String,
     id: GlobalId,
+    output_index: usize,
+    upsert_key: Option<Vec<usize>>,
     ok_source: SourceType<G>,
     description: IngestionDescription<CollectionMetadata>,
     as_of: Antichain<G::Timestamp>,
@@ -262,6 +310,7 @@ fn render_source_stream<G>(
     Collection<G, DataflowError, Diff>,
     Vec<Rc<dyn Any>>,
     Stream<G, (OutputIndex, HealthStatusUpdate)>,
+    Option<Collection<G, Row, Diff>>,
 )
 where
     G: Scope<Timestamp = Timestamp>,
@@ -273,8 +322,9 @@ where
         envelope,
         connection: _,
         timestamp_interval: _,
+        value_decoding_errors,
     } = description.desc;
-    let (stream, errors, health) = {
+    let (stream, errors, health, dead_letters) = {
         let (key_encoding, value_encoding) = match encoding {
             SourceDataEncoding::KeyValue { key, value } => (Some(key), value),
             SourceDataEncoding::Single(value) => (None, value),
@@ -309,7 +359,7 @@ where
                 confluent_wire_format,
             );
             needed_tokens.push(Rc::new(token));
-            (oks, None, empty(scope))
+            (oks, None, empty(scope), None)
         } else {
             // Depending on the type of _raw_ source produced for the given source
             // connection, render the _decode_ part of the pipeline, that turns a raw data
@@ -338,6 +388,24 @@ where
                 needed_tokens.push(Rc::new(tok));
             }
 
+            let (decoded_stream, dead_letters) = match value_decoding_errors {
+                ValueDecodingErrors::Error => (decoded_stream, None),
+                ValueDecodingErrors::Skip => {
+                    let (decoded_stream, _) = split_value_decoding_errors(
+                        &decoded_stream,
+                        base_source_config.source_statistics.clone(),
+                    );
+                    (decoded_stream, None)
+                }
+                ValueDecodingErrors::Inline { .. } => {
+                    let (decoded_stream, dead_letters) = split_value_decoding_errors(
+                        &decoded_stream,
+                        base_source_config.source_statistics.clone(),
+                    );
+                    (decoded_stream, Some(dead_letters))
+                }
+            };
+
             // render envelopes
             let (envelope_ok, envelope_err, envelope_health) = match &envelope {
                 SourceEnvelope::Debezium(dbz_envelope) => {
@@ -377,189 +445,47 @@ where
                 }
                 SourceEnvelope::Upsert(upsert_envelope) => {
                     let upsert_input = upsert_commands(decoded_stream, upsert_envelope.clone());
-
-                    let persist_clients = Arc::clone(&storage_state.persist_clients);
-                    // TODO: Get this to work with the as_of.
-                    let resume_upper = base_source_config.resume_uppers[&id].clone();
-
-                    let upper_ts = resume_upper
-                        .as_option()
-                        .expect("resuming an already finished ingestion")
-                        .clone();
-                    let (upsert, health_update) = scope.scoped(
-                        &format!("upsert_rehydration_backpressure({})", id),
-                        |scope| {
-                            let (previous, previous_token, feedback_handle, backpressure_metrics) =
-                                if Timestamp::minimum() < upper_ts {
-                                    let as_of = Antichain::from_elem(upper_ts.saturating_sub(1));
-
-                                    let backpressure_max_inflight_bytes =
-                                        get_backpressure_max_inflight_bytes(
-                                            &storage_state
-                                                .dataflow_parameters
-                                                .storage_dataflow_max_inflight_bytes_config,
-                                            &storage_state.instance_context.cluster_memory_limit,
-                                        );
-
-                                    let (feedback_handle, flow_control, backpressure_metrics) =
-                                        if let Some(storage_dataflow_max_inflight_bytes) =
-                                            backpressure_max_inflight_bytes
-                                        {
-                                            tracing::info!(
-                                                ?backpressure_max_inflight_bytes,
-                                                "timely-{} using backpressure in upsert for source {}",
-                                                base_source_config.worker_id,
-                                                id
-                                            );
-                                            if !storage_state
-                                                .dataflow_parameters
-                                                .storage_dataflow_max_inflight_bytes_config
-                                                .disk_only
-                                                || storage_state
-                                                    .instance_context
-                                                    .scratch_directory
-                                                    .is_some()
-                                            {
-                                                let (feedback_handle, feedback_data) =
-                                                    scope.feedback(Default::default());
-
-                                                let backpressure_metrics =
-                                                    Some(BackpressureMetrics {
-                                                        emitted_bytes: Arc::new(
-                                                            base_source_config
-                                                                .base_metrics
-                                                                .upsert_backpressure_specific
-                                                                .emitted_bytes
-                                                                .get_delete_on_drop_counter(vec![
-                                                                    id.to_string(),
-                                                                    scope.index().to_string(),
-                                                                ]),
-                                                        ),
-                                                        last_backpressured_bytes: Arc::new(
-                                                            base_source_config
-                                                                .base_metrics
-                                                                .upsert_backpressure_specific
-                                                                .last_backpressured_bytes
-                                                                .get_delete_on_drop_gauge(vec![
-                                                                    id.to_string(),
-                                                                    scope.index().to_string(),
-                                                                ]),
-                                                        ),
-                                                        retired_bytes: Arc::new(
-                                                            base_source_config
-                                                                .base_metrics
-                                                                .upsert_backpressure_specific
-                                                                .retired_bytes
-                                                                .get_delete_on_drop_counter(vec![
-                                                                    id.to_string(),
-                                                                    scope.index().to_string(),
-                                                                ]),
-                                                        ),
-                                                    });
-                                                (
-                                                    Some(feedback_handle),
-                                                    Some(persist_source::FlowControl {
-                                                        progress_stream: feedback_data,
-                                                        max_inflight_bytes:
-                                                            storage_dataflow_max_inflight_bytes,
-                                                        summary: (Default::default(), 1),
-                                                        metrics: backpressure_metrics.clone(),
-                                                    }),
-                                                    backpressure_metrics,
-                                                )
-                                            } else {
-                                                (None, None, None)
-                                            }
-                                        } else {
-                                            (None, None, None)
-                                        };
-                                    let (stream, tok) = persist_source::persist_source_core(
-                                        scope,
-                                        id,
-                                        persist_clients,
-                                        description.ingestion_metadata,
-                                        Some(as_of),
-                                        Antichain::new(),
-                                        None,
-                                        flow_control,
-                                        // Copy the logic in DeltaJoin/Get/Join to start.
-                                        |_timer, count| count > 1_000_000,
-                                    );
-                                    (
-                                        stream.as_collection(),
-                                        Some(tok),
-                                        feedback_handle,
-                                        backpressure_metrics,
-                                    )
-                                } else {
-                                    (Collection::new(empty(scope)), None, None, None)
-                                };
-                            let (upsert, health_update, upsert_token) = crate::render::upsert::upsert(
-                                &upsert_input.enter(scope),
-                                upsert_envelope.clone(),
-                                refine_antichain(&resume_upper),
-                                previous,
-                                previous_token,
-                                base_source_config.clone(),
-                                &storage_state.instance_context,
-                                &storage_state.dataflow_parameters,
-                                backpressure_metrics,
-                            );
-
-                            // Even though we register the `persist_sink` token at a top-level,
-                            // which will stop any data from being committed, we also register
-                            // a token for the `upsert` operator which may be in the middle of
-                            // rehydration processing the `persist_source` input above.
-                            needed_tokens.push(upsert_token);
-
-                            use mz_timely_util::probe::ProbeNotify;
-                            let handle = mz_timely_util::probe::Handle::default();
-                            let upsert = upsert.inner.probe_notify_with(vec![handle.clone()]);
-                            let probe = mz_timely_util::probe::source(
-                                scope.clone(),
-                                format!("upsert_probe({id})"),
-                                handle,
-                            );
-
-                            // If configured, delay raw sources until we rehydrate the upsert
-                            // source. Otherwise, drop the token, unblocking the sources at the
-                            // end rendering.
-                            if storage_state
-                                .dataflow_parameters
-                                .delay_sources_past_rehydration
-                            {
-                                crate::render::upsert::rehydration_finished(
-                                    scope.clone(),
-                                    &base_source_config,
-                                    rehydrated_token,
-                                    refine_antichain(&resume_upper),
-                                    &probe,
-                                );
-                            } else {
-                                drop(rehydrated_token)
-                            };
-
-                            // If backpressure is enabled, we probe the upsert operator's
-                            // output, which is the easiest way to extract frontier information.
-                            let upsert = match feedback_handle {
-                                Some(feedback_handle) => {
-                                    probe.connect_loop(feedback_handle);
-                                    upsert.as_collection()
-                                }
-                                None => upsert.as_collection(),
-                            };
-
-                            (upsert.leave(), health_update.leave())
-                        },
+                    let (upsert_ok, upsert_err, health_update) = render_upsert(
+                        scope,
+                        upsert_input,
+                        upsert_envelope.key_indices.clone(),
+                        false,
+                        upsert_envelope.key_ttl,
+                        description.ingestion_metadata,
+                        base_source_config,
+                        storage_state,
+                        rehydrated_token,
+                        &mut needed_tokens,
                     );
-
-                    let (upsert_ok, upsert_err) = upsert.inner.ok_err(split_ok_err);
-
-                    (
-                        upsert_ok.as_collection(),
-                        Some(upsert_err.as_collection()),
-                        health_update,
-                    )
+                    (upsert_ok, Some(upsert_err), health_update)
+                }
+                SourceEnvelope::None(_) if upsert_key.is_some() => {
+                    let key_indices = upsert_key.expect("checked above");
+                    let (export_id, export) = description
+                        .source_exports
+                        .iter()
+                        .find(|(_, export)| export.output_index == output_index)
+                        .expect("outputs are source exports");
+                    // Each table keeps its own upsert state, so the upsert operator is identified
+                    // by the table's subsource rather than the ingestion.
+                    let config = RawSourceCreationConfig {
+                        id: *export_id,
+                        ..base_source_config
+                    };
+                    let upsert_input = postgres_upsert_commands(decoded_stream, &key_indices);
+                    let (upsert_ok, upsert_err, health_update) = render_upsert(
+                        scope,
+                        upsert_input,
+                        key_indices,
+                        true,
+                        None,
+                        export.storage_metadata.clone(),
+                        config,
+                        storage_state,
+                        rehydrated_token,
+                        &mut needed_tokens,
+                    );
+                    (upsert_ok, Some(upsert_err), health_update)
                 }
                 SourceEnvelope::None(none_envelope) => {
                     let results = append_metadata_to_value(decoded_stream);
@@ -578,6 +504,7 @@ where
                 envelope_ok,
                 envelope_err,
                 decode_health.concat(&envelope_health),
+                dead_letters,
             )
         }
     };
@@ -599,7 +526,245 @@ where
     };
 
     // Return the collections and any needed tokens.
-    (collection, err_collection, needed_tokens, health)
+    (
+        collection,
+        err_collection,
+        needed_tokens,
+        health,
+        dead_letters,
+    )
+}
+
+/// Splits the records whose value failed to decode out of `decoded`, counting them as skipped in
+/// the source statistics.
+///
+/// Returns the remaining records along with a row for each split out record, made up of its
+/// decoding error, its raw bytes and its metadata columns.
+fn split_value_decoding_errors<G>(
+    decoded: &Collection<G, DecodeResult, Diff>,
+    source_statistics: SourceStatistics,
+) -> (Collection<G, DecodeResult, Diff>, Collection<G, Row, Diff>)
+where
+    G: Scope,
+{
+    let (ok, dead_letters) = decoded.inner.ok_err(move |(result, time, diff)| {
+        if let Some(Err(err)) = &result.value {
+            source_statistics.inc_records_skipped_by(1);
+            let mut row = Row::default();
+            let mut packer = row.packer();
+            packer.push(Datum::String(&err.kind.to_string()));
+            packer.push(Datum::Bytes(&err.raw));
+            packer.extend(result.metadata.iter());
+            return Err((row, time, diff));
+        }
+        Ok((result, time, diff))
+    });
+    (ok.as_collection(), dead_letters.as_collection())
+}
+
+/// Renders the upsert operator on the commands in `upsert_input`, after rehydrating its state from
+/// the collection described by `metadata`, which holds the output of the operator's previous
+/// incarnations. See [`crate::render::upsert::upsert`] for the meaning of `partial_values` and
+/// `key_ttl`.
+#[allow(clippy::too_many_arguments)]
+fn render_upsert<G, O>(
+    scope: &mut G,
+    upsert_input: Collection<G, (UpsertKey, Option<UpsertValue>, O), Diff>,
+    key_indices: Vec<usize>,
+    partial_values: bool,
+    key_ttl: Option<Duration>,
+    metadata: CollectionMetadata,
+    config: RawSourceCreationConfig,
+    storage_state: &mut crate::storage_state::StorageState,
+    rehydrated_token: impl std::any::Any + 'static,
+    needed_tokens: &mut Vec<Rc<dyn Any>>,
+) -> (
+    Collection<G, Row, Diff>,
+    Collection<G, DataflowError, Diff>,
+    Stream<G, (OutputIndex, HealthStatusUpdate)>,
+)
+where
+    G: Scope<Timestamp = Timestamp>,
+    O: timely::ExchangeData + Ord,
+{
+    let persist_clients = Arc::clone(&storage_state.persist_clients);
+    // TODO: Get this to work with the as_of.
+    let resume_upper = config.resume_uppers[&config.id].clone();
+
+    let upper_ts = resume_upper
+        .as_option()
+        .expect("resuming an already finished ingestion")
+        .clone();
+    let (upsert, health_update) = scope.scoped(
+        &format!("upsert_rehydration_backpressure({})", config.id),
+        |scope| {
+            let (previous, previous_token, feedback_handle, backpressure_metrics) =
+                if Timestamp::minimum() < upper_ts {
+                    let as_of = Antichain::from_elem(upper_ts.saturating_sub(1));
+
+                    let backpressure_max_inflight_bytes = get_backpressure_max_inflight_bytes(
+                        &storage_state
+                            .dataflow_parameters
+                            .storage_dataflow_max_inflight_bytes_config,
+                        &storage_state.instance_context.cluster_memory_limit,
+                    );
+
+                    let (feedback_handle, flow_control, backpressure_metrics) =
+                        if let Some(storage_dataflow_max_inflight_bytes) =
+                            backpressure_max_inflight_bytes
+                        {
+                            tracing::info!(
+                                ?backpressure_max_inflight_bytes,
+                                "timely-{} using backpressure in upsert for source {}",
+                                config.worker_id,
+                                config.id
+                            );
+                            if !storage_state
+                                .dataflow_parameters
+                                .storage_dataflow_max_inflight_bytes_config
+                                .disk_only
+                                || storage_state.instance_context.scratch_directory.is_some()
+                            {
+                                let (feedback_handle, feedback_data) =
+                                    scope.feedback(Default::default());
+
+                                let backpressure_metrics = Some(BackpressureMetrics {
+                                    emitted_bytes: Arc::new(
+                                        config
+                                            .base_metrics
+                                            .upsert_backpressure_specific
+                                            .emitted_bytes
+                                            .get_delete_on_drop_counter(vec![
+                                                config.id.to_string(),
+                                                scope.index().to_string(),
+                                            ]),
+                                    ),
+                                    last_backpressured_bytes: Arc::new(
+                                        config
+                                            .base_metrics
+                                            .upsert_backpressure_specific
+                                            .last_backpressured_bytes
+                                            .get_delete_on_drop_gauge(vec![
+                                                config.id.to_string(),
+                                                scope.index().to_string(),
+                                            ]),
+                                    ),
+                                    retired_bytes: Arc::new(
+                                        config
+                                            .base_metrics
+                                            .upsert_backpressure_specific
+                                            .retired_bytes
+                                            .get_delete_on_drop_counter(vec![
+                                                config.id.to_string(),
+                                                scope.index().to_string(),
+                                            ]),
+                                    ),
+                                });
+                                (
+                                    Some(feedback_handle),
+                                    Some(persist_source::FlowControl {
+                                        progress_stream: feedback_data,
+                                        max_inflight_bytes: storage_dataflow_max_inflight_bytes,
+                                        summary: (Default::default(), 1),
+                                        metrics: backpressure_metrics.clone(),
+                                    }),
+                                    backpressure_metrics,
+                                )
+                            } else {
+                                (None, None, None)
+                            }
+                        } else {
+                            (None, None, None)
+                        };
+                    let (stream, tok) = persist_source::persist_source_core(
+                        scope,
+                        config.id,
+                        persist_clients,
+                        metadata,
+                        Some(as_of),
+                        Antichain::new(),
+                        None,
+                        flow_control,
+                        // Copy the logic in DeltaJoin/Get/Join to start.
+                        |_timer, count| count > 1_000_000,
+                    );
+                    (
+                        stream.as_collection(),
+                        Some(tok),
+                        feedback_handle,
+                        backpressure_metrics,
+                    )
+                } else {
+                    (Collection::new(empty(scope)), None, None, None)
+                };
+            let (upsert, health_update, upsert_token) = crate::render::upsert::upsert(
+                &upsert_input.enter(scope),
+                key_indices,
+                partial_values,
+                key_ttl,
+                refine_antichain(&resume_upper),
+                previous,
+                previous_token,
+                config.clone(),
+                &storage_state.instance_context,
+                &storage_state.dataflow_parameters,
+                backpressure_metrics,
+            );
+
+            // Even though we register the `persist_sink` token at a top-level,
+            // which will stop any data from being committed, we also register
+            // a token for the `upsert` operator which may be in the middle of
+            // rehydration processing the `persist_source` input above.
+            needed_tokens.push(upsert_token);
+
+            use mz_timely_util::probe::ProbeNotify;
+            let handle = mz_timely_util::probe::Handle::default();
+            let upsert = upsert.inner.probe_notify_with(vec![handle.clone()]);
+            let probe = mz_timely_util::probe::source(
+                scope.clone(),
+                format!("upsert_probe({})", config.id),
+                handle,
+            );
+
+            // If configured, delay raw sources until we rehydrate the upsert
+            // source. Otherwise, drop the token, unblocking the sources at the
+            // end rendering.
+            if storage_state
+                .dataflow_parameters
+                .delay_sources_past_rehydration
+            {
+                crate::render::upsert::rehydration_finished(
+                    scope.clone(),
+                    &config,
+                    rehydrated_token,
+                    refine_antichain(&resume_upper),
+                    &probe,
+                );
+            } else {
+                drop(rehydrated_token)
+            };
+
+            // If backpressure is enabled, we probe the upsert operator's
+            // output, which is the easiest way to extract frontier information.
+            let upsert = match feedback_handle {
+                Some(feedback_handle) => {
+                    probe.connect_loop(feedback_handle);
+                    upsert.as_collection()
+                }
+                None => upsert.as_collection(),
+            };
+
+            (upsert.leave(), health_update.leave())
+        },
+    );
+
+    let (upsert_ok, upsert_err) = upsert.inner.ok_err(split_ok_err);
+
+    (
+        upsert_ok.as_collection(),
+        upsert_err.as_collection(),
+        health_update,
+    )
 }
 
 // Returns the maximum limit of inflight bytes for backpressure based on given config
@@ -730,6 +895,38 @@ fn upsert_commands<G: Scope>(
     })
 }
 
+/// Convert from streams of [`DecodeResult`] produced by a Postgres source for a table ingested in
+/// upsert mode to UpsertCommands.
+///
+/// Additions are upserts of the key of the row, where any column that is `Datum::Dummy` keeps its
+/// previous value, and retractions are deletions of the key of the row. An upsert at the same LSN
+/// as a deletion of the same key supersedes it, which happens when a table is truncated and then
+/// written to in the same transaction.
+fn postgres_upsert_commands<G: Scope>(
+    input: Collection<G, DecodeResult, Diff>,
+    key_indices: &[usize],
+) -> Collection<G, (UpsertKey, Option<UpsertValue>, (MzOffset, bool)), Diff> {
+    let mut key_indices = key_indices.to_vec();
+    key_indices.sort_unstable();
+    input
+        .inner
+        .map(move |(result, time, diff)| {
+            let row = match result.value {
+                Some(Ok(row)) => row,
+                _ => unreachable!("Postgres sources produce rows"),
+            };
+            let key = UpsertKey::from_value(Ok(&row), &key_indices);
+            let is_upsert = diff > 0;
+            let value = is_upsert.then(|| Ok(row));
+            (
+                (key, value, (result.position_for_upsert, is_upsert)),
+                time,
+                1,
+            )
+        })
+        .as_collection()
+}
+
 /// Convert from streams of [`DecodeResult`] to Rows, inserting the Key according to [`KeyEnvelope`]
 fn flatten_results_prepend_keys<G>(
     none_envelope: &NoneEnvelope,
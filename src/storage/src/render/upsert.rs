@@ -10,21 +10,22 @@
 use std::any::Any;
 use std::cell::RefCell;
 use std::cmp::Reverse;
+use std::collections::BTreeSet;
 use std::convert::AsRef;
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use differential_dataflow::hashable::Hashable;
 use differential_dataflow::{AsCollection, Collection};
 use futures::future::FutureExt;
-use itertools::Itertools;
 use mz_ore::error::ErrorExt;
 use mz_repr::{Datum, DatumVec, Diff, Row};
 use mz_storage_operators::metrics::BackpressureMetrics;
-use mz_storage_types::errors::{DataflowError, EnvelopeError, UpsertError};
-use mz_storage_types::sources::UpsertEnvelope;
+use mz_storage_types::errors::{
+    DataflowError, DecodeError, DecodeErrorKind, EnvelopeError, UpsertError, UpsertValueError,
+};
 use mz_timely_util::builder_async::{
     AsyncOutputHandle, Event as AsyncEvent, OperatorBuilder as AsyncOperatorBuilder,
 };
@@ -35,11 +36,12 @@ use timely::dataflow::channels::pushers::TeeCore;
 use timely::dataflow::operators::Capability;
 use timely::dataflow::{Scope, ScopeParent, Stream};
 use timely::order::{PartialOrder, TotalOrder};
+use timely::progress::timestamp::Refines;
 use timely::progress::{Antichain, Timestamp};
 
 use crate::render::sources::OutputIndex;
 use crate::render::upsert::types::{
-    upsert_bincode_opts, AutoSpillBackend, InMemoryHashMap, RocksDBParams, UpsertState,
+    upsert_bincode_opts, AutoSpillBackend, InMemoryHashMap, RocksDBParams, StateValue, UpsertState,
     UpsertStateBackend,
 };
 use crate::source::types::{HealthStatus, HealthStatusUpdate, UpsertMetrics};
@@ -188,12 +190,23 @@ pub fn rehydration_finished<G, T>(
 
 /// Resumes an upsert computation at `resume_upper` given as inputs a collection of upsert commands
 /// and the collection of the previous output of this operator.
+///
+/// If `partial_values` is set, the values of the commands may be partial: any of their columns
+/// that is `Datum::Dummy` stands for the value of that column in the previous value of the key.
+///
+/// If `key_ttl` is set, keys that are not updated for that long are retracted from the output.
+/// Time is measured in the (reclocked) timestamps of the input, so expirations are deterministic,
+/// except for those of keys rehydrated from `previous` (see `KeyExpirations`).
+///
 /// Returns a tuple of
 /// - A collection of the computed upsert operator and,
 /// - A health update stream to propagate errors
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn upsert<G: Scope, O: timely::ExchangeData + Ord>(
     input: &Collection<G, (UpsertKey, Option<UpsertValue>, O), Diff>,
-    upsert_envelope: UpsertEnvelope,
+    key_indices: Vec<usize>,
+    partial_values: bool,
+    key_ttl: Option<Duration>,
     resume_upper: Antichain<G::Timestamp>,
     previous: Collection<G, Result<Row, DataflowError>, Diff>,
     previous_token: Option<Rc<dyn Any>>,
@@ -207,7 +220,7 @@ pub(crate) fn upsert<G: Scope, O: timely::ExchangeData + Ord>(
     Rc<dyn Any>,
 )
 where
-    G::Timestamp: TotalOrder,
+    G::Timestamp: TotalOrder + Refines<mz_repr::Timestamp>,
 {
     let upsert_metrics = UpsertMetrics::new(
         &source_config.base_metrics,
@@ -223,6 +236,8 @@ where
         wait_for_input_resumption,
         shrink_upsert_unused_buffers_by_ratio: dataflow_paramters
             .shrink_upsert_unused_buffers_by_ratio,
+        partial_values,
+        key_ttl,
     };
 
     if let Some(scratch_directory) = instance_context.scratch_directory.as_ref() {
@@ -253,7 +268,7 @@ where
         if allow_auto_spill {
             upsert_inner(
                 input,
-                upsert_envelope.key_indices,
+                key_indices,
                 resume_upper,
                 previous,
                 previous_token,
@@ -277,7 +292,7 @@ where
         } else {
             upsert_inner(
                 input,
-                upsert_envelope.key_indices,
+                key_indices,
                 resume_upper,
                 previous,
                 previous_token,
@@ -310,7 +325,7 @@ where
         );
         upsert_inner(
             input,
-            upsert_envelope.key_indices,
+            key_indices,
             resume_upper,
             previous,
             previous_token,
@@ -358,6 +373,77 @@ struct UpsertConfig {
     // before we finalize `rehydration`.
     wait_for_input_resumption: bool,
     shrink_upsert_unused_buffers_by_ratio: usize,
+    // Whether the values of the commands can be partial, see `upsert`.
+    partial_values: bool,
+    // How long keys are kept without being updated, see `upsert`.
+    key_ttl: Option<Duration>,
+}
+
+/// Schedules the expiration of the keys of the upsert state, i.e. the time at which they will have
+/// gone without an update for the key TTL of the source.
+///
+/// The time of the latest update of a key is kept in its `StateValue`, which is the source of truth
+/// for its expiration. This only keeps a queue of candidate expirations, ordered by time, so the
+/// expiring keys can be found without scanning the state. A candidate is stale if its key has
+/// been updated or deleted since, and must be checked against the state when it comes up.
+///
+/// The state is rehydrated from a snapshot of the output of the previous incarnation of the
+/// operator, which no longer tells when its keys were last updated. Rehydrated keys are instead
+/// considered last updated at the time of the snapshot, so they can outlive their TTL by up to the
+/// TTL after a restart, but never expire early.
+///
+/// Note that this queue is kept in memory even when the upsert state itself lives on disk, and
+/// holds an entry per key of the state.
+struct KeyExpirations<T> {
+    /// The TTL, in milliseconds.
+    ttl: mz_repr::Timestamp,
+    /// The candidate expirations.
+    queue: BTreeSet<(T, UpsertKey)>,
+}
+
+impl<T> KeyExpirations<T>
+where
+    T: Timestamp + TotalOrder + Refines<mz_repr::Timestamp>,
+{
+    fn new(ttl: Duration) -> Self {
+        KeyExpirations {
+            ttl: mz_repr::Timestamp::try_from(ttl).unwrap_or(mz_repr::Timestamp::MAX),
+            queue: BTreeSet::new(),
+        }
+    }
+
+    /// Returns the time at which a key last updated at `last_update` expires.
+    fn expiration(&self, last_update: mz_repr::Timestamp) -> T {
+        T::to_inner(last_update.saturating_add(self.ttl))
+    }
+
+    /// Schedules the expiration of `key`, which was last updated at `last_update`.
+    fn schedule(&mut self, key: UpsertKey, last_update: mz_repr::Timestamp) {
+        self.queue.insert((self.expiration(last_update), key));
+    }
+
+    /// Cancels the expiration of `key` scheduled for its update at `last_update`, e.g. because it
+    /// has been updated again or deleted.
+    fn cancel(&mut self, key: UpsertKey, last_update: mz_repr::Timestamp) {
+        self.queue.remove(&(self.expiration(last_update), key));
+    }
+
+    /// Returns the keys with candidate expirations before `upper`.
+    fn expiring(&self, upper: &Antichain<T>) -> impl Iterator<Item = &UpsertKey> {
+        self.queue
+            .iter()
+            .take_while(|(expiration, _)| !upper.less_equal(expiration))
+            .map(|(_, key)| key)
+    }
+
+    /// Removes and returns the next key with a candidate expiration before `upper`.
+    fn pop_expiring(&mut self, upper: &Antichain<T>) -> Option<UpsertKey> {
+        let (expiration, _) = self.queue.first()?;
+        if upper.less_equal(expiration) {
+            return None;
+        }
+        self.queue.pop_first().map(|(_, key)| key)
+    }
 }
 
 fn upsert_inner<G: Scope, O: timely::ExchangeData + Ord, F, Fut, US>(
@@ -376,7 +462,7 @@ fn upsert_inner<G: Scope, O: timely::ExchangeData + Ord, F, Fut, US>(
     Rc<dyn Any>,
 )
 where
-    G::Timestamp: TotalOrder,
+    G::Timestamp: TotalOrder + Refines<mz_repr::Timestamp>,
     F: FnOnce() -> Fut + 'static,
     Fut: std::future::Future<Output = US>,
     US: UpsertStateBackend,
@@ -393,6 +479,8 @@ where
 
     let rehydration_started = Instant::now();
 
+    let command_key_indices = key_indices.clone();
+
     // We only care about UpsertValueError since this is the only error that we can retract
     let previous = previous.flat_map(move |result| {
         let value = match result {
@@ -426,6 +514,7 @@ where
         );
         let mut events = vec![];
         let mut snapshot_upper = Antichain::from_elem(Timestamp::minimum());
+        let mut expirations = upsert_config.key_ttl.map(KeyExpirations::new);
 
         let mut stash = vec![];
         let mut input_upper = Antichain::from_elem(Timestamp::minimum());
@@ -459,7 +548,13 @@ where
                 Some(AsyncEvent::Data(_cap, data)) => {
                     events.extend(data.drain(..).filter_map(|((key, value), ts, diff)| {
                         if !resume_upper.less_equal(&ts) {
-                            Some((key, value, diff))
+                            let ts = ts.to_outer();
+                            if let Some(expirations) = expirations.as_mut() {
+                                if diff > 0 {
+                                    expirations.schedule(key, ts);
+                                }
+                            }
+                            Some((key, value, ts, diff))
                         } else {
                             None
                         }
@@ -473,7 +568,13 @@ where
                     Some(AsyncEvent::Data(_cap, data)) => {
                         events.extend(data.drain(..).filter_map(|((key, value), ts, diff)| {
                             if !resume_upper.less_equal(&ts) {
-                                Some((key, value, diff))
+                                let ts = ts.to_outer();
+                                if let Some(expirations) = expirations.as_mut() {
+                                    if diff > 0 {
+                                        expirations.schedule(key, ts);
+                                    }
+                                }
+                                Some((key, value, ts, diff))
                             } else {
                                 None
                             }
@@ -579,6 +680,14 @@ where
                     for (_, key, _, _) in stash.iter().take(idx) {
                         commands_state.entry(*key).or_default();
                     }
+                    // Keys that expire before `upper` need to be read as well, so that they can
+                    // be retracted.
+                    if let Some(expirations) = expirations.as_ref() {
+                        for key in expirations.expiring(&upper) {
+                            commands_state.entry(*key).or_default();
+                        }
+                    }
+                    let mut keys_expired = 0;
 
                     // These iterators iterate in the same order because `commands_state`
                     // is an `IndexMap`.
@@ -602,11 +711,9 @@ where
 
                     // From the prefix that can be emitted we can deduplicate based on (ts, key) in
                     // order to only process the command with the maximum order within the (ts,
-                    // key) group. This is achieved by wrapping order in `Reverse(order)` above.
-                    let mut commands = stash.drain(..idx).dedup_by(|a, b| {
-                        let ((a_ts, a_key, _, _), (b_ts, b_key, _, _)) = (a, b);
-                        a_ts == b_ts && a_key == b_key
-                    });
+                    // key) group, skipping over the rest of the group below. This is achieved by
+                    // wrapping order in `Reverse(order)` above.
+                    let mut commands = stash.drain(..idx).peekable();
 
                     let bincode_opts = types::upsert_bincode_opts();
                     // Upsert the values into `commands_state`, by recording the latest
@@ -621,7 +728,18 @@ where
                     // This "mini-upsert" technique is actually useful in `UpsertState`'s
                     // `merge_snapshot_chunk` implementation, minimizing gets and puts on
                     // the `UpsertStateBackend` implementations. In some sense, its "upsert all the way down".
-                    while let Some((ts, key, _, value)) = commands.next() {
+                    while let Some((ts, key, _, mut value)) = commands.next() {
+                        // The superseded commands of the group can still provide the missing
+                        // columns of a partial value, newest first.
+                        let mut history = upsert_config.partial_values;
+                        while let Some((_, _, _, older)) =
+                            commands.next_if(|(next_ts, next_key, _, _)| next_ts == &ts && next_key == &key)
+                        {
+                            if history && is_partial(&value) {
+                                history = resolve_partial_value(&mut value, older.as_ref());
+                            }
+                        }
+
                         let command_state = commands_state
                             .get_mut(&key)
                             .expect("key missing from commands_state");
@@ -630,10 +748,50 @@ where
                             cs.ensure_decoded(bincode_opts);
                         }
 
+                        // A key that expired before this command no longer has a previous value.
+                        if let Some(expirations) = expirations.as_mut() {
+                            let expiration = command_state
+                                .value
+                                .as_ref()
+                                .map(|cs| expirations.expiration(cs.last_update()))
+                                .filter(|expiration| expiration <= &ts);
+                            if let Some(expiration) = expiration {
+                                let old_value = command_state.value.take().unwrap();
+                                expirations.cancel(key, old_value.last_update());
+                                let expiration =
+                                    std::cmp::max(expiration, output_cap.time().clone());
+                                output_updates.push((old_value.to_decoded(), expiration, -1));
+                                keys_expired += 1;
+                            }
+                        }
+
+                        if history && is_partial(&value) {
+                            let previous = match &command_state.value {
+                                Some(StateValue::Decoded { value: previous, .. }) => Some(previous),
+                                _ => None,
+                            };
+                            resolve_partial_value(&mut value, previous);
+                        }
+                        if let Some(Ok(row)) = &value {
+                            if upsert_config.partial_values && is_partial(&value) {
+                                value = Some(Err(missing_columns_error(row, &command_key_indices)));
+                            }
+                        }
+
+                        if let (Some(expirations), Some(old_value)) =
+                            (expirations.as_mut(), command_state.value.as_ref())
+                        {
+                            expirations.cancel(key, old_value.last_update());
+                        }
                         match value {
                             Some(value) => {
-                                if let Some(old_value) =
-                                    command_state.value.replace(value.clone().into())
+                                let last_update = ts.clone().to_outer();
+                                if let Some(expirations) = expirations.as_mut() {
+                                    expirations.schedule(key, last_update);
+                                }
+                                if let Some(old_value) = command_state
+                                    .value
+                                    .replace(StateValue::decoded(value.clone(), last_update))
                                 {
                                     output_updates.push((old_value.to_decoded(), ts.clone(), -1));
                                 }
@@ -647,12 +805,39 @@ where
                         }
                     }
 
+                    // Retract the keys that expire before `upper`, including the ones that were
+                    // last updated by the commands above. Candidates of keys that have been
+                    // updated or deleted since are skipped.
+                    if let Some(expirations) = expirations.as_mut() {
+                        while let Some(key) = expirations.pop_expiring(&upper) {
+                            let command_state = commands_state
+                                .get_mut(&key)
+                                .expect("expiring key missing from commands_state");
+                            let expiration = command_state
+                                .value
+                                .as_ref()
+                                .map(|cs| expirations.expiration(cs.last_update()))
+                                .filter(|expiration| !upper.less_equal(expiration));
+                            if let Some(expiration) = expiration {
+                                let mut old_value = command_state.value.take().unwrap();
+                                old_value.ensure_decoded(bincode_opts);
+                                let expiration =
+                                    std::cmp::max(expiration, output_cap.time().clone());
+                                output_updates.push((old_value.to_decoded(), expiration, -1));
+                                keys_expired += 1;
+                            }
+                        }
+                    }
+
                     match state
                         .multi_put(commands_state.drain(..).map(|(k, cv)| {
                             (
                                 k,
                                 types::PutValue {
-                                    value: cv.value.map(|cv| cv.to_decoded()),
+                                    value: cv.value.map(|mut cv| {
+                                        cv.ensure_decoded(bincode_opts);
+                                        cv
+                                    }),
                                     previous_persisted_size: cv
                                         .size
                                         .map(|v| v.try_into().expect("less than i64 size")),
@@ -673,6 +858,10 @@ where
                         }
                     }
 
+                    if keys_expired > 0 {
+                        source_metrics.inc_keys_expired_by(keys_expired);
+                    }
+
                     // Emit the _consolidated_ changes to the output.
                     output_handle
                         .give_container(&output_cap, &mut output_updates)
@@ -696,6 +885,51 @@ where
     )
 }
 
+/// Reports whether `value` is a partial value, i.e. has columns that stand for the columns of the
+/// previous value of its key.
+fn is_partial(value: &Option<UpsertValue>) -> bool {
+    match value {
+        Some(Ok(row)) => row.iter().any(|datum| matches!(datum, Datum::Dummy)),
+        _ => false,
+    }
+}
+
+/// Fills in the missing columns of the partial `value` from `older`, an older value of the same
+/// key.
+///
+/// Returns whether even older values of the key can still provide missing columns, which is not
+/// the case once a deletion or an error cuts the history of the key short.
+fn resolve_partial_value(value: &mut Option<UpsertValue>, older: Option<&UpsertValue>) -> bool {
+    let (Some(Ok(row)), Some(Ok(older))) = (value.as_mut(), older) else {
+        return false;
+    };
+    let resolved = Row::pack(
+        row.iter()
+            .zip(older.iter())
+            .map(|(datum, older)| match datum {
+                Datum::Dummy => older,
+                datum => datum,
+            }),
+    );
+    *row = resolved;
+    true
+}
+
+/// Produces the error that takes the place of a partial value whose missing columns could not be
+/// resolved from the previous values of its key.
+fn missing_columns_error(row: &Row, key_indices: &[usize]) -> UpsertError {
+    let datums = row.unpack();
+    UpsertError::Value(UpsertValueError {
+        for_key: Row::pack(key_indices.iter().map(|idx| datums[*idx])),
+        inner: DecodeError {
+            kind: DecodeErrorKind::Text(
+                "previous value of partially updated key missing from upsert state".into(),
+            ),
+            raw: vec![],
+        },
+    })
+}
+
 /// Emit the given error, and stall till the dataflow is restarted.
 async fn process_upsert_state_error<G: Scope>(
     context: String,
@@ -713,6 +947,7 @@ async fn process_upsert_state_error<G: Scope>(
             hint: None,
         },
         should_halt: true,
+        ignored_columns: None,
     };
     health_output.give(health_cap, (0, update)).await;
     std::future::pending::<()>().await;
@@ -131,12 +131,18 @@ pub struct PutValue<V> {
 /// to store it any way they want. During consolidation of snapshots, the logic is more complex.
 /// See the docs on `StateValue::merge_update` for more information.
 ///
+/// In both modes, we also store the timestamp of the latest update of the key, which is what
+/// key TTLs are measured from.
+///
 /// This struct is not part of the `UpsertStateBackend` public API, but implementing that API without
 /// using it is considered hard-mode.
 #[derive(Clone, serde::Serialize, serde::Deserialize, Debug)]
 pub enum StateValue {
     Snapshotting(Snapshotting),
-    Decoded(UpsertValue),
+    Decoded {
+        value: UpsertValue,
+        last_update: mz_repr::Timestamp,
+    },
 }
 
 /// A value as produced during consolidation of a snapshot.
@@ -146,6 +152,7 @@ pub struct Snapshotting {
     len_sum: Wrapping<i64>,
     checksum_sum: Wrapping<i64>,
     diff_sum: Wrapping<i64>,
+    last_update: mz_repr::Timestamp,
 }
 
 impl fmt::Display for Snapshotting {
@@ -158,13 +165,12 @@ impl fmt::Display for Snapshotting {
     }
 }
 
-impl From<UpsertValue> for StateValue {
-    fn from(uv: UpsertValue) -> Self {
-        Self::Decoded(uv)
+impl StateValue {
+    /// A `Decoded` value that was last updated at `last_update`.
+    pub fn decoded(value: UpsertValue, last_update: mz_repr::Timestamp) -> Self {
+        Self::Decoded { value, last_update }
     }
-}
 
-impl StateValue {
     /// We use a XOR trick in order to accumulate the snapshot without having to store the full
     /// unconsolidated history in memory. For all (value, diff) updates of a key we track:
     /// - diff_sum = SUM(diff)
@@ -172,6 +178,9 @@ impl StateValue {
     /// - len_sum = SUM(len(bincode(value)) * diff)
     /// - value_xor = XOR(bincode(value))
     ///
+    /// We also track the maximum `ts` of the updates, which is the time of the latest update of
+    /// the key: the latest update of a present key is the insertion of its current value.
+    ///
     /// ## Return value
     /// Returns a `bool` indicating whether or not the current merged value is able to be deleted.
     ///
@@ -203,6 +212,7 @@ impl StateValue {
     pub fn merge_update(
         &mut self,
         value: UpsertValue,
+        ts: mz_repr::Timestamp,
         diff: mz_repr::Diff,
         bincode_opts: BincodeOpts,
         bincode_buffer: &mut Vec<u8>,
@@ -212,8 +222,11 @@ impl StateValue {
             len_sum,
             checksum_sum,
             diff_sum,
+            last_update,
         }) = self
         {
+            *last_update = std::cmp::max(*last_update, ts);
+
             bincode_buffer.clear();
             bincode_opts
                 .serialize_into(&mut *bincode_buffer, &value)
@@ -285,7 +298,10 @@ impl StateValue {
                             "invalid upsert state: checksum_sum does not match, state: {}",
                             snapshotting
                         );
-                        *self = Self::Decoded(bincode_opts.deserialize(value).unwrap());
+                        *self = Self::Decoded {
+                            value: bincode_opts.deserialize(value).unwrap(),
+                            last_update: snapshotting.last_update,
+                        };
                     }
                     0 => {
                         assert_eq!(
@@ -323,11 +339,19 @@ impl StateValue {
     /// Pull out the `Decoded` value for a `StateValue`, after `ensure_decoded` has been called.
     pub fn to_decoded(self) -> UpsertValue {
         match self {
-            Self::Decoded(v) => v,
+            Self::Decoded { value, .. } => value,
             _ => panic!("called `to_decoded without calling `ensure_decoded`"),
         }
     }
 
+    /// The timestamp of the latest update of the key.
+    pub fn last_update(&self) -> mz_repr::Timestamp {
+        match self {
+            Self::Snapshotting(Snapshotting { last_update, .. }) => *last_update,
+            Self::Decoded { last_update, .. } => *last_update,
+        }
+    }
+
     /// The size of a `StateValue`, in memory. This is:
     /// 1. only used in the `InMemoryHashMap` implementation.
     /// 2. An estimate (it only looks at value sizes, and not errors)
@@ -340,13 +364,13 @@ impl StateValue {
                 u64::cast_from(value_xor.len()) + u64::cast_from(std::mem::size_of::<Self>())
             }
 
-            Self::Decoded(Ok(row)) => {
+            Self::Decoded { value: Ok(row), .. } => {
                 // `Row::byte_len` includes the size of `Row`, which is also in `Self`, so we
                 // subtract it.
                 u64::cast_from(row.byte_len()) + u64::cast_from(std::mem::size_of::<Self>())
                     - u64::cast_from(std::mem::size_of::<mz_repr::Row>())
             }
-            Self::Decoded(Err(_)) => {
+            Self::Decoded { value: Err(_), .. } => {
                 // Assume errors are rare enough to not move the needle.
                 0
             }
@@ -671,7 +695,7 @@ pub struct UpsertState<S> {
 
     // We need to iterator over `merges` in `merge_snapshot_chunk`
     // twice, so we have a scratch vector for this.
-    merge_scratch: Vec<(UpsertKey, UpsertValue, mz_repr::Diff)>,
+    merge_scratch: Vec<(UpsertKey, UpsertValue, mz_repr::Timestamp, mz_repr::Diff)>,
     // "mini-upsert" map used in `merge_snapshot_chunk`, plus a
     // scratch vector for calling `multi_get`
     merge_upsert_scratch: indexmap::IndexMap<UpsertKey, UpsertValueAndSize>,
@@ -737,7 +761,8 @@ where
         completed: bool,
     ) -> Result<(), anyhow::Error>
     where
-        M: IntoIterator<Item = (UpsertKey, UpsertValue, mz_repr::Diff)> + ExactSizeIterator,
+        M: IntoIterator<Item = (UpsertKey, UpsertValue, mz_repr::Timestamp, mz_repr::Diff)>
+            + ExactSizeIterator,
     {
         fail::fail_point!("fail_merge_snapshot_chunk", |_| {
             Err(anyhow::anyhow!("Error merging snapshot values"))
@@ -773,7 +798,7 @@ where
             self.merge_upsert_scratch.extend(
                 self.merge_scratch
                     .iter()
-                    .map(|(k, _, _)| (*k, UpsertValueAndSize::default())),
+                    .map(|(k, _, _, _)| (*k, UpsertValueAndSize::default())),
             );
             self.multi_get_scratch
                 .extend(self.merge_upsert_scratch.iter().map(|(k, _)| *k));
@@ -784,7 +809,7 @@ where
                 )
                 .await?;
 
-            for (key, value, diff) in self.merge_scratch.drain(..) {
+            for (key, value, ts, diff) in self.merge_scratch.drain(..) {
                 stats.updates += 1;
                 if diff > 0 {
                     stats.inserts += 1;
@@ -794,7 +819,7 @@ where
                 let entry = self.merge_upsert_scratch.get_mut(&key).unwrap();
                 let val = entry.value.get_or_insert_with(Default::default);
 
-                if val.merge_update(value, diff, self.bincode_opts, &mut self.bincode_buffer) {
+                if val.merge_update(value, ts, diff, self.bincode_opts, &mut self.bincode_buffer) {
                     entry.value = None;
                 }
             }
@@ -880,24 +905,13 @@ where
     /// repeated keys.
     pub async fn multi_put<P>(&mut self, puts: P) -> Result<(), anyhow::Error>
     where
-        P: IntoIterator<Item = (UpsertKey, PutValue<UpsertValue>)>,
+        P: IntoIterator<Item = (UpsertKey, PutValue<StateValue>)>,
     {
         fail::fail_point!("fail_state_multi_put", |_| {
             Err(anyhow::anyhow!("Error putting values into state"))
         });
         let now = Instant::now();
-        let stats = self
-            .inner
-            .multi_put(puts.into_iter().map(|(k, pv)| {
-                (
-                    k,
-                    PutValue {
-                        value: pv.value.map(StateValue::Decoded),
-                        previous_persisted_size: pv.previous_persisted_size,
-                    },
-                )
-            }))
-            .await?;
+        let stats = self.inner.multi_put(puts).await?;
 
         self.metrics
             .multi_put_latency
@@ -959,15 +973,16 @@ mod tests {
         let opts = upsert_bincode_opts();
 
         let mut s = StateValue::Snapshotting(Snapshotting::default());
+        let ts = mz_repr::Timestamp::default();
 
         let small_row = Ok(mz_repr::Row::default());
         let longer_row = Ok(mz_repr::Row::pack([mz_repr::Datum::Null]));
-        s.merge_update(small_row, 1, opts, &mut buf);
-        s.merge_update(longer_row.clone(), -1, opts, &mut buf);
+        s.merge_update(small_row, ts, 1, opts, &mut buf);
+        s.merge_update(longer_row.clone(), ts, -1, opts, &mut buf);
         // This clears the retraction of the `longer_row`, but the
         // `value_xor` is the length of the `longer_row`. This tests
         // that we are tracking checksums correctly.
-        s.merge_update(longer_row, 1, opts, &mut buf);
+        s.merge_update(longer_row, ts, 1, opts, &mut buf);
 
         // Assert that the `Snapshotting` value is fully merged.
         s.ensure_decoded(opts);
@@ -982,11 +997,12 @@ mod tests {
         let opts = upsert_bincode_opts();
 
         let mut s = StateValue::Snapshotting(Snapshotting::default());
+        let ts = mz_repr::Timestamp::default();
 
         let small_row = Ok(mz_repr::Row::default());
         let longer_row = Ok(mz_repr::Row::pack([mz_repr::Datum::Null]));
-        s.merge_update(longer_row.clone(), 1, opts, &mut buf);
-        s.merge_update(small_row.clone(), -1, opts, &mut buf);
+        s.merge_update(longer_row.clone(), ts, 1, opts, &mut buf);
+        s.merge_update(small_row.clone(), ts, -1, opts, &mut buf);
 
         s.ensure_decoded(opts);
     }
@@ -1000,12 +1016,13 @@ mod tests {
         let opts = upsert_bincode_opts();
 
         let mut s = StateValue::Snapshotting(Snapshotting::default());
+        let ts = mz_repr::Timestamp::default();
 
         let small_row = Ok(mz_repr::Row::default());
         let longer_row = Ok(mz_repr::Row::pack([mz_repr::Datum::Null]));
-        s.merge_update(longer_row.clone(), 1, opts, &mut buf);
-        s.merge_update(small_row.clone(), -1, opts, &mut buf);
-        s.merge_update(longer_row.clone(), 1, opts, &mut buf);
+        s.merge_update(longer_row.clone(), ts, 1, opts, &mut buf);
+        s.merge_update(small_row.clone(), ts, -1, opts, &mut buf);
+        s.merge_update(longer_row.clone(), ts, 1, opts, &mut buf);
 
         s.ensure_decoded(opts);
     }
@@ -1017,12 +1034,13 @@ mod tests {
         let opts = upsert_bincode_opts();
 
         let mut s = StateValue::Snapshotting(Snapshotting::default());
+        let ts = mz_repr::Timestamp::default();
 
         let small_row = Ok(mz_repr::Row::pack([mz_repr::Datum::Int64(2)]));
         let longer_row = Ok(mz_repr::Row::pack([mz_repr::Datum::Int64(1)]));
-        s.merge_update(longer_row.clone(), 1, opts, &mut buf);
-        s.merge_update(small_row.clone(), -1, opts, &mut buf);
-        s.merge_update(longer_row.clone(), 1, opts, &mut buf);
+        s.merge_update(longer_row.clone(), ts, 1, opts, &mut buf);
+        s.merge_update(small_row.clone(), ts, -1, opts, &mut buf);
+        s.merge_update(longer_row.clone(), ts, 1, opts, &mut buf);
 
         s.ensure_decoded(opts);
     }
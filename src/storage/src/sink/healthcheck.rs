@@ -83,6 +83,7 @@ impl Healthchecker {
                 self.status_shard,
                 &*MZ_SINK_STATUS_HISTORY_DESC,
                 status_update.hint(),
+                &[],
             )
             .await;
 
@@ -46,7 +46,7 @@ use prometheus::core::AtomicU64;
 use rdkafka::client::ClientContext;
 use rdkafka::consumer::{BaseConsumer, Consumer, ConsumerContext};
 use rdkafka::error::{KafkaError, KafkaResult, RDKafkaError, RDKafkaErrorCode};
-use rdkafka::message::{Header, Message, OwnedHeaders, OwnedMessage, ToBytes};
+use rdkafka::message::{Header, Headers, Message, OwnedHeaders, OwnedMessage, ToBytes};
 use rdkafka::producer::{BaseRecord, DeliveryResult, Producer, ProducerContext, ThreadedProducer};
 use rdkafka::{Offset, TopicPartitionList};
 use serde::{Deserialize, Serialize};
@@ -69,6 +69,17 @@ use crate::storage_state::StorageState;
 // the upstream system comes back online.
 const BACKOFF_CLAMP: Duration = Duration::from_secs(30);
 
+/// The header that carries the timestamp of each record in the data topic.
+const TIMESTAMP_HEADER: &str = "materialize-timestamp";
+
+/// The header that carries the ID of the sink that wrote each record in the data topic. It lets
+/// us recover the progress of a sink from its data topic, even if other sinks write to it too.
+const SINK_ID_HEADER: &str = "materialize-sink-id";
+
+/// The number of offsets of each partition of the data topic that are read at a time when
+/// recovering the progress of a sink from its data topic.
+const DATA_TOPIC_READ_WINDOW: i64 = 1000;
+
 impl<G> SinkRender<G> for KafkaSinkConnection
 where
     G: Scope<Timestamp = Timestamp>,
@@ -107,7 +118,8 @@ where
         // of workers.
         let peers = sinked_collection.inner.scope().peers();
         let worker_index = sinked_collection.inner.scope().index();
-        let active_write_worker = (usize::cast_from(sink_id.hashed()) % peers) == worker_index;
+        let active_write_worker =
+            KafkaSinkConnection::active_worker(sink_id, peers) == worker_index;
 
         // Only the active_write_worker will ever produce data so all other workers have
         // an empty frontier.  It's necessary to insert all of these into `storage_state.
@@ -127,6 +139,7 @@ where
             self.clone(),
             sink.envelope,
             sink.as_of.clone(),
+            sink.resuming,
             Rc::clone(&shared_frontier),
             storage_state.sink_metrics.kafka.clone(),
             storage_state
@@ -406,7 +419,7 @@ impl KafkaSinkState {
         connection: KafkaSinkConnection,
         sink_name: String,
         sink_id: &GlobalId,
-        worker_id: String,
+        worker_id: usize,
         write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
         metrics: &KafkaBaseMetrics,
         connection_context: &ConnectionContext,
@@ -418,7 +431,7 @@ impl KafkaSinkState {
             metrics,
             &connection.topic,
             &sink_id.to_string(),
-            &worker_id,
+            &worker_id.to_string(),
         ));
 
         let retry_manager = Arc::new(Mutex::new(KafkaSinkSendRetryManager::new()));
@@ -431,6 +444,18 @@ impl KafkaSinkState {
 
         let healthchecker = healthchecker.map(Mutex::new);
 
+        // The progress topic might have been deleted after the sink was created. Recreate it, in
+        // which case the sink recovers its progress from the data topic.
+        halt_on_err(
+            &healthchecker,
+            *sink_id,
+            &internal_cmd_tx,
+            mz_storage_client::sink::ensure_kafka_progress_topic(&connection, connection_context)
+                .await,
+        )
+        .await;
+
+        let transactional_id = KafkaSinkConnection::transactional_id(*sink_id, worker_id);
         let producer = halt_on_err(
             &healthchecker,
             *sink_id,
@@ -471,7 +496,7 @@ impl KafkaSinkState {
                             // different settings for this value to see if it makes a
                             // big difference.
                             "queue.buffering.max.ms" => format!("{}", 10),
-                            "transactional.id" => format!("mz-producer-{sink_id}-{worker_id}"),
+                            "transactional.id" => transactional_id,
                         },
                     )
                     .await
@@ -517,7 +542,7 @@ impl KafkaSinkState {
             ready_rows: VecDeque::new(),
             retry_manager,
             progress_topic: connection.progress.topic,
-            progress_key: format!("mz-sink-{sink_id}"),
+            progress_key: KafkaSinkConnection::progress_key(*sink_id),
             progress_client: Some(Arc::new(progress_client)),
             healthchecker,
             internal_cmd_tx,
@@ -602,9 +627,12 @@ impl KafkaSinkState {
             .expect("Infinite retry cannot fail");
     }
 
+    /// Determines the point from which the sink resumes, based on the progress topic or, if that
+    /// doesn't contain a record for this sink and the sink is `resuming`, the data topic.
     async fn determine_latest_progress_record(
         &mut self,
-    ) -> Result<Option<Timestamp>, anyhow::Error> {
+        resuming: bool,
+    ) -> Result<Option<ResumePoint>, anyhow::Error> {
         // Polls a message from a Kafka Source.  Blocking so should always be called on background
         // thread.
         fn get_next_message<C>(
@@ -639,7 +667,7 @@ impl KafkaSinkState {
             progress_key: &str,
             progress_client: &BaseConsumer<C>,
             timeout: Duration,
-        ) -> Result<Option<Timestamp>, anyhow::Error>
+        ) -> Result<Option<ResumePoint>, anyhow::Error>
         where
             C: ConsumerContext,
         {
@@ -706,16 +734,22 @@ impl KafkaSinkState {
                 debug_assert!(offset >= latest_offset.unwrap_or(0));
                 latest_offset = Some(offset);
 
-                let timestamp_opt = if &key == progress_key_bytes {
+                let progress_opt = if &key == progress_key_bytes {
                     let progress: ProgressRecord = serde_json::from_slice(&message)?;
-                    Some(progress.timestamp)
+                    Some(progress)
                 } else {
                     None
                 };
 
-                if let Some(ts) = timestamp_opt {
-                    if ts >= latest_ts.unwrap_or_else(timely::progress::Timestamp::minimum) {
-                        latest_ts = Some(ts);
+                if let Some(progress) = progress_opt {
+                    let ts = progress.timestamp;
+                    if progress.reset {
+                        // A reset overrides everything that came before it, even if it moves the
+                        // sink's progress backwards.
+                        latest_ts = Some(ResumePoint::Reset(ts));
+                    } else if latest_ts.map_or(true, |latest: ResumePoint| ts >= latest.timestamp())
+                    {
+                        latest_ts = Some(ResumePoint::Committed(ts));
                     }
                 }
 
@@ -744,12 +778,137 @@ impl KafkaSinkState {
             Ok(latest_ts)
         }
 
+        // Retrieves the latest committed timestamp of the records that the sink wrote to the data
+        // topic. Blocking so should always be called on background thread.
+        //
+        // Every record in the data topic is written in the same transaction as the progress
+        // record for its timestamp, so reading the committed records of the data topic tells us
+        // exactly which timestamps have been published, even without a progress topic.
+        fn get_latest_data_ts<C>(
+            topic: &str,
+            sink_id: &str,
+            client: &BaseConsumer<C>,
+            timeout: Duration,
+        ) -> Result<Option<Timestamp>, anyhow::Error>
+        where
+            C: ConsumerContext,
+        {
+            let partitions = mz_kafka_util::client::get_partitions(client.client(), topic, timeout)
+                .with_context(|| format!("Unable to fetch metadata about data topic {}", topic))?;
+
+            let mut latest_ts = None;
+            for partition in partitions {
+                let (lo, hi) = client
+                    .fetch_watermarks(topic, partition, timeout)
+                    .map_err(|e| {
+                        anyhow!(
+                            "Failed to fetch metadata while reading from data topic: {}",
+                            e
+                        )
+                    })?;
+                // The sink writes out its records in timestamp order, so the latest timestamp of a
+                // partition is that of its last committed record. We read the partition backwards
+                // in windows from its high watermark until we find it, which usually only reads
+                // its tail. Earlier windows only need to be read if the tail consists of aborted
+                // transactions, control records, or records of other writers.
+                let mut end = hi;
+                while end > lo {
+                    let start = std::cmp::max(lo, end - DATA_TOPIC_READ_WINDOW);
+                    if let Some(ts) = get_latest_window_ts(
+                        topic, partition, start, end, sink_id, client, timeout,
+                    )? {
+                        if latest_ts.map_or(true, |latest| ts > latest) {
+                            latest_ts = Some(ts);
+                        }
+                        break;
+                    }
+                    end = start;
+                }
+            }
+            Ok(latest_ts)
+        }
+
+        // Retrieves the latest timestamp of the committed records that the sink wrote to the
+        // offsets `start..end` of `partition` of the data topic. Blocking so should always be
+        // called on background thread.
+        fn get_latest_window_ts<C>(
+            topic: &str,
+            partition: i32,
+            start: i64,
+            end: i64,
+            sink_id: &str,
+            client: &BaseConsumer<C>,
+            timeout: Duration,
+        ) -> Result<Option<Timestamp>, anyhow::Error>
+        where
+            C: ConsumerContext,
+        {
+            let mut tps = TopicPartitionList::new();
+            tps.add_partition_offset(topic, partition, Offset::Offset(start))?;
+            client
+                .assign(&tps)
+                .with_context(|| format!("Error seeking in data topic {}:{}", topic, partition))?;
+
+            let mut latest_ts = None;
+            loop {
+                match client.poll(timeout) {
+                    Some(Ok(message)) => {
+                        if message.offset() >= end {
+                            break;
+                        }
+                        let mut sink_id_matches = false;
+                        let mut ts = None;
+                        if let Some(headers) = message.headers() {
+                            for header in headers.iter() {
+                                match (header.key, header.value) {
+                                    (SINK_ID_HEADER, Some(value)) => {
+                                        sink_id_matches = value == sink_id.as_bytes();
+                                    }
+                                    (TIMESTAMP_HEADER, Some(value)) => {
+                                        ts =
+                                            Some(std::str::from_utf8(value)?.parse::<Timestamp>()?);
+                                    }
+                                    _ => {}
+                                }
+                            }
+                        }
+                        // Only records that are known to have been written by this sink count.
+                        // Records without a sink ID header may have been written by anyone,
+                        // including versions of this sink that predate the header.
+                        if let (true, Some(ts)) = (sink_id_matches, ts) {
+                            if latest_ts.map_or(true, |latest| ts > latest) {
+                                latest_ts = Some(ts);
+                            }
+                        }
+                    }
+                    Some(Err(KafkaError::PartitionEOF(_))) => break,
+                    Some(Err(err)) => bail!("Failed to process message {}", err),
+                    None => {}
+                }
+
+                // Stop once the next possible offset is past the window. Aborted transactions and
+                // control records are skipped without being returned, so we cannot rely on seeing
+                // a message at the end of the window.
+                let position = client
+                    .position()?
+                    .find_partition(topic, partition)
+                    .ok_or_else(|| anyhow!("No position for known partition"))?
+                    .offset();
+                if let Offset::Offset(i) = position {
+                    if i >= end {
+                        break;
+                    }
+                }
+            }
+            Ok(latest_ts)
+        }
+
         let progress_client = self
             .progress_client
             .take()
             .expect("Claiming just-created progress client");
         // Only actually used for retriable errors.
-        Retry::default()
+        let latest_ts = Retry::default()
             .max_tries(3)
             .clamp_backoff(Duration::from_secs(60 * 10))
             .retry_async(|_| async {
@@ -770,12 +929,54 @@ impl KafkaSinkState {
                 .await
                 .unwrap_or_else(|e| bail!(e))
             })
-            .await
+            .await?;
+        if latest_ts.is_some() {
+            return Ok(latest_ts);
+        }
+
+        // A new sink has no progress to recover, even if the data topic already contains records,
+        // e.g. because it was used by an earlier sink with the same ID in another environment.
+        if !resuming {
+            return Ok(None);
+        }
+
+        // The progress topic does not know about this sink, even though the sink made progress before,
+        // so its progress was lost. Recover it from the data topic.
+        let recovered_ts = Retry::default()
+            .max_tries(3)
+            .clamp_backoff(Duration::from_secs(60 * 10))
+            .retry_async(|_| async {
+                let topic = self.topic.clone();
+                let sink_id = self.sink_id.to_string();
+                let progress_client = Arc::clone(&progress_client);
+                task::spawn_blocking(
+                    || format!("get_latest_data_ts:{}", self.name),
+                    move || {
+                        get_latest_data_ts(
+                            &topic,
+                            &sink_id,
+                            &progress_client,
+                            DEFAULT_FETCH_METADATA_TIMEOUT,
+                        )
+                    },
+                )
+                .await
+                .unwrap_or_else(|e| bail!(e))
+            })
+            .await?;
+        if let Some(ts) = recovered_ts {
+            info!(
+                "{}: recovered progress from data topic {}: {:?}",
+                self.name, self.topic, ts
+            );
+        }
+        Ok(recovered_ts.map(ResumePoint::Recovered))
     }
 
     async fn send_progress_record(&self, transaction_id: Timestamp) {
         let encoded = serde_json::to_vec(&ProgressRecord {
             timestamp: transaction_id,
+            reset: false,
         })
         .expect("serialization to vec cannot fail");
         let record = BaseRecord::to(&self.progress_topic)
@@ -980,6 +1181,7 @@ fn kafka<G>(
     connection: KafkaSinkConnection,
     envelope: Option<SinkEnvelope>,
     as_of: SinkAsOf,
+    resuming: bool,
     write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
     metrics: KafkaBaseMetrics,
     sink_statistics: StorageStatistics<SinkStatisticsUpdate, SinkStatisticsMetrics>,
@@ -1046,6 +1248,7 @@ where
         name,
         connection,
         as_of,
+        resuming,
         shared_gate_ts,
         write_frontier,
         metrics,
@@ -1067,12 +1270,16 @@ where
 ///
 /// Updates that are not beyond the given [`SinkAsOf`] and/or the `gate_ts` in
 /// [`KafkaSinkConnection`] will be discarded without producing them.
+///
+/// If `resuming` is set, the sink may have published data before, and recovers its progress from
+/// that data if its progress records are missing.
 pub fn produce_to_kafka<G>(
     stream: Stream<G, ((Option<Vec<u8>>, Option<Vec<u8>>), Timestamp, Diff)>,
     id: GlobalId,
     name: String,
     connection: KafkaSinkConnection,
     as_of: SinkAsOf,
+    resuming: bool,
     shared_gate_ts: Rc<Cell<Option<Timestamp>>>,
     write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
     metrics: KafkaBaseMetrics,
@@ -1094,7 +1301,7 @@ where
 
     // We want exactly one worker to send all the data to the sink topic.
     let hashed_id = id.hashed();
-    let is_active_worker = usize::cast_from(hashed_id) % worker_count == worker_id;
+    let is_active_worker = KafkaSinkConnection::active_worker(id, worker_count) == worker_id;
 
     let mut input = builder.new_input(&stream, Exchange::new(move |_| hashed_id));
 
@@ -1121,7 +1328,7 @@ where
             connection,
             name,
             &id,
-            worker_id.to_string(),
+            worker_id,
             write_frontier,
             &metrics,
             &connection_context,
@@ -1140,27 +1347,50 @@ where
         )
         .await;
 
-        let latest_ts = s.determine_latest_progress_record().await;
-        let latest_ts = s.halt_on_err(latest_ts).await;
+        let resume_point = s.determine_latest_progress_record(resuming).await;
+        let resume_point = s.halt_on_err(resume_point).await;
         info!(
-            "{}: initial as_of: {:?}, latest progress record: {:?}",
-            s.name, as_of.frontier, latest_ts
+            "{}: initial as_of: {:?}, resume point: {:?}",
+            s.name, as_of.frontier, resume_point
         );
+
+        let latest_ts = resume_point.map(|point| point.timestamp());
         shared_gate_ts.set(latest_ts);
 
-        if let Some(gate) = latest_ts {
-            assert!(
-                PartialOrder::less_equal(&as_of.frontier, &Antichain::from_elem(gate)),
-                "{}: some element of the Sink as_of frontier is too \
-                    far advanced for our output-gating timestamp: \
-                    as_of {:?}, gate_ts: {:?}",
-                s.name,
-                as_of.frontier,
-                gate
-            );
+        if let Some(point) = resume_point {
+            let gate = point.timestamp();
+            let gate_beyond_as_of =
+                PartialOrder::less_equal(&as_of.frontier, &Antichain::from_elem(gate));
+            match point {
+                ResumePoint::Committed(_) => assert!(
+                    gate_beyond_as_of,
+                    "{}: some element of the Sink as_of frontier is too \
+                        far advanced for our output-gating timestamp: \
+                        as_of {:?}, gate_ts: {:?}",
+                    s.name, as_of.frontier, gate
+                ),
+                // Reset and recovered progress is not tied to the sink's write frontier, so it
+                // can lag behind the as_of. All updates at or before the as_of have been advanced
+                // to the as_of, so we can't tell which of them have been published already.
+                // Publishing them again would break exactly-once, so require the progress to be
+                // reset explicitly instead.
+                ResumePoint::Reset(_) | ResumePoint::Recovered(_) if !gate_beyond_as_of => {
+                    s.halt_on_err(Err(anyhow!(
+                        "resume point {:?} is not beyond the as_of {:?}, so the sink cannot \
+                            resume without publishing some updates again; reset its progress \
+                            to a timestamp beyond the as_of with ALTER SINK ... RESET PROGRESS",
+                        point,
+                        as_of.frontier
+                    )))
+                    .await
+                }
+                ResumePoint::Reset(_) | ResumePoint::Recovered(_) => {}
+            }
             s.maybe_update_progress(&gate);
         }
 
+        let sink_id_bytes = id.to_string().into_bytes();
+
         s.update_status(SinkStatus::Running).await;
 
         while let Some(event) = input.next_mut().await {
@@ -1239,10 +1469,17 @@ where
                             };
 
                             let ts_bytes = ts.to_string().into_bytes();
-                            let record = record.headers(OwnedHeaders::new().insert(Header {
-                                key: "materialize-timestamp",
-                                value: Some(&ts_bytes),
-                            }));
+                            let record = record.headers(
+                                OwnedHeaders::new()
+                                    .insert(Header {
+                                        key: TIMESTAMP_HEADER,
+                                        value: Some(&ts_bytes),
+                                    })
+                                    .insert(Header {
+                                        key: SINK_ID_HEADER,
+                                        value: Some(&sink_id_bytes),
+                                    }),
+                            );
 
                             let size_for_stats =
                                 u64::cast_from(record.payload.as_ref().map_or(0, |p| p.len()))
@@ -1400,4 +1637,28 @@ where
 /// information should be added elsewhere instead of overloading this record.
 struct ProgressRecord {
     timestamp: Timestamp,
+    /// Whether this record was written by `ALTER SINK ... RESET PROGRESS`, in which case it
+    /// overrides all progress records that precede it in the progress topic.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    reset: bool,
+}
+
+/// The point from which a restarted Kafka sink resumes publishing updates.
+#[derive(Debug, Clone, Copy)]
+enum ResumePoint {
+    /// The latest progress record the sink committed to its progress topic.
+    Committed(Timestamp),
+    /// A progress record written by `ALTER SINK ... RESET PROGRESS`.
+    Reset(Timestamp),
+    /// The latest timestamp found in the data topic, which is used when the progress topic does
+    /// not contain any record for the sink, e.g. because it was deleted or compacted away.
+    Recovered(Timestamp),
+}
+
+impl ResumePoint {
+    fn timestamp(&self) -> Timestamp {
+        match self {
+            ResumePoint::Committed(ts) | ResumePoint::Reset(ts) | ResumePoint::Recovered(ts) => *ts,
+        }
+    }
 }
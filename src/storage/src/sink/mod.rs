@@ -12,6 +12,7 @@
 mod healthcheck;
 mod kafka;
 pub mod metrics;
+mod postgres;
 
 pub use healthcheck::{Healthchecker, SinkStatus};
 pub(crate) use metrics::KafkaBaseMetrics;
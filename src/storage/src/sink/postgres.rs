@@ -0,0 +1,533 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A sink that upserts its updates into a PostgreSQL table.
+//!
+//! All updates at a timestamp are applied in a single transaction, together with an update to a
+//! progress table that records the latest timestamp the sink has written. When the sink restarts
+//! it reads the progress table and skips every update at or before the recorded timestamp, which
+//! makes the sink exactly-once.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::future;
+use std::rc::Rc;
+
+use anyhow::{anyhow, Context};
+use bytes::BytesMut;
+use differential_dataflow::{Collection, Hashable};
+use itertools::Itertools;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_repr::{Diff, GlobalId, RelationDesc, Row, ScalarType, Timestamp};
+use mz_sql_parser::ast::display::AstDisplay;
+use mz_sql_parser::ast::Ident;
+use mz_storage_client::client::SinkStatisticsUpdate;
+use mz_storage_types::connections::ConnectionContext;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::{MetadataFilled, PostgresSinkConnection, SinkAsOf, StorageSinkDesc};
+use mz_timely_util::builder_async::{Event, OperatorBuilder as AsyncOperatorBuilder};
+use timely::dataflow::channels::pact::Exchange;
+use timely::dataflow::Scope;
+use timely::progress::{Antichain, Timestamp as _};
+use timely::PartialOrder;
+use tokio_postgres::Client;
+use tracing::info;
+
+use crate::internal_control::{InternalCommandSender, InternalStorageCommand};
+use crate::render::sinks::{HealthcheckerArgs, SinkRender};
+use crate::sink::{Healthchecker, SinkStatus};
+use crate::statistics::{SinkStatisticsMetrics, StorageStatistics};
+use crate::storage_state::StorageState;
+
+impl<G> SinkRender<G> for PostgresSinkConnection
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    fn uses_keys(&self) -> bool {
+        true
+    }
+
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        Some(self.key_desc_and_indices.1.as_slice())
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        None
+    }
+
+    fn render_continuous_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<MetadataFilled, Timestamp>,
+        sink_id: GlobalId,
+        sinked_collection: Collection<G, (Option<Row>, Option<Row>), Diff>,
+        _err_collection: Collection<G, DataflowError, Diff>,
+        healthchecker_args: HealthcheckerArgs,
+    ) -> Option<Rc<dyn Any>>
+    where
+        G: Scope<Timestamp = Timestamp>,
+    {
+        // Only the worker the operator below exchanges all updates to ever writes to the table,
+        // so all other workers report an empty write frontier.
+        let peers = sinked_collection.inner.scope().peers();
+        let worker_index = sinked_collection.inner.scope().index();
+        let active_write_worker = (usize::cast_from(sink_id.hashed()) % peers) == worker_index;
+        let shared_frontier = Rc::new(RefCell::new(if active_write_worker {
+            Antichain::from_elem(Timestamp::minimum())
+        } else {
+            Antichain::new()
+        }));
+
+        let token = postgres(
+            sinked_collection,
+            sink_id,
+            self.clone(),
+            sink.as_of.clone(),
+            Rc::clone(&shared_frontier),
+            storage_state
+                .sink_statistics
+                .get(&sink_id)
+                .expect("statistics initialized")
+                .clone(),
+            storage_state.connection_context.clone(),
+            healthchecker_args,
+            Rc::clone(&storage_state.internal_cmd_tx),
+        );
+
+        storage_state
+            .sink_write_frontiers
+            .insert(sink_id, shared_frontier);
+
+        Some(token)
+    }
+}
+
+/// Writes the updates of `collection` to the table of `connection`.
+///
+/// This operator exchanges all updates to a single worker by hashing on the given sink `id`.
+/// Updates are only written once the input frontier has passed their time, and the updates of
+/// each timestamp are written in a single transaction. If there is more than one update for a
+/// key at a timestamp, which can only happen when the key is `NOT ENFORCED`, an arbitrary one of
+/// them is written.
+///
+/// Updates that are not beyond the given [`SinkAsOf`] or the timestamp recorded in the progress
+/// table are discarded without writing them.
+fn postgres<G>(
+    collection: Collection<G, (Option<Row>, Option<Row>), Diff>,
+    id: GlobalId,
+    connection: PostgresSinkConnection,
+    as_of: SinkAsOf,
+    write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
+    sink_statistics: StorageStatistics<SinkStatisticsUpdate, SinkStatisticsMetrics>,
+    connection_context: ConnectionContext,
+    healthchecker_args: HealthcheckerArgs,
+    internal_cmd_tx: Rc<RefCell<dyn InternalCommandSender>>,
+) -> Rc<dyn Any>
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    let name = format!("postgres-{}", id);
+    let stream = &collection.inner;
+
+    let worker_id = stream.scope().index();
+    let worker_count = stream.scope().peers();
+    let hashed_id = id.hashed();
+    let is_active_worker = usize::cast_from(hashed_id) % worker_count == worker_id;
+
+    let mut builder = AsyncOperatorBuilder::new(name.clone(), stream.scope());
+    let mut input = builder.new_input(stream, Exchange::new(move |_| hashed_id));
+
+    let button = builder.build(move |_capabilities| async move {
+        if !is_active_worker {
+            return;
+        }
+
+        let mut healthchecker = match healthchecker_args.status_shard_id {
+            Some(status_shard_id) => Some(
+                Healthchecker::new(
+                    id,
+                    &healthchecker_args.persist_clients,
+                    healthchecker_args.persist_location.clone(),
+                    status_shard_id,
+                    healthchecker_args.now_fn.clone(),
+                )
+                .await
+                .expect("error initializing healthchecker"),
+            ),
+            None => None,
+        };
+        update_status(&mut healthchecker, SinkStatus::Starting).await;
+
+        let writer = PostgresSinkWriter::new(id, connection, &connection_context).await;
+        let mut writer = halt_on_err(&mut healthchecker, id, &internal_cmd_tx, writer).await;
+        let gate_ts = writer.read_progress().await;
+        let gate_ts = halt_on_err(&mut healthchecker, id, &internal_cmd_tx, gate_ts).await;
+        info!(
+            "{}: initial as_of: {:?}, gate ts: {:?}",
+            name, as_of.frontier, gate_ts
+        );
+
+        if let Some(gate) = gate_ts {
+            // All updates at or before the as_of have been advanced to the as_of, so we can't
+            // tell which of them have been written already. Worse, a key that was deleted after
+            // the gate ts and by the as_of has been compacted out of the updates at the as_of,
+            // so its stale row would never be deleted from the table. Halt instead of writing a
+            // table that silently diverges from the sink's input.
+            if !PartialOrder::less_equal(&as_of.frontier, &Antichain::from_elem(gate)) {
+                let error = anyhow!(
+                    "progress {:?} recorded in the target table's database is not beyond the \
+                        as_of {:?}, so updates between the two can't be written; truncate the \
+                        table and recreate the sink to write it from scratch",
+                    gate,
+                    as_of.frontier
+                );
+                halt_on_err(&mut healthchecker, id, &internal_cmd_tx, Err(error)).await
+            }
+        }
+        let mut latest_progress_ts = gate_ts.unwrap_or_else(Timestamp::minimum);
+
+        update_status(&mut healthchecker, SinkStatus::Running).await;
+
+        // The latest update for each key, by timestamp.
+        let mut pending_rows: BTreeMap<Timestamp, BTreeMap<Row, Option<Row>>> = BTreeMap::new();
+
+        while let Some(event) = input.next_mut().await {
+            match event {
+                Event::Data(_, rows) => {
+                    for ((key, value), time, diff) in rows.drain(..) {
+                        let should_emit = if as_of.strict {
+                            as_of.frontier.less_than(&time)
+                        } else {
+                            as_of.frontier.less_equal(&time)
+                        };
+                        let previously_written = Some(time) <= gate_ts;
+                        if !should_emit || previously_written || diff == 0 {
+                            continue;
+                        }
+                        let key = key.expect("postgres sinks are keyed");
+                        pending_rows.entry(time).or_default().insert(key, value);
+                    }
+                }
+                Event::Progress(frontier) => {
+                    let closed_ts: Vec<Timestamp> = pending_rows
+                        .keys()
+                        .filter(|ts| !frontier.less_equal(*ts))
+                        .copied()
+                        .collect();
+                    for ts in closed_ts {
+                        let rows = pending_rows.remove(&ts).expect("known to exist");
+                        let count = u64::cast_from(rows.len());
+                        info!("{}: writing {} updates at {:?}", name, count, ts);
+                        sink_statistics.inc_messages_staged_by(count);
+
+                        let bytes = writer.write(ts, rows).await;
+                        let bytes =
+                            halt_on_err(&mut healthchecker, id, &internal_cmd_tx, bytes).await;
+                        sink_statistics.inc_bytes_staged_by(bytes);
+                        sink_statistics.inc_messages_committed_by(count);
+                        sink_statistics.inc_bytes_committed_by(bytes);
+
+                        assert!(write_frontier.borrow().less_equal(&ts));
+                        latest_progress_ts = std::cmp::max(latest_progress_ts, ts);
+                    }
+
+                    // Recording progress before the as_of could make a restarted sink skip
+                    // updates it has not written yet, because it can't tell them apart from
+                    // updates it has written.
+                    if !PartialOrder::less_than(&as_of.frontier, &frontier) {
+                        continue;
+                    }
+
+                    match frontier.into_option() {
+                        Some(frontier) => {
+                            // A frontier of `t` means we might still receive updates at `t`, so
+                            // everything up to and including `t - 1` has been written.
+                            let progress_ts = frontier.saturating_sub(1);
+                            if progress_ts > latest_progress_ts {
+                                let result = writer.write_progress(progress_ts).await;
+                                halt_on_err(&mut healthchecker, id, &internal_cmd_tx, result).await;
+                                latest_progress_ts = progress_ts;
+                            }
+
+                            let mut write_frontier = write_frontier.borrow_mut();
+                            assert!(write_frontier.less_equal(&progress_ts));
+                            write_frontier.clear();
+                            write_frontier.insert(progress_ts);
+                        }
+                        None => {
+                            info!("{}: advancing write frontier to empty", name);
+                            write_frontier.borrow_mut().clear();
+                        }
+                    }
+                }
+            }
+        }
+    });
+
+    Rc::new(button.press_on_drop())
+}
+
+/// The connection to the upstream database and the statements the sink runs on it.
+struct PostgresSinkWriter {
+    client: Client,
+    /// The key of the sink's record in the progress table.
+    progress_key: String,
+    key_desc: RelationDesc,
+    value_desc: RelationDesc,
+    upsert_statement: String,
+    delete_statement: String,
+    read_progress_statement: String,
+    write_progress_statement: String,
+}
+
+impl PostgresSinkWriter {
+    /// Connects to the upstream database and creates the progress table if it doesn't exist.
+    async fn new(
+        sink_id: GlobalId,
+        connection: PostgresSinkConnection,
+        connection_context: &ConnectionContext,
+    ) -> Result<Self, anyhow::Error> {
+        let client = connection
+            .connection
+            .config(&*connection_context.secrets_reader)
+            .await?
+            .connect("postgres_sink")
+            .await?;
+
+        let quote = |name: &str| Ident::from(name.to_string()).to_ast_string();
+        let table = format!("{}.{}", quote(&connection.schema), quote(&connection.table));
+        let progress_table = format!(
+            "{}.{}",
+            quote(&connection.schema),
+            quote(&connection.progress_table)
+        );
+
+        let (key_desc, key_indices) = connection.key_desc_and_indices;
+        let value_desc = connection.value_desc;
+        let keys = key_desc
+            .iter_names()
+            .map(|name| quote(name.as_str()))
+            .collect::<Vec<_>>();
+        let columns = value_desc
+            .iter_names()
+            .map(|name| quote(name.as_str()))
+            .join(", ");
+
+        let non_keys = value_desc
+            .iter_names()
+            .enumerate()
+            .filter(|(idx, _name)| !key_indices.contains(idx))
+            .map(|(_idx, name)| {
+                let name = quote(name.as_str());
+                format!("{name} = EXCLUDED.{name}")
+            })
+            .collect::<Vec<_>>();
+        let on_conflict = if non_keys.is_empty() {
+            "DO NOTHING".to_string()
+        } else {
+            format!("DO UPDATE SET {}", non_keys.join(", "))
+        };
+
+        let upsert_statement = format!(
+            "INSERT INTO {table} ({columns}) \
+            SELECT {columns} FROM json_populate_recordset(NULL::{table}, $1::text::json) \
+            ON CONFLICT ({}) {on_conflict}",
+            keys.join(", ")
+        );
+        let delete_statement = format!(
+            "DELETE FROM {table} AS t \
+            USING json_populate_recordset(NULL::{table}, $1::text::json) AS r \
+            WHERE {}",
+            keys.iter()
+                .map(|key| format!("t.{key} = r.{key}"))
+                .join(" AND ")
+        );
+
+        client
+            .batch_execute(&format!(
+                "CREATE TABLE IF NOT EXISTS {progress_table} \
+                (sink_id text PRIMARY KEY, \"timestamp\" bigint NOT NULL)"
+            ))
+            .await
+            .with_context(|| format!("creating progress table {progress_table}"))?;
+
+        Ok(PostgresSinkWriter {
+            client,
+            progress_key: format!("{}-{sink_id}", connection.environment_id),
+            key_desc,
+            value_desc,
+            upsert_statement,
+            delete_statement,
+            read_progress_statement: format!(
+                "SELECT \"timestamp\" FROM {progress_table} WHERE sink_id = $1"
+            ),
+            write_progress_statement: format!(
+                "INSERT INTO {progress_table} (sink_id, \"timestamp\") VALUES ($1, $2) \
+                ON CONFLICT (sink_id) DO UPDATE SET \"timestamp\" = EXCLUDED.\"timestamp\""
+            ),
+        })
+    }
+
+    /// Returns the latest timestamp the sink has written all updates of, if any.
+    async fn read_progress(&self) -> Result<Option<Timestamp>, anyhow::Error> {
+        let row = self
+            .client
+            .query_opt(self.read_progress_statement.as_str(), &[&self.progress_key])
+            .await?;
+        match row {
+            Some(row) => Ok(Some(Timestamp::try_from(row.get::<_, i64>(0))?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Records that the sink has written all updates up to and including `ts`.
+    async fn write_progress(&self, ts: Timestamp) -> Result<(), anyhow::Error> {
+        self.client
+            .execute(
+                self.write_progress_statement.as_str(),
+                &[&self.progress_key, &i64::try_from(ts)?],
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Applies the updates at `ts` and records progress for `ts` in a single transaction.
+    ///
+    /// The updates are sent in chunks, so a timestamp with many updates takes several statements
+    /// within the transaction.
+    ///
+    /// Returns the number of bytes sent to the upstream database.
+    async fn write(
+        &mut self,
+        ts: Timestamp,
+        rows: BTreeMap<Row, Option<Row>>,
+    ) -> Result<u64, anyhow::Error> {
+        let mut deletes = vec![];
+        let mut upserts = vec![];
+        for (key, value) in rows {
+            match value {
+                Some(value) => upserts.push(value),
+                None => deletes.push(key),
+            }
+        }
+
+        let txn = self.client.transaction().await?;
+        let mut bytes = 0;
+        // Apply deletes first, so that rows that move to a new key don't conflict with
+        // themselves on other unique constraints of the table.
+        for deletes in encode_records(&self.key_desc, &deletes) {
+            bytes += u64::cast_from(deletes.len());
+            txn.execute(self.delete_statement.as_str(), &[&deletes])
+                .await?;
+        }
+        for upserts in encode_records(&self.value_desc, &upserts) {
+            bytes += u64::cast_from(upserts.len());
+            txn.execute(self.upsert_statement.as_str(), &[&upserts])
+                .await?;
+        }
+        txn.execute(
+            self.write_progress_statement.as_str(),
+            &[&self.progress_key, &i64::try_from(ts)?],
+        )
+        .await?;
+        txn.commit().await?;
+
+        Ok(bytes)
+    }
+}
+
+/// The size in bytes after which [`encode_records`] starts a new chunk. A single statement
+/// parameter can't be larger than 1GiB, so a timestamp with many updates is written with several
+/// statements.
+const MAX_CHUNK_BYTES: usize = 64 << 20;
+
+/// Encodes `rows` as JSON arrays of objects that map the names of the columns of `desc` to the
+/// text encoding of their values, which is what `json_populate_recordset` expects.
+///
+/// The rows are split into chunks of roughly [`MAX_CHUNK_BYTES`] each.
+fn encode_records(desc: &RelationDesc, rows: &[Row]) -> Vec<String> {
+    let mut buf = BytesMut::new();
+    let mut chunks = vec![];
+    let mut chunk = String::new();
+    for row in rows {
+        let record = row
+            .iter()
+            .zip(desc.iter())
+            .map(|(datum, (name, typ))| {
+                let value = match mz_pgrepr::Value::from_datum(datum, &typ.scalar_type) {
+                    None => serde_json::Value::Null,
+                    Some(value) => {
+                        buf.clear();
+                        value.encode_text(&mut buf);
+                        let text = std::str::from_utf8(&buf).expect("text encoding is UTF-8");
+                        // `json_populate_recordset` takes JSON values as is for `json` and
+                        // `jsonb` columns instead of parsing their text encoding.
+                        if matches!(typ.scalar_type, ScalarType::Jsonb) {
+                            serde_json::from_str(text).expect("jsonb encodes valid JSON")
+                        } else {
+                            serde_json::Value::String(text.to_string())
+                        }
+                    }
+                };
+                (name.as_str().to_string(), value)
+            })
+            .collect::<serde_json::Map<_, _>>();
+        chunk.push(if chunk.is_empty() { '[' } else { ',' });
+        chunk.push_str(&serde_json::Value::Object(record).to_string());
+        if chunk.len() >= MAX_CHUNK_BYTES {
+            chunk.push(']');
+            chunks.push(std::mem::take(&mut chunk));
+        }
+    }
+    if !chunk.is_empty() {
+        chunk.push(']');
+        chunks.push(chunk);
+    }
+    chunks
+}
+
+async fn update_status(healthchecker: &mut Option<Healthchecker>, status: SinkStatus) {
+    if let Some(hc) = healthchecker {
+        hc.update_status(status).await;
+    }
+}
+
+/// Report a SinkStatus::Stalled and then halt with the same message.
+async fn halt_on_err<T>(
+    healthchecker: &mut Option<Healthchecker>,
+    sink_id: GlobalId,
+    internal_cmd_tx: &RefCell<dyn InternalCommandSender>,
+    result: Result<T, anyhow::Error>,
+) -> T {
+    match result {
+        Ok(t) => t,
+        Err(error) => {
+            update_status(
+                healthchecker,
+                SinkStatus::Stalled {
+                    error: format!("{}", error.display_with_causes()),
+                    hint: None,
+                },
+            )
+            .await;
+            internal_cmd_tx
+                .borrow_mut()
+                .broadcast(InternalStorageCommand::SuspendAndRestart {
+                    id: sink_id,
+                    reason: error.to_string(),
+                });
+
+            // Make sure to never return, preventing the sink from writing
+            // out anything it might regret in the future.
+            future::pending().await
+        }
+    }
+}
@@ -8,8 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use std::any::Any;
-use std::collections::btree_map::Entry;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::convert::Infallible;
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
@@ -22,8 +21,8 @@ use differential_dataflow::{AsCollection, Collection};
 use futures::StreamExt;
 use maplit::btreemap;
 use mz_kafka_util::client::{
-    get_partitions, BrokerRewritingClientContext, MzClientContext, PartitionId,
-    DEFAULT_FETCH_METADATA_TIMEOUT,
+    get_matching_topic_partitions, get_partitions, BrokerRewritingClientContext, MzClientContext,
+    PartitionId, DEFAULT_FETCH_METADATA_TIMEOUT,
 };
 use mz_ore::error::ErrorExt;
 use mz_ore::thread::{JoinHandleExt, UnparkOnDropHandle};
@@ -32,11 +31,11 @@ use mz_repr::adt::timestamp::CheckedTimestamp;
 use mz_repr::{Datum, Diff, GlobalId, Row};
 use mz_storage_types::connections::{ConnectionContext, StringOrSecret};
 use mz_storage_types::sources::{
-    KafkaMetadataKind, KafkaSourceConnection, MzOffset, SourceTimestamp,
+    KafkaMetadataKind, KafkaPartition, KafkaSourceConnection, MzOffset, SourceTimestamp,
 };
 use mz_timely_util::antichain::AntichainExt;
 use mz_timely_util::builder_async::OperatorBuilder as AsyncOperatorBuilder;
-use mz_timely_util::order::Partitioned;
+use mz_timely_util::order::{Interval, Partitioned, RangeBound};
 use rdkafka::client::Client;
 use rdkafka::consumer::base_consumer::PartitionQueue;
 use rdkafka::consumer::{BaseConsumer, Consumer, ConsumerContext};
@@ -45,10 +44,10 @@ use rdkafka::message::{BorrowedMessage, Headers};
 use rdkafka::statistics::Statistics;
 use rdkafka::topic_partition_list::Offset;
 use rdkafka::{ClientContext, Message, TopicPartitionList};
+use regex::Regex;
 use timely::dataflow::operators::Capability;
 use timely::dataflow::{Scope, Stream};
 use timely::progress::Antichain;
-use timely::PartialOrder;
 use tokio::sync::Notify;
 use tracing::{error, info, trace, warn};
 
@@ -60,8 +59,10 @@ mod metrics;
 
 /// Contains all information necessary to ingest data from Kafka
 pub struct KafkaSourceReader {
-    /// Name of the topic on which this source is backed on
+    /// Name of the topic on which this source is backed on, or the pattern selecting its topics
     topic_name: String,
+    /// Whether `topic_name` is a pattern selecting the topics of this source
+    topic_is_pattern: bool,
     /// Name of the source (will have format kafka-source-id)
     source_name: String,
     /// Source global ID
@@ -77,13 +78,13 @@ pub struct KafkaSourceReader {
     /// The most recently read offset for each partition known to this source
     /// reader. An offset of -1 indicates that no prior message has been read
     /// for the given partition.
-    last_offsets: BTreeMap<PartitionId, i64>,
+    last_offsets: BTreeMap<KafkaPartition, i64>,
     /// The offset to start reading from for each partition.
-    start_offsets: BTreeMap<PartitionId, i64>,
+    start_offsets: BTreeMap<KafkaPartition, i64>,
     /// Channel to receive Kafka statistics JSON blobs from the stats callback.
     stats_rx: crossbeam_channel::Receiver<Jsonb>,
     /// The last partition info we received. For each partition we also fetch the high watermark.
-    partition_info: Arc<Mutex<Option<BTreeMap<PartitionId, i64>>>>,
+    partition_info: Arc<Mutex<Option<PartitionInfo>>>,
     /// A handle to the spawned metadata thread
     // Drop order is important here, we want the thread to be unparked after the `partition_info`
     // Arc has been dropped, so that the unpacked thread notices it and exits immediately
@@ -95,14 +96,24 @@ pub struct KafkaSourceReader {
     /// The latest status detected by the metadata refresh thread.
     health_status: Arc<Mutex<Option<HealthStatus>>>,
     /// Per partition capabilities used to produce messages
-    partition_capabilities: BTreeMap<PartitionId, PartitionCapability>,
+    partition_capabilities: BTreeMap<KafkaPartition, PartitionCapability>,
+}
+
+/// The partitions of the upstream topics of a source, as of one metadata refresh.
+#[derive(Debug)]
+struct PartitionInfo {
+    /// The high watermark of every partition.
+    watermarks: BTreeMap<KafkaPartition, i64>,
+    /// The topics matched by a `TOPIC PATTERN` whose metadata reported an error. These topics
+    /// exist, but their partitions are missing from `watermarks`.
+    unavailable_topics: BTreeSet<String>,
 }
 
 struct PartitionCapability {
     /// The capability of the data produced
-    data: Capability<Partitioned<PartitionId, MzOffset>>,
+    data: Capability<Partitioned<KafkaPartition, MzOffset>>,
     /// The capability of the progress stream
-    progress: Capability<Partitioned<PartitionId, MzOffset>>,
+    progress: Capability<Partitioned<KafkaPartition, MzOffset>>,
 }
 
 pub struct KafkaOffsetCommiter {
@@ -114,14 +125,14 @@ pub struct KafkaOffsetCommiter {
 impl SourceRender for KafkaSourceConnection {
     type Key = Option<Vec<u8>>;
     type Value = Option<Vec<u8>>;
-    type Time = Partitioned<PartitionId, MzOffset>;
+    type Time = Partitioned<KafkaPartition, MzOffset>;
 
-    fn render<G: Scope<Timestamp = Partitioned<PartitionId, MzOffset>>>(
+    fn render<G: Scope<Timestamp = Partitioned<KafkaPartition, MzOffset>>>(
         self,
         scope: &mut G,
         config: RawSourceCreationConfig,
         connection_context: ConnectionContext,
-        resume_uppers: impl futures::Stream<Item = Antichain<Partitioned<PartitionId, MzOffset>>>
+        resume_uppers: impl futures::Stream<Item = Antichain<Partitioned<KafkaPartition, MzOffset>>>
             + 'static,
         start_signal: impl std::future::Future<Output = ()> + 'static,
     ) -> (
@@ -144,18 +155,21 @@ impl SourceRender for KafkaSourceConnection {
         let (mut health_output, health_stream) = builder.new_output();
 
         let button = builder.build(move |caps| async move {
-            let [mut data_cap, mut progress_cap, health_cap]: [_; 3] = caps.try_into().unwrap();
+            let [data_cap, progress_cap, health_cap]: [_; 3] = caps.try_into().unwrap();
 
             // Start offsets is a map from partition to the next offset to read from.
             let mut start_offsets: BTreeMap<_, i64> = self
-                .start_offsets.clone()
+                .start_offsets
+                .clone()
                 .into_iter()
+                .map(|(pid, offset)| (KafkaPartition::new(String::new(), pid), offset))
                 .filter(|(pid, _offset)| config.responsible_for(pid))
-                .map(|(k, v)| (k, v))
                 .collect();
 
             let mut partition_capabilities = BTreeMap::new();
-            let mut max_pid = None;
+            // Capabilities for the ranges of partitions that have not been discovered yet.
+            let mut gap_capabilities = vec![];
+            let mut known_partitions = BTreeSet::new();
             let resume_upper = Antichain::from_iter(
                 config.source_resume_uppers[&config.id]
                     .iter()
@@ -163,28 +177,33 @@ impl SourceRender for KafkaSourceConnection {
             );
             for ts in resume_upper.elements() {
                 if let Some(pid) = ts.partition() {
-                    max_pid = std::cmp::max(max_pid, Some(*pid));
+                    known_partitions.insert(pid.clone());
                     if config.responsible_for(pid) {
                         let restored_offset = i64::try_from(ts.timestamp().offset)
                             .expect("restored kafka offsets must fit into i64");
                         if let Some(start_offset) = start_offsets.get_mut(pid) {
                             *start_offset = std::cmp::max(restored_offset, *start_offset);
                         } else {
-                            start_offsets.insert(*pid, restored_offset);
+                            start_offsets.insert(pid.clone(), restored_offset);
                         }
 
-                        let part_ts = Partitioned::with_partition(*pid, ts.timestamp().clone());
+                        let part_ts =
+                            Partitioned::with_partition(pid.clone(), ts.timestamp().clone());
                         let part_cap = PartitionCapability {
                             data: data_cap.delayed(&part_ts),
                             progress: progress_cap.delayed(&part_ts),
                         };
-                        partition_capabilities.insert(*pid, part_cap);
+                        partition_capabilities.insert(pid.clone(), part_cap);
                     }
+                } else {
+                    gap_capabilities.push(PartitionCapability {
+                        data: data_cap.delayed(ts),
+                        progress: progress_cap.delayed(ts),
+                    });
                 }
             }
-            let future_ts = Partitioned::with_range(max_pid, None, MzOffset::from(0));
-            data_cap.downgrade(&future_ts);
-            progress_cap.downgrade(&future_ts);
+            drop(data_cap);
+            drop(progress_cap);
 
             info!(
                 source_id = config.id.to_string(),
@@ -195,7 +214,10 @@ impl SourceRender for KafkaSourceConnection {
 
             let group_id = self.group_id(config.id);
             let KafkaSourceConnection {
-                connection, topic, ..
+                connection,
+                topic,
+                topic_is_pattern,
+                ..
             } = self;
             let (stats_tx, stats_rx) = crossbeam_channel::unbounded();
             let health_status = Arc::new(Mutex::new(None));
@@ -263,6 +285,7 @@ impl SourceRender for KafkaSourceConnection {
                             hint: None,
                         },
                         should_halt: true,
+                        ignored_columns: None,
                     };
                     health_output.give(&health_cap, (0, update)).await;
                     // IMPORTANT: wedge forever until the `SuspendAndRestart` is processed.
@@ -288,6 +311,10 @@ impl SourceRender for KafkaSourceConnection {
             let metadata_thread_handle = {
                 let partition_info = Arc::downgrade(&partition_info);
                 let topic = topic.clone();
+                let topic_pattern = topic_is_pattern.then(|| {
+                    Regex::new(&format!("^(?:{topic})$"))
+                        .expect("topic pattern validated during purification")
+                });
                 let consumer = Arc::clone(&consumer);
                 let metadata_refresh_interval = connection
                     .options
@@ -320,7 +347,12 @@ impl SourceRender for KafkaSourceConnection {
                             "kafka metadata thread: starting..."
                         );
                         while let Some(partition_info) = partition_info.upgrade() {
-                            let result = fetch_partition_info(consumer.client(), &topic);
+                            let result = match &topic_pattern {
+                                None => fetch_partition_info(consumer.client(), &topic),
+                                Some(pattern) => {
+                                    fetch_topic_pattern_info(consumer.client(), pattern)
+                                }
+                            };
                             trace!(
                                 source_id = config.id.to_string(),
                                 worker_id = config.worker_id,
@@ -359,13 +391,17 @@ impl SourceRender for KafkaSourceConnection {
                     .unwrap()
                     .unpark_on_drop()
             };
-            let partition_ids = start_offsets.keys().copied().collect();
+            let partition_ids = start_offsets
+                .keys()
+                .map(|pid| (upstream_topic(&topic, pid).to_string(), pid.partition))
+                .collect();
 
             let source_metrics = SourceReaderMetrics::new(&config.base_metrics, config.id);
             let offset_commit_metrics = source_metrics.offset_commit_metrics();
 
             let mut reader = KafkaSourceReader {
                 topic_name: topic.clone(),
+                topic_is_pattern,
                 source_name: config.name.clone(),
                 id: config.id,
                 partition_consumers: Vec::new(),
@@ -376,12 +412,15 @@ impl SourceRender for KafkaSourceConnection {
                 start_offsets,
                 stats_rx,
                 partition_info,
-                metadata_columns: self.metadata_columns.into_iter().map(|(_name, kind)| kind).collect(),
+                metadata_columns: self
+                    .metadata_columns
+                    .into_iter()
+                    .map(|(_name, kind)| kind)
+                    .collect(),
                 _metadata_thread_handle: metadata_thread_handle,
                 partition_metrics: KafkaPartitionMetrics::new(
                     config.base_metrics.clone(),
                     partition_ids,
-                    topic.clone(),
                     config.id,
                 ),
                 health_status,
@@ -416,12 +455,18 @@ impl SourceRender for KafkaSourceConnection {
             };
             tokio::pin!(offset_commit_loop);
 
-            let mut prev_pid_info: Option<BTreeMap<PartitionId, i64>> = None;
+            // The latest high watermark of every known partition.
+            let mut prev_watermarks: BTreeMap<KafkaPartition, i64> = BTreeMap::new();
             loop {
                 let partition_info = reader.partition_info.lock().unwrap().take();
-                if let Some(partitions) = partition_info {
-                    let max_pid = partitions.keys().last().cloned();
-                    let future_ts = Partitioned::with_range(max_pid, None, MzOffset::from(0));
+                if let Some(PartitionInfo {
+                    watermarks: partitions,
+                    unavailable_topics,
+                }) = partition_info
+                {
+                    // The range of partitions up to +inf is never exhausted, so there is always
+                    // at least one gap capability at which we can report errors.
+                    let err_cap = &gap_capabilities[0].data;
 
                     // Topics are identified by name but it's possible that a user recreates a
                     // topic with the same name but different configuration. Ideally we'd want to
@@ -430,63 +475,158 @@ impl SourceRender for KafkaSourceConnection {
                     // something like KIP-516 so we're left with heuristics.
                     //
                     // The first heuristic is whether the reported number of partitions went down
-                    if !PartialOrder::less_equal(data_cap.time(), &future_ts) {
-                        let prev_pid_count = prev_pid_info.map(|info| info.len()).unwrap_or(0);
-                        let pid_count = partitions.len();
-                        let err = SourceReaderError::other_definite(
-                            anyhow!( "topic was recreated: partition count regressed from {prev_pid_count} to {pid_count}")
-                        );
-                        let time = data_cap.time().clone();
-                        data_output.give(&data_cap, ((0, Err(err)), time, 1)).await;
+                    if let Some(err) = partition_count_regression(&known_partitions, &partitions) {
+                        let time = err_cap.time().clone();
+                        data_output.give(err_cap, ((0, Err(err)), time, 1)).await;
                         return;
                     }
 
-                    // The second heuristic is whether the high watermark regressed
-                    if let Some(prev_pid_info) = prev_pid_info {
-                        for (pid, prev_upper) in prev_pid_info {
-                            let upper = partitions[&pid];
-                            if !(prev_upper <= upper) {
-                                let err = SourceReaderError::other_definite(
-                                    anyhow!("topic was recreated: high watermark of partition {pid} regressed from {prev_upper} to {upper}")
-                                );
-                                let time = data_cap.time().clone();
-                                data_output.give(&data_cap, ((0, Err(err)), time, 1)).await;
-                                return;
-                            }
+                    // The second heuristic is whether the high watermark regressed. A partition
+                    // that is missing from this refresh keeps its previous high watermark, so a
+                    // topic that is recreated between two refreshes is caught as well.
+                    for (pid, &upper) in &partitions {
+                        let Some(&prev_upper) = prev_watermarks.get(pid) else {
+                            continue;
+                        };
+                        if !(prev_upper <= upper) {
+                            let topic = if pid.topic.is_empty() {
+                                "topic".to_string()
+                            } else {
+                                format!("topic {}", pid.topic)
+                            };
+                            let err = SourceReaderError::other_definite(anyhow!(
+                                "{topic} was recreated: high watermark of partition {} \
+                                regressed from {prev_upper} to {upper}",
+                                pid.partition
+                            ));
+                            let time = err_cap.time().clone();
+                            data_output.give(err_cap, ((0, Err(err)), time, 1)).await;
+                            return;
+                        }
+                    }
+                    prev_watermarks
+                        .extend(partitions.iter().map(|(pid, upper)| (pid.clone(), *upper)));
+
+                    // Topics matched by a `TOPIC PATTERN` can be deleted. Their partitions will
+                    // never produce data again, so we stop reading them and release their
+                    // capabilities, which lets the frontier of the source pass them.
+                    if reader.topic_is_pattern {
+                        let deleted_partitions: Vec<_> = known_partitions
+                            .iter()
+                            .filter(|pid| {
+                                !partitions.contains_key(*pid)
+                                    && !unavailable_topics.contains(&pid.topic)
+                                    && !partitions.keys().any(|p| p.topic == pid.topic)
+                            })
+                            .cloned()
+                            .collect();
+                        for pid in deleted_partitions {
+                            known_partitions.remove(&pid);
+                            prev_watermarks.remove(&pid);
+                            reader.remove_partition(&pid);
                         }
                     }
 
-                    for (&pid, &upper) in &partitions {
-                        if config.responsible_for(pid) {
-                            reader.ensure_partition(pid);
-                            if let Entry::Vacant(entry) = reader.partition_capabilities.entry(pid) {
-                                let start_offset = match reader.start_offsets.get(&pid) {
-                                    Some(&offset) => offset.try_into().unwrap(),
-                                    None => 0u64,
-                                };
-                                let part_min_ts = Partitioned::with_partition(pid, MzOffset::from(start_offset));
-                                let upper_offset = MzOffset::from(u64::try_from(upper).expect("invalid negative offset"));
-                                let part_upper_ts = Partitioned::with_partition(pid, upper_offset);
-
-                                // This is the moment at which we have discovered a new partition
-                                // and we need to make sure we produce its initial snapshot at a
-                                // single timestamp so that the source transitions from no data
-                                // from this partition to all the data of this partition. We do
-                                // this by initializing the data capability to the starting offset
-                                // and, importantly, the progress capability directly to the high
-                                // watermark. This jump of the progress capability ensures that
-                                // everything until the high watermark will be reclocked to a
-                                // single point.
-                                entry.insert(PartitionCapability {
-                                    data: data_cap.delayed(&part_min_ts),
-                                    progress: progress_cap.delayed(&part_upper_ts),
+                    // Every partition we haven't seen before lies in one of the gaps between the
+                    // partitions we know about. We split those gaps around the new partitions,
+                    // dropping the pieces that can't contain any partition.
+                    let new_partitions: Vec<_> = partitions
+                        .iter()
+                        .filter(|(pid, _)| !known_partitions.contains(*pid))
+                        .collect();
+
+                    // The only partitions that lie in no gap are those of deleted topics, whose
+                    // capabilities have been released. If such a partition shows up again, its
+                    // topic was recreated and we can't tell which of its data we have ingested.
+                    let recreated = new_partitions.iter().find(|(pid, _)| {
+                        !gap_capabilities
+                            .iter()
+                            .any(|gap| match gap.data.time().interval() {
+                                Interval::Range(lower, upper) => lower < *pid && upper > *pid,
+                                _ => false,
+                            })
+                    });
+                    if let Some((pid, _)) = recreated {
+                        let err = SourceReaderError::other_definite(anyhow!(
+                            "topic {} was recreated after it was deleted",
+                            upstream_topic(&topic, pid)
+                        ));
+                        let time = err_cap.time().clone();
+                        data_output.give(err_cap, ((0, Err(err)), time, 1)).await;
+                        return;
+                    }
+                    if !new_partitions.is_empty() {
+                        for gap in std::mem::take(&mut gap_capabilities) {
+                            let interval = gap.data.time().interval().clone();
+                            let Interval::Range(mut lower, upper) = interval else {
+                                unreachable!("gap capabilities always cover a range of partitions");
+                            };
+                            let mut split = false;
+                            for &(pid, &upper_offset) in &new_partitions {
+                                if !(lower < *pid && upper > *pid) {
+                                    continue;
+                                }
+                                let pid_bound = RangeBound::Elem(pid.clone());
+                                if !is_empty_gap(&lower, &pid_bound) {
+                                    let gap_ts = gap_timestamp(&lower, &pid_bound);
+                                    gap_capabilities.push(PartitionCapability {
+                                        data: gap.data.delayed(&gap_ts),
+                                        progress: gap.progress.delayed(&gap_ts),
+                                    });
+                                }
+                                if config.responsible_for(pid) {
+                                    let start_offset = match reader.start_offsets.get(pid) {
+                                        Some(&offset) => offset.try_into().unwrap(),
+                                        None => 0u64,
+                                    };
+                                    let part_min_ts = Partitioned::with_partition(
+                                        pid.clone(),
+                                        MzOffset::from(start_offset),
+                                    );
+                                    let upper_offset = MzOffset::from(
+                                        u64::try_from(upper_offset)
+                                            .expect("invalid negative offset"),
+                                    );
+                                    let part_upper_ts =
+                                        Partitioned::with_partition(pid.clone(), upper_offset);
+
+                                    // This is the moment at which we have discovered a new partition
+                                    // and we need to make sure we produce its initial snapshot at a
+                                    // single timestamp so that the source transitions from no data
+                                    // from this partition to all the data of this partition. We do
+                                    // this by initializing the data capability to the starting offset
+                                    // and, importantly, the progress capability directly to the high
+                                    // watermark. This jump of the progress capability ensures that
+                                    // everything until the high watermark will be reclocked to a
+                                    // single point.
+                                    let part_cap = PartitionCapability {
+                                        data: gap.data.delayed(&part_min_ts),
+                                        progress: gap.progress.delayed(&part_upper_ts),
+                                    };
+                                    reader.partition_capabilities.insert(pid.clone(), part_cap);
+                                }
+                                lower = pid_bound;
+                                split = true;
+                            }
+                            if !split {
+                                gap_capabilities.push(gap);
+                            } else if !is_empty_gap(&lower, &upper) {
+                                let gap_ts = gap_timestamp(&lower, &upper);
+                                gap_capabilities.push(PartitionCapability {
+                                    data: gap.data.delayed(&gap_ts),
+                                    progress: gap.progress.delayed(&gap_ts),
                                 });
                             }
                         }
+                        known_partitions
+                            .extend(new_partitions.into_iter().map(|(pid, _)| pid.clone()));
+                    }
+
+                    for pid in partitions.keys() {
+                        if config.responsible_for(pid) {
+                            reader.ensure_partition(pid.clone());
+                        }
                     }
-                    data_cap.downgrade(&future_ts);
-                    progress_cap.downgrade(&future_ts);
-                    prev_pid_info = Some(partitions);
                 }
 
                 // Poll the consumer once. We split the consumer's partitions out into separate
@@ -499,9 +639,14 @@ impl SourceRender for KafkaSourceConnection {
                 while let Some(result) = reader.consumer.poll(Duration::from_secs(0)) {
                     match result {
                         Err(e) => {
+                            let topic_kind = if reader.topic_is_pattern {
+                                "topic pattern"
+                            } else {
+                                "topic"
+                            };
                             let error = format!(
-                                "kafka error when polling consumer for source: {} topic: {} : {}",
-                                reader.source_name, reader.topic_name, e
+                                "kafka error when polling consumer for source: {} {}: {} : {}",
+                                reader.source_name, topic_kind, reader.topic_name, e
                             );
                             let status =
                                 HealthStatusUpdate::status(HealthStatus::StalledWithError {
@@ -511,8 +656,16 @@ impl SourceRender for KafkaSourceConnection {
                             health_output.give(&health_cap, (0, status)).await;
                         }
                         Ok(message) => {
-                            let (message, ts) =
-                                construct_source_message(&message, &reader.metadata_columns);
+                            let (message, ts) = construct_source_message(
+                                &message,
+                                &reader.metadata_columns,
+                                reader.topic_is_pattern,
+                            );
+                            // Messages of partitions of deleted topics can still be in flight
+                            // after we stopped reading them.
+                            if !reader.last_offsets.contains_key(&ts.0) {
+                                continue;
+                            }
                             if let Some((msg, time, diff)) = reader.handle_message(message, ts) {
                                 let pid = time.partition().unwrap();
                                 let part_cap = &reader.partition_capabilities[pid].data;
@@ -540,17 +693,19 @@ impl SourceRender for KafkaSourceConnection {
                             }
                             Ok(None) => continue,
                             Err(err) => {
-                                let pid = consumer.pid();
+                                let pid = consumer.partition();
                                 let last_offset = reader
                                     .last_offsets
-                                    .get(&pid)
+                                    .get(pid)
                                     .expect("partition known to be installed");
 
                                 let status = HealthStatus::StalledWithError {
                                     error: format!(
-                                        "error consuming from source: {} topic: {topic}: partition:\
-                                        {pid} last processed offset: {last_offset} : {err}",
-                                        config.name
+                                        "error consuming from source: {} topic: {}: partition:\
+                                        {} last processed offset: {last_offset} : {err}",
+                                        config.name,
+                                        upstream_topic(&topic, pid),
+                                        pid.partition,
                                     ),
                                     hint: None,
                                 };
@@ -566,14 +721,17 @@ impl SourceRender for KafkaSourceConnection {
                 reader.partition_consumers = consumers;
 
                 let positions = reader.consumer.position().unwrap();
-                let topic_positions = positions.elements_for_topic(&reader.topic_name);
-                for position in topic_positions {
+                for position in positions.elements() {
                     // The offset begins in the `Offset::Invalid` state in which case we simply
                     // skip this partition.
                     if let Offset::Offset(offset) = position.offset() {
-                        let pid = position.partition();
+                        let pid = kafka_partition(
+                            reader.topic_is_pattern,
+                            position.topic(),
+                            position.partition(),
+                        );
                         let upper_offset = MzOffset::from(u64::try_from(offset).unwrap());
-                        let upper = Partitioned::with_partition(pid, upper_offset);
+                        let upper = Partitioned::with_partition(pid.clone(), upper_offset);
 
                         let part_cap = reader.partition_capabilities.get_mut(&pid).unwrap();
                         part_cap.data.downgrade(&upper);
@@ -617,7 +775,7 @@ impl SourceRender for KafkaSourceConnection {
 impl KafkaOffsetCommiter {
     async fn commit_offsets(
         &self,
-        frontier: Antichain<Partitioned<PartitionId, MzOffset>>,
+        frontier: Antichain<Partitioned<KafkaPartition, MzOffset>>,
     ) -> Result<(), anyhow::Error> {
         use rdkafka::consumer::CommitMode;
 
@@ -636,7 +794,8 @@ impl KafkaOffsetCommiter {
             for (pid, offset) in offsets {
                 let offset_to_commit =
                     Offset::Offset(offset.offset.try_into().expect("offset to be vald i64"));
-                tpl.add_partition_offset(&self.topic_name, pid, offset_to_commit)
+                let topic = upstream_topic(&self.topic_name, &pid);
+                tpl.add_partition_offset(topic, pid.partition, offset_to_commit)
                     .expect("offset known to be valid");
             }
             let consumer = Arc::clone(&self.consumer);
@@ -652,77 +811,123 @@ impl KafkaOffsetCommiter {
 
 impl KafkaSourceReader {
     /// Ensures that a partition queue for `pid` exists.
-    fn ensure_partition(&mut self, pid: PartitionId) {
+    fn ensure_partition(&mut self, pid: KafkaPartition) {
         if self.last_offsets.contains_key(&pid) {
             return;
         }
 
         let start_offset = self.start_offsets.get(&pid).copied().unwrap_or(0);
-        self.create_partition_queue(pid, Offset::Offset(start_offset));
+        self.create_partition_queue(pid.clone(), Offset::Offset(start_offset));
 
         let prev = self.last_offsets.insert(pid, start_offset - 1);
 
         assert!(prev.is_none());
     }
 
-    /// Creates a new partition queue for `partition_id`.
-    fn create_partition_queue(&mut self, partition_id: PartitionId, initial_offset: Offset) {
+    /// Stops reading from `pid`, whose topic has been deleted, and releases its capabilities.
+    fn remove_partition(&mut self, pid: &KafkaPartition) {
+        self.partition_capabilities.remove(pid);
+        self.start_offsets.remove(pid);
+        if self.last_offsets.remove(pid).is_none() {
+            return;
+        }
+
+        let topic = upstream_topic(&self.topic_name, pid).to_string();
         info!(
             source_id = self.id.to_string(),
             worker_id = self.worker_id,
             num_workers = self.worker_count,
-            "activating Kafka queue for topic {}, partition {}",
-            self.topic_name,
-            partition_id,
+            "deactivating Kafka queue for deleted topic {}, partition {}",
+            topic,
+            pid.partition,
         );
 
-        // Collect old partition assignments
+        self.partition_consumers.retain(|pc| pc.partition != *pid);
         let tpl = self.consumer.assignment().unwrap();
-        // Create list from assignments
         let mut partition_list = TopicPartitionList::new();
-        for partition in tpl.elements_for_topic(&self.topic_name) {
+        for partition in tpl.elements() {
+            if partition.topic() == topic && partition.partition() == pid.partition {
+                continue;
+            }
             partition_list
                 .add_partition_offset(partition.topic(), partition.partition(), partition.offset())
                 .expect("offset known to be valid");
         }
-        // Add new partition
-        partition_list
-            .add_partition_offset(&self.topic_name, partition_id, initial_offset)
-            .expect("offset known to be valid");
         self.consumer
             .assign(&partition_list)
             .expect("assignment known to be valid");
+        self.split_partition_queues();
+        assert_eq!(
+            self.consumer.assignment().unwrap().count(),
+            self.partition_consumers.len()
+        );
+    }
 
-        // Since librdkafka v1.6.0, we need to recreate all partition queues
-        // after every call to `self.consumer.assign`.
+    /// Recreates the queues of all partition consumers, which is necessary after every call to
+    /// `self.consumer.assign` since librdkafka v1.6.0.
+    fn split_partition_queues(&mut self) {
         let context = Arc::clone(self.consumer.context());
         for pc in &mut self.partition_consumers {
             pc.partition_queue = self
                 .consumer
-                .split_partition_queue(&self.topic_name, pc.pid)
+                .split_partition_queue(&pc.topic, pc.partition.partition)
                 .expect("partition known to be valid");
             pc.partition_queue.set_nonempty_callback({
                 let context = Arc::clone(&context);
                 move || context.inner().activate()
             });
         }
+    }
 
+    /// Creates a new partition queue for `partition`.
+    fn create_partition_queue(&mut self, partition: KafkaPartition, initial_offset: Offset) {
+        let topic = upstream_topic(&self.topic_name, &partition).to_string();
+        let partition_id = partition.partition;
+        info!(
+            source_id = self.id.to_string(),
+            worker_id = self.worker_id,
+            num_workers = self.worker_count,
+            "activating Kafka queue for topic {}, partition {}",
+            topic,
+            partition_id,
+        );
+
+        // Collect old partition assignments
+        let tpl = self.consumer.assignment().unwrap();
+        // Create list from assignments
+        let mut partition_list = TopicPartitionList::new();
+        for partition in tpl.elements() {
+            partition_list
+                .add_partition_offset(partition.topic(), partition.partition(), partition.offset())
+                .expect("offset known to be valid");
+        }
+        // Add new partition
+        partition_list
+            .add_partition_offset(&topic, partition_id, initial_offset)
+            .expect("offset known to be valid");
+        self.consumer
+            .assign(&partition_list)
+            .expect("assignment known to be valid");
+
+        // Since librdkafka v1.6.0, we need to recreate all partition queues
+        // after every call to `self.consumer.assign`.
+        self.split_partition_queues();
+
+        let context = Arc::clone(self.consumer.context());
         let mut partition_queue = self
             .consumer
-            .split_partition_queue(&self.topic_name, partition_id)
+            .split_partition_queue(&topic, partition_id)
             .expect("partition known to be valid");
         partition_queue.set_nonempty_callback(move || context.inner().activate());
         self.partition_consumers.push(PartitionConsumer::new(
-            partition_id,
+            partition,
+            topic,
             partition_queue,
             self.metadata_columns.clone(),
+            self.topic_is_pattern,
         ));
         assert_eq!(
-            self.consumer
-                .assignment()
-                .unwrap()
-                .elements_for_topic(&self.topic_name)
-                .len(),
+            self.consumer.assignment().unwrap().count(),
             self.partition_consumers.len()
         );
     }
@@ -730,10 +935,11 @@ impl KafkaSourceReader {
     /// Fast-forward consumer to specified Kafka Offset. Prints a warning if failed to do so
     /// Assumption: if offset does not exist (for instance, because of compaction), will seek
     /// to the next available offset
-    fn fast_forward_consumer(&self, pid: PartitionId, next_offset: i64) {
+    fn fast_forward_consumer(&self, pid: &KafkaPartition, next_offset: i64) {
+        let topic = upstream_topic(&self.topic_name, pid);
         let res = self.consumer.seek(
-            &self.topic_name,
-            pid,
+            topic,
+            pid.partition,
             Offset::Offset(next_offset),
             Duration::from_secs(1),
         );
@@ -741,7 +947,7 @@ impl KafkaSourceReader {
             Ok(_) => {
                 let res = self.consumer.position().unwrap_or_default().to_topic_map();
                 let position = res
-                    .get(&(self.topic_name.clone(), pid))
+                    .get(&(topic.to_string(), pid.partition))
                     .and_then(|p| match p {
                         Offset::Offset(o) => Some(o),
                         _ => None,
@@ -798,15 +1004,19 @@ impl KafkaSourceReader {
         while let Ok(stats) = self.stats_rx.try_recv() {
             match serde_json::from_str::<Statistics>(&stats.to_string()) {
                 Ok(statistics) => {
-                    let topic = statistics.topics.get(&self.topic_name);
-                    match topic {
-                        Some(topic) => {
-                            for (id, partition) in &topic.partitions {
-                                self.partition_metrics
-                                    .set_offset_max(*id, partition.hi_offset);
-                            }
+                    if !self.topic_is_pattern && !statistics.topics.contains_key(&self.topic_name) {
+                        error!("No stats found for topic: {}", &self.topic_name);
+                    }
+                    for (name, topic) in &statistics.topics {
+                        // Sources created with `TOPIC PATTERN` are only assigned partitions of
+                        // matching topics.
+                        if !self.topic_is_pattern && *name != self.topic_name {
+                            continue;
+                        }
+                        for (id, partition) in &topic.partitions {
+                            self.partition_metrics
+                                .set_offset_max(name, *id, partition.hi_offset);
                         }
-                        None => error!("No stats found for topic: {}", &self.topic_name),
                     }
                 }
                 Err(e) => {
@@ -821,10 +1031,10 @@ impl KafkaSourceReader {
     fn handle_message(
         &mut self,
         message: SourceMessage<Option<Vec<u8>>, Option<Vec<u8>>>,
-        (partition, offset): (PartitionId, MzOffset),
+        (partition, offset): (KafkaPartition, MzOffset),
     ) -> Option<(
         SourceMessage<Option<Vec<u8>>, Option<Vec<u8>>>,
-        Partitioned<PartitionId, MzOffset>,
+        Partitioned<KafkaPartition, MzOffset>,
         Diff,
     )> {
         // Offsets are guaranteed to be 1) monotonically increasing *unless* there is
@@ -862,7 +1072,7 @@ impl KafkaSourceReader {
                 last_offset + 1,
             );
             // Seek to the *next* offset that we have not yet processed
-            self.fast_forward_consumer(partition, last_offset + 1);
+            self.fast_forward_consumer(&partition, last_offset + 1);
             // We explicitly should not consume the message as we have already processed it
             // However, we make sure to activate the source to make sure that we get a chance
             // to read from this consumer again (even if no new data arrives)
@@ -879,9 +1089,10 @@ impl KafkaSourceReader {
 fn construct_source_message(
     msg: &BorrowedMessage<'_>,
     metadata_columns: &[KafkaMetadataKind],
+    topic_is_pattern: bool,
 ) -> (
     SourceMessage<Option<Vec<u8>>, Option<Vec<u8>>>,
-    (PartitionId, MzOffset),
+    (KafkaPartition, MzOffset),
 ) {
     let pid = msg.partition();
     let Ok(offset) = u64::try_from(msg.offset()) else {
@@ -897,6 +1108,7 @@ fn construct_source_message(
         match kind {
             KafkaMetadataKind::Partition => packer.push(Datum::from(pid)),
             KafkaMetadataKind::Offset => packer.push(Datum::UInt64(offset)),
+            KafkaMetadataKind::Topic => packer.push(Datum::String(msg.topic())),
             KafkaMetadataKind::Timestamp => {
                 let ts = msg
                     .timestamp()
@@ -937,30 +1149,39 @@ fn construct_source_message(
         value: msg.payload().map(|p| p.to_vec()),
         metadata,
     };
-    (msg, (pid, offset.into()))
+    let partition = kafka_partition(topic_is_pattern, msg.topic(), pid);
+    (msg, (partition, offset.into()))
 }
 
 /// Wrapper around a partition containing the underlying consumer
 struct PartitionConsumer {
-    /// the partition id with which this consumer is associated
-    pid: PartitionId,
+    /// the partition with which this consumer is associated
+    partition: KafkaPartition,
+    /// the upstream topic of the partition
+    topic: String,
     /// The underlying Kafka partition queue
     partition_queue: PartitionQueue<BrokerRewritingClientContext<GlueConsumerContext>>,
     /// Additional metadata columns requested by the user
     metadata_columns: Vec<KafkaMetadataKind>,
+    /// Whether the source was created with `TOPIC PATTERN`
+    topic_is_pattern: bool,
 }
 
 impl PartitionConsumer {
     /// Creates a new partition consumer from underlying Kafka consumer
     fn new(
-        pid: PartitionId,
+        partition: KafkaPartition,
+        topic: String,
         partition_queue: PartitionQueue<BrokerRewritingClientContext<GlueConsumerContext>>,
         metadata_columns: Vec<KafkaMetadataKind>,
+        topic_is_pattern: bool,
     ) -> Self {
         PartitionConsumer {
-            pid,
+            partition,
+            topic,
             partition_queue,
             metadata_columns,
+            topic_is_pattern,
         }
     }
 
@@ -975,14 +1196,15 @@ impl PartitionConsumer {
     ) -> Result<
         Option<(
             SourceMessage<Option<Vec<u8>>, Option<Vec<u8>>>,
-            (PartitionId, MzOffset),
+            (KafkaPartition, MzOffset),
         )>,
         KafkaError,
     > {
         match self.partition_queue.poll(Duration::from_millis(0)) {
             Some(Ok(msg)) => {
-                let (msg, ts) = construct_source_message(&msg, &self.metadata_columns);
-                assert_eq!(ts.0, self.pid);
+                let (msg, ts) =
+                    construct_source_message(&msg, &self.metadata_columns, self.topic_is_pattern);
+                assert_eq!(ts.0, self.partition);
                 Ok(Some((msg, ts)))
             }
             Some(Err(err)) => Err(err),
@@ -990,9 +1212,9 @@ impl PartitionConsumer {
         }
     }
 
-    /// Return the partition id for this PartitionConsumer
-    fn pid(&self) -> PartitionId {
-        self.pid
+    /// Return the partition for this PartitionConsumer
+    fn partition(&self) -> &KafkaPartition {
+        &self.partition
     }
 }
 
@@ -1134,14 +1356,124 @@ mod tests {
 fn fetch_partition_info<C: ClientContext>(
     client: &Client<C>,
     topic: &str,
-) -> Result<BTreeMap<PartitionId, i64>, anyhow::Error> {
+) -> Result<PartitionInfo, anyhow::Error> {
     let pids = get_partitions(client, topic, DEFAULT_FETCH_METADATA_TIMEOUT)?;
 
-    let mut result = BTreeMap::new();
+    let mut watermarks = BTreeMap::new();
 
     for pid in pids {
         let (_low, high) = client.fetch_watermarks(topic, pid, DEFAULT_FETCH_METADATA_TIMEOUT)?;
-        result.insert(pid, high);
+        watermarks.insert(KafkaPartition::new(String::new(), pid), high);
     }
-    Ok(result)
+    Ok(PartitionInfo {
+        watermarks,
+        unavailable_topics: BTreeSet::new(),
+    })
+}
+
+/// Fetches the list of partitions of all topics matching `pattern` and their corresponding high
+/// watermark
+fn fetch_topic_pattern_info<C: ClientContext>(
+    client: &Client<C>,
+    pattern: &Regex,
+) -> Result<PartitionInfo, anyhow::Error> {
+    let topics = get_matching_topic_partitions(
+        client,
+        |topic| pattern.is_match(topic),
+        DEFAULT_FETCH_METADATA_TIMEOUT,
+    )?;
+
+    let mut watermarks = BTreeMap::new();
+    let mut unavailable_topics = BTreeSet::new();
+
+    for (topic, pids) in topics {
+        let Some(pids) = pids else {
+            unavailable_topics.insert(topic);
+            continue;
+        };
+        for pid in pids {
+            let (_low, high) =
+                client.fetch_watermarks(&topic, pid, DEFAULT_FETCH_METADATA_TIMEOUT)?;
+            watermarks.insert(KafkaPartition::new(topic.clone(), pid), high);
+        }
+    }
+    Ok(PartitionInfo {
+        watermarks,
+        unavailable_topics,
+    })
+}
+
+/// Returns the partition of a source that partition `pid` of the upstream `topic` corresponds
+/// to. Only sources created with `TOPIC PATTERN` record the topic of their partitions.
+fn kafka_partition(topic_is_pattern: bool, topic: &str, pid: PartitionId) -> KafkaPartition {
+    let topic = if topic_is_pattern {
+        topic.to_string()
+    } else {
+        String::new()
+    };
+    KafkaPartition::new(topic, pid)
+}
+
+/// Returns the upstream topic of `partition`, which is `topic_name` for sources that read a
+/// single topic.
+fn upstream_topic<'a>(topic_name: &'a str, partition: &'a KafkaPartition) -> &'a str {
+    if partition.topic.is_empty() {
+        topic_name
+    } else {
+        &partition.topic
+    }
+}
+
+/// Checks whether a topic lost any of the partitions we previously discovered, which indicates
+/// that it was recreated. Topics that are missing from `partitions` altogether are ignored, as
+/// topics matched by a `TOPIC PATTERN` may be deleted.
+fn partition_count_regression(
+    known_partitions: &BTreeSet<KafkaPartition>,
+    partitions: &BTreeMap<KafkaPartition, i64>,
+) -> Option<SourceReaderError> {
+    let topics: BTreeSet<_> = partitions.keys().map(|pid| pid.topic.as_str()).collect();
+    for topic in topics {
+        let known = known_partitions.iter().filter(|pid| pid.topic == topic);
+        if known.clone().all(|pid| partitions.contains_key(pid)) {
+            continue;
+        }
+        let prev_pid_count = known.count();
+        let pid_count = partitions.keys().filter(|pid| pid.topic == topic).count();
+        let topic = if topic.is_empty() {
+            "topic".to_string()
+        } else {
+            format!("topic {topic}")
+        };
+        return Some(SourceReaderError::other_definite(anyhow!(
+            "{topic} was recreated: partition count regressed from {prev_pid_count} to {pid_count}"
+        )));
+    }
+    None
+}
+
+/// Reports whether no partition can ever appear strictly between `lower` and `upper`.
+fn is_empty_gap(lower: &RangeBound<KafkaPartition>, upper: &RangeBound<KafkaPartition>) -> bool {
+    match (lower, upper) {
+        // Sources reading a single topic know that partition ids start at zero. Any topic name
+        // can precede the first topic of a `TOPIC PATTERN` source.
+        (RangeBound::Bottom, RangeBound::Elem(upper)) => {
+            upper.topic.is_empty() && upper.partition == 0
+        }
+        (RangeBound::Elem(lower), RangeBound::Elem(upper)) => {
+            lower.topic == upper.topic && lower.partition.checked_add(1) == Some(upper.partition)
+        }
+        _ => false,
+    }
+}
+
+/// Returns the timestamp of the capability covering the partitions between `lower` and `upper`.
+fn gap_timestamp(
+    lower: &RangeBound<KafkaPartition>,
+    upper: &RangeBound<KafkaPartition>,
+) -> Partitioned<KafkaPartition, MzOffset> {
+    let elem = |bound: &RangeBound<KafkaPartition>| match bound {
+        RangeBound::Elem(pid) => Some(pid.clone()),
+        RangeBound::Bottom | RangeBound::Top => None,
+    };
+    Partitioned::with_range(elem(lower), elem(upper), MzOffset::from(0))
 }
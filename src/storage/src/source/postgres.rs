@@ -50,6 +50,12 @@
 //! The error streams from both of those operators are published to the source status and also
 //! trigger a restart of the dataflow.
 //!
+//! Authentication failures get special treatment: the credentials of a connection can be rotated
+//! behind our back by an external secrets manager, so both the table reader and the replication
+//! reader discard any cached copies of the connection's secrets, read them again and reconnect
+//! with backoff, reporting the source as reconnecting rather than stalled. Only once
+//! `pg_source_auth_retry_timeout` has elapsed is the failure reported as a transient error.
+//!
 //! ```text
 //!    ┏━━━━━━━━━━━━━━┓
 //!    ┃    table     ┃
@@ -83,17 +89,18 @@ use std::any::Any;
 use std::collections::BTreeMap;
 use std::convert::Infallible;
 use std::rc::Rc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use differential_dataflow::Collection;
 use mz_expr::{EvalError, MirScalarExpr};
 use mz_ore::error::ErrorExt;
 use mz_postgres_util::desc::PostgresTableDesc;
 use mz_postgres_util::PostgresError;
-use mz_repr::{Datum, Diff, Row};
+use mz_repr::{Datum, Diff, GlobalId, Row};
 use mz_sql_parser::ast::{display::AstDisplay, Ident};
 use mz_storage_types::connections::ConnectionContext;
 use mz_storage_types::errors::SourceErrorDetails;
+use mz_storage_types::parameters::PgSourceAuthRetryConfig;
 use mz_storage_types::sources::{MzOffset, PostgresSourceConnection, SourceTimestamp};
 use serde::{Deserialize, Serialize};
 use timely::dataflow::operators::{Concat, Map};
@@ -102,6 +109,7 @@ use timely::progress::Antichain;
 use tokio_postgres::error::SqlState;
 use tokio_postgres::types::PgLsn;
 use tokio_postgres::{Client, SimpleQueryMessage, SimpleQueryRow};
+use tracing::warn;
 
 use crate::source::types::{HealthStatus, HealthStatusUpdate, SourceRender};
 use crate::source::{RawSourceCreationConfig, SourceMessage, SourceReaderError};
@@ -163,20 +171,21 @@ impl SourceRender for PostgresSourceConnection {
             }
         }
 
-        let (snapshot_updates, rewinds, snapshot_err, snapshot_token) = snapshot::render(
-            scope.clone(),
-            config.clone(),
-            self.clone(),
-            context.clone(),
-            subsource_resume_uppers.clone(),
-            table_info.clone(),
-        );
+        let (snapshot_updates, rewinds, snapshot_err, snapshot_health, snapshot_token) =
+            snapshot::render(
+                scope.clone(),
+                config.clone(),
+                self.clone(),
+                context.clone(),
+                subsource_resume_uppers.clone(),
+                table_info.clone(),
+            );
 
-        let (repl_updates, uppers, repl_err, repl_token) = replication::render(
+        let (repl_updates, uppers, repl_err, repl_health, repl_token) = replication::render(
             scope.clone(),
             config,
-            self,
-            context,
+            self.clone(),
+            context.clone(),
             subsource_resume_uppers,
             table_info,
             &rewinds,
@@ -193,6 +202,11 @@ impl SourceRender for PostgresSourceConnection {
         });
 
         let health = snapshot_err.concat(&repl_err).flat_map(move |err| {
+            // The dataflow is about to restart. If the credentials were rejected, make sure the
+            // restarted dataflow reads them anew rather than retrying with cached copies.
+            if err.is_authentication_failure() {
+                invalidate_credentials(&self, &context);
+            }
             let update = HealthStatus::StalledWithError {
                 error: err.display_with_causes().to_string(),
                 hint: None,
@@ -201,6 +215,7 @@ impl SourceRender for PostgresSourceConnection {
             let halt_status = HealthStatusUpdate {
                 update: update.clone(),
                 should_halt: true,
+                ignored_columns: None,
             };
             let mut statuses = vec![(0, halt_status)];
 
@@ -209,11 +224,13 @@ impl SourceRender for PostgresSourceConnection {
                 let status = HealthStatusUpdate {
                     update: update.clone(),
                     should_halt: false,
+                    ignored_columns: None,
                 };
                 (*index, status)
             }));
             statuses
         });
+        let health = health.concat(&snapshot_health).concat(&repl_health);
 
         let token = Rc::new((snapshot_token, repl_token));
         (updates, Some(uppers), health, token)
@@ -254,6 +271,19 @@ pub enum TransientError {
     Generic(#[from] anyhow::Error),
 }
 
+impl TransientError {
+    /// Reports whether the upstream database rejected our credentials.
+    fn is_authentication_failure(&self) -> bool {
+        let err = match self {
+            TransientError::SQLClient(err) => err,
+            TransientError::PostgresError(PostgresError::Postgres(err)) => err,
+            _ => return false,
+        };
+        err.code() == Some(&SqlState::INVALID_PASSWORD)
+            || err.code() == Some(&SqlState::INVALID_AUTHORIZATION_SPECIFICATION)
+    }
+}
+
 /// A definite error that always ends up in the collection of a specific table.
 #[derive(Debug, Clone, Serialize, Deserialize, thiserror::Error)]
 pub enum DefiniteError {
@@ -288,6 +318,103 @@ impl From<DefiniteError> for SourceReaderError {
     }
 }
 
+/// Reads the current credentials of `connection` and returns the configuration to connect to the
+/// upstream with.
+async fn connection_config(
+    connection: &PostgresSourceConnection,
+    context: &ConnectionContext,
+    config: &RawSourceCreationConfig,
+) -> Result<mz_postgres_util::Config, TransientError> {
+    Ok(connection
+        .connection
+        .config(&*context.secrets_reader)
+        .await?
+        .tcp_timeouts(config.params.pg_source_tcp_timeouts.clone()))
+}
+
+/// Reads the current credentials of `connection` and opens a replication connection with them.
+async fn connect_replication(
+    connection: &PostgresSourceConnection,
+    context: &ConnectionContext,
+    config: &RawSourceCreationConfig,
+) -> Result<(mz_postgres_util::Config, Client), TransientError> {
+    let connection_config = connection_config(connection, context, config).await?;
+    let client = connection_config.connect_replication().await?;
+    Ok((connection_config, client))
+}
+
+/// Discards any cached copies of the secrets holding the credentials of `connection`, so that
+/// the next connection attempt observes their current contents.
+fn invalidate_credentials(connection: &PostgresSourceConnection, context: &ConnectionContext) {
+    for id in connection.connection.credential_secrets() {
+        context.secrets_reader.invalidate(id);
+    }
+}
+
+/// The backoff before the first attempt to reconnect after an authentication failure.
+const AUTH_RETRY_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// The status reported while reconnecting after the upstream rejected our credentials.
+fn auth_retry_status() -> HealthStatusUpdate {
+    HealthStatusUpdate::status(HealthStatus::Reconnecting {
+        reason: "credentials rotated, reconnecting".into(),
+    })
+}
+
+/// Exponential backoff for reconnecting after the upstream rejected our credentials.
+struct AuthRetry {
+    deadline: Instant,
+    backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl AuthRetry {
+    fn new(config: &PgSourceAuthRetryConfig) -> Self {
+        AuthRetry {
+            deadline: Instant::now() + config.timeout,
+            backoff: std::cmp::min(AUTH_RETRY_INITIAL_BACKOFF, config.max_backoff),
+            max_backoff: config.max_backoff,
+        }
+    }
+
+    /// Returns how long to wait before the next attempt, or `None` if we should give up.
+    fn next_backoff(&mut self) -> Option<Duration> {
+        let remaining = self.deadline.checked_duration_since(Instant::now())?;
+        if remaining.is_zero() {
+            return None;
+        }
+        let backoff = std::cmp::min(self.backoff, remaining);
+        self.backoff = std::cmp::min(self.backoff * 2, self.max_backoff);
+        Some(backoff)
+    }
+
+    /// Handles a failed attempt of source `id` to connect to the upstream. If the upstream
+    /// rejected our credentials and we haven't given up yet, discards any cached copies of the
+    /// credentials of `connection` and returns how long to wait before the next attempt.
+    /// Otherwise returns `err`.
+    fn retry(
+        &mut self,
+        err: TransientError,
+        id: GlobalId,
+        connection: &PostgresSourceConnection,
+        context: &ConnectionContext,
+    ) -> Result<Duration, TransientError> {
+        if !err.is_authentication_failure() {
+            return Err(err);
+        }
+        let Some(backoff) = self.next_backoff() else {
+            return Err(err);
+        };
+        warn!(
+            %id,
+            "authentication failed, re-reading credentials and reconnecting in {backoff:?}: {}",
+            err.display_with_causes()
+        );
+        invalidate_credentials(connection, context);
+        Ok(backoff)
+    }
+}
+
 /// Ensures the replication slot of this connection is created.
 async fn ensure_replication_slot(client: &Client, slot: &str) -> Result<(), TransientError> {
     let slot = Ident::from(slot).to_ast_string();
@@ -355,15 +482,21 @@ async fn simple_query_opt(
     }
 }
 
-/// Casts a text row into the target types
+/// Casts a text row into the target types. The columns whose indices are in `absent`, which must be
+/// sorted, have no value and are represented by `Datum::Dummy` in the result instead.
 fn cast_row(
     casts: &[MirScalarExpr],
     datums: &[Datum<'_>],
+    absent: &[usize],
     row: &mut Row,
 ) -> Result<(), DefiniteError> {
     let arena = mz_repr::RowArena::new();
     let mut packer = row.packer();
-    for column_cast in casts {
+    for (i, column_cast) in casts.iter().enumerate() {
+        if absent.binary_search(&i).is_ok() {
+            packer.push(Datum::Dummy);
+            continue;
+        }
         let datum = column_cast
             .eval(datums, &arena)
             .map_err(DefiniteError::CastError)?;
@@ -66,16 +66,44 @@
 //! messages at previous LSNs, because they would have been already generated and received. We
 //! therefore connect the keepalive messages directly to our capability.
 //!
+//! # Truncation
+//!
+//! An upstream `TRUNCATE` is handled according to the truncate behavior configured for each
+//! table. By default the subsource is permanently errored. Tables configured to ignore truncations
+//! simply skip the message. For tables configured to retract, the replication reader needs the
+//! contents of the subsource as of the truncate LSN, which it can only learn from persist. It
+//! therefore holds back its progress at the truncate LSN until the source's committed upper
+//! reaches it, at which point the persisted contents of the subsource are exactly its contents
+//! before the truncation. The reader then reads a snapshot of the subsource's shard and retracts
+//! every row in it at the truncate LSN.
+//!
+//! # Upsert mode
+//!
+//! Updates and deletes only carry the full old row of tables with `REPLICA IDENTITY FULL`, and
+//! updates never carry the TOASTed values they leave unchanged unless the old row is present.
+//! Tables that are ingested in upsert mode don't need either: the replication reader turns their
+//! changes into upserts and deletions of their primary key and leaves out the columns it doesn't
+//! know, which are then recovered from the upsert state that is kept for the table's subsource.
+//! Within a transaction the reader already collapses the changes to each key into a single
+//! command, since the commands of a transaction all happen at its LSN.
+//!
+//! Since the snapshot of such a table is made of upserts as well, the rewinding of its snapshot
+//! simply drops the commands in the replication stream that the snapshot already reflects.
+//!
 //! [1]: https://www.postgresql.org/docs/15/protocol-replication.html#PROTOCOL-REPLICATION-START-REPLICATION
 //! [2]: https://www.postgresql.org/message-id/CAFPTHDZS9O9WG02EfayBd6oONzK%2BqfUxS6AbVLJ7W%2BKECza2gg%40mail.gmail.com
 
 use std::any::Any;
+use std::cell::RefCell;
+use std::collections::btree_map::Entry;
 use std::collections::BTreeMap;
 use std::convert::Infallible;
 use std::pin::pin;
 use std::rc::Rc;
+use std::sync::Arc;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
+use anyhow::{anyhow, Context};
 use bytes::Bytes;
 use differential_dataflow::{AsCollection, Collection};
 use futures::{future, future::select, FutureExt, Stream as AsyncStream, StreamExt, TryStreamExt};
@@ -87,6 +115,7 @@ use serde::{Deserialize, Serialize};
 use timely::dataflow::channels::pact::Exchange;
 use timely::dataflow::{Scope, Stream};
 use timely::progress::Antichain;
+use timely::PartialOrder;
 use tokio_postgres::replication::LogicalReplicationStream;
 use tokio_postgres::types::PgLsn;
 use tokio_postgres::Client;
@@ -96,17 +125,22 @@ use mz_expr::MirScalarExpr;
 use mz_ore::cast::CastFrom;
 use mz_ore::collections::HashSet;
 use mz_ore::result::ResultExt;
+use mz_persist_client::read::ReadHandle;
+use mz_persist_client::Diagnostics;
+use mz_persist_types::codec_impls::UnitSchema;
 use mz_postgres_util::desc::PostgresTableDesc;
-use mz_repr::{Datum, DatumVec, Diff, GlobalId, Row};
+use mz_repr::{Datum, DatumVec, Diff, GlobalId, Row, Timestamp};
 use mz_sql_parser::ast::{display::AstDisplay, Ident};
 use mz_storage_types::connections::ConnectionContext;
-use mz_storage_types::sources::{MzOffset, PostgresSourceConnection};
+use mz_storage_types::sources::{
+    MzOffset, PostgresSourceConnection, PostgresTruncateBehavior, SourceData,
+};
 use mz_timely_util::builder_async::{Event as AsyncEvent, OperatorBuilder as AsyncOperatorBuilder};
 use mz_timely_util::operator::StreamExt as TimelyStreamExt;
 
 use crate::source::postgres::verify_schema;
 use crate::source::postgres::{metrics::PgSourceMetrics, DefiniteError, TransientError};
-use crate::source::types::SourceReaderError;
+use crate::source::types::{HealthStatus, HealthStatusUpdate, SourceReaderError};
 use crate::source::RawSourceCreationConfig;
 
 /// Postgres epoch is 2000-01-01T00:00:00Z
@@ -146,6 +180,7 @@ pub(crate) fn render<G: Scope<Timestamp = MzOffset>>(
     Collection<G, (usize, Result<Row, SourceReaderError>), Diff>,
     Stream<G, Infallible>,
     Stream<G, Rc<TransientError>>,
+    Stream<G, (usize, HealthStatusUpdate)>,
     Rc<dyn Any>,
 ) {
     let op_name = format!("ReplicationReader({})", config.id);
@@ -155,6 +190,8 @@ pub(crate) fn render<G: Scope<Timestamp = MzOffset>>(
     let mut rewind_input = builder.new_input(rewind_stream, Exchange::new(move |_| slot_reader));
     let (mut data_output, data_stream) = builder.new_output();
     let (_upper_output, upper_stream) = builder.new_output();
+    let (mut health_output, health_stream) = builder.new_output();
+    let (mut truncate_output, truncate_stream) = builder.new_output();
 
     let metrics = PgSourceMetrics::new(&config.base_metrics, config.id);
     metrics.tables.set(u64::cast_from(table_info.len()));
@@ -164,21 +201,41 @@ pub(crate) fn render<G: Scope<Timestamp = MzOffset>>(
         let table_info = reader_table_info;
         Box::pin(async move {
             let (id, worker_id) = (config.id, config.worker_id);
-            let [data_cap, upper_cap]: &mut [_; 2] = caps.try_into().unwrap();
+            let [data_cap, upper_cap, health_cap, truncate_cap]: &mut [_; 4] =
+                caps.try_into().unwrap();
             let (data_cap, upper_cap) = (data_cap.as_mut().unwrap(), upper_cap.as_mut().unwrap());
+            let health_cap = health_cap.as_ref().unwrap();
+            let truncate_cap = truncate_cap.as_mut().unwrap();
 
             if !config.responsible_for("slot") {
                 return Ok(());
             }
 
+            // Connect to the upstream. If it rejects our credentials they might have been
+            // rotated by an external secrets manager, so we read them again and retry for a
+            // while before reporting an error.
+            let mut auth_retry = super::AuthRetry::new(&config.params.pg_source_auth_retry_config);
+            let mut reconnecting = false;
+            let (connection_config, replication_client) = loop {
+                match super::connect_replication(&connection, &context, &config).await {
+                    Ok(connected) => break connected,
+                    Err(err) => {
+                        let backoff = auth_retry.retry(err, id, &connection, &context)?;
+                        if !reconnecting {
+                            health_output.give(health_cap, (0, super::auth_retry_status())).await;
+                            reconnecting = true;
+                        }
+                        tokio::time::sleep(backoff).await;
+                    }
+                }
+            };
+            if reconnecting {
+                let status = HealthStatusUpdate::status(HealthStatus::Running);
+                health_output.give(health_cap, (0, status)).await;
+            }
+
             // Determine the slot lsn.
-            let connection_config = connection
-                .connection
-                .config(&*context.secrets_reader)
-                .await?
-                .tcp_timeouts(config.params.pg_source_tcp_timeouts.clone());
             let slot = &connection.publication_details.slot;
-            let replication_client = connection_config.connect_replication().await?;
             super::ensure_replication_slot(&replication_client, slot).await?;
             let slot_lsn = super::fetch_slot_resume_lsn(&replication_client, slot).await?;
 
@@ -195,6 +252,7 @@ pub(crate) fn render<G: Scope<Timestamp = MzOffset>>(
             };
             data_cap.downgrade(&resume_lsn);
             upper_cap.downgrade(&resume_lsn);
+            truncate_cap.downgrade(&resume_lsn);
             trace!(%id, "timely-{worker_id} replication reader started lsn={}", resume_lsn);
 
 
@@ -213,7 +271,13 @@ pub(crate) fn render<G: Scope<Timestamp = MzOffset>>(
             }
             trace!(%id, "timely-{worker_id} pending rewinds {rewinds:?}");
 
-            let mut committed_uppers = pin!(committed_uppers);
+            // Remember the latest committed upper, which tells us when the truncations of tables
+            // that retract their contents can be processed.
+            let committed_upper = Rc::new(RefCell::new(Antichain::from_elem(MzOffset::from(0))));
+            let mut committed_uppers = pin!(committed_uppers.inspect({
+                let committed_upper = Rc::clone(&committed_upper);
+                move |upper| *committed_upper.borrow_mut() = upper.clone()
+            }));
 
             let client = connection_config.connect("replication metadata").await?;
             if let Err(err) = ensure_publication_exists(&client, &connection.publication).await? {
@@ -235,6 +299,35 @@ pub(crate) fn render<G: Scope<Timestamp = MzOffset>>(
                 return Ok(());
             }
 
+            // Report the upstream columns that were added to the tables since the source was
+            // created. Changes to them while the source runs are reported as they appear in the
+            // replication stream.
+            let mut ignored_columns = BTreeMap::new();
+            let upstream_info = mz_postgres_util::publication_info(
+                &connection_config,
+                &connection.publication,
+                None,
+            )
+            .await?;
+            for current_desc in upstream_info {
+                let Some((output_index, expected_desc, _)) = table_info.get(&current_desc.oid) else {
+                    continue;
+                };
+                if expected_desc.determine_compatibility(&current_desc).is_err() {
+                    continue;
+                }
+                let columns: Vec<String> = expected_desc
+                    .added_columns(&current_desc)
+                    .into_iter()
+                    .map(String::from)
+                    .collect();
+                if !columns.is_empty() {
+                    let status = HealthStatusUpdate::ignored_columns(columns.clone());
+                    health_output.give(health_cap, (*output_index, status)).await;
+                }
+                ignored_columns.insert(current_desc.oid, columns);
+            }
+
             let slot = &connection.publication_details.slot;
             let mut stream = pin!(raw_stream(
                 &config,
@@ -248,8 +341,11 @@ pub(crate) fn render<G: Scope<Timestamp = MzOffset>>(
             .peekable());
 
             let mut errored = HashSet::new();
+            // The truncations that are waiting for the committed upper to reach their LSN, in LSN
+            // order.
+            let mut pending_truncates: Vec<(MzOffset, u32)> = Vec::new();
             let mut container = Vec::new();
-            let max_capacity = timely::container::buffer::default_capacity::<((u32, Result<Vec<Option<Bytes>>, DefiniteError>), MzOffset, Diff)>();
+            let max_capacity = timely::container::buffer::default_capacity::<((u32, Result<Tuple, DefiniteError>), MzOffset, Diff)>();
 
             while let Some(event) = stream.as_mut().next().await {
                 use LogicalReplicationMessage::*;
@@ -260,36 +356,115 @@ pub(crate) fn render<G: Scope<Timestamp = MzOffset>>(
                         Begin(begin) => {
                             let commit_lsn = MzOffset::from(begin.final_lsn());
 
-                            let mut tx = pin!(extract_transaction(
-                                stream.by_ref(),
-                                commit_lsn,
-                                &table_info,
-                                &connection_config,
-                                &metrics,
-                                &connection.publication,
-                                &mut errored
-                            ));
-
-                            trace!(%id, "timely-{worker_id} extracting transaction at {commit_lsn}");
-                            while let Some((oid, event, diff)) = tx.try_next().await? {
-                                if !table_info.contains_key(&oid) {
-                                    continue;
-                                }
-                                let data = (oid, event);
-                                if let Some((rewind_cap, req)) = rewinds.get(&oid) {
-                                    if commit_lsn <= req.snapshot_lsn {
-                                        let update = (data.clone(), MzOffset::from(0), -diff);
-                                        data_output.give(rewind_cap, update).await;
+                            let mut unretractable = vec![];
+                            // The last command for each key of the tables ingested in upsert mode.
+                            let mut upserts: BTreeMap<(u32, Vec<Option<Bytes>>), (Tuple, Diff)> = BTreeMap::new();
+                            {
+                                let mut tx = pin!(extract_transaction(
+                                    stream.by_ref(),
+                                    commit_lsn,
+                                    &table_info,
+                                    &connection.truncate_behaviors,
+                                    &connection.upsert_keys,
+                                    &connection_config,
+                                    &metrics,
+                                    &connection.publication,
+                                    &mut errored
+                                ));
+
+                                trace!(%id, "timely-{worker_id} extracting transaction at {commit_lsn}");
+                                while let Some(event) = tx.try_next().await? {
+                                    let (oid, event, diff) = match event {
+                                        TransactionEvent::Update(oid, event, diff) => (oid, event, diff),
+                                        TransactionEvent::IgnoredColumns(oid, columns) => {
+                                            let Some((output_index, _, _)) = table_info.get(&oid) else {
+                                                continue;
+                                            };
+                                            let known = ignored_columns.get(&oid).map_or(&[][..], Vec::as_slice);
+                                            if known != columns.as_slice() {
+                                                let status = HealthStatusUpdate::ignored_columns(columns.clone());
+                                                health_output.give(health_cap, (*output_index, status)).await;
+                                                ignored_columns.insert(oid, columns);
+                                            }
+                                            continue;
+                                        }
+                                        TransactionEvent::Truncate(oid) => {
+                                            if !table_info.contains_key(&oid) {
+                                                continue;
+                                            }
+                                            // If the snapshot of the table is still being rewound
+                                            // its contents as of the truncation were never
+                                            // persisted, so there is nothing to retract them with.
+                                            let rewinding = rewinds
+                                                .get(&oid)
+                                                .map_or(false, |(_, req)| commit_lsn <= req.snapshot_lsn);
+                                            if rewinding {
+                                                unretractable.push(oid);
+                                                let update = ((oid, Err(DefiniteError::TableTruncated)), commit_lsn, 1);
+                                                container.push(update);
+                                            } else {
+                                                // The updates to the table earlier in this
+                                                // transaction are wiped out by the truncation.
+                                                container.retain(|((update_oid, _), lsn, _)| {
+                                                    *update_oid != oid || *lsn != commit_lsn
+                                                });
+                                                upserts.retain(|(update_oid, _), _| *update_oid != oid);
+                                                pending_truncates.push((commit_lsn, oid));
+                                            }
+                                            continue;
+                                        }
+                                    };
+                                    let Some((output_index, _, _)) = table_info.get(&oid) else {
+                                        continue;
+                                    };
+                                    let event = match (connection.upsert_keys.get(output_index), event) {
+                                        (Some(key_indices), Ok(mut tuple)) => {
+                                            // The snapshot already reflects the commands up to its LSN.
+                                            let rewinding = rewinds
+                                                .get(&oid)
+                                                .map_or(false, |(_, req)| commit_lsn <= req.snapshot_lsn);
+                                            if rewinding {
+                                                continue;
+                                            }
+                                            let key = tuple.key(key_indices);
+                                            match upserts.entry((oid, key)) {
+                                                Entry::Occupied(mut entry) => {
+                                                    let (older, older_diff) = entry.get();
+                                                    if diff > 0 && *older_diff > 0 {
+                                                        tuple.fill_absent(older);
+                                                    }
+                                                    entry.insert((tuple, diff));
+                                                }
+                                                Entry::Vacant(entry) => {
+                                                    entry.insert((tuple, diff));
+                                                }
+                                            }
+                                            continue;
+                                        }
+                                        (_, event) => event,
+                                    };
+                                    let data = (oid, event);
+                                    if let Some((rewind_cap, req)) = rewinds.get(&oid) {
+                                        if commit_lsn <= req.snapshot_lsn {
+                                            let update = (data.clone(), MzOffset::from(0), -diff);
+                                            data_output.give(rewind_cap, update).await;
+                                        }
                                     }
+                                    assert!(new_upper <= commit_lsn, "new_upper={} tx_lsn={}", new_upper, commit_lsn);
+                                    container.push((data, commit_lsn, diff));
                                 }
-                                assert!(new_upper <= commit_lsn, "new_upper={} tx_lsn={}", new_upper, commit_lsn);
-                                container.push((data, commit_lsn, diff));
+                            }
+                            errored.extend(unretractable);
+                            for ((oid, _), (tuple, diff)) in upserts {
+                                container.push(((oid, Ok(tuple)), commit_lsn, diff));
                             }
                             new_upper = commit_lsn + 1;
                             if container.len() > max_capacity {
                                 data_output.give_container(data_cap, &mut container).await;
+                                let new_upper = hold_for_truncates(new_upper, &pending_truncates);
                                 upper_cap.downgrade(&new_upper);
                                 data_cap.downgrade(&new_upper);
+                                truncate_cap.downgrade(&new_upper);
                             }
                         },
                         _ => return Err(TransientError::BareTransactionEvent),
@@ -305,8 +480,44 @@ pub(crate) fn render<G: Scope<Timestamp = MzOffset>>(
                 let will_yield = stream.as_mut().peek().now_or_never().is_none();
                 if will_yield {
                     data_output.give_container(data_cap, &mut container).await;
+
+                    // Retract the contents of the tables whose truncations are now covered by the
+                    // committed upper.
+                    let committed_upper = committed_upper.borrow().clone();
+                    while let Some(&(truncate_lsn, oid)) = pending_truncates.first() {
+                        if !PartialOrder::less_equal(&Antichain::from_elem(truncate_lsn), &committed_upper) {
+                            break;
+                        }
+                        pending_truncates.remove(0);
+                        let (output_index, _, _) = &table_info[&oid];
+                        trace!(%id, "timely-{worker_id} retracting output {output_index} truncated at {truncate_lsn}");
+                        // The contents are streamed out of persist and retracted as they arrive,
+                        // without consolidating them first, so that truncating a large table does
+                        // not require holding all of it in memory.
+                        let (mut read_handle, as_of) =
+                            open_output_reader(&config, *output_index).await?;
+                        let contents = read_handle
+                            .snapshot_and_stream(as_of.clone())
+                            .await
+                            .map_err(|since| {
+                                anyhow!("snapshot at {as_of:?} unavailable: {since:?}")
+                            })?;
+                        let mut contents = pin!(contents);
+                        while let Some(((data, _), _, diff)) = contents.next().await {
+                            let data = data.map_err(|err| anyhow!("invalid source data: {err}"))?;
+                            // Errors in the subsource are not part of its contents.
+                            if let Ok(row) = data.0 {
+                                let update = ((*output_index, row), truncate_lsn, -diff);
+                                truncate_output.give(truncate_cap, update).await;
+                            }
+                        }
+                        read_handle.expire().await;
+                    }
+
+                    let new_upper = hold_for_truncates(new_upper, &pending_truncates);
                     upper_cap.downgrade(&new_upper);
                     data_cap.downgrade(&new_upper);
+                    truncate_cap.downgrade(&new_upper);
                     rewinds.retain(|_, (_, req)| data_cap.time() <= &req.snapshot_lsn);
                 }
             }
@@ -323,27 +534,113 @@ pub(crate) fn render<G: Scope<Timestamp = MzOffset>>(
     let mut datum_vec = DatumVec::new();
     let replication_updates = raw_collection.map(move |(oid, event)| {
         let (output_index, _, casts) = &table_info[&oid];
-        let event = event.and_then(|row| {
+        let event = event.and_then(|tuple| {
             let mut datums = datum_vec.borrow();
-            for col in row.iter() {
+            for col in tuple.columns.iter() {
                 let datum = col.as_deref().map(super::decode_utf8_text).transpose()?;
                 datums.push(datum.unwrap_or(Datum::Null));
             }
-            super::cast_row(casts, &datums, &mut final_row)?;
+            super::cast_row(casts, &datums, &tuple.absent, &mut final_row)?;
             Ok(final_row.clone())
         });
 
         (*output_index, event.err_into())
     });
 
+    // The retractions of truncated tables are already decoded
+    let truncate_updates = truncate_stream
+        .distribute()
+        .as_collection()
+        .map(|(output_index, row)| (output_index, Ok(row)));
+
     (
-        replication_updates,
+        replication_updates.concat(&truncate_updates),
         upper_stream,
         errors,
+        health_stream,
         Rc::new(button.press_on_drop()),
     )
 }
 
+/// Returns the LSN that the capabilities of the replication reader can be downgraded to, given that
+/// they would otherwise be downgraded to `upper` and that `pending_truncates` still need to be
+/// retracted.
+fn hold_for_truncates(upper: MzOffset, pending_truncates: &[(MzOffset, u32)]) -> MzOffset {
+    match pending_truncates.first() {
+        Some((truncate_lsn, _)) => std::cmp::min(upper, *truncate_lsn),
+        None => upper,
+    }
+}
+
+/// Opens a reader for the persist shard of the subsource at `output_index` and returns it along
+/// with a timestamp its current contents can be read at.
+///
+/// Must only be called once the committed upper of the source has reached the LSN as of which the
+/// contents are needed, while holding back the capabilities of the source at that LSN. The
+/// persisted contents then reflect exactly the updates before that LSN, no matter the timestamp
+/// they are read at.
+async fn open_output_reader(
+    config: &RawSourceCreationConfig,
+    output_index: usize,
+) -> Result<
+    (
+        ReadHandle<SourceData, (), Timestamp, Diff>,
+        Antichain<Timestamp>,
+    ),
+    TransientError,
+> {
+    let (export_id, export) = config
+        .source_exports
+        .iter()
+        .find(|(_, export)| export.output_index == output_index)
+        .expect("truncated tables are source exports");
+    let metadata = &export.storage_metadata;
+    let diagnostics = || Diagnostics {
+        shard_name: export_id.to_string(),
+        handle_purpose: format!("truncate retraction for {}", config.id),
+    };
+
+    let client = config
+        .persist_clients
+        .open(metadata.persist_location.clone())
+        .await
+        .context("creating persist client")?;
+    let mut write_handle = client
+        .open_writer::<SourceData, (), Timestamp, Diff>(
+            metadata.data_shard,
+            Arc::new(metadata.relation_desc.clone()),
+            Arc::new(UnitSchema),
+            diagnostics(),
+        )
+        .await
+        .context("opening subsource shard")?;
+    let mut read_handle = client
+        .open_leased_reader::<SourceData, (), Timestamp, Diff>(
+            metadata.data_shard,
+            Arc::new(metadata.relation_desc.clone()),
+            Arc::new(UnitSchema),
+            diagnostics(),
+        )
+        .await
+        .context("opening subsource shard")?;
+
+    // The shard keeps advancing while we hold back our capabilities, so we eventually find a
+    // readable timestamp.
+    let as_of = loop {
+        let upper = write_handle.fetch_recent_upper().await;
+        if let Some(as_of) = upper.as_option().and_then(|upper| upper.step_back()) {
+            let as_of = Antichain::from_elem(as_of);
+            if PartialOrder::less_equal(read_handle.since(), &as_of) {
+                break as_of;
+            }
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    };
+
+    write_handle.expire().await;
+    Ok((read_handle, as_of))
+}
+
 /// Produces the logical replication stream while taking care of regularly sending standby
 /// keepalive messages with the provided `uppers` stream.
 ///
@@ -429,6 +726,51 @@ fn raw_stream<'a>(
     })
 }
 
+/// A tuple of the replication stream. Its columns are text encoded, or `None` for `NULL`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Tuple {
+    columns: Vec<Option<Bytes>>,
+    /// The sorted indices of the columns that have no value in the tuple. Only the tuples of tables
+    /// ingested in upsert mode have such columns: the TOASTed values left unchanged by an update
+    /// and the columns of a deleted row that are not part of its key.
+    absent: Vec<usize>,
+}
+
+impl Tuple {
+    /// Returns the values of the key columns of the tuple.
+    fn key(&self, key_indices: &[usize]) -> Vec<Option<Bytes>> {
+        key_indices
+            .iter()
+            .map(|i| self.columns[*i].clone())
+            .collect()
+    }
+
+    /// Takes the values of the absent columns of this tuple from `older`, an older tuple with the
+    /// same key.
+    fn fill_absent(&mut self, older: &Tuple) {
+        self.absent.retain(|i| {
+            if older.absent.binary_search(i).is_ok() {
+                return true;
+            }
+            self.columns[*i] = older.columns[*i].clone();
+            false
+        });
+    }
+}
+
+/// An event extracted from a transaction of the replication stream.
+enum TransactionEvent {
+    /// An update to the table with the given OID. For tables that are ingested in upsert mode,
+    /// additions are upserts and retractions are deletions of the key of the tuple.
+    Update(u32, Result<Tuple, DefiniteError>, Diff),
+    /// The table with the given OID was truncated and its contents must be retracted.
+    Truncate(u32),
+    /// The table with the given OID is still compatible with its upstream schema, but has the
+    /// given columns upstream that are ignored because they were added after the source was
+    /// created.
+    IgnoredColumns(u32, Vec<String>),
+}
+
 /// Extracts a single transaction from the replication stream delimited by a BEGIN and COMMIT
 /// message. The BEGIN message must have already been consumed from the stream before calling this
 /// function.
@@ -437,14 +779,19 @@ fn extract_transaction<'a>(
         + 'a,
     commit_lsn: MzOffset,
     table_info: &'a BTreeMap<u32, (usize, PostgresTableDesc, Vec<MirScalarExpr>)>,
+    truncate_behaviors: &'a BTreeMap<usize, PostgresTruncateBehavior>,
+    upsert_keys: &'a BTreeMap<usize, Vec<usize>>,
     connection_config: &'a mz_postgres_util::Config,
     metrics: &'a PgSourceMetrics,
     publication: &'a str,
     errored_tables: &'a mut HashSet<u32>,
-) -> impl AsyncStream<
-    Item = Result<(u32, Result<Vec<Option<Bytes>>, DefiniteError>, Diff), TransientError>,
-> + 'a {
+) -> impl AsyncStream<Item = Result<TransactionEvent, TransientError>> + 'a {
     use LogicalReplicationMessage::*;
+    // The key of the tables ingested in upsert mode
+    let upsert_key = |rel_id: u32| {
+        let (output_index, _, _) = table_info.get(&rel_id)?;
+        upsert_keys.get(output_index)
+    };
     async_stream::try_stream!({
         let mut stream = pin!(stream);
         metrics.transactions.inc();
@@ -466,7 +813,39 @@ fn extract_transaction<'a>(
                 Insert(body) => {
                     metrics.inserts.inc();
                     let row = unpack_tuple(body.tuple().tuple_data());
-                    yield (body.rel_id(), row, 1);
+                    yield TransactionEvent::Update(body.rel_id(), row, 1);
+                }
+                Update(body) if upsert_key(body.rel_id()).is_some() => {
+                    metrics.updates.inc();
+                    let key_indices = upsert_key(body.rel_id()).expect("checked above");
+                    let old_tuple = body.old_tuple().map(|tuple| tuple.tuple_data());
+                    let new_tuple = unpack_upsert_tuple(body.new_tuple().tuple_data(), old_tuple);
+                    // The old key is only present if it differs from the new one, or if the table
+                    // has `REPLICA IDENTITY FULL`.
+                    if let Some(old_key) = body.old_tuple().or(body.key_tuple()) {
+                        let old_key = unpack_key_tuple(old_key.tuple_data(), key_indices);
+                        if old_key.key(key_indices) != new_tuple.key(key_indices) {
+                            let old_key = check_upsert_key(old_key, key_indices);
+                            yield TransactionEvent::Update(body.rel_id(), old_key, -1);
+                        }
+                    }
+                    let new_tuple = check_upsert_key(new_tuple, key_indices);
+                    yield TransactionEvent::Update(body.rel_id(), new_tuple, 1);
+                }
+                Delete(body) if upsert_key(body.rel_id()).is_some() => {
+                    let key_indices = upsert_key(body.rel_id()).expect("checked above");
+                    match body.old_tuple().or(body.key_tuple()) {
+                        Some(old_key) => {
+                            metrics.deletes.inc();
+                            let old_key = unpack_key_tuple(old_key.tuple_data(), key_indices);
+                            let old_key = check_upsert_key(old_key, key_indices);
+                            yield TransactionEvent::Update(body.rel_id(), old_key, -1);
+                        }
+                        None => {
+                            let err = Err(DefiniteError::DefaultReplicaIdentity);
+                            yield TransactionEvent::Update(body.rel_id(), err, 1);
+                        }
+                    }
                 }
                 Update(body) => match body.old_tuple() {
                     Some(old_tuple) => {
@@ -479,22 +858,24 @@ fn extract_transaction<'a>(
                                     _ => new,
                                 });
                         let old_row = unpack_tuple(old_tuple.tuple_data());
-                        yield (body.rel_id(), old_row, -1);
+                        yield TransactionEvent::Update(body.rel_id(), old_row, -1);
                         let new_row = unpack_tuple(new_tuple);
-                        yield (body.rel_id(), new_row, 1);
+                        yield TransactionEvent::Update(body.rel_id(), new_row, 1);
                     }
                     None => {
-                        yield (body.rel_id(), Err(DefiniteError::DefaultReplicaIdentity), 1);
+                        let err = Err(DefiniteError::DefaultReplicaIdentity);
+                        yield TransactionEvent::Update(body.rel_id(), err, 1);
                     }
                 },
                 Delete(body) => match body.old_tuple() {
                     Some(old_tuple) => {
                         metrics.deletes.inc();
                         let row = unpack_tuple(old_tuple.tuple_data());
-                        yield (body.rel_id(), row, -1);
+                        yield TransactionEvent::Update(body.rel_id(), row, -1);
                     }
                     None => {
-                        yield (body.rel_id(), Err(DefiniteError::DefaultReplicaIdentity), 1);
+                        let err = Err(DefiniteError::DefaultReplicaIdentity);
+                        yield TransactionEvent::Update(body.rel_id(), err, 1);
                     }
                 },
                 Relation(body) => {
@@ -512,16 +893,40 @@ fn extract_transaction<'a>(
                         .await?;
                         let upstream_info = upstream_info.into_iter().map(|t| (t.oid, t)).collect();
 
-                        if let Err(err) = verify_schema(rel_id, expected_desc, &upstream_info) {
-                            errored_tables.insert(rel_id);
-                            yield (rel_id, Err(err), 1);
+                        match verify_schema(rel_id, expected_desc, &upstream_info) {
+                            Ok(()) => {
+                                let ignored_columns = expected_desc
+                                    .added_columns(&upstream_info[&rel_id])
+                                    .into_iter()
+                                    .map(String::from)
+                                    .collect();
+                                yield TransactionEvent::IgnoredColumns(rel_id, ignored_columns);
+                            }
+                            Err(err) => {
+                                errored_tables.insert(rel_id);
+                                yield TransactionEvent::Update(rel_id, Err(err), 1);
+                            }
                         }
                     }
                 }
                 Truncate(body) => {
                     for &rel_id in body.rel_ids() {
-                        if errored_tables.insert(rel_id) {
-                            yield (rel_id, Err(DefiniteError::TableTruncated), 1);
+                        if errored_tables.contains(&rel_id) {
+                            continue;
+                        }
+                        let behavior = table_info
+                            .get(&rel_id)
+                            .and_then(|(output_index, _, _)| truncate_behaviors.get(output_index));
+                        match behavior {
+                            Some(PostgresTruncateBehavior::Retract) => {
+                                yield TransactionEvent::Truncate(rel_id);
+                            }
+                            Some(PostgresTruncateBehavior::Ignore) => metrics.ignored.inc(),
+                            Some(PostgresTruncateBehavior::Error) | None => {
+                                errored_tables.insert(rel_id);
+                                let err = Err(DefiniteError::TableTruncated);
+                                yield TransactionEvent::Update(rel_id, err, 1);
+                            }
                         }
                     }
                 }
@@ -546,7 +951,7 @@ fn extract_transaction<'a>(
 
 /// Unpacks an iterator of TupleData into a list of nullable bytes or an error if this can't be
 /// done.
-fn unpack_tuple<'a, I>(tuple_data: I) -> Result<Vec<Option<Bytes>>, DefiniteError>
+fn unpack_tuple<'a, I>(tuple_data: I) -> Result<Tuple, DefiniteError>
 where
     I: IntoIterator<Item = &'a TupleData>,
     I::IntoIter: ExactSizeIterator,
@@ -561,7 +966,68 @@ where
         };
         row.push(datum);
     }
-    Ok(row)
+    Ok(Tuple {
+        columns: row,
+        absent: vec![],
+    })
+}
+
+/// Unpacks the new tuple of an update to a table ingested in upsert mode. The TOASTed values that
+/// the update left unchanged are taken from the old tuple, if the table has `REPLICA IDENTITY
+/// FULL`, and are otherwise absent.
+fn unpack_upsert_tuple(tuple_data: &[TupleData], old_tuple_data: Option<&[TupleData]>) -> Tuple {
+    let mut tuple = Tuple {
+        columns: Vec::with_capacity(tuple_data.len()),
+        absent: vec![],
+    };
+    for (i, data) in tuple_data.iter().enumerate() {
+        let data = match (data, old_tuple_data) {
+            (TupleData::UnchangedToast, Some(old)) => &old[i],
+            (data, _) => data,
+        };
+        let datum = match data {
+            TupleData::Text(bytes) => Some(bytes.clone()),
+            TupleData::Null => None,
+            TupleData::UnchangedToast => {
+                tuple.absent.push(i);
+                None
+            }
+        };
+        tuple.columns.push(datum);
+    }
+    tuple
+}
+
+/// Unpacks the old key of an update or delete to a table ingested in upsert mode. Only the key
+/// columns are kept, since the other columns are only present with `REPLICA IDENTITY FULL`.
+fn unpack_key_tuple(tuple_data: &[TupleData], key_indices: &[usize]) -> Tuple {
+    let mut tuple = Tuple {
+        columns: vec![None; tuple_data.len()],
+        absent: vec![],
+    };
+    for (i, data) in tuple_data.iter().enumerate() {
+        match data {
+            TupleData::Text(bytes) if key_indices.contains(&i) => {
+                tuple.columns[i] = Some(bytes.clone());
+            }
+            TupleData::Null if key_indices.contains(&i) => {}
+            _ => tuple.absent.push(i),
+        }
+    }
+    tuple
+}
+
+/// Ensures that the key of a tuple of a table ingested in upsert mode is known. A key column can
+/// only be absent if it holds an unchanged TOASTed value, which the upsert state cannot recover
+/// since the key is needed to look it up.
+fn check_upsert_key(tuple: Tuple, key_indices: &[usize]) -> Result<Tuple, DefiniteError> {
+    if key_indices
+        .iter()
+        .any(|i| tuple.absent.binary_search(i).is_ok())
+    {
+        return Err(DefiniteError::MissingToast);
+    }
+    Ok(tuple)
 }
 
 /// Ensures the publication exists on the server. It returns an outer transient error in case of
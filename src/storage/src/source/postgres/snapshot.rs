@@ -160,7 +160,7 @@ use mz_timely_util::operator::StreamExt as TimelyStreamExt;
 
 use crate::source::postgres::replication::RewindRequest;
 use crate::source::postgres::{simple_query_opt, verify_schema, DefiniteError, TransientError};
-use crate::source::types::SourceReaderError;
+use crate::source::types::{HealthStatus, HealthStatusUpdate, SourceReaderError};
 use crate::source::RawSourceCreationConfig;
 
 /// Renders the snapshot dataflow. See the module documentation for more information.
@@ -175,6 +175,7 @@ pub(crate) fn render<G: Scope<Timestamp = MzOffset>>(
     Collection<G, (usize, Result<Row, SourceReaderError>), Diff>,
     Stream<G, RewindRequest>,
     Stream<G, Rc<TransientError>>,
+    Stream<G, (usize, HealthStatusUpdate)>,
     Rc<dyn Any>,
 ) {
     let op_name = format!("TableReader({})", config.id);
@@ -185,11 +186,12 @@ pub(crate) fn render<G: Scope<Timestamp = MzOffset>>(
     let (mut raw_handle, raw_data) = builder.new_output();
     let (mut rewinds_handle, rewinds) = builder.new_output();
     let (mut snapshot_handle, snapshot) = builder.new_output();
+    let (mut health_handle, health) = builder.new_output();
 
     // This operator needs to broadcast data to itself in order to synchronize the transaction
     // snapshot. However, none of the feedback capabilities result in output messages and for the
     // feedback edge specifically having a default conncetion would result in a loop.
-    let disconnected = vec![Antichain::new(); 3];
+    let disconnected = vec![Antichain::new(); 4];
     let mut snapshot_input = builder.new_input_connection(&feedback_data, Pipeline, disconnected);
 
     // The export id must be sent to all workes, so we broadcast the feedback connection
@@ -231,8 +233,10 @@ pub(crate) fn render<G: Scope<Timestamp = MzOffset>>(
             let id = config.id;
             let worker_id = config.worker_id;
 
-            let [data_cap, rewind_cap, snapshot_cap]: &mut [_; 3] = caps.try_into().unwrap();
+            let [data_cap, rewind_cap, snapshot_cap, health_cap]: &mut [_; 4] =
+                caps.try_into().unwrap();
             let data_cap = data_cap.as_mut().unwrap();
+            let health_cap = health_cap.as_ref().unwrap();
             trace!(
                 %id,
                 "timely-{worker_id} initializing table reader with {} tables to snapshot",
@@ -245,15 +249,41 @@ pub(crate) fn render<G: Scope<Timestamp = MzOffset>>(
                 return Ok(());
             }
 
-            let connection_config = connection
-                .connection
-                .config(&*context.secrets_reader)
-                .await?
-                .tcp_timeouts(config.params.pg_source_tcp_timeouts.clone());
+            // Connect to the upstream, re-reading the credentials and retrying for a while if it
+            // rejects them, exactly like the replication reader does.
             let task_name = format!("timely-{worker_id} PG snapshotter");
+            let mut auth_retry = super::AuthRetry::new(&config.params.pg_source_auth_retry_config);
+            let mut reconnecting = false;
+            let client = loop {
+                let connected = async {
+                    let connection_config =
+                        super::connection_config(&connection, &context, &config).await?;
+                    // Only the snapshot leader needs a replication connection.
+                    let client = if is_snapshot_leader {
+                        connection_config.connect_replication().await?
+                    } else {
+                        connection_config.connect(&task_name).await?
+                    };
+                    Ok::<_, TransientError>(client)
+                };
+                match connected.await {
+                    Ok(client) => break client,
+                    Err(err) => {
+                        let backoff = auth_retry.retry(err, id, &connection, &context)?;
+                        if !reconnecting {
+                            health_handle.give(health_cap, (0, super::auth_retry_status())).await;
+                            reconnecting = true;
+                        }
+                        tokio::time::sleep(backoff).await;
+                    }
+                }
+            };
+            if reconnecting {
+                let status = HealthStatusUpdate::status(HealthStatus::Running);
+                health_handle.give(health_cap, (0, status)).await;
+            }
 
-            let client = if is_snapshot_leader {
-                let client = connection_config.connect_replication().await?;
+            if is_snapshot_leader {
                 // The main slot must be created *before* we start snapshotting so that we can be
                 // certain that the temporarly slot created for the snapshot start at an LSN that
                 // is greater than or equal to that of the main slot.
@@ -263,12 +293,7 @@ pub(crate) fn render<G: Scope<Timestamp = MzOffset>>(
                 trace!(%id, "timely-{worker_id} exporting snapshot info {snapshot_info:?}");
                 let cap = snapshot_cap.as_ref().unwrap();
                 snapshot_handle.give(cap, snapshot_info).await;
-
-                client
-            } else {
-                // Only the snapshot leader needs a replication connection.
-                connection_config.connect(&task_name).await?
-            };
+            }
 
             // Configure statement_timeout based on param. We want to be able to
             // override the server value here in case it's set too low,
@@ -381,7 +406,7 @@ pub(crate) fn render<G: Scope<Timestamp = MzOffset>>(
         let event = event.and_then(|bytes| {
             decode_copy_row(&bytes, casts.len(), &mut text_row)?;
             let datums = datum_vec.borrow_with(&text_row);
-            super::cast_row(casts, &datums, &mut final_row)?;
+            super::cast_row(casts, &datums, &[], &mut final_row)?;
             Ok(final_row.clone())
         });
 
@@ -392,6 +417,7 @@ pub(crate) fn render<G: Scope<Timestamp = MzOffset>>(
         snapshot_updates,
         rewinds,
         errors,
+        health,
         Rc::new(button.press_on_drop()),
     )
 }
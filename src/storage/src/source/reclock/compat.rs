@@ -52,6 +52,8 @@ pub struct PersistHandle<FromTime: SourceTimestamp, IntoTime: Timestamp + Lattic
     pending_batch: Vec<(FromTime, IntoTime, Diff)>,
     // Reports `self`'s write frontier.
     shared_write_frontier: Rc<RefCell<Antichain<IntoTime>>>,
+    // The description of the remap shard, used to encode bindings.
+    remap_relation_desc: RelationDesc,
 }
 
 impl<FromTime: Timestamp, IntoTime: Timestamp> PersistHandle<FromTime, IntoTime>
@@ -90,7 +92,7 @@ where
         let (write_handle, mut read_handle) = persist_client
             .open(
                 remap_shard,
-                Arc::new(remap_relation_desc),
+                Arc::new(remap_relation_desc.clone()),
                 Arc::new(UnitSchema),
                 Diagnostics {
                     shard_name: remap_collection_id.to_string(),
@@ -160,6 +162,7 @@ where
             write_handle,
             pending_batch: vec![],
             shared_write_frontier,
+            remap_relation_desc,
         })
     }
 }
@@ -216,7 +219,8 @@ where
         new_upper: Antichain<Self::IntoTime>,
     ) -> Result<(), UpperMismatch<Self::IntoTime>> {
         let row_updates = updates.into_iter().map(|(from_ts, into_ts, diff)| {
-            ((SourceData(Ok(from_ts.encode_row())), ()), into_ts, diff)
+            let row = from_ts.encode_row_for_desc(&self.remap_relation_desc);
+            ((SourceData(Ok(row)), ()), into_ts, diff)
         });
 
         match self
@@ -56,6 +56,7 @@ use mz_storage_client::healthcheck::MZ_SOURCE_STATUS_HISTORY_DESC;
 use mz_storage_types::connections::ConnectionContext;
 use mz_storage_types::controller::CollectionMetadata;
 use mz_storage_types::errors::SourceError;
+use mz_storage_types::parameters::PgSourceAuthRetryConfig;
 use mz_storage_types::sources::encoding::SourceDataEncoding;
 use mz_storage_types::sources::{MzOffset, SourceConnection, SourceExport, SourceTimestamp};
 use mz_timely_util::antichain::AntichainExt;
@@ -161,6 +162,8 @@ pub struct SourceCreationParams {
     /// Sets timeouts specific to PG replication streams
     pub pg_source_tcp_timeouts: mz_postgres_util::TcpTimeoutConfig,
     pub pg_source_snapshot_statement_timeout: Duration,
+    /// How to retry when the upstream rejects the credentials of a PG source.
+    pub pg_source_auth_retry_config: PgSourceAuthRetryConfig,
 }
 
 /// Creates a source dataflow operator graph from a source connection. The type of SourceConnection
@@ -365,6 +368,10 @@ struct HealthState<'a> {
     healths: Vec<Option<HealthStatus>>,
     last_reported_status: Option<HealthStatus>,
     halt_with: Option<HealthStatus>,
+    /// The upstream columns that the source ignores, as last reported by the source and as last
+    /// written to the status shard.
+    ignored_columns: Vec<String>,
+    last_reported_ignored_columns: Vec<String>,
 }
 
 impl<'a> HealthState<'a> {
@@ -388,6 +395,8 @@ impl<'a> HealthState<'a> {
             healths: vec![None; worker_count],
             last_reported_status: None,
             halt_with: None,
+            ignored_columns: vec![],
+            last_reported_ignored_columns: vec![],
         }
     }
 }
@@ -491,6 +500,7 @@ pub(crate) fn health_operator<'g, G: Scope<Timestamp = ()>>(
                             status_shard,
                             &*MZ_SOURCE_STATUS_HISTORY_DESC,
                             status.hint(),
+                            &[],
                         )
                         .await;
                     }
@@ -506,6 +516,7 @@ pub(crate) fn health_operator<'g, G: Scope<Timestamp = ()>>(
                         source_id,
                         healths,
                         halt_with,
+                        ignored_columns,
                         ..
                     } = match health_states.get_mut(&output_index) {
                         Some(health) => health,
@@ -527,12 +538,17 @@ pub(crate) fn health_operator<'g, G: Scope<Timestamp = ()>>(
                     let HealthStatusUpdate {
                         update,
                         should_halt,
+                        ignored_columns: new_ignored_columns,
                     } = health_event;
 
                     if should_halt {
                         *halt_with = Some(update.clone());
                     }
 
+                    if let Some(new_ignored_columns) = new_ignored_columns {
+                        *ignored_columns = new_ignored_columns;
+                    }
+
                     let update = Some(update);
                     // Keep the max of the messages in each round; this ensures that errors don't
                     // get lost while also letting us frequently update to the newest status.
@@ -550,6 +566,8 @@ pub(crate) fn health_operator<'g, G: Scope<Timestamp = ()>>(
                         persist_details,
                         last_reported_status,
                         halt_with,
+                        ignored_columns,
+                        last_reported_ignored_columns,
                     } = health_states
                         .get_mut(&output_index)
                         .expect("known to exist");
@@ -557,7 +575,9 @@ pub(crate) fn health_operator<'g, G: Scope<Timestamp = ()>>(
                     let overall_status = healths.iter().filter_map(Option::as_ref).max();
 
                     if let Some(new_status) = overall_status {
-                        if last_reported_status.as_ref() != Some(&new_status) {
+                        if last_reported_status.as_ref() != Some(&new_status)
+                            || last_reported_ignored_columns != ignored_columns
+                        {
                             info!(
                                 "Health transition for source {source_id}: \
                                   {last_reported_status:?} -> {new_status:?}"
@@ -572,11 +592,13 @@ pub(crate) fn health_operator<'g, G: Scope<Timestamp = ()>>(
                                     *status_shard,
                                     &*MZ_SOURCE_STATUS_HISTORY_DESC,
                                     new_status.hint(),
+                                    ignored_columns,
                                 )
                                 .await;
                             }
 
                             *last_reported_status = Some(new_status.clone());
+                            *last_reported_ignored_columns = ignored_columns.clone();
                         }
                     }
 
@@ -94,6 +94,9 @@ pub trait SourceRender {
 pub struct HealthStatusUpdate {
     pub update: HealthStatus,
     pub should_halt: bool,
+    /// The upstream columns that the source ignores because they were added after the source was
+    /// created, if they changed. Only reported along with a [`HealthStatus::Running`] update.
+    pub ignored_columns: Option<Vec<String>>,
 }
 
 /// NB: we derive Ord here, so the enum order matters. Generally, statuses later in the list
@@ -103,13 +106,22 @@ pub struct HealthStatusUpdate {
 pub enum HealthStatus {
     Starting,
     Running,
-    StalledWithError { error: String, hint: Option<String> },
+    /// The source lost its connection for an expected reason, e.g. because its
+    /// credentials were rotated, and is reconnecting on its own. Reported with
+    /// the `starting` status so that it isn't mistaken for a hard failure.
+    Reconnecting {
+        reason: String,
+    },
+    StalledWithError {
+        error: String,
+        hint: Option<String>,
+    },
 }
 
 impl HealthStatus {
     pub fn name(&self) -> &'static str {
         match self {
-            HealthStatus::Starting => "starting",
+            HealthStatus::Starting | HealthStatus::Reconnecting { .. } => "starting",
             HealthStatus::Running => "running",
             HealthStatus::StalledWithError { .. } => "stalled",
         }
@@ -118,13 +130,16 @@ impl HealthStatus {
     pub fn error(&self) -> Option<&str> {
         match self {
             HealthStatus::Starting | HealthStatus::Running => None,
+            HealthStatus::Reconnecting { reason } => Some(reason),
             HealthStatus::StalledWithError { error, .. } => Some(error),
         }
     }
 
     pub fn hint(&self) -> Option<&str> {
         match self {
-            HealthStatus::Starting | HealthStatus::Running => None,
+            HealthStatus::Starting | HealthStatus::Running | HealthStatus::Reconnecting { .. } => {
+                None
+            }
             HealthStatus::StalledWithError { error: _, hint } => hint.as_deref(),
         }
     }
@@ -136,6 +151,17 @@ impl HealthStatusUpdate {
         HealthStatusUpdate {
             update,
             should_halt: false,
+            ignored_columns: None,
+        }
+    }
+
+    /// Generates a [`HealthStatusUpdate`] that reports the upstream columns that the source
+    /// ignores.
+    pub(crate) fn ignored_columns(ignored_columns: Vec<String>) -> Self {
+        HealthStatusUpdate {
+            update: HealthStatus::Running,
+            should_halt: false,
+            ignored_columns: Some(ignored_columns),
         }
     }
 }
@@ -36,6 +36,8 @@ pub(crate) struct SourceStatisticsMetricsDefinitions {
     pub(crate) envelope_state_bytes: UIntGaugeVec,
     pub(crate) envelope_state_count: UIntGaugeVec,
     pub(crate) rehydration_latency_ms: UIntGaugeVec,
+    pub(crate) keys_expired: IntCounterVec,
+    pub(crate) records_skipped: IntCounterVec,
 }
 
 impl SourceStatisticsMetricsDefinitions {
@@ -81,6 +83,16 @@ impl SourceStatisticsMetricsDefinitions {
                 help: "The amount of time in milliseconds it took for the worker to rehydrate the source envelope state. This will be specific to the envelope in use.",
                 var_labels: ["source_id", "worker_id", "parent_source_id", "shard_id"],
             )),
+            keys_expired: registry.register(metric!(
+                name: "mz_source_keys_expired",
+                help: "The number of keys the worker has expired from the source envelope state because they outlived the key TTL of the source.",
+                var_labels: ["source_id", "worker_id", "parent_source_id", "shard_id"],
+            )),
+            records_skipped: registry.register(metric!(
+                name: "mz_source_records_skipped",
+                help: "The number of records the worker has left out of the source because their value failed to decode.",
+                var_labels: ["source_id", "worker_id", "parent_source_id", "shard_id"],
+            )),
         }
     }
 }
@@ -96,6 +108,8 @@ pub struct SourceStatisticsMetrics {
     pub(crate) envelope_state_bytes: DeleteOnDropGauge<'static, AtomicU64, Vec<String>>,
     pub(crate) envelope_state_count: DeleteOnDropGauge<'static, AtomicU64, Vec<String>>,
     pub(crate) rehydration_latency_ms: DeleteOnDropGauge<'static, AtomicU64, Vec<String>>,
+    pub(crate) keys_expired: DeleteOnDropCounter<'static, AtomicU64, Vec<String>>,
+    pub(crate) records_skipped: DeleteOnDropCounter<'static, AtomicU64, Vec<String>>,
 }
 
 impl SourceStatisticsMetrics {
@@ -174,6 +188,24 @@ impl SourceStatisticsMetrics {
                 .source_statistics
                 .rehydration_latency_ms
                 .get_delete_on_drop_gauge(vec![
+                    id.to_string(),
+                    worker_id.to_string(),
+                    parent_source_id.to_string(),
+                    shard.clone(),
+                ]),
+            keys_expired: metrics
+                .source_statistics
+                .keys_expired
+                .get_delete_on_drop_counter(vec![
+                    id.to_string(),
+                    worker_id.to_string(),
+                    parent_source_id.to_string(),
+                    shard.clone(),
+                ]),
+            records_skipped: metrics
+                .source_statistics
+                .records_skipped
+                .get_delete_on_drop_counter(vec![
                     id.to_string(),
                     worker_id.to_string(),
                     parent_source_id.to_string(),
@@ -319,6 +351,8 @@ impl StorageStatistics<SourceStatisticsUpdate, SourceStatisticsMetrics> {
                     envelope_state_bytes: 0,
                     envelope_state_count: 0,
                     rehydration_latency_ms: None,
+                    keys_expired: 0,
+                    records_skipped: 0,
                 },
                 SourceStatisticsMetrics::new(id, worker_id, metrics, parent_source_id, shard_id),
             ))),
@@ -448,6 +482,20 @@ impl StorageStatistics<SourceStatisticsUpdate, SourceStatisticsMetrics> {
         cur.1.rehydration_latency_ms = Some(value);
         cur.2.rehydration_latency_ms.set(value);
     }
+
+    /// Increment the `keys_expired` stat.
+    pub fn inc_keys_expired_by(&self, value: u64) {
+        let mut cur = self.stats.borrow_mut();
+        cur.1.keys_expired = cur.1.keys_expired + value;
+        cur.2.keys_expired.inc_by(value);
+    }
+
+    /// Increment the `records_skipped` stat.
+    pub fn inc_records_skipped_by(&self, value: u64) {
+        let mut cur = self.stats.borrow_mut();
+        cur.1.records_skipped = cur.1.records_skipped + value;
+        cur.2.records_skipped.inc_by(value);
+    }
 }
 
 impl StorageStatistics<SinkStatisticsUpdate, SinkStatisticsMetrics> {
@@ -863,6 +863,7 @@ impl<'w, A: Allocate> Worker<'w, A> {
             InternalStorageCommand::UpdateConfiguration {
                 pg_source_tcp_timeouts,
                 pg_source_snapshot_statement_timeout,
+                pg_source_auth_retry_config,
                 rocksdb,
                 storage_dataflow_max_inflight_bytes_config,
                 auto_spill_config,
@@ -871,6 +872,7 @@ impl<'w, A: Allocate> Worker<'w, A> {
             } => self.storage_state.dataflow_parameters.update(
                 pg_source_tcp_timeouts,
                 pg_source_snapshot_statement_timeout,
+                pg_source_auth_retry_config,
                 rocksdb,
                 auto_spill_config,
                 storage_dataflow_max_inflight_bytes_config,
@@ -1095,13 +1097,29 @@ impl<'w, A: Allocate> Worker<'w, A> {
                             stale_exports.remove(&export.id);
                             // If we've been asked to create an export that is
                             // already installed, the descriptions must match
-                            // exactly.
-                            assert_eq!(
-                                *existing, export.description,
-                                "New export with same ID {:?}",
-                                export.id,
-                            );
-                            false
+                            // exactly, unless the export was restarted or
+                            // repointed at a new input in the meantime. In that
+                            // case we restart it with the new description. A
+                            // restarted export only differs in its `as_of`.
+                            if existing.from == export.description.from {
+                                let mut restarted = existing.clone();
+                                restarted.as_of = export.description.as_of.clone();
+                                assert_eq!(
+                                    restarted, export.description,
+                                    "New export with same ID {:?}",
+                                    export.id,
+                                );
+                                *existing != export.description
+                            } else {
+                                assert!(
+                                    existing
+                                        .connection
+                                        .is_input_alteration_of(&export.description.connection),
+                                    "New export with same ID {:?}",
+                                    export.id,
+                                );
+                                true
+                            }
                         } else {
                             true
                         }
@@ -1189,6 +1207,7 @@ impl StorageState {
                         pg_source_tcp_timeouts: params.pg_source_tcp_timeouts,
                         pg_source_snapshot_statement_timeout: params
                             .pg_source_snapshot_statement_timeout,
+                        pg_source_auth_retry_config: params.pg_source_auth_retry_config,
                         rocksdb: params.upsert_rocksdb_tuning_config,
                         storage_dataflow_max_inflight_bytes_config: params
                             .storage_dataflow_max_inflight_bytes_config,
@@ -1246,7 +1265,21 @@ impl StorageState {
                 for export in exports {
                     // Remember the sink description to facilitate possible
                     // reconciliation later.
-                    self.exports.insert(export.id, export.description.clone());
+                    let previous = self.exports.insert(export.id, export.description.clone());
+
+                    // An export we already know about was repointed at a new
+                    // input. Yank the token of the running sink dataflow so
+                    // that it shuts down before we render its replacement.
+                    if previous.is_some() {
+                        self.sink_tokens.remove(&export.id);
+                    }
+
+                    // While the sink switches over from its previous input it
+                    // only reads its new input from the switch on.
+                    let mut handle_since = export.description.as_of.frontier.clone();
+                    if let Some(previous_from) = &export.description.previous_from {
+                        handle_since.join_assign(&previous_from.switch_frontier);
+                    }
 
                     self.reported_frontiers.insert(
                         export.id,
@@ -1259,7 +1292,7 @@ impl StorageState {
                             export.id,
                             &export.description.from_storage_metadata,
                             export.description.from_storage_metadata.data_shard,
-                            export.description.as_of.frontier.clone(),
+                            handle_since,
                             Arc::clone(&self.persist_clients),
                         ),
                     );
@@ -104,7 +104,7 @@ use mz_storage::DecodeMetrics;
 use mz_storage_types::sources::encoding::SourceDataEncoding;
 use mz_storage_types::sources::{
     GenericSourceConnection, SourceData, SourceDesc, SourceEnvelope, SourceTimestamp,
-    TestScriptSourceConnection,
+    TestScriptSourceConnection, ValueDecodingErrors,
 };
 use timely::progress::{Antichain, Timestamp as _};
 
@@ -123,6 +123,7 @@ pub fn run_script_source(
         encoding,
         envelope,
         timestamp_interval,
+        value_decoding_errors: ValueDecodingErrors::Error,
     };
 
     build_and_run_source(desc, timestamp_interval, move |upper, mut read| {
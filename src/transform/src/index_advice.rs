@@ -0,0 +1,198 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Find the indexes that would have helped a query that had to read its inputs from persist.
+//!
+//! We look for the same patterns that `LiteralConstraints` and `JoinImplementation` know how to
+//! speed up when a matching index exists:
+//!  - an MFP on top of a `Get` whose filter contains `#c = literal` predicates, which could be
+//!    served by a lookup into an index on those columns, and
+//!  - a `Join` input that is (an MFP on top of) a `Get`, which could reuse an index on the
+//!    columns that participate in the join's equivalences instead of building a new arrangement.
+//!
+//! The analysis is purely syntactic and does not check whether the suggested index exists. It is
+//! meant to be run on plans that didn't use any index for the given collections.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use mz_expr::{Id, JoinInputMapper, MapFilterProject, MirRelationExpr, MirScalarExpr};
+use mz_repr::GlobalId;
+
+/// How a query would use a suggested index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IndexUsage {
+    /// A lookup of literal values, e.g., `WHERE a = 5`.
+    Lookup,
+    /// An input to a join on the key columns.
+    Join,
+}
+
+/// An index that would have helped a query.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct IndexCandidate {
+    /// The collection to index.
+    pub on_id: GlobalId,
+    /// The columns of `on_id` to use as the index key, in ascending order.
+    pub key: Vec<usize>,
+    /// How the query would use the index.
+    pub usage: IndexUsage,
+}
+
+/// Returns the indexes on collections in `candidates_for` that would have helped evaluate `expr`.
+pub fn index_candidates(
+    expr: &MirRelationExpr,
+    candidates_for: &BTreeSet<GlobalId>,
+) -> BTreeSet<IndexCandidate> {
+    let mut result = BTreeSet::new();
+    expr.visit_pre(|e| {
+        if let Some((on_id, mfp)) = mfp_over_get(e, candidates_for) {
+            let key = mfp
+                .predicates
+                .iter()
+                .filter_map(|(_, predicate)| match predicate.any_expr_eq_literal() {
+                    Some(MirScalarExpr::Column(c)) if c < mfp.input_arity => Some(c),
+                    _ => None,
+                })
+                .collect::<BTreeSet<_>>();
+            if !key.is_empty() {
+                result.insert(IndexCandidate {
+                    on_id,
+                    key: key.into_iter().collect(),
+                    usage: IndexUsage::Lookup,
+                });
+            }
+        }
+        if let MirRelationExpr::Join {
+            inputs,
+            equivalences,
+            ..
+        } = e
+        {
+            let input_mapper = JoinInputMapper::new(inputs);
+            let bases = inputs
+                .iter()
+                .map(|input| {
+                    let mut input = input;
+                    while let MirRelationExpr::ArrangeBy { input: inner, .. } = input {
+                        input = inner;
+                    }
+                    mfp_over_get(input, candidates_for)
+                })
+                .collect::<Vec<_>>();
+            let mut keys: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
+            for class in equivalences {
+                for expr in class {
+                    let MirScalarExpr::Column(c) = expr else {
+                        continue;
+                    };
+                    let (local, index) = input_mapper.map_column_to_local(*c);
+                    if let Some((_, mfp)) = &bases[index] {
+                        // Map the column through the MFP's projection; only columns that are
+                        // passed through unchanged can be part of an index on the `Get`.
+                        let column = mfp.projection[local];
+                        if column < mfp.input_arity {
+                            keys.entry(index).or_default().insert(column);
+                        }
+                    }
+                }
+            }
+            for (index, key) in keys {
+                let (on_id, _) = bases[index].as_ref().expect("only bases have keys");
+                result.insert(IndexCandidate {
+                    on_id: *on_id,
+                    key: key.into_iter().collect(),
+                    usage: IndexUsage::Join,
+                });
+            }
+        }
+    });
+    result
+}
+
+/// If `expr` is an MFP on top of a `Get` of a collection in `candidates_for`, returns the id of
+/// the collection and the MFP.
+fn mfp_over_get(
+    expr: &MirRelationExpr,
+    candidates_for: &BTreeSet<GlobalId>,
+) -> Option<(GlobalId, MapFilterProject)> {
+    let (mfp, input) = MapFilterProject::extract_from_expression(expr);
+    match input {
+        MirRelationExpr::Get {
+            id: Id::Global(id), ..
+        } if candidates_for.contains(id) => Some((*id, mfp)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_expr::BinaryFunc;
+    use mz_repr::{Datum, RelationType, ScalarType};
+
+    use super::*;
+
+    fn get(id: u64) -> MirRelationExpr {
+        MirRelationExpr::global_get(
+            GlobalId::User(id),
+            RelationType::new(vec![ScalarType::Int64.nullable(true); 3]),
+        )
+    }
+
+    fn eq_literal(column: usize) -> MirScalarExpr {
+        MirScalarExpr::column(column).call_binary(
+            MirScalarExpr::literal_ok(Datum::Int64(5), ScalarType::Int64),
+            BinaryFunc::Eq,
+        )
+    }
+
+    #[mz_ore::test]
+    fn test_lookup_candidates() {
+        let candidates_for = BTreeSet::from([GlobalId::User(1)]);
+        let expr = get(1)
+            .filter(vec![eq_literal(2), eq_literal(0)])
+            .project(vec![1]);
+        assert_eq!(
+            index_candidates(&expr, &candidates_for),
+            BTreeSet::from([IndexCandidate {
+                on_id: GlobalId::User(1),
+                key: vec![0, 2],
+                usage: IndexUsage::Lookup,
+            }])
+        );
+
+        // Collections that aren't candidates are ignored.
+        let expr = get(2).filter(vec![eq_literal(0)]);
+        assert_eq!(index_candidates(&expr, &candidates_for), BTreeSet::new());
+    }
+
+    #[mz_ore::test]
+    fn test_join_candidates() {
+        let candidates_for = BTreeSet::from([GlobalId::User(1), GlobalId::User(2)]);
+        // The second input is projected, so its column 0 is column 2 of the `Get`.
+        let expr = MirRelationExpr::join(
+            vec![get(1), get(2).project(vec![2, 0])],
+            vec![vec![(0, 1), (1, 0)]],
+        );
+        assert_eq!(
+            index_candidates(&expr, &candidates_for),
+            BTreeSet::from([
+                IndexCandidate {
+                    on_id: GlobalId::User(1),
+                    key: vec![1],
+                    usage: IndexUsage::Join,
+                },
+                IndexCandidate {
+                    on_id: GlobalId::User(2),
+                    key: vec![2],
+                    usage: IndexUsage::Join,
+                },
+            ])
+        );
+    }
+}
@@ -110,6 +110,7 @@ pub mod dataflow;
 pub mod demand;
 pub mod fold_constants;
 pub mod fusion;
+pub mod index_advice;
 pub mod join_implementation;
 pub mod literal_constraints;
 pub mod literal_lifting;
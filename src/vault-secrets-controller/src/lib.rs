@@ -0,0 +1,596 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+// BEGIN LINT CONFIG
+// DO NOT EDIT. Automatically generated by bin/gen-lints.
+// Have complaints about the noise? See the note in misc/python/materialize/cli/gen-lints.py first.
+#![allow(unknown_lints)]
+#![allow(clippy::style)]
+#![allow(clippy::complexity)]
+#![allow(clippy::large_enum_variant)]
+#![allow(clippy::mutable_key_type)]
+#![allow(clippy::stable_sort_primitive)]
+#![allow(clippy::map_entry)]
+#![allow(clippy::box_default)]
+#![allow(clippy::drain_collect)]
+#![warn(clippy::bool_comparison)]
+#![warn(clippy::clone_on_ref_ptr)]
+#![warn(clippy::no_effect)]
+#![warn(clippy::unnecessary_unwrap)]
+#![warn(clippy::dbg_macro)]
+#![warn(clippy::todo)]
+#![warn(clippy::wildcard_dependencies)]
+#![warn(clippy::zero_prefixed_literal)]
+#![warn(clippy::borrowed_box)]
+#![warn(clippy::deref_addrof)]
+#![warn(clippy::double_must_use)]
+#![warn(clippy::double_parens)]
+#![warn(clippy::extra_unused_lifetimes)]
+#![warn(clippy::needless_borrow)]
+#![warn(clippy::needless_question_mark)]
+#![warn(clippy::needless_return)]
+#![warn(clippy::redundant_pattern)]
+#![warn(clippy::redundant_slicing)]
+#![warn(clippy::redundant_static_lifetimes)]
+#![warn(clippy::single_component_path_imports)]
+#![warn(clippy::unnecessary_cast)]
+#![warn(clippy::useless_asref)]
+#![warn(clippy::useless_conversion)]
+#![warn(clippy::builtin_type_shadow)]
+#![warn(clippy::duplicate_underscore_argument)]
+#![warn(clippy::double_neg)]
+#![warn(clippy::unnecessary_mut_passed)]
+#![warn(clippy::wildcard_in_or_patterns)]
+#![warn(clippy::crosspointer_transmute)]
+#![warn(clippy::excessive_precision)]
+#![warn(clippy::overflow_check_conditional)]
+#![warn(clippy::as_conversions)]
+#![warn(clippy::match_overlapping_arm)]
+#![warn(clippy::zero_divided_by_zero)]
+#![warn(clippy::must_use_unit)]
+#![warn(clippy::suspicious_assignment_formatting)]
+#![warn(clippy::suspicious_else_formatting)]
+#![warn(clippy::suspicious_unary_op_formatting)]
+#![warn(clippy::mut_mutex_lock)]
+#![warn(clippy::print_literal)]
+#![warn(clippy::same_item_push)]
+#![warn(clippy::useless_format)]
+#![warn(clippy::write_literal)]
+#![warn(clippy::redundant_closure)]
+#![warn(clippy::redundant_closure_call)]
+#![warn(clippy::unnecessary_lazy_evaluations)]
+#![warn(clippy::partialeq_ne_impl)]
+#![warn(clippy::redundant_field_names)]
+#![warn(clippy::transmutes_expressible_as_ptr_casts)]
+#![warn(clippy::unused_async)]
+#![warn(clippy::disallowed_methods)]
+#![warn(clippy::disallowed_macros)]
+#![warn(clippy::disallowed_types)]
+#![warn(clippy::from_over_into)]
+// END LINT CONFIG
+
+//! A secrets controller backed by the KV version 2 secrets engine of
+//! [HashiCorp Vault](https://developer.hashicorp.com/vault).
+//!
+//! Each secret is stored as a single Vault secret at `<mount>/<prefix><id>`
+//! whose `value` field holds the base64-encoded secret contents.
+//!
+//! To try the controller out against a local development server:
+//!
+//! ```text
+//! $ vault server -dev -dev-root-token-id=root
+//! $ VAULT_TOKEN=root bin/environmentd -- \
+//!     --secrets-controller=vault \
+//!     --vault-secrets-controller-address=http://127.0.0.1:8200
+//! ```
+//!
+//! The tests in this crate run against such a server when the
+//! `MZ_VAULT_EXTERNAL_TEST_ADDR` environment variable is set.
+
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, bail, Context};
+use async_trait::async_trait;
+use mz_repr::GlobalId;
+use mz_secrets::{SecretsController, SecretsReader};
+use reqwest::{Method, RequestBuilder, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tracing::{debug, info};
+
+/// The environment variable from which a static Vault token is read.
+pub const VAULT_TOKEN_ENV: &str = "VAULT_TOKEN";
+/// The environment variable from which the AppRole role ID is read.
+pub const VAULT_ROLE_ID_ENV: &str = "VAULT_ROLE_ID";
+/// The environment variable from which the AppRole secret ID is read.
+pub const VAULT_SECRET_ID_ENV: &str = "VAULT_SECRET_ID";
+
+/// The default mount path of the KV version 2 secrets engine.
+pub const DEFAULT_KV_MOUNT: &str = "secret";
+/// The default mount path of the AppRole auth method.
+pub const DEFAULT_APPROLE_MOUNT: &str = "approle";
+
+/// How to authenticate with Vault.
+#[derive(Clone)]
+pub enum VaultAuth {
+    /// A static token.
+    Token(String),
+    /// A file containing a token, e.g. the sink of a Vault agent's auto-auth.
+    ///
+    /// The file is re-read whenever Vault rejects the token it contained.
+    TokenFile(PathBuf),
+    /// The AppRole auth method. The client token obtained by logging in is
+    /// short-lived, so the controller logs in again before its lease expires
+    /// or whenever Vault rejects it.
+    AppRole {
+        /// The mount path of the AppRole auth method.
+        mount: String,
+        role_id: String,
+        secret_id: String,
+    },
+}
+
+impl VaultAuth {
+    /// Determines how to authenticate with Vault.
+    ///
+    /// A token file, if specified, takes precedence. Otherwise, the AppRole
+    /// auth method is used if the `VAULT_ROLE_ID` and `VAULT_SECRET_ID`
+    /// environment variables are set, and a static token is read from the
+    /// `VAULT_TOKEN` environment variable if they are not. Credentials are
+    /// deliberately never accepted on the command line.
+    pub fn from_env(
+        token_file: Option<PathBuf>,
+        approle_mount: Option<String>,
+    ) -> Result<VaultAuth, anyhow::Error> {
+        if let Some(path) = token_file {
+            return Ok(VaultAuth::TokenFile(path));
+        }
+        if let Ok(role_id) = std::env::var(VAULT_ROLE_ID_ENV) {
+            let secret_id = std::env::var(VAULT_SECRET_ID_ENV).with_context(|| {
+                format!("{VAULT_ROLE_ID_ENV} is set but {VAULT_SECRET_ID_ENV} is not")
+            })?;
+            return Ok(VaultAuth::AppRole {
+                mount: approle_mount.unwrap_or_else(|| DEFAULT_APPROLE_MOUNT.into()),
+                role_id,
+                secret_id,
+            });
+        }
+        if let Ok(token) = std::env::var(VAULT_TOKEN_ENV) {
+            return Ok(VaultAuth::Token(token));
+        }
+        bail!(
+            "no Vault credentials found: specify a token file, \
+             or set {VAULT_ROLE_ID_ENV} and {VAULT_SECRET_ID_ENV}, or set {VAULT_TOKEN_ENV}"
+        )
+    }
+
+    /// Reports whether logging in again can yield a different token.
+    fn is_refreshable(&self) -> bool {
+        !matches!(self, VaultAuth::Token(_))
+    }
+}
+
+// Manually implemented to prevent leaking credentials in logs.
+impl fmt::Debug for VaultAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VaultAuth::Token(_) => f.debug_tuple("Token").field(&"( ... )").finish(),
+            VaultAuth::TokenFile(path) => f.debug_tuple("TokenFile").field(path).finish(),
+            VaultAuth::AppRole { mount, role_id, .. } => f
+                .debug_struct("AppRole")
+                .field("mount", mount)
+                .field("role_id", role_id)
+                .field("secret_id", &"( ... )")
+                .finish(),
+        }
+    }
+}
+
+/// Configures a [`VaultSecretsClient`].
+#[derive(Clone, Debug)]
+pub struct VaultConfig {
+    /// The address of the Vault server, e.g. `https://vault.example.com:8200`.
+    pub address: String,
+    /// The mount path of the KV version 2 secrets engine.
+    pub mount: String,
+    /// The path, relative to the mount, under which secrets are stored.
+    pub prefix: String,
+    /// The Vault Enterprise namespace to operate in, if any.
+    pub namespace: Option<String>,
+    /// How to authenticate with Vault.
+    pub auth: VaultAuth,
+}
+
+#[derive(Clone, Debug)]
+pub struct VaultSecretsController {
+    /// The client shared by the controller and all of its readers.
+    pub client: Arc<VaultSecretsClient>,
+}
+
+impl VaultSecretsController {
+    pub fn new(config: VaultConfig) -> Result<Self, anyhow::Error> {
+        Ok(VaultSecretsController {
+            client: Arc::new(VaultSecretsClient::new(config)?),
+        })
+    }
+}
+
+#[async_trait]
+impl SecretsController for VaultSecretsController {
+    async fn ensure(&self, id: GlobalId, contents: &[u8]) -> Result<(), anyhow::Error> {
+        let body = WriteRequest {
+            data: SecretData {
+                value: base64::encode(contents),
+            },
+        };
+        let url = self.client.url("data", &id.to_string());
+        let response = self
+            .client
+            .send(|| self.client.http.post(&url).json(&body))
+            .await?;
+        error_for_status(response).await?;
+        Ok(())
+    }
+
+    async fn delete(&self, id: GlobalId) -> Result<(), anyhow::Error> {
+        // Deleting the metadata permanently removes all versions of the secret.
+        let url = self.client.url("metadata", &id.to_string());
+        let response = self.client.send(|| self.client.http.delete(&url)).await?;
+        match response.status() {
+            // Secret is already deleted.
+            StatusCode::NOT_FOUND => Ok(()),
+            _ => {
+                error_for_status(response).await?;
+                Ok(())
+            }
+        }
+    }
+
+    async fn list(&self) -> Result<Vec<GlobalId>, anyhow::Error> {
+        let url = self.client.url("metadata", "");
+        let response = self
+            .client
+            .send(|| {
+                self.client
+                    .http
+                    .request(Method::GET, &url)
+                    .query(&[("list", "true")])
+            })
+            .await?;
+        // Vault reports an empty directory as missing.
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(vec![]);
+        }
+        let response: ListResponse = error_for_status(response).await?.json().await?;
+        // Ignore invalidly named objects, including subdirectories.
+        Ok(response
+            .data
+            .keys
+            .iter()
+            .filter_map(|key| key.parse().ok())
+            .collect())
+    }
+
+    fn reader(&self) -> Arc<dyn SecretsReader> {
+        Arc::clone(&self.client) as Arc<dyn SecretsReader>
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct VaultSecretsClient {
+    http: reqwest::Client,
+    address: String,
+    mount: String,
+    prefix: String,
+    namespace: Option<String>,
+    auth: VaultAuth,
+    /// The token most recently obtained from `auth`, shared between clones of
+    /// this client.
+    token: Arc<Mutex<Option<VaultToken>>>,
+}
+
+impl VaultSecretsClient {
+    pub fn new(config: VaultConfig) -> Result<Self, anyhow::Error> {
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .context("building vault http client")?;
+        let prefix = match config.prefix.trim_matches('/') {
+            "" => String::new(),
+            prefix => format!("{prefix}/"),
+        };
+        Ok(VaultSecretsClient {
+            http,
+            address: config.address.trim_end_matches('/').into(),
+            mount: config.mount.trim_matches('/').into(),
+            prefix,
+            namespace: config.namespace,
+            auth: config.auth,
+            token: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Returns the URL of the KV version 2 endpoint of kind `kind` (e.g.,
+    /// `data` or `metadata`) for the secret named `name`.
+    fn url(&self, kind: &str, name: &str) -> String {
+        format!(
+            "{}/v1/{}/{}/{}{}",
+            self.address, self.mount, kind, self.prefix, name
+        )
+    }
+
+    /// Sends the request built by `build`, authenticating again and retrying
+    /// once if Vault rejects the current token.
+    async fn send<F>(&self, build: F) -> Result<Response, anyhow::Error>
+    where
+        F: Fn() -> RequestBuilder,
+    {
+        let response = self.send_once(&build).await?;
+        if response.status() == StatusCode::FORBIDDEN && self.auth.is_refreshable() {
+            debug!("vault rejected token; authenticating again");
+            *self.token.lock().await = None;
+            return self.send_once(&build).await;
+        }
+        Ok(response)
+    }
+
+    async fn send_once<F>(&self, build: &F) -> Result<Response, anyhow::Error>
+    where
+        F: Fn() -> RequestBuilder,
+    {
+        let token = self.token().await?;
+        let request = self.with_namespace(build().header("X-Vault-Token", token));
+        Ok(request.send().await?)
+    }
+
+    fn with_namespace(&self, request: RequestBuilder) -> RequestBuilder {
+        match &self.namespace {
+            Some(namespace) => request.header("X-Vault-Namespace", namespace),
+            None => request,
+        }
+    }
+
+    /// Returns a valid token, logging in if there is none or if the current
+    /// one is about to expire.
+    async fn token(&self) -> Result<String, anyhow::Error> {
+        let mut token = self.token.lock().await;
+        match &*token {
+            Some(t) if t.renew_at.map_or(true, |at| Instant::now() < at) => Ok(t.token.clone()),
+            _ => {
+                let t = self.login().await?;
+                let secret = t.token.clone();
+                *token = Some(t);
+                Ok(secret)
+            }
+        }
+    }
+
+    async fn login(&self) -> Result<VaultToken, anyhow::Error> {
+        match &self.auth {
+            VaultAuth::Token(token) => Ok(VaultToken {
+                token: token.clone(),
+                renew_at: None,
+            }),
+            VaultAuth::TokenFile(path) => {
+                let token = tokio::fs::read_to_string(path)
+                    .await
+                    .with_context(|| format!("reading vault token file {}", path.display()))?;
+                Ok(VaultToken {
+                    token: token.trim().into(),
+                    renew_at: None,
+                })
+            }
+            VaultAuth::AppRole {
+                mount,
+                role_id,
+                secret_id,
+            } => {
+                let url = format!("{}/v1/auth/{}/login", self.address, mount);
+                let request = self
+                    .http
+                    .post(url)
+                    .json(&AppRoleLoginRequest { role_id, secret_id });
+                let response = self.with_namespace(request).send().await?;
+                let response: LoginResponse = error_for_status(response)
+                    .await
+                    .context("vault approle login")?
+                    .json()
+                    .await?;
+                let lease = Duration::from_secs(response.auth.lease_duration);
+                info!(lease = ?lease, "logged in to vault using approle");
+                // Log in again once two thirds of the lease have elapsed, so
+                // that in-flight requests never race the token's expiry. A
+                // zero lease means the token does not expire.
+                let renew_at = (!lease.is_zero()).then(|| Instant::now() + lease * 2 / 3);
+                Ok(VaultToken {
+                    token: response.auth.client_token,
+                    renew_at,
+                })
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl SecretsReader for VaultSecretsClient {
+    async fn read(&self, id: GlobalId) -> Result<Vec<u8>, anyhow::Error> {
+        let url = self.url("data", &id.to_string());
+        let response = self.send(|| self.http.get(&url)).await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            bail!("secret {id} not found in vault");
+        }
+        let response: ReadResponse = error_for_status(response).await?.json().await?;
+        base64::decode(response.data.data.value)
+            .map_err(|e| anyhow!("internal error: secret {id} is not valid base64: {e}"))
+    }
+}
+
+/// A Vault token, along with the time after which it should be replaced.
+///
+/// Note: we manually implement Debug to prevent leaking the token in logs.
+struct VaultToken {
+    token: String,
+    renew_at: Option<Instant>,
+}
+
+impl fmt::Debug for VaultToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VaultToken")
+            .field("token", &"( ... )")
+            .field("renew_at", &self.renew_at)
+            .finish()
+    }
+}
+
+/// Converts an unsuccessful response into an error that includes the
+/// messages Vault reported.
+async fn error_for_status(response: Response) -> Result<Response, anyhow::Error> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let errors = match response.json::<ErrorResponse>().await {
+        Ok(response) if !response.errors.is_empty() => response.errors.join("; "),
+        _ => "no error details".into(),
+    };
+    bail!("vault request failed with status {status}: {errors}")
+}
+
+#[derive(Serialize, Deserialize)]
+struct SecretData {
+    value: String,
+}
+
+#[derive(Serialize)]
+struct WriteRequest {
+    data: SecretData,
+}
+
+#[derive(Deserialize)]
+struct ReadResponse {
+    data: ReadResponseData,
+}
+
+#[derive(Deserialize)]
+struct ReadResponseData {
+    data: SecretData,
+}
+
+#[derive(Deserialize)]
+struct ListResponse {
+    data: ListResponseData,
+}
+
+#[derive(Deserialize)]
+struct ListResponseData {
+    keys: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct AppRoleLoginRequest<'a> {
+    role_id: &'a str,
+    secret_id: &'a str,
+}
+
+#[derive(Deserialize)]
+struct LoginResponse {
+    auth: LoginResponseAuth,
+}
+
+#[derive(Deserialize)]
+struct LoginResponseAuth {
+    client_token: String,
+    lease_duration: u64,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    errors: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use super::*;
+
+    /// The environment variable that holds the address of the Vault server
+    /// to run external tests against, e.g. one started with
+    /// `vault server -dev`. Credentials are read as described in
+    /// [`VaultAuth::from_env`].
+    const EXTERNAL_TESTS_VAULT_ADDR: &str = "MZ_VAULT_EXTERNAL_TEST_ADDR";
+
+    fn test_config(address: &str, prefix: &str, auth: VaultAuth) -> VaultConfig {
+        VaultConfig {
+            address: address.into(),
+            mount: DEFAULT_KV_MOUNT.into(),
+            prefix: prefix.into(),
+            namespace: None,
+            auth,
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_url() {
+        let auth = VaultAuth::Token("root".into());
+        let client = VaultSecretsClient::new(test_config(
+            "http://127.0.0.1:8200/",
+            "/materialize/env/",
+            auth.clone(),
+        ))
+        .unwrap();
+        assert_eq!(
+            client.url("data", "u1"),
+            "http://127.0.0.1:8200/v1/secret/data/materialize/env/u1"
+        );
+        assert_eq!(
+            client.url("metadata", ""),
+            "http://127.0.0.1:8200/v1/secret/metadata/materialize/env/"
+        );
+
+        let client = VaultSecretsClient::new(test_config("http://vault", "", auth)).unwrap();
+        assert_eq!(client.url("data", "u1"), "http://vault/v1/secret/data/u1");
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    async fn test_vault_dev_server() {
+        let Ok(address) = std::env::var(EXTERNAL_TESTS_VAULT_ADDR) else {
+            info!("{EXTERNAL_TESTS_VAULT_ADDR} not set; skipping test");
+            return;
+        };
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let auth = VaultAuth::from_env(None, None).unwrap();
+        let controller =
+            VaultSecretsController::new(test_config(&address, &format!("mz-test/{nonce}"), auth))
+                .unwrap();
+        let reader = controller.reader();
+
+        assert_eq!(controller.list().await.unwrap(), vec![]);
+
+        let id = GlobalId::User(1);
+        controller.ensure(id, b"hunter2").await.unwrap();
+        assert_eq!(reader.read(id).await.unwrap(), b"hunter2");
+        controller.ensure(id, &[0, 159, 146, 150]).await.unwrap();
+        assert_eq!(reader.read(id).await.unwrap(), vec![0, 159, 146, 150]);
+        assert_eq!(controller.list().await.unwrap(), vec![id]);
+
+        controller.delete(id).await.unwrap();
+        controller.delete(id).await.unwrap();
+        assert!(reader.read(id).await.is_err());
+        assert_eq!(controller.list().await.unwrap(), vec![]);
+    }
+}